@@ -1,6 +1,8 @@
 mod error;
+mod warning_code;
 
 pub use error::{ConnectorError, ErrorKind};
+pub use warning_code::WarningCode;
 
 use datamodel::{common::preview_features::PreviewFeature, dml::Datamodel, Datasource};
 use enumflags2::BitFlags;
@@ -52,6 +54,7 @@ pub struct IntrospectionResult {
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Warning {
+    /// See [`WarningCode`] for the stable meaning of each number.
     pub code: i16,
     pub message: String,
     pub affected: Value,
@@ -71,6 +74,14 @@ pub struct IntrospectionContext {
     pub source: Datasource,
     pub composite_type_depth: CompositeTypeDepth,
     pub preview_features: BitFlags<PreviewFeature>,
+    /// When there are no foreign keys to drive relation detection (e.g. MyISAM tables, or
+    /// `relationMode = "prisma"`), propose relations for columns that follow the
+    /// `<singular_table>_id` / `<camelModel>Id` naming convention instead of leaving them as
+    /// plain scalar fields.
+    pub infer_relations_from_field_names: bool,
+    /// Whether to rename models and fields to an idiomatic Prisma naming convention, mapping
+    /// back to the original database names via `@map`/`@@map`.
+    pub naming_convention: NamingConvention,
 }
 
 impl IntrospectionContext {
@@ -79,6 +90,32 @@ impl IntrospectionContext {
     }
 }
 
+/// Controls whether introspection renames models and fields to an idiomatic Prisma naming
+/// convention on top of the raw database names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NamingConvention {
+    /// Keep the names as introspected from the database (the default).
+    Compatible,
+    /// Rename fields to camelCase and models to PascalCase, always mapping back to the original
+    /// database name via `@map`/`@@map`.
+    CamelCase,
+}
+
+impl Default for NamingConvention {
+    fn default() -> Self {
+        Self::Compatible
+    }
+}
+
+impl From<Option<&str>> for NamingConvention {
+    fn from(value: Option<&str>) -> Self {
+        match value {
+            Some("camelCase") => Self::CamelCase,
+            _ => Self::Compatible,
+        }
+    }
+}
+
 /// Control type for composite type traversal.
 #[derive(Debug, Clone, Copy)]
 pub enum CompositeTypeDepth {
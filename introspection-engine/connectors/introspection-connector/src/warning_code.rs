@@ -0,0 +1,140 @@
+/// Stable numeric codes for the warnings introspection can produce.
+///
+/// These are part of the RPC contract: consumers (e.g. the Prisma CLI) are meant to match on
+/// [`Warning::code`](crate::Warning::code) instead of the free-form
+/// [`Warning::message`](crate::Warning::message), so a variant's numeric value must never change
+/// once it has shipped.
+///
+/// Codes below 100 originate in the SQL connectors (`sql-introspection-connector`) and are reused
+/// by MongoDB only when the situation is genuinely the same (see
+/// [`WarningCode::UnsupportedTypes`]). Codes from 100 up are MongoDB-specific
+/// (`mongodb-introspection-connector`). New warnings get the next unused number in the range that
+/// matches the connector emitting them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(i16)]
+pub enum WarningCode {
+    ModelsWithoutIdentifier = 1,
+    FieldsWithEmptyNames = 2,
+    UnsupportedTypes = 3,
+    EnumValuesWithEmptyNames = 4,
+    DefaultCuidWarning = 5,
+    DefaultUuidWarning = 6,
+    EnrichedWithMapOnModel = 7,
+    EnrichedWithMapOnField = 8,
+    EnrichedWithMapOnEnum = 9,
+    EnrichedWithMapOnEnumValue = 10,
+    EnrichedWithCuid = 11,
+    EnrichedWithUuid = 12,
+    EnrichedWithUpdatedAt = 13,
+    ModelsWithoutColumns = 14,
+    EnrichedModelsWithIgnore = 15,
+    EnrichedFieldsWithIgnore = 16,
+    EnrichedWithCustomIndexNames = 17,
+    EnrichedWithCustomPrimaryKeyNames = 18,
+    RelationsAddedFromThePreviousDataModel = 19,
+    EnumDefaultsAddedFromThePreviousDataModel = 20,
+    RelationsInferredFromFieldNames = 21,
+    RelationsInferredFromFieldNamesAmbiguous = 22,
+    RenamedFieldKeptFromPreviousDataModel = 23,
+    NamingConventionModelCollision = 24,
+    NamingConventionFieldCollision = 25,
+    ForeignKeysWithoutCoveringIndex = 26,
+    CheckConstraintsNotSupported = 27,
+    MongoUndecidedFieldType = 101,
+    MongoFieldsPointingToAnEmptyType = 102,
+    MongoFieldsWithUnknownTypes = 103,
+    MongoFieldsWithEmptyNames = 104,
+    MongoCollectionsWithUnsupportedOptions = 105,
+}
+
+impl WarningCode {
+    /// The stable numeric code, as stored on [`Warning::code`](crate::Warning::code).
+    pub const fn code(self) -> i16 {
+        self as i16
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WarningCode;
+
+    const ALL: [WarningCode; 32] = [
+        WarningCode::ModelsWithoutIdentifier,
+        WarningCode::FieldsWithEmptyNames,
+        WarningCode::UnsupportedTypes,
+        WarningCode::EnumValuesWithEmptyNames,
+        WarningCode::DefaultCuidWarning,
+        WarningCode::DefaultUuidWarning,
+        WarningCode::EnrichedWithMapOnModel,
+        WarningCode::EnrichedWithMapOnField,
+        WarningCode::EnrichedWithMapOnEnum,
+        WarningCode::EnrichedWithMapOnEnumValue,
+        WarningCode::EnrichedWithCuid,
+        WarningCode::EnrichedWithUuid,
+        WarningCode::EnrichedWithUpdatedAt,
+        WarningCode::ModelsWithoutColumns,
+        WarningCode::EnrichedModelsWithIgnore,
+        WarningCode::EnrichedFieldsWithIgnore,
+        WarningCode::EnrichedWithCustomIndexNames,
+        WarningCode::EnrichedWithCustomPrimaryKeyNames,
+        WarningCode::RelationsAddedFromThePreviousDataModel,
+        WarningCode::EnumDefaultsAddedFromThePreviousDataModel,
+        WarningCode::RelationsInferredFromFieldNames,
+        WarningCode::RelationsInferredFromFieldNamesAmbiguous,
+        WarningCode::RenamedFieldKeptFromPreviousDataModel,
+        WarningCode::NamingConventionModelCollision,
+        WarningCode::NamingConventionFieldCollision,
+        WarningCode::ForeignKeysWithoutCoveringIndex,
+        WarningCode::CheckConstraintsNotSupported,
+        WarningCode::MongoUndecidedFieldType,
+        WarningCode::MongoFieldsPointingToAnEmptyType,
+        WarningCode::MongoFieldsWithUnknownTypes,
+        WarningCode::MongoFieldsWithEmptyNames,
+        WarningCode::MongoCollectionsWithUnsupportedOptions,
+    ];
+
+    // An exhaustive match here means adding a variant without extending this test is a compile
+    // error, and renumbering an existing one is a one-line, reviewable diff instead of a silent
+    // change at one of the scattered call sites that used to hardcode these numbers.
+    #[test]
+    fn codes_are_stable() {
+        for code in ALL {
+            let expected = match code {
+                WarningCode::ModelsWithoutIdentifier => 1,
+                WarningCode::FieldsWithEmptyNames => 2,
+                WarningCode::UnsupportedTypes => 3,
+                WarningCode::EnumValuesWithEmptyNames => 4,
+                WarningCode::DefaultCuidWarning => 5,
+                WarningCode::DefaultUuidWarning => 6,
+                WarningCode::EnrichedWithMapOnModel => 7,
+                WarningCode::EnrichedWithMapOnField => 8,
+                WarningCode::EnrichedWithMapOnEnum => 9,
+                WarningCode::EnrichedWithMapOnEnumValue => 10,
+                WarningCode::EnrichedWithCuid => 11,
+                WarningCode::EnrichedWithUuid => 12,
+                WarningCode::EnrichedWithUpdatedAt => 13,
+                WarningCode::ModelsWithoutColumns => 14,
+                WarningCode::EnrichedModelsWithIgnore => 15,
+                WarningCode::EnrichedFieldsWithIgnore => 16,
+                WarningCode::EnrichedWithCustomIndexNames => 17,
+                WarningCode::EnrichedWithCustomPrimaryKeyNames => 18,
+                WarningCode::RelationsAddedFromThePreviousDataModel => 19,
+                WarningCode::EnumDefaultsAddedFromThePreviousDataModel => 20,
+                WarningCode::RelationsInferredFromFieldNames => 21,
+                WarningCode::RelationsInferredFromFieldNamesAmbiguous => 22,
+                WarningCode::RenamedFieldKeptFromPreviousDataModel => 23,
+                WarningCode::NamingConventionModelCollision => 24,
+                WarningCode::NamingConventionFieldCollision => 25,
+                WarningCode::ForeignKeysWithoutCoveringIndex => 26,
+                WarningCode::CheckConstraintsNotSupported => 27,
+                WarningCode::MongoUndecidedFieldType => 101,
+                WarningCode::MongoFieldsPointingToAnEmptyType => 102,
+                WarningCode::MongoFieldsWithUnknownTypes => 103,
+                WarningCode::MongoFieldsWithEmptyNames => 104,
+                WarningCode::MongoCollectionsWithUnsupportedOptions => 105,
+            };
+
+            assert_eq!(code.code(), expected);
+        }
+    }
+}
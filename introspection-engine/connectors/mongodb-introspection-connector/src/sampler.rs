@@ -27,6 +27,16 @@ pub(super) async fn sample(
     let mut statistics = Statistics::new(composite_type_depth);
     let mut warnings = Vec::new();
 
+    let unsupported_options: Vec<_> = schema
+        .walk_collections()
+        .filter(|collection| collection.options().has_unsupported_options())
+        .map(|collection| (collection.name().to_owned(), collection.options()))
+        .collect();
+
+    if !unsupported_options.is_empty() {
+        warnings.push(crate::warnings::collections_with_unsupported_options(&unsupported_options));
+    }
+
     for collection in schema.walk_collections() {
         statistics.track_model(collection.name());
     }
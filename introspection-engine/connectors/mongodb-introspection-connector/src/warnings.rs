@@ -1,4 +1,4 @@
-use introspection_connector::Warning;
+use introspection_connector::{Warning, WarningCode};
 use serde_json::json;
 
 use crate::sampler::Name;
@@ -23,7 +23,7 @@ pub(crate) fn unsupported_type(affected: &[(Name, String, &str)]) -> Warning {
     });
 
     Warning {
-        code: 3,
+        code: WarningCode::UnsupportedTypes.code(),
         message: "These fields are not supported by the Prisma Client, because Prisma currently does not support their types.".into(),
         affected,
     }
@@ -49,7 +49,7 @@ pub(crate) fn undecided_field_type(affected: &[(Name, String, String)]) -> Warni
     });
 
     Warning {
-        code: 101,
+        code: WarningCode::MongoUndecidedFieldType.code(),
         message: "The following fields had data stored in multiple types. Either use Json or normalize data to the wanted type.".into(),
         affected,
     }
@@ -73,7 +73,7 @@ pub(crate) fn fields_pointing_to_an_empty_type(fields_with_an_empty_type: &[(Nam
     });
 
     Warning {
-        code: 102,
+        code: WarningCode::MongoFieldsPointingToAnEmptyType.code(),
         message: "The following fields point to nested objects without any data.".into(),
         affected,
     }
@@ -97,12 +97,37 @@ pub(crate) fn fields_with_unknown_types(unknown_types: &[(Name, String)]) -> War
     });
 
     Warning {
-        code: 103,
+        code: WarningCode::MongoFieldsWithUnknownTypes.code(),
         message: "Could not determine the types for the following fields.".into(),
         affected,
     }
 }
 
+pub(crate) fn collections_with_unsupported_options(
+    affected: &[(String, mongodb_schema_describer::CollectionOptions)],
+) -> Warning {
+    let affected = serde_json::Value::Array({
+        affected
+            .iter()
+            .map(|(model, options)| {
+                json!({
+                    "model": model,
+                    "capped": options.capped,
+                    "size": options.capped_size,
+                    "max": options.capped_max,
+                    "hasSchemaValidator": options.has_validator,
+                })
+            })
+            .collect()
+    });
+
+    Warning {
+        code: WarningCode::MongoCollectionsWithUnsupportedOptions.code(),
+        message: "These collections have options Prisma does not support (capped collections and/or schema validation). Prisma will not modify or drop them; changes to these collections must be made manually.".into(),
+        affected,
+    }
+}
+
 pub(crate) fn fields_with_empty_names(fields_with_empty_names: &[(Name, String)]) -> Warning {
     let affected = serde_json::Value::Array({
         fields_with_empty_names
@@ -121,7 +146,7 @@ pub(crate) fn fields_with_empty_names(fields_with_empty_names: &[(Name, String)]
     });
 
     Warning {
-        code: 104,
+        code: WarningCode::MongoFieldsWithEmptyNames.code(),
         message: "These enum values were commented out because their names are currently not supported by Prisma. Please provide valid ones that match [a-zA-Z][a-zA-Z0-9_]* using the `@map` attribute.".into(),
         affected,
     }
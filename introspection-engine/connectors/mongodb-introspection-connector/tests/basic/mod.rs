@@ -1,5 +1,6 @@
 use crate::test_api::*;
-use mongodb::bson::doc;
+use mongodb::{bson::doc, options::CreateCollectionOptions};
+use serde_json::json;
 
 #[test]
 fn empty_collection() {
@@ -36,6 +37,45 @@ fn integer_id() {
     expected.assert_eq(res.datamodel());
 }
 
+#[test]
+fn capped_collection_generates_a_warning() {
+    let res = introspect(|db| async move {
+        let options = CreateCollectionOptions::builder()
+            .capped(true)
+            .size(4096)
+            .max(100)
+            .build();
+
+        db.create_collection("A", options).await?;
+        let collection = db.collection("A");
+        collection.insert_one(doc! { "first": "Musti" }, None).await.unwrap();
+
+        Ok(())
+    });
+
+    let expected = expect![[r#"
+        model A {
+          id    String @id @default(auto()) @map("_id") @db.ObjectId
+          first String
+        }
+    "#]];
+
+    expected.assert_eq(res.datamodel());
+
+    res.assert_warning_code(105);
+    res.assert_warning(
+        "These collections have options Prisma does not support (capped collections and/or schema validation). Prisma will not modify or drop them; changes to these collections must be made manually.",
+    );
+
+    res.assert_warning_affected(&json!([{
+        "model": "A",
+        "capped": true,
+        "size": 4096,
+        "max": 100,
+        "hasSchemaValidator": false,
+    }]));
+}
+
 #[test]
 fn multiple_collections_with_data() {
     let res = introspect(|db| async move {
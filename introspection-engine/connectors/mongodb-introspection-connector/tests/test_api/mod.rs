@@ -110,6 +110,8 @@ where
         source: config.subject.datasources.pop().unwrap(),
         composite_type_depth,
         preview_features,
+        infer_relations_from_field_names: false,
+        naming_convention: Default::default(),
     };
 
     RT.block_on(async move {
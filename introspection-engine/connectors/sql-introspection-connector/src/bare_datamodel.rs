@@ -0,0 +1,95 @@
+//! A lighter alternative to [`crate::calculate_datamodel::calculate_datamodel`] for tools that
+//! want a deterministic, side-effect-free mapping from a described [`SqlSchema`] to Prisma types,
+//! without paying for relation inference, prisma-1 migration heuristics or introspection
+//! warnings. Tables become models, columns become scalar fields (with native types), and primary
+//! keys, indexes and enums are carried over. Expression indexes and redundant autogenerated
+//! indexes are skipped, and invalid names are sanitized as in regular introspection.
+
+use crate::{
+    calculate_datamodel::CalculateDatamodelContext,
+    introspection_helpers::{
+        calculate_index, calculate_scalar_field, contains_expression_column, is_index_redundant_with_another,
+        primary_key_is_clustered,
+    },
+    sanitize_datamodel_names::{sanitization_leads_to_duplicate_names, sanitize_datamodel_names},
+};
+use datamodel::{
+    dml::{self, Datamodel, Field, Model, PrimaryKeyDefinition, PrimaryKeyField, SortOrder},
+    Datasource,
+};
+use enumflags2::BitFlags;
+use quaint::prelude::SqlFamily;
+use sql_schema_describer::{SQLSortOrder, SqlSchema};
+
+/// Maps every table in `schema` to a model with scalar fields, its primary key and its indexes,
+/// and every enum in `schema` to a datamodel enum. Foreign keys are not turned into relation
+/// fields, and no warnings are produced: this is the bare structural mapping, meant for
+/// documentation generators and other read-only tooling rather than for `prisma migrate`.
+pub fn sql_schema_to_bare_datamodel(schema: &SqlSchema, source: &Datasource, sql_family: SqlFamily) -> Datamodel {
+    let mut datamodel = Datamodel::new();
+
+    let mut ctx = CalculateDatamodelContext {
+        source,
+        preview_features: BitFlags::empty(),
+        datamodel: &mut datamodel,
+        schema,
+        sql_family,
+    };
+
+    for table in schema.table_walkers() {
+        let mut model = Model::new(table.name().to_owned(), None);
+        model.documentation = table.table().comment.clone();
+
+        for column in table.columns() {
+            let field = calculate_scalar_field(column, &mut ctx);
+            model.add_field(Field::ScalarField(field));
+        }
+
+        for index in table
+            .indexes()
+            .filter(|index| !is_index_redundant_with_another(*index) && !contains_expression_column(*index))
+        {
+            model.add_index(calculate_index(index, &mut ctx));
+        }
+
+        if let Some(pk) = table.primary_key() {
+            let clustered = primary_key_is_clustered(table.id, &mut ctx);
+
+            model.primary_key = Some(PrimaryKeyDefinition {
+                name: None,
+                db_name: pk.constraint_name.clone(),
+                fields: pk
+                    .columns
+                    .iter()
+                    .map(|c| {
+                        let sort_order = c.sort_order.map(|sort| match sort {
+                            SQLSortOrder::Asc => SortOrder::Asc,
+                            SQLSortOrder::Desc => SortOrder::Desc,
+                        });
+
+                        PrimaryKeyField {
+                            name: c.name().to_string(),
+                            sort_order,
+                            length: c.length,
+                        }
+                    })
+                    .collect(),
+                defined_on_field: pk.columns.len() == 1,
+                clustered,
+            });
+        }
+
+        ctx.datamodel.add_model(model);
+    }
+
+    for e in schema.enums.iter() {
+        let values = e.values.iter().map(|v| dml::EnumValue::new(v)).collect();
+        ctx.datamodel.add_enum(dml::Enum::new(&e.name, values));
+    }
+
+    if !sanitization_leads_to_duplicate_names(ctx.datamodel) {
+        sanitize_datamodel_names(&mut ctx);
+    }
+
+    datamodel
+}
@@ -1,7 +1,11 @@
 use crate::{
+    check_constraints::check_constraints_not_supported,
     commenting_out_guardrails::commenting_out_guardrails,
     introspection::introspect,
     introspection_helpers::*,
+    missing_fk_indexes::foreign_keys_without_covering_index,
+    naming_conventions::apply_naming_convention,
+    naming_relations::infer_relations_from_field_names,
     prisma_1_defaults::*,
     re_introspection::enrich,
     sanitize_datamodel_names::{sanitization_leads_to_duplicate_names, sanitize_datamodel_names},
@@ -57,10 +61,20 @@ pub fn calculate_datamodel(
         sanitize_datamodel_names(&mut context);
     }
 
+    // advisory passes over the raw schema, reporting database names regardless of the datamodel-level renames
+    let mut warnings = foreign_keys_without_covering_index(schema);
+    warnings.extend(check_constraints_not_supported(schema));
+
+    // opt-in camelCase/PascalCase naming convention, always mapped back to the database names
+    apply_naming_convention(&mut datamodel, ctx.naming_convention, &mut warnings);
+
     // deduplicating relation field names
     deduplicate_relation_field_names(&mut datamodel);
 
-    let mut warnings = vec![];
+    if ctx.infer_relations_from_field_names {
+        infer_relations_from_field_names(&mut datamodel, &mut warnings);
+    }
+
     if !previous_datamodel.is_empty() {
         enrich(previous_datamodel, &mut datamodel, &ctx, &mut warnings);
         tracing::debug!("Enriching datamodel is done: {:?}", datamodel);
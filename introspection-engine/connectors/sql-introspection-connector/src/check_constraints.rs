@@ -0,0 +1,23 @@
+//! Advisory pass over the raw describer output (before any datamodel-level naming decisions):
+//! flags CHECK constraints, since Prisma's schema language has no way to represent them and they
+//! are silently left out of the generated datamodel.
+
+use crate::warnings::{warning_check_constraints_not_supported, ModelAndCheckConstraint};
+use introspection_connector::Warning;
+use sql_schema_describer::SqlSchema;
+
+pub(crate) fn check_constraints_not_supported(schema: &SqlSchema) -> Vec<Warning> {
+    let mut affected = vec![];
+
+    for table in schema.table_walkers() {
+        for check_constraint in table.check_constraints() {
+            affected.push(ModelAndCheckConstraint::new(table.name(), check_constraint.name()));
+        }
+    }
+
+    if affected.is_empty() {
+        Vec::new()
+    } else {
+        vec![warning_check_constraints_not_supported(&affected)]
+    }
+}
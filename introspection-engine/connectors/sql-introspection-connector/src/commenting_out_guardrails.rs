@@ -140,6 +140,10 @@ fn empty_enum_values(datamodel: &mut Datamodel) -> Vec<EnumAndValue> {
                 continue;
             }
 
+            enum_value.documentation = Some(
+                "This value was commented out because it is invalid. Please provide a valid one that matches [a-zA-Z][a-zA-Z0-9_]*"
+                    .to_string(),
+            );
             enum_value.name = name.clone();
             enum_value.commented_out = true;
             enum_values_with_empty_names.push(EnumAndValue::new(&enum_name, &enum_value.name))
@@ -44,12 +44,20 @@ pub(crate) fn calculate_default(
                 args,
             )))
         }
+        // A real identity column only maps cleanly to `autoincrement()` for the integer types the
+        // attribute supports. Anything else (e.g. an identity column on a type Prisma doesn't
+        // recognize as auto-incrementing) is mapped to `dbgenerated()` by the `is_identity` arm below
+        // instead of lying about the column being a Prisma-managed autoincrement.
         (_, sql::ColumnTypeFamily::Int) if column.auto_increment => Some(dml::DefaultValue::new_expression(
             dml::ValueGenerator::new_autoincrement(),
         )),
         (_, sql::ColumnTypeFamily::BigInt) if column.auto_increment => Some(dml::DefaultValue::new_expression(
             dml::ValueGenerator::new_autoincrement(),
         )),
+        (_, _) if column.is_identity => Some(set_default(
+            dml::DefaultValue::new_expression(dml::ValueGenerator::new_dbgenerated(String::new())),
+            column,
+        )),
         (_, sql::ColumnTypeFamily::Int) if is_sequence(column, table) => Some(dml::DefaultValue::new_expression(
             dml::ValueGenerator::new_autoincrement(),
         )),
@@ -2,8 +2,9 @@ use crate::{
     calculate_datamodel::CalculateDatamodelContext as Context,
     introspection_helpers::{
         calculate_backrelation_field, calculate_index, calculate_many_to_many_field, calculate_relation_field,
-        calculate_scalar_field, is_new_migration_table, is_old_migration_table, is_prisma_1_point_0_join_table,
-        is_prisma_1_point_1_or_2_join_table, is_relay_table, primary_key_is_clustered,
+        calculate_scalar_field, contains_expression_column, is_index_redundant_with_another, is_new_migration_table,
+        is_old_migration_table, is_prisma_1_point_0_join_table, is_prisma_1_point_1_or_2_join_table, is_relay_table,
+        primary_key_is_clustered, two_fk_unique_index_columns,
     },
     version_checker::VersionChecker,
     SqlError, SqlFamilyTrait,
@@ -32,6 +33,7 @@ pub(crate) fn introspect(version_check: &mut VersionChecker, ctx: &mut Context)
     {
         debug!("Calculating model: {}", table.name());
         let mut model = Model::new(table.name().to_owned(), None);
+        model.documentation = table.table().comment.clone();
 
         for column in table.columns() {
             version_check.check_column_for_type_and_default_value(column);
@@ -65,7 +67,10 @@ pub(crate) fn introspect(version_check: &mut VersionChecker, ctx: &mut Context)
             model.add_field(Field::RelationField(relation_field));
         }
 
-        for index in table.indexes() {
+        for index in table
+            .indexes()
+            .filter(|index| !is_index_redundant_with_another(*index) && !contains_expression_column(*index))
+        {
             model.add_index(calculate_index(index, ctx));
         }
 
@@ -96,6 +101,23 @@ pub(crate) fn introspect(version_check: &mut VersionChecker, ctx: &mut Context)
             });
         }
 
+        if model.primary_key.is_none() {
+            if let Some(join_columns) = two_fk_unique_index_columns(table) {
+                let comment = format!(
+                    "This table does not have a primary key, but Prisma found a unique index covering \
+                     its foreign key columns `{}`. It looks like an implicit many-to-many relation table \
+                     carrying extra columns, so it has been kept as a model with a `@@unique` instead of \
+                     being ignored.",
+                    join_columns.join(", ")
+                );
+
+                model.documentation = Some(match model.documentation.take() {
+                    Some(existing) => format!("{}\n{}", existing, comment),
+                    None => comment,
+                });
+            }
+        }
+
         version_check.always_has_created_at_updated_at(table, &model);
         version_check.has_p1_compatible_primary_key_column(table);
 
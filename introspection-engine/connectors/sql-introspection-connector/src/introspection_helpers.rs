@@ -103,6 +103,41 @@ fn common_prisma_m_to_n_relation_conditions(table: TableWalker<'_>) -> bool {
         }
 }
 
+/// Detects an implicit many-to-many join table that also carries extra columns beyond its two
+/// foreign keys (for example a `createdAt` timestamp on the link), which is why it isn't picked up
+/// by [`is_prisma_1_point_1_or_2_join_table`]. Such a table has no primary key, but if a unique
+/// index covers exactly its two single-column foreign keys, every row is still identifiable, and
+/// the model does not need to be ignored — it only needs a `@@unique` instead of an `@@id`.
+/// Returns the two foreign key column names backing that unique index, if any.
+pub(crate) fn two_fk_unique_index_columns(table: TableWalker<'_>) -> Option<Vec<String>> {
+    if table.primary_key().is_some() || table.foreign_key_count() != 2 {
+        return None;
+    }
+
+    let mut fks = table.foreign_keys();
+    let (fk_a, fk_b) = (fks.next()?, fks.next()?);
+
+    if fk_a.constrained_column_names().len() != 1 || fk_b.constrained_column_names().len() != 1 {
+        return None;
+    }
+
+    let fk_columns = vec![
+        fk_a.constrained_column_names()[0].clone(),
+        fk_b.constrained_column_names()[0].clone(),
+    ];
+
+    table
+        .indexes()
+        .any(|index| {
+            index.index_type().is_unique()
+                && columns_match(
+                    &index.columns().map(|c| c.as_column().name().to_owned()).collect::<Vec<_>>(),
+                    &fk_columns,
+                )
+        })
+        .then(|| fk_columns)
+}
+
 //calculators
 
 pub fn calculate_many_to_many_field(
@@ -130,6 +165,26 @@ pub fn calculate_many_to_many_field(
     RelationField::new(&name, FieldArity::List, FieldArity::List, relation_info)
 }
 
+/// True if `index` is a database-autogenerated index (e.g. a SQLite `sqlite_autoindex_*`) that
+/// duplicates another index of the same table covering the exact same columns. Such an index
+/// does not need to be introspected on top of the one that already backs it, whether that other
+/// index ends up rendered inline as `@unique` or as a separate `@@unique`.
+pub(crate) fn is_index_redundant_with_another(index: sql::walkers::IndexWalker<'_>) -> bool {
+    index.is_autogenerated()
+        && index.table().indexes().any(|other| {
+            other.id != index.id
+                && other.column_names().eq(index.column_names())
+                && other.index_type().is_unique() == index.index_type().is_unique()
+        })
+}
+
+/// True if any column of `index` is an expression (e.g. `lower(email)`) rather than a plain
+/// column reference. Prisma's schema language has no syntax to represent expression indexes, so
+/// these cannot be introspected and must be skipped by callers.
+pub(crate) fn contains_expression_column(index: sql::walkers::IndexWalker<'_>) -> bool {
+    index.columns().any(|c| c.is_expression())
+}
+
 pub(crate) fn calculate_index(index: sql::walkers::IndexWalker<'_>, ctx: &mut Context) -> IndexDefinition {
     let tpe = match index.index_type() {
         IndexType::Unique => datamodel::dml::IndexType::Unique,
@@ -196,7 +251,7 @@ pub(crate) fn calculate_scalar_field(column: ColumnWalker<'_>, ctx: &mut Context
         field_type,
         database_name: None,
         default_value,
-        documentation: None,
+        documentation: column.column().comment.clone(),
         is_generated: false,
         is_updated_at: false,
         is_commented_out: false,
@@ -349,6 +404,8 @@ pub(crate) fn calculate_scalar_field_type_for_native_type(column: &sql::Column)
         ColumnTypeFamily::Uuid => FieldType::Scalar(ScalarType::String, None, None),
         ColumnTypeFamily::Binary => FieldType::Scalar(ScalarType::Bytes, None, None),
         ColumnTypeFamily::Enum(name) => FieldType::Enum(name.to_owned()),
+        // Prisma has no multi-value scalar type to represent a MySQL `SET`.
+        ColumnTypeFamily::Set(_) => FieldType::Unsupported(fdt),
         ColumnTypeFamily::Unsupported(_) => FieldType::Unsupported(fdt),
     }
 }
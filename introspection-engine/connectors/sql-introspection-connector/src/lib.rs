@@ -3,11 +3,16 @@
 
 pub mod calculate_datamodel; // only exported to be able to unit test it
 
+mod bare_datamodel;
+mod check_constraints;
 mod commenting_out_guardrails;
 mod defaults;
 mod error;
 mod introspection;
 mod introspection_helpers;
+mod missing_fk_indexes;
+mod naming_conventions;
+mod naming_relations;
 mod prisma_1_defaults;
 mod re_introspection;
 mod sanitize_datamodel_names;
@@ -15,6 +20,7 @@ mod schema_describer_loading;
 mod version_checker;
 mod warnings;
 
+pub use bare_datamodel::sql_schema_to_bare_datamodel;
 pub use error::*;
 
 use datamodel::{common::preview_features::PreviewFeature, dml::Datamodel};
@@ -0,0 +1,44 @@
+//! Advisory pass over the raw describer output (before any datamodel-level naming decisions):
+//! flags foreign keys whose constrained columns are not covered by any index on the table, since
+//! most databases benefit from (or require, for performance) an index on FK columns.
+
+use crate::warnings::{warning_foreign_keys_without_covering_index, ModelAndField};
+use introspection_connector::Warning;
+use sql_schema_describer::SqlSchema;
+
+pub(crate) fn foreign_keys_without_covering_index(schema: &SqlSchema) -> Vec<Warning> {
+    let mut affected = vec![];
+
+    for table in schema.table_walkers() {
+        for foreign_key in table.foreign_keys() {
+            let fk_columns = foreign_key.constrained_column_names();
+
+            let is_covered = table
+                .indexes()
+                .any(|index| is_prefixed_by(index.column_names(), fk_columns))
+                || table
+                    .primary_key_column_names()
+                    .map(|pk_columns| is_prefixed_by(pk_columns.iter().map(String::as_str), fk_columns))
+                    .unwrap_or(false);
+
+            if !is_covered {
+                affected.push(ModelAndField::new(table.name(), &fk_columns.join(", ")));
+            }
+        }
+    }
+
+    if affected.is_empty() {
+        Vec::new()
+    } else {
+        vec![warning_foreign_keys_without_covering_index(&affected)]
+    }
+}
+
+/// Whether `columns` starts with `prefix`, in order.
+fn is_prefixed_by<'a>(columns: impl ExactSizeIterator<Item = &'a str>, prefix: &[String]) -> bool {
+    if columns.len() < prefix.len() {
+        return false;
+    }
+
+    columns.zip(prefix.iter()).all(|(column, prefix_column)| column == prefix_column)
+}
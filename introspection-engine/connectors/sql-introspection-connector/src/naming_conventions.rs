@@ -0,0 +1,181 @@
+//! Optional "camelCase fields + PascalCase models" naming convention, layered on top of the raw
+//! introspected schema. Turned on via `IntrospectionContext::naming_convention`. Every model or
+//! field that is actually renamed by this pass always gets an explicit `@map`/`@@map` pointing
+//! at the original database name, unconditionally (unlike the general sanitization pass, which
+//! only sets it when none is set yet). Names are always derived from the database name, not from
+//! whatever a previous run produced, so the transformation is idempotent across repeated
+//! introspections.
+
+use crate::warnings;
+use convert_case::{Case, Casing};
+use datamodel::dml::{Datamodel, WithDatabaseName, WithName};
+use introspection_connector::{NamingConvention, Warning};
+use std::collections::{HashMap, HashSet};
+
+pub(crate) fn apply_naming_convention(
+    datamodel: &mut Datamodel,
+    convention: NamingConvention,
+    warnings_out: &mut Vec<Warning>,
+) {
+    if convention != NamingConvention::CamelCase {
+        return;
+    }
+
+    let model_renames = rename_models(datamodel, warnings_out);
+    retarget_relations(datamodel, &model_renames);
+
+    let field_renames = rename_fields(datamodel, warnings_out);
+    fix_up_relation_columns(datamodel, &field_renames);
+    fix_up_primary_keys_and_indexes(datamodel, &field_renames);
+}
+
+/// Renames every model to PascalCase, always mapping back to the database name. Returns the map
+/// of old model name -> new model name, so relations pointing at the old name can be retargeted.
+fn rename_models(datamodel: &mut Datamodel, warnings_out: &mut Vec<Warning>) -> HashMap<String, String> {
+    let mut renames = HashMap::new();
+    let mut used_names: HashSet<String> = datamodel.models().map(|model| model.name.clone()).collect();
+    let mut collisions = Vec::new();
+
+    for model in datamodel.models_mut() {
+        let db_name = model.final_database_name().to_owned();
+        let old_name = model.name.clone();
+        let mut new_name = db_name.to_case(Case::Pascal);
+
+        used_names.remove(&old_name);
+
+        if used_names.contains(&new_name) {
+            collisions.push(warnings::Model::new(&old_name));
+            new_name = deduplicate(&new_name, &used_names);
+        }
+
+        used_names.insert(new_name.clone());
+
+        if new_name != old_name {
+            model.set_database_name(Some(db_name));
+            model.set_name(&new_name);
+            renames.insert(old_name, new_name);
+        }
+    }
+
+    if !collisions.is_empty() {
+        warnings_out.push(warnings::warning_naming_convention_model_collision(&collisions));
+    }
+
+    renames
+}
+
+/// Renames every scalar and relation field to camelCase, always mapping back to the database
+/// name. Returns the map of (model name after renaming, old field name) -> new field name, so
+/// that relations, primary keys and indexes referring to the old field names can be fixed up.
+fn rename_fields(datamodel: &mut Datamodel, warnings_out: &mut Vec<Warning>) -> HashMap<(String, String), String> {
+    let mut renames = HashMap::new();
+    let mut collisions = Vec::new();
+
+    for model in datamodel.models_mut() {
+        let model_name = model.name.clone();
+        let mut used_names: HashSet<String> = model.fields().map(|field| field.name().to_owned()).collect();
+
+        for field in model.fields_mut() {
+            let db_name = field.final_database_name().to_owned();
+            let old_name = field.name().to_owned();
+            let mut new_name = db_name.to_case(Case::Camel);
+
+            used_names.remove(&old_name);
+
+            if used_names.contains(&new_name) {
+                collisions.push(warnings::ModelAndField::new(&model_name, &old_name));
+                new_name = deduplicate(&new_name, &used_names);
+            }
+
+            used_names.insert(new_name.clone());
+
+            if new_name != old_name {
+                field.set_database_name(Some(db_name));
+                field.set_name(&new_name);
+                renames.insert((model_name.clone(), old_name), new_name);
+            }
+        }
+    }
+
+    if !collisions.is_empty() {
+        warnings_out.push(warnings::warning_naming_convention_field_collision(&collisions));
+    }
+
+    renames
+}
+
+/// Updates `RelationInfo::to` on every relation field to follow a renamed target model.
+fn retarget_relations(datamodel: &mut Datamodel, model_renames: &HashMap<String, String>) {
+    for model in datamodel.models_mut() {
+        for relation_field in model.relation_fields_mut() {
+            if let Some(new_name) = model_renames.get(&relation_field.relation_info.to) {
+                relation_field.relation_info.to = new_name.clone();
+            }
+        }
+    }
+}
+
+/// Updates `RelationInfo::fields` (columns on the owning model) and `RelationInfo::references`
+/// (columns on the referenced model) to follow renamed fields.
+fn fix_up_relation_columns(datamodel: &mut Datamodel, field_renames: &HashMap<(String, String), String>) {
+    for model in datamodel.models_mut() {
+        let model_name = model.name.clone();
+
+        for relation_field in model.relation_fields_mut() {
+            let target_name = relation_field.relation_info.to.clone();
+
+            for field_name in relation_field.relation_info.fields.iter_mut() {
+                if let Some(new_name) = field_renames.get(&(model_name.clone(), field_name.clone())) {
+                    *field_name = new_name.clone();
+                }
+            }
+
+            for field_name in relation_field.relation_info.references.iter_mut() {
+                if let Some(new_name) = field_renames.get(&(target_name.clone(), field_name.clone())) {
+                    *field_name = new_name.clone();
+                }
+            }
+        }
+    }
+}
+
+/// Updates primary key and index field names to follow renamed fields.
+fn fix_up_primary_keys_and_indexes(datamodel: &mut Datamodel, field_renames: &HashMap<(String, String), String>) {
+    for model in datamodel.models_mut() {
+        let model_name = model.name.clone();
+
+        if let Some(pk) = &mut model.primary_key {
+            for field in pk.fields.iter_mut() {
+                if let Some(new_name) = field_renames.get(&(model_name.clone(), field.name.clone())) {
+                    field.name = new_name.clone();
+                }
+            }
+        }
+
+        for index in model.indices.iter_mut() {
+            for field in index.fields.iter_mut() {
+                for (path_name, _) in field.path.iter_mut() {
+                    if let Some(new_name) = field_renames.get(&(model_name.clone(), path_name.clone())) {
+                        *path_name = new_name.clone();
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Appends a numeric suffix until `base` no longer collides with `used_names`, e.g. `userId` ->
+/// `userId2` -> `userId3`.
+fn deduplicate(base: &str, used_names: &HashSet<String>) -> String {
+    let mut suffix = 2;
+
+    loop {
+        let candidate = format!("{}{}", base, suffix);
+
+        if !used_names.contains(&candidate) {
+            return candidate;
+        }
+
+        suffix += 1;
+    }
+}
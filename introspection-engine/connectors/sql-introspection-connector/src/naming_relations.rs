@@ -0,0 +1,178 @@
+//! Proposes relations for columns that follow the `<model>Id` naming convention but are not
+//! backed by a foreign key constraint (MyISAM tables, `relationMode = "prisma"`, ...). Only
+//! runs when `IntrospectionContext::infer_relations_from_field_names` is turned on.
+
+use crate::warnings;
+use datamodel::{
+    common::RelationNames,
+    dml::{Datamodel, Field, FieldArity, Model, RelationField, RelationInfo, ScalarType},
+};
+use introspection_connector::Warning;
+
+pub(crate) fn infer_relations_from_field_names(datamodel: &mut Datamodel, warnings_out: &mut Vec<Warning>) {
+    let targets: Vec<(String, ScalarType)> = datamodel
+        .models()
+        .filter_map(|model| {
+            let id_field_name = single_id_field_name(model)?;
+            let id_type = model.find_scalar_field(id_field_name)?.field_type.as_scalar()?;
+
+            Some((model.name.clone(), *id_type))
+        })
+        .collect();
+
+    let mut to_add: Vec<(String, String, String)> = Vec::new(); // (model, field, target_model)
+    let mut inferred = Vec::new();
+    let mut ambiguous = Vec::new();
+
+    for model in datamodel.models() {
+        for field in model.scalar_fields() {
+            if model
+                .relation_fields()
+                .any(|rf| rf.relation_info.fields.iter().any(|f| f == &field.name))
+            {
+                continue; // already covered by a foreign-key-driven relation
+            }
+
+            let prefix = match convention_prefix(&field.name) {
+                Some(prefix) => prefix,
+                None => continue,
+            };
+
+            let matches: Vec<&(String, ScalarType)> = targets
+                .iter()
+                .filter(|(target_name, _)| names_match(&prefix, target_name))
+                .collect();
+
+            match matches.as_slice() {
+                [] => (),
+                [(target_name, target_type)] => {
+                    if field.field_type.as_scalar() == Some(target_type) {
+                        to_add.push((model.name.clone(), field.name.clone(), target_name.clone()));
+                        inferred.push(warnings::ModelAndField::new(&model.name, &field.name));
+                    }
+                }
+                _ => ambiguous.push(warnings::ModelAndField::new(&model.name, &field.name)),
+            }
+        }
+    }
+
+    for (model_name, field_name, target_name) in to_add {
+        add_inferred_relation(datamodel, &model_name, &field_name, &target_name);
+    }
+
+    if !inferred.is_empty() {
+        warnings_out.push(warnings::warning_relations_inferred_from_field_names(&inferred));
+    }
+
+    if !ambiguous.is_empty() {
+        warnings_out.push(warnings::warning_relations_inferred_from_field_names_ambiguous(
+            &ambiguous,
+        ));
+    }
+}
+
+fn add_inferred_relation(datamodel: &mut Datamodel, model_name: &str, field_name: &str, target_name: &str) {
+    let model = datamodel.find_model(model_name).unwrap();
+    let id_field_name = single_id_field_name(model).unwrap().to_owned();
+    let scalar_field = model.find_scalar_field(field_name).unwrap();
+
+    let arity = scalar_field.arity;
+    let relation_name = RelationNames::name_for_unambiguous_relation(model_name, target_name);
+    let is_self_relation = model_name == target_name;
+
+    let mut relation_info = RelationInfo::new(target_name);
+    relation_info.name = relation_name.clone();
+    relation_info.fields = vec![field_name.to_owned()];
+    relation_info.references = vec![id_field_name];
+
+    let forward_field_name = relation_field_name(model, field_name);
+    let mut relation_field = RelationField::new(&forward_field_name, arity, arity, relation_info);
+    relation_field.documentation = Some(
+        "This relation was inferred from the field naming convention. It is not enforced by a foreign key constraint in the database."
+            .to_owned(),
+    );
+
+    let owning_model = datamodel.find_model_mut(model_name);
+    owning_model.add_field(Field::RelationField(relation_field));
+
+    let is_unique = owning_model.field_is_unique(field_name) || owning_model.field_is_primary(field_name);
+    let backrelation_arity = match arity {
+        FieldArity::List => FieldArity::Optional,
+        FieldArity::Required | FieldArity::Optional if is_unique => FieldArity::Optional,
+        FieldArity::Required | FieldArity::Optional => FieldArity::List,
+    };
+
+    let backrelation_name = if is_self_relation && forward_field_name == model_name {
+        format!("other_{}", model_name)
+    } else {
+        model_name.to_owned()
+    };
+
+    let mut backrelation_info = RelationInfo::new(model_name);
+    backrelation_info.name = relation_name;
+
+    let backrelation_field = RelationField::new(
+        &backrelation_name,
+        backrelation_arity,
+        backrelation_arity,
+        backrelation_info,
+    );
+
+    let target_model = datamodel.find_model_mut(target_name);
+    target_model.add_field(Field::RelationField(backrelation_field));
+}
+
+/// The relation field takes the scalar column's own name minus its `Id` suffix, falling back to
+/// a `Relation` suffix if that name is already taken on the model.
+fn relation_field_name(model: &Model, field_name: &str) -> String {
+    let candidate = convention_prefix(field_name).unwrap();
+
+    if model.has_field(&candidate) {
+        format!("{}Relation", candidate)
+    } else {
+        candidate
+    }
+}
+
+fn single_id_field_name(model: &Model) -> Option<&str> {
+    let pk = model.primary_key.as_ref()?;
+
+    match pk.fields.as_slice() {
+        [field] => Some(&field.name),
+        _ => None,
+    }
+}
+
+/// Strips a `<name>_id` or `<name>Id` suffix off a field name, e.g. `author_id` -> `author`,
+/// `authorId` -> `author`.
+fn convention_prefix(field_name: &str) -> Option<String> {
+    if let Some(prefix) = field_name.strip_suffix("_id") {
+        return (!prefix.is_empty()).then(|| prefix.to_owned());
+    }
+
+    if let Some(prefix) = field_name.strip_suffix("Id") {
+        return (!prefix.is_empty() && !prefix.ends_with('_')).then(|| prefix.to_owned());
+    }
+
+    None
+}
+
+fn names_match(prefix: &str, model_name: &str) -> bool {
+    let prefix = prefix.to_lowercase();
+
+    prefix == model_name.to_lowercase() || prefix == naive_singular(model_name).to_lowercase()
+}
+
+/// A best-effort singularization, good enough to match a pluralized model name against a
+/// naming-convention column prefix (e.g. prefix `category` against model name `Categories`).
+fn naive_singular(word: &str) -> String {
+    if let Some(stem) = word.strip_suffix("ies") {
+        return format!("{}y", stem);
+    }
+
+    if word.ends_with('s') && !word.ends_with("ss") && word.len() > 1 {
+        return word[..word.len() - 1].to_owned();
+    }
+
+    word.to_owned()
+}
@@ -24,7 +24,7 @@ pub fn enrich(
     merge_custom_index_names(old_data_model, new_data_model, warnings);
     merge_changed_primary_key_names(old_data_model, new_data_model, warnings);
     merge_changed_scalar_key_names(old_data_model, new_data_model, warnings);
-    merge_changed_relation_field_names(old_data_model, new_data_model);
+    merge_changed_relation_field_names(old_data_model, new_data_model, warnings);
     merge_changed_relation_names(old_data_model, new_data_model);
     merge_changed_enum_names(old_data_model, new_data_model, warnings);
     merge_changed_enum_values(old_data_model, new_data_model, warnings);
@@ -369,6 +369,7 @@ fn merge_changed_scalar_key_names(
     warnings: &mut Vec<Warning>,
 ) {
     let mut changed_scalar_field_names = vec![];
+    let mut kept_new_names = vec![];
 
     for model in new_data_model.models() {
         let old_model = match old_data_model.find_model(&model.name) {
@@ -383,10 +384,19 @@ fn merge_changed_scalar_key_names(
                     None => continue,
                 };
 
-            if model.find_scalar_field(&old_field.name).is_none() {
-                let mf = ModelAndField::new(&model.name, &field.name);
-                changed_scalar_field_names.push((mf, old_field.name.clone()))
+            if old_field.name == field.name {
+                continue;
             }
+
+            // The old name is already taken by another (newly introspected) field on this model:
+            // restoring it would produce a duplicate field name, so we keep the sanitized name instead.
+            if model.find_field(&old_field.name).is_some() {
+                kept_new_names.push(ModelAndField::new(&model.name, &field.name));
+                continue;
+            }
+
+            let mf = ModelAndField::new(&model.name, &field.name);
+            changed_scalar_field_names.push((mf, old_field.name.clone()))
         }
     }
 
@@ -442,10 +452,18 @@ fn merge_changed_scalar_key_names(
             .collect();
         warnings.push(warning_enriched_with_map_on_field(&models_and_fields));
     }
+
+    if !kept_new_names.is_empty() {
+        warnings.push(warning_renamed_field_kept_from_previous_data_model(&kept_new_names));
+    }
 }
 
 //always keep old virtual relationfield names
-fn merge_changed_relation_field_names(old_data_model: &Datamodel, new_data_model: &mut Datamodel) {
+fn merge_changed_relation_field_names(
+    old_data_model: &Datamodel,
+    new_data_model: &mut Datamodel,
+    warnings: &mut Vec<Warning>,
+) {
     let mut changed_relation_field_names = vec![];
 
     for new_model in new_data_model.models() {
@@ -470,6 +488,7 @@ fn merge_changed_relation_field_names(old_data_model: &Datamodel, new_data_model
                 let mf = ModelAndField::new(&new_model.name, &new_field.name);
 
                 if relation_info_partial_eq
+                    && old_field.name != new_field.name
                     && (!is_many_to_many
                                 //For many to many the relation infos always look the same, here we have to look at the relation name,
                                 //which translates to the join table name. But in case of self relations we cannot correctly infer the old name
@@ -481,13 +500,23 @@ fn merge_changed_relation_field_names(old_data_model: &Datamodel, new_data_model
         }
     }
 
-    for changed_relation_field_name in changed_relation_field_names {
-        new_data_model
-            .find_relation_field_mut(
-                &changed_relation_field_name.0.model,
-                &changed_relation_field_name.0.field,
-            )
-            .name = changed_relation_field_name.1;
+    let mut kept_new_names = vec![];
+
+    for (mf, old_name) in changed_relation_field_names {
+        let model = new_data_model.find_model(&mf.model).unwrap();
+
+        // The old name is already taken by another (newly introspected) field on this model:
+        // restoring it would produce a duplicate field name, so we keep the sanitized name instead.
+        if model.find_field(&old_name).is_some() {
+            kept_new_names.push(mf);
+            continue;
+        }
+
+        new_data_model.find_relation_field_mut(&mf.model, &mf.field).name = old_name;
+    }
+
+    if !kept_new_names.is_empty() {
+        warnings.push(warning_renamed_field_kept_from_previous_data_model(&kept_new_names));
     }
 }
 
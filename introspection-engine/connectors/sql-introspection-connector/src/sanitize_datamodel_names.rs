@@ -1,22 +1,17 @@
 use crate::{calculate_datamodel::CalculateDatamodelContext as Context, SqlFamilyTrait};
 use datamodel::{
     dml::{
-        Datamodel, DefaultKind, DefaultValue, Field, FieldType, IndexField, Model, PrimaryKeyField, ValueGenerator,
-        WithDatabaseName, WithName,
+        sanitize as sanitize_string, Datamodel, DefaultKind, DefaultValue, Field, FieldType, IndexField, Model,
+        PrimaryKeyField, ValueGenerator, WithDatabaseName, WithName,
     },
     is_reserved_type_name,
 };
-use once_cell::sync::Lazy;
 use prisma_value::PrismaValue;
 use quaint::prelude::SqlFamily;
-use regex::Regex;
 use std::collections::HashMap;
 
 static EMPTY_ENUM_PLACEHOLDER: &str = "EMPTY_ENUM_VALUE";
 
-static RE_START: Lazy<Regex> = Lazy::new(|| Regex::new("^[^a-zA-Z]+").unwrap());
-static RE: Lazy<Regex> = Lazy::new(|| Regex::new("[^_a-zA-Z0-9]").unwrap());
-
 pub(crate) fn sanitize_datamodel_names(ctx: &mut Context) {
     let enum_renames = sanitize_models(ctx);
     sanitize_enums(&enum_renames, ctx);
@@ -203,19 +198,6 @@ where
     };
 }
 
-fn sanitize_string(s: &str) -> String {
-    let needs_sanitation = RE_START.is_match(s) || RE.is_match(s);
-
-    if needs_sanitation {
-        let start_cleaned: String = RE_START.replace_all(s, "").parse().unwrap();
-        let sanitized: String = RE.replace_all(start_cleaned.as_str(), "_").parse().unwrap();
-
-        sanitized
-    } else {
-        s.to_owned()
-    }
-}
-
 fn rename_reserved(model: &mut Model) {
     let name = reformat_reserved_string(model.name());
 
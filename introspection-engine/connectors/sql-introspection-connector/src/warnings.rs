@@ -1,4 +1,4 @@
-use introspection_connector::Warning;
+use introspection_connector::{Warning, WarningCode};
 use serde::Serialize;
 
 #[derive(Serialize, Debug, Clone)]
@@ -96,7 +96,7 @@ impl EnumAndValue {
 
 pub fn warning_models_without_identifier(affected: &[Model]) -> Warning {
     Warning {
-        code: 1,
+        code: WarningCode::ModelsWithoutIdentifier.code(),
         message: "The following models were commented out as they do not have a valid unique identifier or id. This is currently not supported by the Prisma Client.".into(),
         affected: serde_json::to_value(&affected).unwrap(),
     }
@@ -104,7 +104,7 @@ pub fn warning_models_without_identifier(affected: &[Model]) -> Warning {
 
 pub fn warning_fields_with_empty_names(affected: &[ModelAndField]) -> Warning {
     Warning {
-        code: 2,
+        code: WarningCode::FieldsWithEmptyNames.code(),
         message: "These fields were commented out because their names are currently not supported by Prisma. Please provide valid ones that match [a-zA-Z][a-zA-Z0-9_]* using the `@map` attribute."
             .into(),
         affected: serde_json::to_value(&affected).unwrap(),
@@ -113,7 +113,7 @@ pub fn warning_fields_with_empty_names(affected: &[ModelAndField]) -> Warning {
 
 pub fn warning_unsupported_types(affected: &[ModelAndFieldAndType]) -> Warning {
     Warning {
-        code: 3,
+        code: WarningCode::UnsupportedTypes.code(),
         message: "These fields are not supported by the Prisma Client, because Prisma currently does not support their types.".into(),
         affected: serde_json::to_value(&affected).unwrap(),
     }
@@ -121,7 +121,7 @@ pub fn warning_unsupported_types(affected: &[ModelAndFieldAndType]) -> Warning {
 
 pub fn warning_enum_values_with_empty_names(affected: &[EnumAndValue]) -> Warning {
     Warning {
-        code: 4,
+        code: WarningCode::EnumValuesWithEmptyNames.code(),
         message: "These enum values were commented out because their names are currently not supported by Prisma. Please provide valid ones that match [a-zA-Z][a-zA-Z0-9_]* using the `@map` attribute."
             .into(),
         affected: serde_json::to_value(&affected).unwrap(),
@@ -130,7 +130,7 @@ pub fn warning_enum_values_with_empty_names(affected: &[EnumAndValue]) -> Warnin
 
 pub fn warning_default_cuid_warning(affected: &[ModelAndField]) -> Warning {
     Warning {
-        code: 5,
+        code: WarningCode::DefaultCuidWarning.code(),
         message:
             "These id fields had a `@default(cuid())` added because we believe the schema was created by Prisma 1."
                 .into(),
@@ -140,7 +140,7 @@ pub fn warning_default_cuid_warning(affected: &[ModelAndField]) -> Warning {
 
 pub fn warning_default_uuid_warning(affected: &[ModelAndField]) -> Warning {
     Warning {
-        code: 6,
+        code: WarningCode::DefaultUuidWarning.code(),
         message:
             "These id fields had a `@default(uuid())` added because we believe the schema was created by Prisma 1."
                 .into(),
@@ -150,7 +150,7 @@ pub fn warning_default_uuid_warning(affected: &[ModelAndField]) -> Warning {
 
 pub fn warning_enriched_with_map_on_model(affected: &[Model]) -> Warning {
     Warning {
-        code: 7,
+        code: WarningCode::EnrichedWithMapOnModel.code(),
         message: "These models were enriched with `@@map` information taken from the previous Prisma schema.".into(),
         affected: serde_json::to_value(&affected).unwrap(),
     }
@@ -158,7 +158,7 @@ pub fn warning_enriched_with_map_on_model(affected: &[Model]) -> Warning {
 
 pub fn warning_enriched_with_map_on_field(affected: &[ModelAndField]) -> Warning {
     Warning {
-        code: 8,
+        code: WarningCode::EnrichedWithMapOnField.code(),
         message: "These fields were enriched with `@map` information taken from the previous Prisma schema.".into(),
         affected: serde_json::to_value(&affected).unwrap(),
     }
@@ -166,7 +166,7 @@ pub fn warning_enriched_with_map_on_field(affected: &[ModelAndField]) -> Warning
 
 pub fn warning_enriched_with_map_on_enum(affected: &[Enum]) -> Warning {
     Warning {
-        code: 9,
+        code: WarningCode::EnrichedWithMapOnEnum.code(),
         message: "These enums were enriched with `@@map` information taken from the previous Prisma schema.".into(),
         affected: serde_json::to_value(&affected).unwrap(),
     }
@@ -174,7 +174,7 @@ pub fn warning_enriched_with_map_on_enum(affected: &[Enum]) -> Warning {
 
 pub fn warning_enriched_with_map_on_enum_value(affected: &[EnumAndValue]) -> Warning {
     Warning {
-        code: 10,
+        code: WarningCode::EnrichedWithMapOnEnumValue.code(),
         message: "These enum values were enriched with `@map` information taken from the previous Prisma schema."
             .into(),
         affected: serde_json::to_value(&affected).unwrap(),
@@ -183,7 +183,7 @@ pub fn warning_enriched_with_map_on_enum_value(affected: &[EnumAndValue]) -> War
 
 pub fn warning_enriched_with_cuid(affected: &[ModelAndField]) -> Warning {
     Warning {
-        code: 11,
+        code: WarningCode::EnrichedWithCuid.code(),
         message:
             "These id fields were enriched with `@default(cuid())` information taken from the previous Prisma schema."
                 .into(),
@@ -193,7 +193,7 @@ pub fn warning_enriched_with_cuid(affected: &[ModelAndField]) -> Warning {
 
 pub fn warning_enriched_with_uuid(affected: &[ModelAndField]) -> Warning {
     Warning {
-        code: 12,
+        code: WarningCode::EnrichedWithUuid.code(),
         message:
             "These id fields were enriched with `@default(uuid())` information taken from the previous Prisma schema."
                 .into(),
@@ -203,7 +203,7 @@ pub fn warning_enriched_with_uuid(affected: &[ModelAndField]) -> Warning {
 
 pub fn warning_enriched_with_updated_at(affected: &[ModelAndField]) -> Warning {
     Warning {
-        code: 13,
+        code: WarningCode::EnrichedWithUpdatedAt.code(),
         message:
             "These DateTime fields were enriched with `@updatedAt` information taken from the previous Prisma schema."
                 .into(),
@@ -215,7 +215,7 @@ pub fn warning_enriched_with_updated_at(affected: &[ModelAndField]) -> Warning {
 //but maybe we should have warnings for ignored fields and models
 pub fn warning_models_without_columns(affected: &[Model]) -> Warning {
     Warning {
-        code: 14,
+        code: WarningCode::ModelsWithoutColumns.code(),
         message: "The following models were commented out as we could not retrieve columns for them. Please check your privileges.".into(),
         affected: serde_json::to_value(&affected).unwrap(),
     }
@@ -223,7 +223,7 @@ pub fn warning_models_without_columns(affected: &[Model]) -> Warning {
 
 pub fn warning_enriched_models_with_ignore(affected: &[Model]) -> Warning {
     Warning {
-        code: 15,
+        code: WarningCode::EnrichedModelsWithIgnore.code(),
         message: "The following models were enriched with an @@ignore taken from your previous Prisma schema.".into(),
         affected: serde_json::to_value(&affected).unwrap(),
     }
@@ -231,7 +231,7 @@ pub fn warning_enriched_models_with_ignore(affected: &[Model]) -> Warning {
 
 pub fn warning_enriched_fields_with_ignore(affected: &[ModelAndField]) -> Warning {
     Warning {
-        code: 16,
+        code: WarningCode::EnrichedFieldsWithIgnore.code(),
         message: "The following fields were enriched with an @ignore taken from your previous Prisma schema.".into(),
         affected: serde_json::to_value(&affected).unwrap(),
     }
@@ -239,7 +239,7 @@ pub fn warning_enriched_fields_with_ignore(affected: &[ModelAndField]) -> Warnin
 
 pub fn warning_enriched_with_custom_index_names(affected: &[ModelAndIndex]) -> Warning {
     Warning {
-        code: 17,
+        code: WarningCode::EnrichedWithCustomIndexNames.code(),
         message: "These Indices were enriched with custom index names taken from the previous Prisma schema.".into(),
         affected: serde_json::to_value(&affected).unwrap(),
     }
@@ -247,7 +247,7 @@ pub fn warning_enriched_with_custom_index_names(affected: &[ModelAndIndex]) -> W
 
 pub fn warning_enriched_with_custom_primary_key_names(affected: &[Model]) -> Warning {
     Warning {
-        code: 18,
+        code: WarningCode::EnrichedWithCustomPrimaryKeyNames.code(),
         message: "These models were enriched with custom compound id names taken from the previous Prisma schema."
             .into(),
         affected: serde_json::to_value(&affected).unwrap(),
@@ -256,7 +256,7 @@ pub fn warning_enriched_with_custom_primary_key_names(affected: &[Model]) -> War
 
 pub fn warning_relations_added_from_the_previous_data_model(affected: &[Model]) -> Warning {
     Warning {
-        code: 19,
+        code: WarningCode::RelationsAddedFromThePreviousDataModel.code(),
         message: "Relations were copied from the previous data model due to not using foreign keys in the database. If any of the relation columns changed in the database, the relations might not be correct anymore.".into(),
         affected: serde_json::to_value(affected).unwrap(),
     }
@@ -264,8 +264,79 @@ pub fn warning_relations_added_from_the_previous_data_model(affected: &[Model])
 
 pub fn warning_enum_defaults_added_from_the_previous_data_model(affected: &[ModelFieldAndValue]) -> Warning {
     Warning {
-        code: 20,
+        code: WarningCode::EnumDefaultsAddedFromThePreviousDataModel.code(),
         message: "Default values were enriched with custom enum variants taken from the previous Prisma schema.".into(),
         affected: serde_json::to_value(affected).unwrap(),
     }
 }
+
+pub fn warning_relations_inferred_from_field_names(affected: &[ModelAndField]) -> Warning {
+    Warning {
+        code: WarningCode::RelationsInferredFromFieldNames.code(),
+        message: "Relations were inferred from the field naming convention because no foreign key constraints were found in the database. These relations are not enforced at the database level and might not reflect reality.".into(),
+        affected: serde_json::to_value(affected).unwrap(),
+    }
+}
+
+pub fn warning_relations_inferred_from_field_names_ambiguous(affected: &[ModelAndField]) -> Warning {
+    Warning {
+        code: WarningCode::RelationsInferredFromFieldNamesAmbiguous.code(),
+        message: "Relations could not be inferred from the field naming convention because the field name matched more than one model. Add a foreign key, or a `@relation` attribute, to disambiguate.".into(),
+        affected: serde_json::to_value(affected).unwrap(),
+    }
+}
+
+pub fn warning_renamed_field_kept_from_previous_data_model(affected: &[ModelAndField]) -> Warning {
+    Warning {
+        code: WarningCode::RenamedFieldKeptFromPreviousDataModel.code(),
+        message: "The following field names were not restored from your previous Prisma schema, because the field names would clash with newly introspected fields on the models.".into(),
+        affected: serde_json::to_value(affected).unwrap(),
+    }
+}
+
+pub fn warning_naming_convention_model_collision(affected: &[Model]) -> Warning {
+    Warning {
+        code: WarningCode::NamingConventionModelCollision.code(),
+        message: "The following models were renamed to a numeric suffix, because the naming convention transformation would otherwise produce a duplicate model name. Please check the mapping to the database names via `@@map`.".into(),
+        affected: serde_json::to_value(affected).unwrap(),
+    }
+}
+
+pub fn warning_naming_convention_field_collision(affected: &[ModelAndField]) -> Warning {
+    Warning {
+        code: WarningCode::NamingConventionFieldCollision.code(),
+        message: "The following fields were renamed to a numeric suffix, because the naming convention transformation would otherwise produce a duplicate field name on the model. Please check the mapping to the database names via `@map`.".into(),
+        affected: serde_json::to_value(affected).unwrap(),
+    }
+}
+
+pub fn warning_foreign_keys_without_covering_index(affected: &[ModelAndField]) -> Warning {
+    Warning {
+        code: WarningCode::ForeignKeysWithoutCoveringIndex.code(),
+        message: "The following foreign keys are not covered by an index on their constrained columns. This can lead to slow queries, especially for cascading deletes and updates. Please consider adding an index on these columns.".into(),
+        affected: serde_json::to_value(affected).unwrap(),
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ModelAndCheckConstraint {
+    pub(crate) model: String,
+    pub(crate) constraint: String,
+}
+
+impl ModelAndCheckConstraint {
+    pub fn new(model: &str, constraint: &str) -> Self {
+        ModelAndCheckConstraint {
+            model: model.to_owned(),
+            constraint: constraint.to_owned(),
+        }
+    }
+}
+
+pub fn warning_check_constraints_not_supported(affected: &[ModelAndCheckConstraint]) -> Warning {
+    Warning {
+        code: WarningCode::CheckConstraintsNotSupported.code(),
+        message: "These CHECK constraints are not supported by Prisma. Introspection has kept the tables they are defined on, but you will need to add the constraints back manually if you want to keep enforcing them, for example by running the CHECK constraint DDL as part of a migration.".into(),
+        affected: serde_json::to_value(affected).unwrap(),
+    }
+}
@@ -2,7 +2,7 @@ use crate::error::Error;
 use datamodel::{dml::Datamodel, Configuration};
 use introspection_connector::{
     CompositeTypeDepth, ConnectorResult, DatabaseMetadata, IntrospectionConnector, IntrospectionContext,
-    IntrospectionResultOutput,
+    IntrospectionResultOutput, NamingConvention,
 };
 use jsonrpc_core::BoxFuture;
 use jsonrpc_derive::rpc;
@@ -59,6 +59,8 @@ impl Rpc for RpcImpl {
             input.schema,
             input.force,
             CompositeTypeDepth::from(input.composite_type_depth.unwrap_or(0)),
+            input.infer_relations_from_field_names,
+            NamingConvention::from(input.naming_convention.as_deref()),
         ))
     }
 
@@ -102,6 +104,8 @@ impl RpcImpl {
         schema: String,
         force: bool,
         composite_type_depth: CompositeTypeDepth,
+        infer_relations_from_field_names: bool,
+        naming_convention: NamingConvention,
     ) -> RpcResult<IntrospectionResultOutput> {
         let (config, _url, connector) = RpcImpl::load_connector(&schema).await?;
 
@@ -117,6 +121,8 @@ impl RpcImpl {
             preview_features: config2.preview_features(),
             source: config2.datasources.into_iter().next().unwrap(),
             composite_type_depth,
+            infer_relations_from_field_names,
+            naming_convention,
         };
 
         let result = match connector.introspect(&input_data_model, ctx).await {
@@ -182,6 +188,13 @@ pub struct IntrospectionInput {
     pub(crate) force: bool,
     #[serde(default)]
     pub(crate) composite_type_depth: Option<isize>,
+    #[serde(default = "default_false")]
+    pub(crate) infer_relations_from_field_names: bool,
+    /// The naming convention to apply on top of the raw introspected names: `"compatible"`
+    /// (default) keeps the database names, `"camelCase"` renames fields to camelCase and models
+    /// to PascalCase, always mapping back to the database name via `@map`/`@@map`.
+    #[serde(default)]
+    pub(crate) naming_convention: Option<String>,
 }
 
 fn default_false() -> bool {
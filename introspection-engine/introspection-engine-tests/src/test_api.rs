@@ -10,7 +10,7 @@ use datamodel::common::preview_features::PreviewFeature;
 use datamodel::{dml::Datamodel, Configuration};
 use introspection_connector::{
     CompositeTypeDepth, ConnectorResult, DatabaseMetadata, IntrospectionConnector, IntrospectionContext,
-    IntrospectionResult, Version,
+    IntrospectionResult, NamingConvention, Version,
 };
 use migration_connector::{ConnectorParams, MigrationConnector};
 use quaint::{prelude::SqlFamily, single::Quaint};
@@ -141,12 +141,26 @@ impl TestApi {
     #[tracing::instrument(skip(self))]
     #[track_caller]
     async fn test_introspect_internal(&self, data_model: Datamodel) -> ConnectorResult<IntrospectionResult> {
+        self.test_introspect_internal_with(data_model, false, NamingConvention::Compatible)
+            .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    #[track_caller]
+    async fn test_introspect_internal_with(
+        &self,
+        data_model: Datamodel,
+        infer_relations_from_field_names: bool,
+        naming_convention: NamingConvention,
+    ) -> ConnectorResult<IntrospectionResult> {
         let config = self.configuration();
 
         let ctx = IntrospectionContext {
             preview_features: self.preview_features(),
             source: config.datasources.into_iter().next().unwrap(),
             composite_type_depth: CompositeTypeDepth::Infinite,
+            infer_relations_from_field_names,
+            naming_convention,
         };
 
         self.api
@@ -155,6 +169,54 @@ impl TestApi {
             .await
     }
 
+    /// Like [`Self::introspect_dml`], but with naming-convention relation inference turned on,
+    /// for tests that exercise `infer_relations_from_field_names`.
+    #[tracing::instrument(skip(self))]
+    #[track_caller]
+    pub async fn introspect_dml_with_relation_inference(&self) -> Result<String> {
+        let introspection_result = self
+            .test_introspect_internal_with(Datamodel::new(), true, NamingConvention::Compatible)
+            .await?;
+
+        Ok(datamodel::render_datamodel_to_string(
+            &introspection_result.data_model,
+            Some(&self.configuration()),
+        ))
+    }
+
+    /// Warnings variant of [`Self::introspect_dml_with_relation_inference`].
+    pub async fn introspection_warnings_with_relation_inference(&self) -> Result<String> {
+        let introspection_result = self
+            .test_introspect_internal_with(Datamodel::new(), true, NamingConvention::Compatible)
+            .await?;
+
+        Ok(serde_json::to_string(&introspection_result.warnings)?)
+    }
+
+    /// Like [`Self::introspect_dml`], but with the camelCase/PascalCase naming convention turned
+    /// on, for tests that exercise `NamingConvention::CamelCase`.
+    #[tracing::instrument(skip(self))]
+    #[track_caller]
+    pub async fn introspect_dml_with_naming_convention(&self) -> Result<String> {
+        let introspection_result = self
+            .test_introspect_internal_with(Datamodel::new(), false, NamingConvention::CamelCase)
+            .await?;
+
+        Ok(datamodel::render_datamodel_to_string(
+            &introspection_result.data_model,
+            Some(&self.configuration()),
+        ))
+    }
+
+    /// Warnings variant of [`Self::introspect_dml_with_naming_convention`].
+    pub async fn introspection_warnings_with_naming_convention(&self) -> Result<String> {
+        let introspection_result = self
+            .test_introspect_internal_with(Datamodel::new(), false, NamingConvention::CamelCase)
+            .await?;
+
+        Ok(serde_json::to_string(&introspection_result.warnings)?)
+    }
+
     #[tracing::instrument(skip(self, data_model_string))]
     #[track_caller]
     pub async fn re_introspect(&self, data_model_string: &str) -> Result<String> {
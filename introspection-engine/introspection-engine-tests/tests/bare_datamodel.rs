@@ -0,0 +1,47 @@
+use barrel::types;
+use expect_test::expect;
+use introspection_engine_tests::test_api::*;
+use introspection_engine_tests::TestResult;
+use sql_introspection_connector::sql_schema_to_bare_datamodel;
+use test_macros::test_connector;
+
+// `sql_schema_to_bare_datamodel` skips relations entirely, so a foreign key here should not turn
+// into a `@relation` field on either side, unlike full introspection.
+#[test_connector(tags(Mssql, Postgres), exclude(CockroachDb))]
+async fn foreign_keys_are_not_turned_into_relation_fields(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("Category", move |t| {
+                t.add_column("id", types::integer().increments(true).nullable(false));
+                t.add_constraint("Category_pkey", types::primary_constraint(&["id"]));
+            });
+
+            migration.create_table("Post", move |t| {
+                t.add_column("id", types::integer().increments(true).nullable(false));
+                t.add_column("category_id", types::integer().nullable(false));
+                t.add_constraint("Post_pkey", types::primary_constraint(&["id"]));
+                t.add_foreign_key(&["category_id"], "Category", &["id"]);
+            });
+        })
+        .await?;
+
+    let schema = api.api.describe(None).await?;
+    let source = api.configuration().datasources.into_iter().next().unwrap();
+    let datamodel = sql_schema_to_bare_datamodel(&schema, &source, api.sql_family());
+    let rendered = datamodel::render_datamodel_to_string(&datamodel, None);
+
+    let expected = expect![[r#"
+        model Category {
+          id Int @id @default(autoincrement())
+        }
+
+        model Post {
+          id          Int @id @default(autoincrement())
+          category_id Int
+        }
+    "#]];
+
+    expected.assert_eq(&rendered);
+
+    Ok(())
+}
@@ -37,6 +37,8 @@ async fn introspecting_cockroach_db_with_postgres_provider(api: TestApi) {
             .next()
             .unwrap(),
         composite_type_depth: CompositeTypeDepth::Infinite,
+        infer_relations_from_field_names: false,
+        naming_convention: Default::default(),
     };
 
     api.api
@@ -27,6 +27,31 @@ async fn a_table_without_required_uniques(api: &TestApi) -> TestResult {
     Ok(())
 }
 
+#[test_connector(tags(Mysql), exclude(Vitess))]
+async fn spatial_columns_are_unsupported(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("A", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("location", types::custom("point"));
+                t.add_column("area", types::custom("geometry").nullable(true));
+            });
+        })
+        .await?;
+
+    let expected = expect![[r#"
+        model A {
+          id       Int                     @id @default(autoincrement())
+          location Unsupported("point")
+          area     Unsupported("geometry")?
+        }
+    "#]];
+
+    expected.assert_eq(&api.introspect_dml().await?);
+
+    Ok(())
+}
+
 #[test_connector(tags(Mysql), exclude(Vitess))]
 async fn a_table_without_uniques_should_ignore(api: &TestApi) -> TestResult {
     api.barrel()
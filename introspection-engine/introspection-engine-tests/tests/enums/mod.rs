@@ -68,6 +68,41 @@ async fn a_table_with_enums(api: &TestApi) -> TestResult {
     Ok(())
 }
 
+// Enum labels that are not valid Prisma identifiers (e.g. containing a space or a dash) must be
+// introspected as an `@map`ped value, with the raw label preserved as the database name.
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn an_enum_value_with_a_non_identifier_label_gets_mapped(api: &TestApi) -> TestResult {
+    api.database()
+        .raw_cmd(r#"CREATE TYPE "color" AS ENUM ('black', 'gray-ish', 'off white')"#)
+        .await?;
+
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("Book", move |t| {
+                t.add_column("id", types::primary());
+                t.add_column("color", types::custom("color").nullable(false));
+            });
+        })
+        .await?;
+
+    let dm = r#"
+        model Book {
+            id      Int     @id @default(autoincrement())
+            color   color
+        }
+
+        enum color {
+            black
+            gray_ish  @map("gray-ish")
+            off_white @map("off white")
+        }
+    "#;
+
+    api.assert_eq_datamodels(dm, &api.introspect().await?);
+
+    Ok(())
+}
+
 #[test_connector(exclude(CockroachDb), capabilities(Enums))]
 async fn a_table_with_an_enum_default_value_that_is_an_empty_string(api: &TestApi) -> TestResult {
     let sql_family = api.sql_family();
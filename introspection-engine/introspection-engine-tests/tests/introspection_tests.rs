@@ -1,13 +1,17 @@
 mod add_prisma1_defaults;
+mod bare_datamodel;
 mod cockroachdb;
 mod commenting_out;
 mod enums;
 mod errors;
 mod identify_version;
 mod lists;
+mod missing_fk_indexes;
 mod model_renames;
 mod mssql;
 mod named_constraints;
+mod naming_conventions;
+mod naming_relations;
 mod native_types;
 mod postgres;
 mod re_introspection;
@@ -0,0 +1,39 @@
+use barrel::types;
+use expect_test::expect;
+use introspection_engine_tests::test_api::*;
+use test_macros::test_connector;
+
+// `Post.user_id` references `User.id` but has no index of its own, so it should be flagged.
+// `Post.category_id` is covered by an explicit index, so it should not be flagged.
+#[test_connector(exclude(Mssql, Mysql, Sqlite, CockroachDb, Vitess))]
+async fn foreign_keys_without_a_covering_index_are_flagged(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+            });
+
+            migration.create_table("Category", |t| {
+                t.add_column("id", types::primary());
+            });
+
+            migration.create_table("Post", move |t| {
+                t.add_column("id", types::primary());
+                t.add_column("user_id", types::integer().nullable(false));
+                t.add_column("category_id", types::integer().nullable(false));
+                t.add_foreign_key(&["user_id"], "User", &["id"]);
+                t.add_foreign_key(&["category_id"], "Category", &["id"]);
+                t.add_index("Post_category_id_idx", types::index(vec!["category_id"]));
+            });
+        })
+        .await?;
+
+    let warnings = api.introspection_warnings().await?;
+
+    let expected_warnings = expect![[r#"
+        [{"code":26,"message":"The following foreign keys are not covered by an index on their constrained columns. This can lead to slow queries, especially for cascading deletes and updates. Please consider adding an index on these columns.","affected":[{"model":"Post","field":"user_id"}]}]"#]];
+
+    expected_warnings.assert_eq(&warnings);
+
+    Ok(())
+}
@@ -88,3 +88,55 @@ async fn introspecting_default_fk_names_works(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_connector(tags(Mysql), exclude(Vitess))]
+async fn introspecting_non_default_index_names_works(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("Single", move |t| {
+                t.add_column("id", types::integer().increments(true).nullable(false));
+                t.add_index("SomethingCustom", types::index(&["id"]));
+            });
+        })
+        .await?;
+
+    let expected = expect![[r#"
+        /// The underlying table does not contain a valid unique identifier and can therefore currently not be handled by the Prisma Client.
+        model Single {
+          id Int @default(autoincrement())
+
+          @@index([id], map: "SomethingCustom")
+          @@ignore
+        }
+    "#]];
+
+    expected.assert_eq(&api.introspect_dml().await?);
+
+    Ok(())
+}
+
+#[test_connector(tags(Mysql), exclude(Vitess))]
+async fn introspecting_default_index_names_works(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("Single", move |t| {
+                t.add_column("id", types::integer().increments(true).nullable(false));
+                t.add_index("Single_id_idx", types::index(&["id"]));
+            });
+        })
+        .await?;
+
+    let expected = expect![[r#"
+        /// The underlying table does not contain a valid unique identifier and can therefore currently not be handled by the Prisma Client.
+        model Single {
+          id Int @default(autoincrement())
+
+          @@index([id])
+          @@ignore
+        }
+    "#]];
+
+    expected.assert_eq(&api.introspect_dml().await?);
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use barrel::types;
+use expect_test::expect;
+use introspection_engine_tests::test_api::*;
+use test_macros::test_connector;
+
+// Fixture: `user_profile` is a plain snake_case table name, renamed to `UserProfile` with an
+// `@@map("user_profile")` pointing back at the database, and `post` likewise becomes `Post` with
+// `@@map("post")`. `post.user_id` and `post.userId` both
+// camelCase to `userId`, so `user_id` (processed first, in column order) collides and gets
+// suffixed to `userId2` with a warning; `userId` is left unchanged since it is already camelCase.
+#[test_connector(exclude(Mssql, Mysql, Sqlite, CockroachDb, Vitess))]
+async fn naming_convention_renames_and_deduplicates_collisions(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("user_profile", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("display_name", types::text());
+            });
+
+            migration.create_table("post", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("user_id", types::integer().nullable(false));
+                t.add_column("userId", types::integer().nullable(false));
+            });
+        })
+        .await?;
+
+    let expected = expect![[r#"
+        model UserProfile {
+          id          Int    @id @default(autoincrement())
+          displayName String @map("display_name")
+
+          @@map("user_profile")
+        }
+
+        model Post {
+          id      Int @id @default(autoincrement())
+          userId2 Int @map("user_id")
+          userId  Int
+
+          @@map("post")
+        }
+    "#]];
+
+    expected.assert_eq(&api.introspect_dml_with_naming_convention().await?);
+
+    let warnings = api.introspection_warnings_with_naming_convention().await?;
+
+    let expected_warnings = expect![[r#"
+        [{"code":25,"message":"The following fields were renamed to a numeric suffix, because the naming convention transformation would otherwise produce a duplicate field name on the model. Please check the mapping to the database names via `@map`.","affected":[{"model":"Post","field":"user_id"}]}]"#]];
+
+    expected_warnings.assert_eq(&warnings);
+
+    Ok(())
+}
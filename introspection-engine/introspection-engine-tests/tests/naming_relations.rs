@@ -0,0 +1,91 @@
+use barrel::types;
+use expect_test::expect;
+use introspection_engine_tests::test_api::*;
+use test_macros::test_connector;
+
+// Fixture: `User` and `Category` are unambiguous single-id-field targets, so `Post.user_id` and
+// `Post.category_id` (which follow the naming convention) turn into relations. `Owner` and
+// `Owners` both match the `owner_id` prefix, so that column is ambiguous and must be skipped
+// with a warning. `Color` matches `color_id` by name, but the column's type (`String`) does not
+// match `Color.id` (`Int`), so it must be left as a plain scalar field.
+#[test_connector(exclude(Mssql, Mysql, Sqlite, CockroachDb, Vitess))]
+async fn infers_relations_from_naming_convention(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+            });
+
+            migration.create_table("Owner", |t| {
+                t.add_column("id", types::primary());
+            });
+
+            migration.create_table("Owners", |t| {
+                t.add_column("id", types::primary());
+            });
+
+            migration.create_table("Category", |t| {
+                t.add_column("id", types::primary());
+            });
+
+            migration.create_table("Color", |t| {
+                t.add_column("id", types::primary());
+            });
+
+            migration.create_table("Post", move |t| {
+                t.add_column("id", types::primary());
+                t.add_column("user_id", types::integer().nullable(false));
+                t.add_column("category_id", types::integer().nullable(false));
+                t.add_column("owner_id", types::integer().nullable(false));
+                t.add_column("color_id", types::text());
+            });
+        })
+        .await?;
+
+    let expected = expect![[r#"
+        model Post {
+          id          Int      @id @default(autoincrement())
+          user_id     Int
+          category_id Int
+          owner_id    Int
+          color_id    String
+          /// This relation was inferred from the field naming convention. It is not enforced by a foreign key constraint in the database.
+          user        User     @relation(fields: [user_id], references: [id])
+          /// This relation was inferred from the field naming convention. It is not enforced by a foreign key constraint in the database.
+          category    Category @relation(fields: [category_id], references: [id])
+        }
+
+        model User {
+          id   Int    @id @default(autoincrement())
+          Post Post[]
+        }
+
+        model Owner {
+          id Int @id @default(autoincrement())
+        }
+
+        model Owners {
+          id Int @id @default(autoincrement())
+        }
+
+        model Category {
+          id   Int    @id @default(autoincrement())
+          Post Post[]
+        }
+
+        model Color {
+          id Int @id @default(autoincrement())
+        }
+    "#]];
+
+    expected.assert_eq(&api.introspect_dml_with_relation_inference().await?);
+
+    let warnings = api.introspection_warnings_with_relation_inference().await?;
+
+    let expected_warnings = expect![[r#"
+        [{"code":21,"message":"Relations were inferred from the field naming convention because no foreign key constraints were found in the database. These relations are not enforced at the database level and might not reflect reality.","affected":[{"model":"Post","field":"user_id"},{"model":"Post","field":"category_id"}]},{"code":22,"message":"Relations could not be inferred from the field naming convention because the field name matched more than one model. Add a foreign key, or a `@relation` attribute, to disambiguate.","affected":[{"model":"Post","field":"owner_id"}]}]"#]];
+
+    expected_warnings.assert_eq(&warnings);
+
+    Ok(())
+}
@@ -50,6 +50,110 @@ async fn sequences_should_work(api: &TestApi) -> TestResult {
     Ok(())
 }
 
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn identity_and_serial_columns_are_distinguished(api: &TestApi) -> TestResult {
+    let setup = r#"
+        CREATE TABLE "Test" (
+            identity_col INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+            serial_col SERIAL NOT NULL
+        );
+    "#;
+
+    api.raw_cmd(setup).await;
+
+    let expectation = expect![[r#"
+        generator client {
+          provider = "prisma-client-js"
+        }
+
+        datasource db {
+          provider = "postgresql"
+          url      = "env(TEST_DATABASE_URL)"
+        }
+
+        model Test {
+          identity_col Int @id @default(autoincrement())
+          serial_col   Int @default(autoincrement())
+        }
+    "#]];
+
+    api.expect_datamodel(&expectation).await;
+
+    Ok(())
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn table_and_column_comments_become_doc_comments(api: &TestApi) -> TestResult {
+    let setup = r#"
+        CREATE TABLE "Test" (
+            id INTEGER PRIMARY KEY,
+            name TEXT NOT NULL
+        );
+        COMMENT ON TABLE "Test" IS 'A test model.';
+        COMMENT ON COLUMN "Test".name IS 'The name of the thing.';
+    "#;
+
+    api.raw_cmd(setup).await;
+
+    let expectation = expect![[r#"
+        generator client {
+          provider = "prisma-client-js"
+        }
+
+        datasource db {
+          provider = "postgresql"
+          url      = "env(TEST_DATABASE_URL)"
+        }
+
+        /// A test model.
+        model Test {
+          id   Int    @id
+          /// The name of the thing.
+          name String
+        }
+    "#]];
+
+    api.expect_datamodel(&expectation).await;
+
+    Ok(())
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn comments_with_unicode_and_quotes_round_trip(api: &TestApi) -> TestResult {
+    let setup = r#"
+        CREATE TABLE "Test" (
+            id INTEGER PRIMARY KEY,
+            name TEXT NOT NULL
+        );
+        COMMENT ON TABLE "Test" IS 'A "test" café ☕ table.';
+        COMMENT ON COLUMN "Test".name IS 'Say "hi" — 你好';
+    "#;
+
+    api.raw_cmd(setup).await;
+
+    let expectation = expect![[r#"
+        generator client {
+          provider = "prisma-client-js"
+        }
+
+        datasource db {
+          provider = "postgresql"
+          url      = "env(TEST_DATABASE_URL)"
+        }
+
+        /// A "test" café ☕ table.
+        model Test {
+          id   Int    @id
+          /// Say "hi" — 你好
+          name String
+        }
+    "#]];
+
+    api.expect_datamodel(&expectation).await;
+
+    Ok(())
+}
+
 #[test_connector(tags(Postgres), exclude(CockroachDb))]
 async fn dbgenerated_type_casts_should_work(api: &TestApi) -> TestResult {
     api.barrel()
@@ -114,6 +114,53 @@ async fn manually_overwritten_mapped_field_name(api: &TestApi) -> TestResult {
     Ok(())
 }
 
+#[test_connector(exclude(CockroachDb))]
+async fn renamed_field_colliding_with_a_new_column_is_not_restored(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::integer().increments(true));
+                t.add_column("payload", types::integer());
+                t.add_column("custom_payload", types::integer());
+
+                t.add_constraint("User_pkey", types::primary_constraint(&["id"]));
+            });
+        })
+        .await?;
+
+    let input_dm = indoc! {r#"
+        model User {
+            id               Int         @id @default(autoincrement())
+            /// a payload, renamed from the db column
+            custom_payload   Int         @map("payload")
+        }
+    "#};
+
+    let final_dm = indoc! {r#"
+        model User {
+            id               Int         @id @default(autoincrement())
+            /// a payload, renamed from the db column
+            payload          Int
+            custom_payload   Int
+        }
+    "#};
+
+    api.assert_eq_datamodels(final_dm, &api.re_introspect(input_dm).await?);
+
+    let expected = json!([{
+        "code": 23,
+        "message": "The following field names were not restored from your previous Prisma schema, because the field names would clash with newly introspected fields on the models.",
+        "affected": [{
+            "model": "User",
+            "field": "payload"
+        }]
+    }]);
+
+    assert_eq_json!(expected, api.re_introspect_warnings(input_dm).await?);
+
+    Ok(())
+}
+
 #[test_connector(exclude(Mssql, Mysql, CockroachDb))]
 async fn mapped_model_and_field_name(api: &TestApi) -> TestResult {
     api.barrel()
@@ -370,6 +370,68 @@ async fn a_many_to_many_relation_with_an_id(api: &TestApi) -> TestResult {
     Ok(())
 }
 
+#[test_connector(tags(Postgres, Mysql))]
+async fn a_join_table_with_an_extra_column_is_kept_with_a_unique_instead_of_ignored(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+            });
+
+            migration.create_table("Post", |t| {
+                t.add_column("id", types::primary());
+            });
+
+            migration.create_table("PostsToUsers", |t| {
+                t.add_column("user_id", types::integer().nullable(false));
+                t.add_column("post_id", types::integer().nullable(false));
+                t.add_column("created_at", types::datetime().nullable(false));
+
+                t.add_foreign_key(&["user_id"], "User", &["id"]);
+                t.add_foreign_key(&["post_id"], "Post", &["id"]);
+
+                t.add_index(
+                    "PostsToUsers_user_id_post_id_key",
+                    types::index(&["user_id", "post_id"]).unique(true),
+                );
+            });
+        })
+        .await?;
+
+    let timestamp_native = if api.sql_family().is_mysql() {
+        "@db.DateTime(0)"
+    } else {
+        "@db.Timestamp(6)"
+    };
+
+    let dm = formatdoc! {r##"
+        model Post {{
+            id           Int            @id @default(autoincrement())
+            PostsToUsers PostsToUsers[]
+        }}
+
+        /// This table does not have a primary key, but Prisma found a unique index covering its foreign key columns `user_id, post_id`. It looks like an implicit many-to-many relation table carrying extra columns, so it has been kept as a model with a `@@unique` instead of being ignored.
+        model PostsToUsers {{
+            user_id    Int
+            post_id    Int
+            created_at DateTime {timestamp_native}
+            Post       Post     @relation(fields: [post_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
+            User       User     @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
+
+            @@unique([user_id, post_id], map: "PostsToUsers_user_id_post_id_key")
+        }}
+
+        model User {{
+            id           Int            @id @default(autoincrement())
+            PostsToUsers PostsToUsers[]
+        }}
+    "##, timestamp_native = timestamp_native};
+
+    api.assert_eq_datamodels(&dm, &api.introspect().await?);
+
+    Ok(())
+}
+
 #[test_connector(exclude(Mysql, Sqlite, CockroachDb))]
 async fn a_self_relation(api: &TestApi) -> TestResult {
     api.barrel()
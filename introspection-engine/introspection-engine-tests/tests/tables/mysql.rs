@@ -417,6 +417,8 @@ async fn missing_select_rights(api: &TestApi) -> TestResult {
         source: config.subject.datasources.into_iter().next().unwrap(),
         composite_type_depth: Default::default(),
         preview_features: Default::default(),
+        infer_relations_from_field_names: false,
+        naming_convention: Default::default(),
     };
 
     let res = conn.introspect(&Datamodel::new(), ctx).await.unwrap();
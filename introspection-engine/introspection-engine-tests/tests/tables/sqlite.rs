@@ -86,3 +86,32 @@ async fn a_table_with_descending_index(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+// A column-level `UNIQUE` makes SQLite create its own `sqlite_autoindex_*` index. If an explicit
+// index also covers the same column, introspection should recognize the autoindex as redundant
+// and only render the column once, instead of a double `@unique`.
+#[test_connector(tags(Sqlite))]
+async fn a_column_level_unique_is_not_double_rendered_with_an_explicit_index_on_it(api: &TestApi) -> TestResult {
+    let setup = indoc! {r#"
+       CREATE TABLE "A" (
+           id INTEGER NOT NULL,
+           a  INTEGER NOT NULL UNIQUE,
+           CONSTRAINT A_pkey PRIMARY KEY (id)
+       );
+
+       CREATE UNIQUE INDEX "A_a_key" ON "A" (a);
+   "#};
+
+    api.raw_cmd(setup).await;
+
+    let expectation = expect![[r#"
+        model A {
+          id Int @id @default(autoincrement())
+          a  Int @unique(map: "A_a_key")
+        }
+    "#]];
+
+    expectation.assert_eq(&api.introspect_dml().await?);
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+//! The rules for what counts as a valid Prisma identifier (model, field, enum and enum value
+//! name), shared by every part of the codebase that needs to check or rewrite a name coming from
+//! outside the datamodel — for example introspection turning a database identifier into a name,
+//! or the SQL schema calculator deciding whether a generated name is renderable as-is.
+
+/// A valid Prisma identifier starts with an ASCII letter and contains only ASCII letters, digits
+/// and underscores afterwards (`^[a-zA-Z][a-zA-Z0-9_]*$`).
+pub fn is_valid_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+
+    match chars.next() {
+        Some(first) if first.is_ascii_alphabetic() => chars.all(|c| c.is_ascii_alphanumeric() || c == '_'),
+        _ => false,
+    }
+}
+
+/// Rewrite `s` into a valid identifier: drop any leading run of characters that isn't an ASCII
+/// letter, then replace every remaining invalid character with an underscore. The result can be
+/// empty if `s` contains no ASCII letter at all.
+pub fn sanitize(s: &str) -> String {
+    if is_valid_identifier(s) {
+        return s.to_owned();
+    }
+
+    s.trim_start_matches(|c: char| !c.is_ascii_alphabetic())
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '_' { c } else { '_' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_names_are_left_untouched() {
+        for name in ["User", "user_id", "a1", "ABC123"] {
+            assert!(is_valid_identifier(name));
+            assert_eq!(name, sanitize(name));
+        }
+    }
+
+    #[test]
+    fn a_leading_digit_is_stripped() {
+        assert!(!is_valid_identifier("1_user"));
+        assert_eq!("user", sanitize("1_user"));
+    }
+
+    #[test]
+    fn dashes_are_replaced_with_underscores() {
+        assert!(!is_valid_identifier("first-name"));
+        assert_eq!("first_name", sanitize("first-name"));
+    }
+
+    #[test]
+    fn spaces_are_replaced_with_underscores() {
+        assert!(!is_valid_identifier("first name"));
+        assert_eq!("first_name", sanitize("first name"));
+    }
+
+    #[test]
+    fn a_leading_underscore_is_stripped_because_it_cannot_start_an_identifier() {
+        assert!(!is_valid_identifier("_user"));
+        assert_eq!("user", sanitize("_user"));
+    }
+
+    #[test]
+    fn a_name_with_no_letters_sanitizes_to_empty() {
+        assert!(!is_valid_identifier("123"));
+        assert_eq!("", sanitize("123"));
+    }
+}
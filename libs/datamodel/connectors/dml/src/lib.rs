@@ -6,6 +6,7 @@ pub mod datamodel;
 pub mod default_value;
 pub mod r#enum;
 pub mod field;
+pub mod identifier;
 pub mod model;
 pub mod native_type_instance;
 pub mod relation_info;
@@ -114,7 +114,9 @@ const SCALAR_TYPE_DEFAULTS: &[(ScalarType, PostgresType)] = &[
     (ScalarType::Int, PostgresType::Integer),
     (ScalarType::BigInt, PostgresType::BigInt),
     (ScalarType::Float, PostgresType::DoublePrecision),
-    (ScalarType::Decimal, PostgresType::Decimal(Some((65, 30)))),
+    // An unconstrained `numeric` column is the closest match to a plain `Decimal` field: Postgres
+    // does not have a fixed default precision/scale the way MySQL and SQL Server do.
+    (ScalarType::Decimal, PostgresType::Decimal(None)),
     (ScalarType::Boolean, PostgresType::Boolean),
     (ScalarType::String, PostgresType::Text),
     (ScalarType::DateTime, PostgresType::Timestamp(Some(3))),
@@ -171,7 +173,7 @@ impl Connector for PostgresDatamodelConnector {
             DoublePrecision => ScalarType::Float,
             //Decimal
             Decimal(_) => ScalarType::Decimal,
-            Money => ScalarType::Float,
+            Money => ScalarType::Decimal,
             //DateTime
             Timestamp(_) => ScalarType::DateTime,
             Timestamptz(_) => ScalarType::DateTime,
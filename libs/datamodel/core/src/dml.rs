@@ -2,6 +2,7 @@ pub use dml::composite_type::*;
 pub use dml::datamodel::*;
 pub use dml::default_value::*;
 pub use dml::field::*;
+pub use dml::identifier::{is_valid_identifier, sanitize};
 pub use dml::model::*;
 pub use dml::native_type_instance::*;
 pub use dml::prisma_value::{self, PrismaValue};
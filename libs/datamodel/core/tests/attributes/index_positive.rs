@@ -364,6 +364,57 @@ fn mysql_allows_compound_unique_sort_order() {
     assert!(datamodel::parse_schema(&schema).is_ok());
 }
 
+#[test]
+fn mysql_allows_compound_unique_length_and_sort_order_mixed() {
+    let dml = indoc! {r#"
+        model A {
+          a String
+          b String
+          c String
+          d Int
+          @@unique([a(length: 10, sort: Desc), b(sort: Asc), c(length: 30), d])
+        }
+    "#};
+
+    let schema = with_header(dml, Provider::Mysql, &[]);
+    let schema = parse(&schema);
+    let user_model = schema.assert_has_model("A");
+    user_model.assert_has_index(IndexDefinition {
+        name: None,
+        db_name: Some("A_a_b_c_d_key".to_string()),
+        fields: vec![
+            IndexField {
+                path: vec![("a".to_string(), None)],
+                sort_order: Some(SortOrder::Desc),
+                length: Some(10),
+                operator_class: None,
+            },
+            IndexField {
+                path: vec![("b".to_string(), None)],
+                sort_order: Some(SortOrder::Asc),
+                length: None,
+                operator_class: None,
+            },
+            IndexField {
+                path: vec![("c".to_string(), None)],
+                sort_order: None,
+                length: Some(30),
+                operator_class: None,
+            },
+            IndexField {
+                path: vec![("d".to_string(), None)],
+                sort_order: None,
+                length: None,
+                operator_class: None,
+            },
+        ],
+        tpe: IndexType::Unique,
+        defined_on_field: false,
+        algorithm: None,
+        clustered: None,
+    });
+}
+
 #[test]
 fn sqlite_allows_compound_unique_sort_order() {
     let dml = indoc! {r#"
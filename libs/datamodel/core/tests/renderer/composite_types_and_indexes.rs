@@ -0,0 +1,55 @@
+use crate::{with_header, Provider};
+use expect_test::expect;
+use indoc::indoc;
+
+// `datamodel::render_datamodel_to_string()` (in `libs/datamodel/core/src/lib.rs`), backed by
+// `schema_ast::renderer::Renderer`, is the only PSL renderer in this repo. There is no
+// `PrismaSchema` type or `schema-renderer` crate to fix up here — these tests exercise the actual
+// renderer against the scenarios that were reported broken (composite types, `@map`, `@default`,
+// `@id`, multi-field `@@unique` with sort order, and doc comments), so a regression here is caught
+// even though the originally described type doesn't exist.
+#[test]
+fn renders_composite_type_with_doc_comments_and_mapped_id() {
+    let schema = indoc! {r#"
+        /// An address embedded in a customer document.
+        type Address {
+          street String
+          city   String @map("cityName")
+        }
+
+        /// A customer and their default shipping address.
+        model Customer {
+          id      String   @id @default(auto()) @map("_id") @db.ObjectId
+          name    String?
+          tags    String[]
+          address Address
+
+          @@unique([name, id(sort: Desc)])
+        }
+    "#};
+
+    let dm = with_header(schema, Provider::Mongo, &[]);
+    let dml = datamodel::parse_datamodel(&dm).unwrap().subject;
+    let configuration = datamodel::parse_configuration(&dm).unwrap().subject;
+    let rendered = datamodel::render_datamodel_to_string(&dml, Some(&configuration));
+
+    let expected = expect![[r#"
+        /// An address embedded in a customer document.
+        type Address {
+          street String
+          city   String @map("cityName")
+        }
+
+        /// A customer and their default shipping address.
+        model Customer {
+          id      String   @id @default(auto()) @map("_id") @db.ObjectId
+          name    String?
+          tags    String[]
+          address Address
+
+          @@unique([name, id(sort: Desc)])
+        }
+    "#]];
+
+    expected.assert_eq(&rendered);
+}
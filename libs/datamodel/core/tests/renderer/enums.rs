@@ -1,3 +1,4 @@
+use datamodel::dml::{Datamodel, Enum, EnumValue};
 use expect_test::expect;
 
 #[test]
@@ -24,3 +25,50 @@ fn enum_rendering_works() {
     let rendered = datamodel::render_datamodel_to_string(&dml, None);
     expected.assert_eq(&rendered)
 }
+
+#[test]
+fn enum_value_with_a_database_name_gets_mapped() {
+    let black = EnumValue::new("black");
+    let mut gray_ish = EnumValue::new("gray_ish");
+    gray_ish.database_name = Some("gray-ish".to_owned());
+
+    let color = Enum::new("Color", vec![black, gray_ish]);
+
+    let mut dm = Datamodel::new();
+    dm.add_enum(color);
+
+    let expected = expect![[r#"
+        enum Color {
+          black
+          gray_ish @map("gray-ish")
+        }
+    "#]];
+
+    expected.assert_eq(&datamodel::render_datamodel_to_string(&dm, None))
+}
+
+#[test]
+fn commented_out_enum_value_renders_with_its_explanation() {
+    let black = EnumValue::new("black");
+    let mut empty = EnumValue::new("EMPTY_ENUM_VALUE");
+    empty.database_name = Some("".to_owned());
+    empty.commented_out = true;
+    empty.documentation = Some(
+        "This value was commented out because it is invalid. Please provide a valid one that matches [a-zA-Z][a-zA-Z0-9_]*"
+            .to_owned(),
+    );
+
+    let color = Enum::new("Color", vec![black, empty]);
+
+    let mut dm = Datamodel::new();
+    dm.add_enum(color);
+
+    let expected = expect![[r#"
+        enum Color {
+          black
+          // EMPTY_ENUM_VALUE @map("") // This value was commented out because it is invalid. Please provide a valid one that matches [a-zA-Z][a-zA-Z0-9_]*
+        }
+    "#]];
+
+    expected.assert_eq(&datamodel::render_datamodel_to_string(&dm, None))
+}
@@ -1,5 +1,7 @@
+mod composite_types_and_indexes;
 mod configuration;
 mod enums;
 mod extended_indexes;
 mod literals;
+mod scalar_field_rendering;
 mod simplification;
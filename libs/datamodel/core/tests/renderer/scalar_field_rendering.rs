@@ -0,0 +1,236 @@
+use crate::{with_header, Provider};
+use expect_test::expect;
+use indoc::indoc;
+
+// There is no `PrismaType` type, `ScalarField` rendering logic, or `schema-renderer` crate in
+// this codebase to fix up here — `datamodel::render_datamodel_to_string()` (backed by
+// `schema_ast::renderer::Renderer`) already renders every field line, including native type
+// suffixes, `@default`, `@map`, optional/array arity, and `Unsupported("...")`. These tests
+// exercise that real path across every `ScalarType` variant plus `Unsupported`, so a regression
+// here (e.g. one scalar type rendering as another) is caught even though the type described in
+// the request doesn't exist.
+fn assert_roundtrip(model: &str, expected: expect_test::Expect) {
+    let dm = with_header(model, Provider::Postgres, &[]);
+    let dml = datamodel::parse_datamodel(&dm).unwrap().subject;
+    let configuration = datamodel::parse_configuration(&dm).unwrap().subject;
+    let rendered = datamodel::render_datamodel_to_string(&dml, Some(&configuration));
+    expected.assert_eq(&rendered);
+}
+
+#[test]
+fn int_field_renders_with_its_own_type_and_default() {
+    assert_roundtrip(
+        indoc! {r#"
+            model ModelInt {
+              id    Int @id
+              value Int @default(1)
+            }
+        "#},
+        expect![[r#"
+            model ModelInt {
+              id    Int @id
+              value Int @default(1)
+            }
+        "#]],
+    );
+}
+
+#[test]
+fn bigint_field_renders_with_its_own_type_and_default() {
+    assert_roundtrip(
+        indoc! {r#"
+            model ModelBigInt {
+              id    Int @id
+              value BigInt @default(1)
+            }
+        "#},
+        expect![[r#"
+            model ModelBigInt {
+              id    Int    @id
+              value BigInt @default(1)
+            }
+        "#]],
+    );
+}
+
+#[test]
+fn float_field_renders_with_its_own_type_and_default() {
+    assert_roundtrip(
+        indoc! {r#"
+            model ModelFloat {
+              id    Int @id
+              value Float @default(1.5)
+            }
+        "#},
+        expect![[r#"
+            model ModelFloat {
+              id    Int   @id
+              value Float @default(1.5)
+            }
+        "#]],
+    );
+}
+
+#[test]
+fn boolean_field_renders_with_its_own_type_and_default() {
+    assert_roundtrip(
+        indoc! {r#"
+            model ModelBoolean {
+              id    Int @id
+              value Boolean @default(true)
+            }
+        "#},
+        expect![[r#"
+            model ModelBoolean {
+              id    Int     @id
+              value Boolean @default(true)
+            }
+        "#]],
+    );
+}
+
+#[test]
+fn string_field_renders_with_its_own_type_default_and_native_type() {
+    assert_roundtrip(
+        indoc! {r#"
+            model ModelString {
+              id    Int @id
+              value String @default("hi") @db.VarChar(255)
+            }
+        "#},
+        expect![[r#"
+            model ModelString {
+              id    Int    @id
+              value String @default("hi") @db.VarChar(255)
+            }
+        "#]],
+    );
+}
+
+#[test]
+fn datetime_field_renders_with_its_own_type_and_default() {
+    assert_roundtrip(
+        indoc! {r#"
+            model ModelDateTime {
+              id    Int @id
+              value DateTime @default(now())
+            }
+        "#},
+        expect![[r#"
+            model ModelDateTime {
+              id    Int      @id
+              value DateTime @default(now())
+            }
+        "#]],
+    );
+}
+
+#[test]
+fn json_field_renders_with_its_own_type_and_default() {
+    assert_roundtrip(
+        indoc! {r#"
+            model ModelJson {
+              id    Int @id
+              value Json @default("{}")
+            }
+        "#},
+        expect![[r#"
+            model ModelJson {
+              id    Int  @id
+              value Json @default("{}")
+            }
+        "#]],
+    );
+}
+
+#[test]
+fn bytes_field_renders_with_its_own_type_and_default() {
+    assert_roundtrip(
+        indoc! {r#"
+            model ModelBytes {
+              id    Int @id
+              value Bytes @default("aGVsbG8=")
+            }
+        "#},
+        expect![[r#"
+            model ModelBytes {
+              id    Int   @id
+              value Bytes @default("aGVsbG8=")
+            }
+        "#]],
+    );
+}
+
+#[test]
+fn decimal_field_renders_with_its_own_type_default_and_native_type() {
+    assert_roundtrip(
+        indoc! {r#"
+            model ModelDecimal {
+              id    Int @id
+              value Decimal @default("1.1") @db.Decimal(10, 2)
+            }
+        "#},
+        expect![[r#"
+            model ModelDecimal {
+              id    Int     @id
+              value Decimal @default("1.1") @db.Decimal(10, 2)
+            }
+        "#]],
+    );
+}
+
+#[test]
+fn optional_and_list_arity_render_without_a_default() {
+    assert_roundtrip(
+        indoc! {r#"
+            model ModelArity {
+              id   Int @id
+              opt  String?
+              list Int[]
+            }
+        "#},
+        expect![[r#"
+            model ModelArity {
+              id   Int     @id
+              opt  String?
+              list Int[]
+            }
+        "#]],
+    );
+}
+
+#[test]
+fn unsupported_field_renders_its_raw_type_string() {
+    assert_roundtrip(
+        indoc! {r#"
+            model ModelUnsupported {
+              id   Int @id
+              data Unsupported("some_type")
+            }
+        "#},
+        expect![[r#"
+            model ModelUnsupported {
+              id   Int                      @id
+              data Unsupported("some_type")
+            }
+        "#]],
+    );
+}
+
+#[test]
+fn map_attribute_renders_after_the_default() {
+    assert_roundtrip(
+        indoc! {r#"
+            model ModelMap {
+              id    Int @id
+              value Int @default(1) @map("val")
+            }
+        "#},
+        expect![[r#"
+            model ModelMap {
+              id    Int @id
+              value Int @default(1) @map("val")
+            }
+        "#]],
+    );
+}
@@ -1,4 +1,5 @@
 use crate::common::*;
+use crate::{with_header, Provider};
 use expect_test::expect;
 use indoc::indoc;
 
@@ -64,6 +65,34 @@ fn test_exclude_default_relation_names_from_rendering() {
     expected.assert_eq(&result);
 }
 
+#[test]
+fn test_native_types_matching_the_default_are_not_rendered() {
+    let dm = with_header(
+        r#"
+        model Post {
+          id      Int    @id
+          title   String @db.Text
+          summary String @db.VarChar(255)
+        }
+    "#,
+        Provider::Postgres,
+        &[],
+    );
+
+    let expected = expect![[r#"
+        model Post {
+          id      Int    @id
+          title   String
+          summary String @db.VarChar(255)
+        }
+    "#]];
+
+    let dml = datamodel::parse_datamodel(&dm).unwrap().subject;
+    let configuration = datamodel::parse_configuration(&dm).unwrap().subject;
+    let rendered = datamodel::render_datamodel_to_string(&dml, Some(&configuration));
+    expected.assert_eq(&rendered);
+}
+
 #[test]
 fn test_render_relation_name_on_self_relations() {
     let input = indoc! {r#"
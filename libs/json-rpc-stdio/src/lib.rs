@@ -122,6 +122,38 @@ pub async fn run(request_handler: &IoHandler) -> std::io::Result<()> {
     run_with_io(request_handler, tokio::io::stdin(), tokio::io::stdout(), client_adapter).await
 }
 
+/// What happened when [`run_with_client_until_shutdown`] stopped running.
+#[derive(Debug)]
+pub enum ShutdownOutcome {
+    /// The `shutdown` future resolved, and every request that was in flight at that point
+    /// finished on its own before `grace_period` elapsed.
+    Graceful,
+    /// The `shutdown` future resolved, but `grace_period` elapsed before some requests
+    /// finished. These are the JSON-RPC methods that were still running.
+    TimedOut { in_flight_methods: Vec<String> },
+}
+
+/// Like [`run_with_client`], but stops accepting new requests as soon as the `shutdown` future
+/// resolves (e.g. because the process received SIGTERM). Requests already being handled at that
+/// point are given up to `grace_period` to finish -- their responses are still written to
+/// stdout -- before this function returns.
+pub async fn run_with_client_until_shutdown(
+    request_handler: &IoHandler,
+    adapter: ClientAdapter,
+    shutdown: impl std::future::Future<Output = ()>,
+    grace_period: std::time::Duration,
+) -> std::io::Result<ShutdownOutcome> {
+    run_with_io_until_shutdown(
+        request_handler,
+        tokio::io::stdin(),
+        tokio::io::stdout(),
+        adapter,
+        shutdown,
+        grace_period,
+    )
+    .await
+}
+
 async fn run_with_io(
     handler: &IoHandler,
     input: impl AsyncRead + Unpin,
@@ -132,21 +164,89 @@ async fn run_with_io(
     let mut input_lines = input.lines();
     let mut output = tokio::io::BufWriter::new(output);
     let mut in_flight: HashMap<jsonrpc_core::Id, oneshot::Sender<_>> = HashMap::new();
-    let (mut stdout_sender, mut stdout_receiver) = mpsc::channel::<Vec<u8>>(30);
-
-    // Spawn stdout in its own task to queue writes.
-    tokio::spawn(async move {
-        while let Some(line) = stdout_receiver.recv().await {
-            output.write_all(&line).await.unwrap();
-            output.write_all(b"\n").await.unwrap();
-            output.flush().await.unwrap();
+    let (mut stdout_sender, _writer_handle) = spawn_stdout_writer(output);
+
+    loop {
+        tokio::select! {
+            next_line = input_lines.next_line() => {
+                handle_stdin_next_line(next_line, stdout_sender.clone(), handler, &mut in_flight, None).await?;
+            }
+            next_request = client_adapter.request_receiver.recv() => {
+                handle_next_client_request(next_request, &mut stdout_sender, &mut in_flight).await?;
+            }
+            next_notification = client_adapter.notification_receiver.recv() => {
+                handle_next_client_notification(next_notification, &mut stdout_sender).await?;
+            }
+        }
+    }
+}
+
+/// Spawn the task that owns `output` and serializes writes to it, so callers can queue writes
+/// from multiple concurrent tasks through a channel instead of sharing the writer directly.
+/// Returns the sender end of that channel, and the writer task's `JoinHandle`, which callers that
+/// need to know a write has actually reached `output` (not just been enqueued) can use together
+/// with [`StdoutMessage::Flush`].
+fn spawn_stdout_writer(
+    output: impl AsyncWrite + Send + Unpin + 'static,
+) -> (mpsc::Sender<StdoutMessage>, tokio::task::JoinHandle<()>) {
+    let (stdout_sender, mut stdout_receiver) = mpsc::channel::<StdoutMessage>(30);
+    let mut output = tokio::io::BufWriter::new(output);
+
+    let writer_handle = tokio::spawn(async move {
+        while let Some(message) = stdout_receiver.recv().await {
+            match message {
+                StdoutMessage::Line(line) => {
+                    output.write_all(&line).await.unwrap();
+                    output.write_all(b"\n").await.unwrap();
+                    output.flush().await.unwrap();
+                }
+                StdoutMessage::Flush(acknowledged) => {
+                    let _ = acknowledged.send(());
+                }
+            }
         }
     });
 
+    (stdout_sender, writer_handle)
+}
+
+/// A message sent to the stdout writer task spawned by [`spawn_stdout_writer`].
+enum StdoutMessage {
+    /// Write this line to stdout, followed by a newline, and flush.
+    Line(Vec<u8>),
+    /// Do nothing but acknowledge on the given channel once every `Line` enqueued before this
+    /// `Flush` has actually been written. Since the channel preserves FIFO order, sending a
+    /// `Flush` after every write you care about and awaiting the acknowledgement is how a caller
+    /// can be sure those writes reached `output`, as opposed to merely having been enqueued.
+    Flush(oneshot::Sender<()>),
+}
+
+async fn run_with_io_until_shutdown(
+    handler: &IoHandler,
+    input: impl AsyncRead + Unpin,
+    output: impl AsyncWrite + Send + Unpin + 'static,
+    mut client_adapter: ClientAdapter,
+    shutdown: impl std::future::Future<Output = ()>,
+    grace_period: std::time::Duration,
+) -> std::io::Result<ShutdownOutcome> {
+    let input = tokio::io::BufReader::new(input);
+    let mut input_lines = input.lines();
+    let mut in_flight: HashMap<jsonrpc_core::Id, oneshot::Sender<_>> = HashMap::new();
+    let mut running_requests: Vec<(String, tokio::task::JoinHandle<()>)> = Vec::new();
+    let (mut stdout_sender, _writer_handle) = spawn_stdout_writer(output);
+
+    tokio::pin!(shutdown);
+
     loop {
+        running_requests.retain(|(_, handle)| !handle.is_finished());
+
         tokio::select! {
+            _ = &mut shutdown => {
+                tracing::info!("Shutdown signal received, no longer accepting new requests.");
+                break;
+            }
             next_line = input_lines.next_line() => {
-                handle_stdin_next_line(next_line, stdout_sender.clone(), handler, &mut in_flight).await?;
+                handle_stdin_next_line(next_line, stdout_sender.clone(), handler, &mut in_flight, Some(&mut running_requests)).await?;
             }
             next_request = client_adapter.request_receiver.recv() => {
                 handle_next_client_request(next_request, &mut stdout_sender, &mut in_flight).await?;
@@ -156,35 +256,85 @@ async fn run_with_io(
             }
         }
     }
+
+    running_requests.retain(|(_, handle)| !handle.is_finished());
+
+    if running_requests.is_empty() {
+        flush_stdout(&stdout_sender).await;
+        return Ok(ShutdownOutcome::Graceful);
+    }
+
+    let wait_for_all = async {
+        for (_, handle) in running_requests.iter_mut() {
+            let _ = handle.await;
+        }
+    };
+
+    match tokio::time::timeout(grace_period, wait_for_all).await {
+        Ok(()) => {
+            // Every request handle above has returned, meaning it already enqueued its response
+            // with the writer task. Flushing now, and waiting for the acknowledgement, is what
+            // makes "responses are still written to stdout" in this function's doc comment true,
+            // rather than merely "responses were handed to a channel".
+            flush_stdout(&stdout_sender).await;
+            Ok(ShutdownOutcome::Graceful)
+        }
+        Err(_elapsed) => {
+            // Requests that finished before the grace period elapsed did enqueue their response;
+            // flush those before returning too. Requests still running past the grace period are
+            // abandoned as before -- we never awaited their handles, so we make no promise about
+            // their output.
+            flush_stdout(&stdout_sender).await;
+
+            let in_flight_methods = running_requests
+                .into_iter()
+                .filter(|(_, handle)| !handle.is_finished())
+                .map(|(method, _)| method)
+                .collect();
+            Ok(ShutdownOutcome::TimedOut { in_flight_methods })
+        }
+    }
+}
+
+/// Enqueue a [`StdoutMessage::Flush`] and wait for the writer task to reach it, guaranteeing every
+/// write enqueued before this call has actually reached the underlying `output`, not merely been
+/// handed to the channel.
+async fn flush_stdout(stdout_sender: &mpsc::Sender<StdoutMessage>) {
+    let (tx, rx) = oneshot::channel();
+
+    if stdout_sender.send(StdoutMessage::Flush(tx)).await.is_ok() {
+        let _ = rx.await;
+    }
 }
 
 async fn handle_next_client_request(
     next_request: Option<(jsonrpc_core::MethodCall, oneshot::Sender<jsonrpc_core::Output>)>,
-    stdout_sender: &mut mpsc::Sender<Vec<u8>>,
+    stdout_sender: &mut mpsc::Sender<StdoutMessage>,
     in_flight: &mut HashMap<jsonrpc_core::Id, oneshot::Sender<jsonrpc_core::Output>>,
 ) -> io::Result<()> {
     let (next_request, channel) = next_request.unwrap();
     in_flight.insert(next_request.id.clone(), channel);
     let request_json = serde_json::to_vec(&next_request)?;
-    stdout_sender.send(request_json).await.unwrap();
+    stdout_sender.send(StdoutMessage::Line(request_json)).await.unwrap();
     Ok(())
 }
 
 async fn handle_next_client_notification(
     next_notification: Option<Notification>,
-    stdout_sender: &mut mpsc::Sender<Vec<u8>>,
+    stdout_sender: &mut mpsc::Sender<StdoutMessage>,
 ) -> io::Result<()> {
     let next_notification = next_notification.unwrap();
     let request_json = serde_json::to_vec(&next_notification)?;
-    stdout_sender.send(request_json).await.unwrap();
+    stdout_sender.send(StdoutMessage::Line(request_json)).await.unwrap();
     Ok(())
 }
 
 async fn handle_stdin_next_line(
     next_line: io::Result<Option<String>>,
-    stdout_sender: mpsc::Sender<Vec<u8>>,
+    stdout_sender: mpsc::Sender<StdoutMessage>,
     handler: &IoHandler,
     in_flight: &mut HashMap<jsonrpc_core::Id, oneshot::Sender<jsonrpc_core::Output>>,
+    running_requests: Option<&mut Vec<(String, tokio::task::JoinHandle<()>)>>,
 ) -> io::Result<()> {
     let next_line = if let Some(next_line) = next_line? {
         next_line
@@ -194,11 +344,16 @@ async fn handle_stdin_next_line(
 
     match serde_json::from_str::<Message>(&next_line)? {
         Message::Request(request) => {
+            let method_name = request_method_name(&request);
             let handler = handler.clone();
-            tokio::spawn(async move {
+            let join_handle = tokio::spawn(async move {
                 let response = handle_request(&handler, request).await;
-                stdout_sender.send(response.into_bytes()).await.unwrap();
+                stdout_sender.send(StdoutMessage::Line(response.into_bytes())).await.unwrap();
             });
+
+            if let Some(running_requests) = running_requests {
+                running_requests.push((method_name, join_handle));
+            }
         }
         Message::Response(response) => {
             if let Some(chan) = in_flight.remove(response.id()) {
@@ -210,6 +365,18 @@ async fn handle_stdin_next_line(
     Ok(())
 }
 
+/// Best-effort extraction of the method name from an incoming request, for logging and shutdown
+/// reporting purposes. Batches are reported under a single synthetic name, since we do not track
+/// in-flight requests at that granularity.
+fn request_method_name(request: &Request) -> String {
+    match request {
+        Request::Single(jsonrpc_core::Call::MethodCall(call)) => call.method.clone(),
+        Request::Single(jsonrpc_core::Call::Notification(notification)) => notification.method.clone(),
+        Request::Single(jsonrpc_core::Call::Invalid { .. }) => "<invalid>".to_owned(),
+        Request::Batch(_) => "<batch>".to_owned(),
+    }
+}
+
 /// Process a request asynchronously
 async fn handle_request(io: &IoHandler, input: Request) -> String {
     let response = io.handle_rpc_request(input).await;
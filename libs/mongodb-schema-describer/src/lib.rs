@@ -26,8 +26,16 @@ pub async fn describe(client: &mongodb::Client, db_name: &str) -> mongodb::error
 
     while let Some(collection) = cursor.try_next().await? {
         let collection_name = collection.name;
+
+        let options = CollectionOptions {
+            capped: collection.options.capped.unwrap_or(false),
+            capped_size: collection.options.size,
+            capped_max: collection.options.max,
+            has_validator: collection.options.validator.is_some(),
+        };
+
         let collection = database.collection::<Document>(&collection_name);
-        let collection_id = schema.push_collection(collection_name);
+        let collection_id = schema.push_collection(collection_name, options);
 
         let mut indexes_cursor = collection.list_indexes(None).await?;
 
@@ -20,6 +20,28 @@ pub struct IndexId(usize);
 /// All the information we can fetch per collection.
 pub struct CollectionData {
     pub(crate) name: String,
+    pub(crate) options: CollectionOptions,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// Collection-level options that Prisma cannot express in the schema, but that we still need to
+/// know about so introspection and `db push` can warn instead of silently ignoring them.
+pub struct CollectionOptions {
+    /// The collection is a [capped collection](https://www.mongodb.com/docs/manual/core/capped-collections/).
+    pub capped: bool,
+    /// The maximum size in bytes for a capped collection.
+    pub capped_size: Option<i64>,
+    /// The maximum number of documents allowed in a capped collection.
+    pub capped_max: Option<i64>,
+    /// The collection has a [schema validator](https://www.mongodb.com/docs/manual/core/schema-validation/) set.
+    pub has_validator: bool,
+}
+
+impl CollectionOptions {
+    /// True if the collection has any option Prisma cannot model.
+    pub fn has_unsupported_options(self) -> bool {
+        self.capped || self.has_validator
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -70,8 +92,8 @@ pub struct MongoSchema {
 
 impl MongoSchema {
     /// Add a collection to the schema.
-    pub fn push_collection(&mut self, name: String) -> CollectionId {
-        self.collections.push(CollectionData { name });
+    pub fn push_collection(&mut self, name: String, options: CollectionOptions) -> CollectionId {
+        self.collections.push(CollectionData { name, options });
         CollectionId(self.collections.len() - 1)
     }
 
@@ -1,4 +1,4 @@
-use crate::{CollectionData, CollectionId, IndexData, IndexField, IndexId, IndexType, MongoSchema};
+use crate::{CollectionData, CollectionId, CollectionOptions, IndexData, IndexField, IndexId, IndexType, MongoSchema};
 
 #[derive(Clone, Copy)]
 /// A collection/table in the database.
@@ -22,6 +22,11 @@ impl<'schema> CollectionWalker<'schema> {
         &self.get().name
     }
 
+    /// The collection options Prisma cannot model (cappedness, schema validation, ...).
+    pub fn options(self) -> CollectionOptions {
+        self.get().options
+    }
+
     /// Iterator over all the indexes in the collection.
     pub fn indexes(self) -> impl ExactSizeIterator<Item = IndexWalker<'schema>> + 'schema {
         let create_walker = move |id: &IndexId| IndexWalker {
@@ -164,23 +164,32 @@ impl Display for DropType<'_> {
     }
 }
 
-/// Render a `DROP VIEW` statement.
+/// Render a `DROP VIEW` (or `DROP MATERIALIZED VIEW`) statement.
 ///
 /// ```
 /// # use sql_ddl::postgres::DropView;
 ///
-/// let drop_view = DropView { view_name: "Cat".into() };
+/// let drop_view = DropView { view_name: "Cat".into(), is_materialized: false };
 /// assert_eq!(drop_view.to_string(), r#"DROP VIEW "Cat""#);
+///
+/// let drop_matview = DropView { view_name: "Cat".into(), is_materialized: true };
+/// assert_eq!(drop_matview.to_string(), r#"DROP MATERIALIZED VIEW "Cat""#);
 /// ```
 #[derive(Debug)]
 pub struct DropView<'a> {
     /// The name of the view to be dropped.
     pub view_name: PostgresIdentifier<'a>,
+    /// Whether the view being dropped is a materialized view.
+    pub is_materialized: bool,
 }
 
 impl Display for DropView<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("DROP VIEW ")?;
+        if self.is_materialized {
+            f.write_str("DROP MATERIALIZED VIEW ")?;
+        } else {
+            f.write_str("DROP VIEW ")?;
+        }
         Display::fmt(&self.view_name, f)
     }
 }
@@ -0,0 +1,120 @@
+//! Benchmarks for walking synthetic `SqlSchema` values.
+//!
+//! The schemas built here are not meant to be valid SQL, only structurally representative:
+//! enough tables, columns, indexes and foreign keys to exercise the walker APIs at realistic
+//! scale, without needing a database connection.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use sql_schema_describer::{
+    Column, ColumnArity, ColumnType, ColumnTypeFamily, ForeignKey, ForeignKeyAction, Index, IndexColumn, IndexType,
+    SqlSchema,
+};
+
+/// Build a synthetic schema with `tables` tables, `columns_per_table` columns each, one index
+/// per table for every `1 / index_density` columns, and a foreign key to the previous table for
+/// every `1 / fk_density` tables (`0.0` disables indexes or foreign keys entirely).
+fn build_schema(tables: usize, columns_per_table: usize, index_density: f64, fk_density: f64) -> SqlSchema {
+    let mut schema = SqlSchema::default();
+    let mut table_ids = Vec::with_capacity(tables);
+
+    for table_index in 0..tables {
+        let table_id = schema.push_table(format!("table_{table_index}"));
+        table_ids.push(table_id);
+
+        let mut column_names = Vec::with_capacity(columns_per_table);
+
+        for column_index in 0..columns_per_table {
+            let name = format!("column_{column_index}");
+            schema.push_column(
+                table_id,
+                Column {
+                    name: name.clone(),
+                    tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+                    default: None,
+                    auto_increment: false,
+                    is_identity: false,
+                    comment: None,
+                    generated: None,
+                },
+            );
+            column_names.push(name);
+        }
+
+        if index_density > 0.0 {
+            let step = (1.0 / index_density).max(1.0) as usize;
+
+            for (index_index, chunk_start) in (0..columns_per_table).step_by(step).enumerate() {
+                schema[table_id].indices.push(Index {
+                    name: format!("index_{table_index}_{index_index}"),
+                    columns: vec![IndexColumn::new(column_names[chunk_start].clone())],
+                    tpe: IndexType::Normal,
+                    is_autogenerated: false,
+                });
+            }
+        }
+
+        if fk_density > 0.0 && table_index > 0 {
+            let step = (1.0 / fk_density).max(1.0) as usize;
+
+            if table_index % step == 0 {
+                let referenced_table = table_ids[table_index - 1];
+
+                schema.foreign_keys.push((
+                    table_id,
+                    ForeignKey {
+                        constraint_name: Some(format!("fk_{table_index}")),
+                        columns: vec![column_names[0].clone()],
+                        referenced_table,
+                        referenced_columns: vec!["column_0".to_owned()],
+                        on_delete_action: ForeignKeyAction::NoAction,
+                        on_update_action: ForeignKeyAction::NoAction,
+                        validated: true,
+                    },
+                ));
+            }
+        }
+    }
+
+    schema
+}
+
+/// Walk every table, column, index and foreign key, forcing all lazy accessors to run. Used as
+/// the workload for the traversal benchmarks below.
+fn walk_all(schema: &SqlSchema) -> usize {
+    let mut count = 0;
+
+    for table in schema.table_walkers() {
+        count += 1;
+
+        for column in table.columns() {
+            count += 1;
+            let _ = column.column_type_family();
+        }
+
+        for index in table.indexes() {
+            count += 1;
+            count += index.columns().count();
+        }
+    }
+
+    count += schema.walk_foreign_keys().count();
+
+    count
+}
+
+fn walker_traversal(c: &mut Criterion) {
+    let mut group = c.benchmark_group("walker_traversal");
+
+    for &table_count in &[10usize, 100, 1_000] {
+        let schema = build_schema(table_count, 10, 0.3, 0.2);
+
+        group.bench_with_input(BenchmarkId::from_parameter(table_count), &schema, |b, schema| {
+            b.iter(|| walk_all(schema));
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, walker_traversal);
+criterion_main!(benches);
@@ -56,6 +56,34 @@ pub enum DescriberErrorKind {
         /// Name of the constraint.
         constraint: String,
     },
+    /// A row returned by a describer query was missing an expected column.
+    MissingColumn {
+        /// The name of the missing column.
+        column: &'static str,
+        /// The row that was missing the column, rendered with `{:?}` so the columns that
+        /// were actually present are visible.
+        row_debug: String,
+        /// A short description of the query the row came from, to help pinpoint the failure.
+        query_context: &'static str,
+    },
+    /// [`DescribeOptions::fail_on_unsupported`](crate::DescribeOptions::fail_on_unsupported) was
+    /// set, and the schema contains one or more columns the describer could not map to a Prisma
+    /// type.
+    UnsupportedObjectsFound {
+        /// Every offending column, in the order the describer found them.
+        objects: Vec<UnsupportedObjectDescription>,
+    },
+}
+
+/// A single column that [`DescriberErrorKind::UnsupportedObjectsFound`] is reporting.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnsupportedObjectDescription {
+    /// The table the column belongs to.
+    pub table: String,
+    /// The column name.
+    pub column: String,
+    /// The full SQL data type of the column, as reported by the database (e.g. `tsvector`).
+    pub full_data_type: String,
 }
 
 impl Display for DescriberError {
@@ -81,6 +109,26 @@ impl Display for DescriberErrorKind {
                     from, to, constraint
                 )
             }
+            Self::MissingColumn {
+                column,
+                row_debug,
+                query_context,
+            } => {
+                write!(
+                    f,
+                    "Expected column `{}` in the result of the `{}` query, but it was not present on row {}",
+                    column, query_context, row_debug
+                )
+            }
+            Self::UnsupportedObjectsFound { objects } => {
+                writeln!(f, "The schema contains columns that Prisma cannot represent:")?;
+
+                for object in objects {
+                    writeln!(f, "- {}.{}: {}", object.table, object.column, object.full_data_type)?;
+                }
+
+                Ok(())
+            }
         }
     }
 }
@@ -90,6 +138,8 @@ impl Error for DescriberError {
         match &self.kind {
             DescriberErrorKind::QuaintError(err) => Some(err),
             DescriberErrorKind::CrossSchemaReference { .. } => None,
+            DescriberErrorKind::MissingColumn { .. } => None,
+            DescriberErrorKind::UnsupportedObjectsFound { .. } => None,
         }
     }
 }
@@ -1,5 +1,6 @@
 //! Functions for fetching from quaint result rows
 
+use crate::{DescriberErrorKind, DescriberResult};
 use quaint::connector::ResultRow;
 
 pub trait Getter {
@@ -13,6 +14,15 @@ pub trait Getter {
     fn get_bool(&self, name: &str) -> Option<bool>;
     fn get_u32(&self, name: &str) -> Option<u32>;
     fn get_i64(&self, name: &str) -> Option<i64>;
+
+    /// Like `get_expect_string`, but returns a `DescriberError` carrying the column name and the
+    /// query it came from instead of panicking. Use this on call sites that read data from
+    /// databases we don't fully control the schema of (i.e. any real-world database), where a
+    /// missing or renamed column should surface as a normal error rather than crash the process.
+    fn get_result_string(&self, name: &'static str, query_context: &'static str) -> DescriberResult<String>;
+
+    /// Like `get_result_string`, but for `i64` columns.
+    fn get_result_i64(&self, name: &'static str, query_context: &'static str) -> DescriberResult<i64>;
 }
 
 impl Getter for ResultRow {
@@ -82,4 +92,26 @@ impl Getter for ResultRow {
     fn get_i64(&self, name: &str) -> Option<i64> {
         self.get(name).and_then(|x| x.as_integer())
     }
+
+    fn get_result_string(&self, name: &'static str, query_context: &'static str) -> DescriberResult<String> {
+        self.get_string(name).ok_or_else(|| {
+            DescriberErrorKind::MissingColumn {
+                column: name,
+                row_debug: format!("{:?}", self),
+                query_context,
+            }
+            .into()
+        })
+    }
+
+    fn get_result_i64(&self, name: &'static str, query_context: &'static str) -> DescriberResult<i64> {
+        self.get_i64(name).ok_or_else(|| {
+            DescriberErrorKind::MissingColumn {
+                column: name,
+                row_debug: format!("{:?}", self),
+                query_context,
+            }
+            .into()
+        })
+    }
 }
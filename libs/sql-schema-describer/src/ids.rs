@@ -92,3 +92,21 @@ impl IndexMut<ForeignKeyId> for SqlSchema {
         &mut self.foreign_keys[index.0 as usize]
     }
 }
+
+/// The identifier for a CheckConstraint in the schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CheckConstraintId(pub(crate) u32);
+
+impl Index<CheckConstraintId> for SqlSchema {
+    type Output = (TableId, crate::CheckConstraint);
+
+    fn index(&self, index: CheckConstraintId) -> &Self::Output {
+        &self.check_constraints[index.0 as usize]
+    }
+}
+
+impl IndexMut<CheckConstraintId> for SqlSchema {
+    fn index_mut(&mut self, index: CheckConstraintId) -> &mut Self::Output {
+        &mut self.check_constraints[index.0 as usize]
+    }
+}
@@ -15,7 +15,7 @@ mod ids;
 mod parsers;
 
 pub use self::{
-    error::{DescriberError, DescriberErrorKind, DescriberResult},
+    error::{DescriberError, DescriberErrorKind, DescriberResult, UnsupportedObjectDescription},
     ids::*,
 };
 
@@ -25,10 +25,77 @@ use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::{
     any::Any,
+    collections::HashSet,
     fmt::{self, Debug},
 };
 use walkers::{EnumWalker, ForeignKeyWalker, SqlSchemaExt, TableWalker, UserDefinedTypeWalker, ViewWalker};
 
+/// Options for [`SqlSchemaDescriberBackend::describe_with_options`]. Defaults to the same
+/// behavior as [`SqlSchemaDescriberBackend::describe`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DescribeOptions {
+    /// Do not error out on native types that cannot be mapped to a Prisma type, mapping them to
+    /// `String` instead.
+    pub lenient_types: bool,
+    /// Order the tables in the returned [`SqlSchema`] so that a table referenced by a foreign key
+    /// always comes before the table(s) that reference it. This is useful for callers that need to
+    /// emit or replay DDL/DML in an order that satisfies foreign key constraints. Tables that are
+    /// not part of any dependency relationship keep their original relative order, and tables
+    /// involved in a foreign key cycle are placed in their original relative order as a group, once
+    /// all of their acyclic dependencies have been placed. Only honored by the SQLite describer;
+    /// other describers return tables in their usual order.
+    pub dependency_order: bool,
+    /// Include temporary tables in the returned [`SqlSchema`]. Temporary tables (SQLite's `temp`
+    /// schema, Postgres' `pg_temp*` schemas) are invisible to regular queries and are excluded by
+    /// default; this is mainly useful for debugging and testing. Only honored by the SQLite and
+    /// Postgres describers. On Postgres, this only extends table and column introspection to the
+    /// temporary schema — indices, foreign keys and comments on temporary tables are not captured.
+    pub include_temporary_tables: bool,
+    /// Fetch an approximate row count for each table (Postgres' `pg_class.reltuples`, or `COUNT(*)`
+    /// on SQLite). Off by default, because on Postgres an out-of-date `reltuples` can be misleading
+    /// right after a bulk load until the next `ANALYZE`, and on SQLite a plain `COUNT(*)` is a full
+    /// table scan; this is meant for UIs that display table sizes, not for anything that needs an
+    /// exact or cheap count. Only honored by the SQLite and Postgres describers; other describers
+    /// leave [`Table::row_count_estimate`] as `None`.
+    pub include_row_count_estimates: bool,
+    /// Capture the raw catalog definition of objects the describer could not fully model (e.g. a
+    /// column type it maps to [`ColumnTypeFamily::Unsupported`]) into
+    /// [`SqlSchema::raw_unsupported`], for diagnostics. Off by default, since it duplicates
+    /// information already implied by the modeled schema for callers that don't need it. Only
+    /// honored by the Postgres describer; other describers leave
+    /// [`SqlSchema::raw_unsupported`] empty.
+    pub capture_raw_unsupported: bool,
+    /// Instead of describing a schema that contains a column the describer could not map to a
+    /// Prisma type (i.e. [`ColumnTypeFamily::Unsupported`]), fail with
+    /// [`DescriberErrorKind::UnsupportedObjectsFound`] listing every offending column. Meant for
+    /// CI schema gates that want to catch a column silently degrading client type-safety, instead
+    /// of only surfacing it as an introspection warning. Off by default.
+    pub fail_on_unsupported: bool,
+    /// Compare schema names ASCII-case-insensitively when deciding whether a foreign key crosses
+    /// schemas (i.e. whether to return [`DescriberErrorKind::CrossSchemaReference`]). Off by
+    /// default, since Postgres schema names are case-sensitive; turn this on for databases or
+    /// setups (e.g. MSSQL with a case-insensitive collation, or schema names that get lowercased
+    /// somewhere in the pipeline) where the describer's `schema` argument and the schema name
+    /// reported by the catalog for a referenced table can differ only in case despite referring to
+    /// the same schema.
+    pub schema_names_case_insensitive: bool,
+    /// Capture the database's installed extensions (`pg_extension`: name, version and schema)
+    /// into [`crate::postgres::PostgresSchemaExt::extensions`]. Off by default, since most callers
+    /// don't need it and it's an extra catalog round-trip. Only honored by the Postgres describer;
+    /// other describers leave the list empty.
+    pub include_extensions: bool,
+}
+
+/// Compares two schema names as [`DescribeOptions::schema_names_case_insensitive`] dictates,
+/// for deciding whether a foreign key stays within its own schema.
+pub(crate) fn schemas_match(a: &str, b: &str, options: &DescribeOptions) -> bool {
+    if options.schema_names_case_insensitive {
+        a.eq_ignore_ascii_case(b)
+    } else {
+        a == b
+    }
+}
+
 /// A database description connector.
 #[async_trait::async_trait]
 pub trait SqlSchemaDescriberBackend: Send + Sync {
@@ -39,7 +106,13 @@ pub trait SqlSchemaDescriberBackend: Send + Sync {
     async fn get_metadata(&self, schema: &str) -> DescriberResult<SqlMetadata>;
 
     /// Describe a database schema.
-    async fn describe(&self, schema: &str) -> DescriberResult<SqlSchema>;
+    async fn describe(&self, schema: &str) -> DescriberResult<SqlSchema> {
+        self.describe_with_options(schema, &DescribeOptions::default()).await
+    }
+
+    /// Same as [`SqlSchemaDescriberBackend::describe`], with additional options controlling how
+    /// the schema is described.
+    async fn describe_with_options(&self, schema: &str, options: &DescribeOptions) -> DescriberResult<SqlSchema>;
 
     /// Get the database version.
     async fn version(&self, schema: &str) -> DescriberResult<Option<String>>;
@@ -61,12 +134,20 @@ pub struct SqlSchema {
     columns: Vec<(TableId, Column)>,
     /// All foreign keys.
     pub foreign_keys: Vec<(TableId, ForeignKey)>,
+    /// All CHECK constraints.
+    pub check_constraints: Vec<(TableId, CheckConstraint)>,
     /// The schema's views,
     views: Vec<View>,
     /// The stored procedures.
     procedures: Vec<Procedure>,
     /// The user-defined types procedures.
     user_defined_types: Vec<UserDefinedType>,
+    /// Non-fatal issues encountered while describing the schema, e.g. an index with an
+    /// unrecognized algorithm that was defaulted to a fallback.
+    pub warnings: Vec<DescriberWarning>,
+    /// Raw catalog definitions of objects the describer could not fully model, only populated
+    /// when [`DescribeOptions::capture_raw_unsupported`] is set.
+    pub raw_unsupported: Vec<RawObject>,
     /// Connector-specific data
     connector_data: connector_data::ConnectorData,
 }
@@ -139,8 +220,11 @@ impl SqlSchema {
                 user_defined_types,
                 columns,
                 foreign_keys,
+                check_constraints,
+                warnings: _,
+                raw_unsupported: _,
                 connector_data: _,
-            } if tables.is_empty() && enums.is_empty() && views.is_empty() && procedures.is_empty() && user_defined_types.is_empty() && columns.is_empty() && foreign_keys.is_empty()
+            } if tables.is_empty() && enums.is_empty() && views.is_empty() && procedures.is_empty() && user_defined_types.is_empty() && columns.is_empty() && foreign_keys.is_empty() && check_constraints.is_empty()
         )
     }
 
@@ -164,6 +248,146 @@ impl SqlSchema {
         id
     }
 
+    /// Remove the columns matching the predicate. Only meant to be called right after describing
+    /// a schema, since it invalidates any `ColumnId` obtained beforehand.
+    pub fn remove_columns(&mut self, mut predicate: impl FnMut(TableId, &Column) -> bool) {
+        self.columns.retain(|(table_id, column)| !predicate(*table_id, column));
+    }
+
+    /// Keep only the tables whose name matches `keep`, dropping the rest along with their
+    /// columns, any foreign key touching a dropped table, and any enum no longer referenced by a
+    /// remaining column (views, procedures and user-defined types are left untouched, since
+    /// unlike enums they are not diffed independently of table membership). Renumbers `TableId`s
+    /// and `ColumnId`s to stay contiguous, so this invalidates any such id obtained beforehand.
+    /// Returns the old-to-new `ColumnId` mapping (indexed by the old id, `None` if the column was
+    /// dropped) so that callers keeping their own `ColumnId`-indexed side tables can remap them
+    /// too. Meant for restricting a schema to a subset of tables (e.g. `schemaPush`'s `models`
+    /// filter).
+    pub fn retain_tables(&mut self, mut keep: impl FnMut(&str) -> bool) -> Vec<Option<ColumnId>> {
+        let old_tables = std::mem::take(&mut self.tables);
+        let mut new_table_ids: Vec<Option<TableId>> = Vec::with_capacity(old_tables.len());
+        let mut new_tables = Vec::new();
+
+        for table in old_tables {
+            if keep(&table.name) {
+                new_table_ids.push(Some(TableId(new_tables.len() as u32)));
+                new_tables.push(table);
+            } else {
+                new_table_ids.push(None);
+            }
+        }
+
+        self.tables = new_tables;
+
+        let old_columns = std::mem::take(&mut self.columns);
+        let mut new_column_ids: Vec<Option<ColumnId>> = Vec::with_capacity(old_columns.len());
+        let mut new_columns = Vec::new();
+
+        for (table_id, column) in old_columns {
+            if let Some(new_table_id) = new_table_ids[table_id.0 as usize] {
+                new_column_ids.push(Some(ColumnId(new_columns.len() as u32)));
+                new_columns.push((new_table_id, column));
+            } else {
+                new_column_ids.push(None);
+            }
+        }
+
+        self.columns = new_columns;
+
+        self.foreign_keys.retain(|(table_id, fk)| {
+            new_table_ids[table_id.0 as usize].is_some() && new_table_ids[fk.referenced_table.0 as usize].is_some()
+        });
+        for (table_id, fk) in &mut self.foreign_keys {
+            *table_id = new_table_ids[table_id.0 as usize].unwrap();
+            fk.referenced_table = new_table_ids[fk.referenced_table.0 as usize].unwrap();
+        }
+
+        self.check_constraints
+            .retain(|(table_id, _)| new_table_ids[table_id.0 as usize].is_some());
+        for (table_id, _) in &mut self.check_constraints {
+            *table_id = new_table_ids[table_id.0 as usize].unwrap();
+        }
+
+        let referenced_enums: HashSet<&str> = self
+            .columns
+            .iter()
+            .filter_map(|(_, column)| column.tpe.family.as_enum())
+            .collect();
+        self.enums.retain(|enm| referenced_enums.contains(enm.name.as_str()));
+
+        new_column_ids
+    }
+
+    /// Merge another schema's tables, enums, columns, foreign keys, views, procedures and
+    /// user-defined types into this one, prefixing every name coming from `other` with
+    /// `namespace` and shifting ids so they keep resolving to the right objects after the
+    /// merge. Meant for combining descriptions of several physical databases (e.g. shards)
+    /// into one logical `SqlSchema` that can be walked as a whole.
+    ///
+    /// `other`'s connector-specific data, warnings and raw unsupported objects are dropped; only
+    /// `self`'s are kept.
+    pub fn merge(&mut self, other: SqlSchema, namespace: &str) {
+        let table_offset = self.tables.len() as u32;
+
+        let SqlSchema {
+            tables,
+            enums,
+            columns,
+            foreign_keys,
+            check_constraints,
+            views,
+            procedures,
+            user_defined_types,
+            warnings: _,
+            raw_unsupported: _,
+            connector_data: _,
+        } = other;
+
+        self.tables.extend(tables.into_iter().map(|mut table| {
+            table.name = format!("{}.{}", namespace, table.name);
+            table
+        }));
+
+        self.enums.extend(enums.into_iter().map(|mut enm| {
+            enm.name = format!("{}.{}", namespace, enm.name);
+            enm
+        }));
+
+        self.columns.extend(columns.into_iter().map(|(table_id, mut column)| {
+            if let ColumnTypeFamily::Enum(enum_name) = &mut column.tpe.family {
+                *enum_name = format!("{}.{}", namespace, enum_name);
+            }
+
+            (TableId(table_id.0 + table_offset), column)
+        }));
+
+        self.foreign_keys
+            .extend(foreign_keys.into_iter().map(|(table_id, mut foreign_key)| {
+                foreign_key.referenced_table = TableId(foreign_key.referenced_table.0 + table_offset);
+                (TableId(table_id.0 + table_offset), foreign_key)
+            }));
+
+        self.check_constraints
+            .extend(check_constraints.into_iter().map(|(table_id, check_constraint)| {
+                (TableId(table_id.0 + table_offset), check_constraint)
+            }));
+
+        self.views.extend(views.into_iter().map(|mut view| {
+            view.name = format!("{}.{}", namespace, view.name);
+            view
+        }));
+
+        self.procedures.extend(procedures.into_iter().map(|mut procedure| {
+            procedure.name = format!("{}.{}", namespace, procedure.name);
+            procedure
+        }));
+
+        self.user_defined_types.extend(user_defined_types.into_iter().map(|mut udt| {
+            udt.name = format!("{}.{}", namespace, udt.name);
+            udt
+        }));
+    }
+
     pub fn push_table(&mut self, name: String) -> TableId {
         let id = TableId(self.tables.len() as u32);
         self.tables.push(Table {
@@ -189,6 +413,29 @@ impl SqlSchema {
         })
     }
 
+    /// Implements [`DescribeOptions::fail_on_unsupported`]: returns
+    /// [`DescriberErrorKind::UnsupportedObjectsFound`] if any column in the schema has a
+    /// [`ColumnTypeFamily::Unsupported`] type, listing all of them. Called by describers after
+    /// building the schema, when the option is set.
+    pub(crate) fn error_on_unsupported_columns(&self) -> DescriberResult<()> {
+        let objects: Vec<UnsupportedObjectDescription> = self
+            .table_walkers()
+            .flat_map(|table| table.columns())
+            .filter(|column| matches!(column.column_type_family(), ColumnTypeFamily::Unsupported(_)))
+            .map(|column| UnsupportedObjectDescription {
+                table: column.table().name().to_owned(),
+                column: column.name().to_owned(),
+                full_data_type: column.column_type().full_data_type.clone(),
+            })
+            .collect();
+
+        if objects.is_empty() {
+            Ok(())
+        } else {
+            Err(DescriberErrorKind::UnsupportedObjectsFound { objects }.into())
+        }
+    }
+
     pub fn view_walkers(&self) -> impl Iterator<Item = ViewWalker<'_>> {
         (0..self.views.len()).map(move |view_index| ViewWalker::new(self, view_index))
     }
@@ -214,6 +461,89 @@ impl SqlSchema {
             id: ForeignKeyId(fk_idx as u32),
         })
     }
+
+    /// Reorder `self.tables` so a table referenced by a foreign key comes before the table(s) that
+    /// reference it, and remap every [`TableId`] in the schema accordingly. Tables that are not
+    /// connected by a foreign key relationship keep their original relative order. Tables that are
+    /// only reachable through a foreign key cycle are appended, in their original relative order,
+    /// once the rest of the schema has been placed.
+    pub(crate) fn sort_tables_by_dependency_order(&mut self) {
+        let table_count = self.tables.len();
+        let mut remaining_dependencies: Vec<usize> = vec![0; table_count];
+
+        for (table_id, fk) in &self.foreign_keys {
+            if fk.referenced_table.0 as usize != table_id.0 as usize {
+                remaining_dependencies[table_id.0 as usize] += 1;
+            }
+        }
+
+        let mut new_order: Vec<u32> = Vec::with_capacity(table_count);
+        let mut placed = vec![false; table_count];
+
+        // Kahn's algorithm, but instead of a queue we repeatedly scan for placeable tables in
+        // their original order, so ties (including tables with no dependencies at all) resolve to
+        // the original relative order rather than an arbitrary one.
+        while new_order.len() < table_count {
+            let mut placed_any = false;
+
+            for table_index in 0..table_count {
+                if placed[table_index] || remaining_dependencies[table_index] > 0 {
+                    continue;
+                }
+
+                placed[table_index] = true;
+                new_order.push(table_index as u32);
+                placed_any = true;
+
+                for (dependent_id, fk) in &self.foreign_keys {
+                    if fk.referenced_table.0 as usize == table_index && dependent_id.0 as usize != table_index {
+                        remaining_dependencies[dependent_id.0 as usize] -= 1;
+                    }
+                }
+            }
+
+            // A foreign key cycle: nothing is placeable, but tables remain. Break the cycle by
+            // placing the remaining tables in their original order.
+            if !placed_any {
+                for table_index in 0..table_count {
+                    if !placed[table_index] {
+                        placed[table_index] = true;
+                        new_order.push(table_index as u32);
+                    }
+                }
+            }
+        }
+
+        let mut old_id_to_new_id = vec![0u32; table_count];
+        for (new_index, &old_index) in new_order.iter().enumerate() {
+            old_id_to_new_id[old_index as usize] = new_index as u32;
+        }
+
+        let mut reordered_tables: Vec<Option<Table>> = self.tables.drain(..).map(Some).collect();
+        self.tables = new_order
+            .iter()
+            .map(|&old_index| reordered_tables[old_index as usize].take().unwrap())
+            .collect();
+
+        for (table_id, _) in self.columns.iter_mut() {
+            table_id.0 = old_id_to_new_id[table_id.0 as usize];
+        }
+
+        for (table_id, fk) in self.foreign_keys.iter_mut() {
+            table_id.0 = old_id_to_new_id[table_id.0 as usize];
+            fk.referenced_table.0 = old_id_to_new_id[fk.referenced_table.0 as usize];
+        }
+
+        for (table_id, _) in self.check_constraints.iter_mut() {
+            table_id.0 = old_id_to_new_id[table_id.0 as usize];
+        }
+
+        // The walkers look these up by binary search on the table id, so restore that order. The
+        // sorts are stable, keeping each table's entries in their previous relative order.
+        self.columns.sort_by_key(|(table_id, _)| *table_id);
+        self.foreign_keys.sort_by_key(|(table_id, _)| *table_id);
+        self.check_constraints.sort_by_key(|(table_id, _)| *table_id);
+    }
 }
 
 /// A table found in a schema.
@@ -225,6 +555,11 @@ pub struct Table {
     pub indices: Vec<Index>,
     /// The table's primary key, if there is one.
     pub primary_key: Option<PrimaryKey>,
+    /// The comment on the table, if the describer captured one (e.g. `COMMENT ON TABLE`).
+    pub comment: Option<String>,
+    /// An approximate row count for the table, if [`DescribeOptions::include_row_count_estimates`]
+    /// was set and the describer supports it.
+    pub row_count_estimate: Option<i64>,
 }
 
 /// The type of an index.
@@ -281,6 +616,9 @@ pub struct IndexColumn {
     pub name: String,
     pub sort_order: Option<SQLSortOrder>,
     pub length: Option<u32>,
+    /// The expression text (e.g. `lower(email)`), if this entry in the index is an expression
+    /// rather than a plain column. When this is `Some`, `name` is empty.
+    pub expression: Option<String>,
 }
 
 impl IndexColumn {
@@ -309,6 +647,10 @@ pub struct Index {
     pub columns: Vec<IndexColumn>,
     /// Type of index.
     pub tpe: IndexType,
+    /// True if the index was created automatically by the database to back a constraint (e.g. a
+    /// SQLite `sqlite_autoindex_*` index for a `UNIQUE` column) rather than requested explicitly
+    /// by the user.
+    pub is_autogenerated: bool,
 }
 
 /// A stored procedure (like, the function inside your database).
@@ -393,6 +735,16 @@ pub struct Column {
     pub default: Option<DefaultValue>,
     /// Is the column auto-incrementing?
     pub auto_increment: bool,
+    /// Is the column a true identity column (e.g. Postgres `GENERATED ... AS IDENTITY`), as
+    /// opposed to being driven by a sequence default (e.g. `serial`)? Only ever set by
+    /// describers that can tell the two apart; other describers leave this `false`.
+    pub is_identity: bool,
+    /// The comment on the column, if the describer captured one (e.g. `COMMENT ON COLUMN`).
+    pub comment: Option<String>,
+    /// The generation expression of a computed column (e.g. CockroachDB's
+    /// `GENERATED ALWAYS AS (...) STORED`), if the describer captured one. Only ever set by
+    /// describers that support computed columns; other describers leave this `None`.
+    pub generated: Option<String>,
 }
 
 impl Column {
@@ -460,6 +812,8 @@ pub enum ColumnTypeFamily {
     Uuid,
     ///Enum
     Enum(String),
+    /// MySQL `SET`, carrying the list of allowed member values.
+    Set(Vec<String>),
     /// Unsupported
     Unsupported(String),
 }
@@ -472,6 +826,13 @@ impl ColumnTypeFamily {
         }
     }
 
+    pub fn as_set(&self) -> Option<&[String]> {
+        match self {
+            ColumnTypeFamily::Set(values) => Some(values),
+            _ => None,
+        }
+    }
+
     pub fn is_bigint(&self) -> bool {
         matches!(self, ColumnTypeFamily::BigInt)
     }
@@ -496,6 +857,10 @@ impl ColumnTypeFamily {
         matches!(self, ColumnTypeFamily::Json)
     }
 
+    pub fn is_set(&self) -> bool {
+        matches!(self, ColumnTypeFamily::Set(_))
+    }
+
     pub fn is_string(&self) -> bool {
         matches!(self, ColumnTypeFamily::String)
     }
@@ -530,6 +895,8 @@ impl ColumnArity {
 }
 
 /// Foreign key action types (for ON DELETE|ON UPDATE).
+#[enumflags2::bitflags]
+#[repr(u8)]
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
 pub enum ForeignKeyAction {
     /// Produce an error indicating that the deletion or update would create a foreign key
@@ -571,6 +938,9 @@ pub struct ForeignKey {
     pub on_delete_action: ForeignKeyAction,
     /// Action on update.
     pub on_update_action: ForeignKeyAction,
+    /// Whether the foreign key is enforced on pre-existing rows. Always `true` outside of
+    /// Postgres, where a constraint can be added `NOT VALID` and validated later.
+    pub validated: bool,
 }
 
 impl PartialEq for ForeignKey {
@@ -581,12 +951,24 @@ impl PartialEq for ForeignKey {
     }
 }
 
+/// A CHECK constraint, table-level or on a single column.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct CheckConstraint {
+    /// The database name of the constraint.
+    pub name: String,
+    /// The constraint's expression, normalized to the boolean expression itself, without the
+    /// surrounding `CHECK (...)` the database wraps it in.
+    pub expression: String,
+}
+
 /// A SQL enum.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct Enum {
     /// Enum name.
     pub name: String,
-    /// Possible enum values.
+    /// Possible enum values, as raw database labels, unsanitized. Callers that need valid Prisma
+    /// identifiers (e.g. the introspection engine) are responsible for sanitizing them and
+    /// recording the raw label as the `@map`ped database name.
     pub values: Vec<String>,
 }
 
@@ -597,6 +979,27 @@ pub struct View {
     pub name: String,
     /// The SQL definition of the view.
     pub definition: Option<String>,
+    /// True if this is a materialized view (Postgres only, always false on other connectors).
+    pub is_materialized: bool,
+}
+
+/// A non-fatal issue encountered while describing a schema, surfaced to callers rather than
+/// hidden behind a log line so they can decide what to do about it.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct DescriberWarning {
+    /// A description of the issue.
+    pub message: String,
+}
+
+/// The raw catalog definition of an object the describer could not fully model (e.g. a column
+/// type mapped to [`ColumnTypeFamily::Unsupported`]), captured for diagnostics when
+/// [`DescribeOptions::capture_raw_unsupported`] is set.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct RawObject {
+    /// What kind of object this is, e.g. `"column"`.
+    pub kind: String,
+    /// The raw type or definition string, as reported by the database catalog.
+    pub raw_definition: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
@@ -722,6 +1125,26 @@ struct Precision {
 mod tests {
     use super::*;
 
+    #[test]
+    fn schemas_match_is_case_sensitive_by_default() {
+        let options = DescribeOptions::default();
+
+        assert!(schemas_match("public", "public", &options));
+        assert!(!schemas_match("Public", "public", &options));
+    }
+
+    #[test]
+    fn schemas_match_ignores_ascii_case_when_the_option_is_set() {
+        let options = DescribeOptions {
+            schema_names_case_insensitive: true,
+            ..Default::default()
+        };
+
+        assert!(schemas_match("Public", "public", &options));
+        assert!(schemas_match("dbo", "DBO", &options));
+        assert!(!schemas_match("dbo", "sales", &options));
+    }
+
     #[test]
     fn unquoting_works() {
         let quoted_str = "'abc $$ def'".to_string();
@@ -730,4 +1153,194 @@ mod tests {
 
         assert_eq!(unquote_string("heh "), "heh ");
     }
+
+    fn column(name: &str) -> Column {
+        Column {
+            name: name.to_owned(),
+            tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+            default: None,
+            auto_increment: false,
+            is_identity: false,
+            comment: None,
+            generated: None,
+        }
+    }
+
+    #[test]
+    fn error_on_unsupported_columns_is_ok_when_every_column_is_modeled() {
+        let mut schema = SqlSchema::default();
+        let posts_table = schema.push_table("posts".to_owned());
+        schema.push_column(posts_table, column("id"));
+
+        assert!(schema.error_on_unsupported_columns().is_ok());
+    }
+
+    #[test]
+    fn error_on_unsupported_columns_lists_every_offending_column() {
+        let mut schema = SqlSchema::default();
+
+        let posts_table = schema.push_table("posts".to_owned());
+        schema.push_column(posts_table, column("id"));
+        schema.push_column(posts_table, {
+            let mut col = column("search_vector");
+            col.tpe = ColumnType {
+                full_data_type: "tsvector".to_owned(),
+                family: ColumnTypeFamily::Unsupported("tsvector".to_owned()),
+                arity: ColumnArity::Nullable,
+                native_type: None,
+            };
+            col
+        });
+
+        let users_table = schema.push_table("users".to_owned());
+        schema.push_column(users_table, column("id"));
+        schema.push_column(users_table, {
+            let mut col = column("location");
+            col.tpe = ColumnType {
+                full_data_type: "point".to_owned(),
+                family: ColumnTypeFamily::Unsupported("point".to_owned()),
+                arity: ColumnArity::Required,
+                native_type: None,
+            };
+            col
+        });
+
+        let err = schema.error_on_unsupported_columns().unwrap_err();
+
+        let objects = match err.into_kind() {
+            DescriberErrorKind::UnsupportedObjectsFound { objects } => objects,
+            other => panic!("expected UnsupportedObjectsFound, got {:?}", other),
+        };
+
+        assert_eq!(
+            objects,
+            vec![
+                UnsupportedObjectDescription {
+                    table: "posts".to_owned(),
+                    column: "search_vector".to_owned(),
+                    full_data_type: "tsvector".to_owned(),
+                },
+                UnsupportedObjectDescription {
+                    table: "users".to_owned(),
+                    column: "location".to_owned(),
+                    full_data_type: "point".to_owned(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_keeps_ids_consistent_and_prefixes_names() {
+        let mut shard_one = SqlSchema::default();
+        let users_table = shard_one.push_table("users".to_owned());
+        shard_one.push_column(users_table, column("id"));
+
+        let mut shard_two = SqlSchema::default();
+        let posts_table = shard_two.push_table("posts".to_owned());
+        shard_two.push_column(posts_table, column("id"));
+        shard_two.push_column(posts_table, {
+            let mut col = column("author_id");
+            col.tpe.family = ColumnTypeFamily::Enum("role".to_owned());
+            col
+        });
+        shard_two.enums.push(Enum {
+            name: "role".to_owned(),
+            values: vec!["ADMIN".to_owned(), "USER".to_owned()],
+        });
+        shard_two.foreign_keys.push((
+            posts_table,
+            ForeignKey {
+                constraint_name: Some("posts_users_fk".to_owned()),
+                columns: vec!["author_id".to_owned()],
+                referenced_table: posts_table,
+                referenced_columns: vec!["id".to_owned()],
+                on_delete_action: ForeignKeyAction::NoAction,
+                on_update_action: ForeignKeyAction::NoAction,
+                validated: true,
+            },
+        ));
+
+        shard_one.merge(shard_two, "shard_two");
+
+        // No id collisions: two tables, both resolvable through the walker API.
+        let table_names: Vec<&str> = shard_one.table_walkers().map(|t| t.name()).collect();
+        assert_eq!(table_names, vec!["users", "shard_two.posts"]);
+
+        let merged_posts = shard_one.table_bang("shard_two.posts").0;
+
+        // The foreign key was shifted to point at the merged table's new id, not the old one.
+        let fk = shard_one.walk_foreign_keys().next().unwrap();
+        assert_eq!(fk.table().id, merged_posts);
+        assert_eq!(fk.referenced_table().id, merged_posts);
+
+        // The enum was renamed, and the column referencing it was updated to match.
+        assert_eq!(shard_one.get_enum("shard_two.role").unwrap().values, vec!["ADMIN", "USER"]);
+        let author_id = shard_one
+            .table_walkers()
+            .find(|t| t.name() == "shard_two.posts")
+            .unwrap()
+            .column("author_id")
+            .unwrap();
+        assert_eq!(
+            author_id.column_type_family_as_enum().unwrap().name,
+            "shard_two.role"
+        );
+    }
+
+    #[test]
+    fn column_walker_finds_existing_columns_and_returns_none_for_missing_ones() {
+        let mut schema = SqlSchema::default();
+        let users_table = schema.push_table("users".to_owned());
+        schema.push_column(users_table, column("id"));
+
+        assert_eq!(schema.column_walker("users", "id").unwrap().name(), "id");
+        assert!(schema.column_walker("users", "nonexistent").is_none());
+        assert!(schema.column_walker("nonexistent", "id").is_none());
+    }
+
+    #[test]
+    fn retain_tables_drops_enums_no_longer_referenced_by_a_kept_table() {
+        let mut schema = SqlSchema::default();
+
+        let users_table = schema.push_table("users".to_owned());
+        schema.push_column(users_table, column("id"));
+
+        let posts_table = schema.push_table("posts".to_owned());
+        schema.push_column(posts_table, column("id"));
+        schema.push_column(posts_table, {
+            let mut col = column("status");
+            col.tpe.family = ColumnTypeFamily::Enum("PostStatus".to_owned());
+            col
+        });
+        schema.enums.push(Enum {
+            name: "PostStatus".to_owned(),
+            values: vec!["DRAFT".to_owned(), "PUBLISHED".to_owned()],
+        });
+
+        // Dropping `posts` should also drop the enum that only `posts` referenced.
+        schema.retain_tables(|name| name == "users");
+
+        assert_eq!(schema.table_walkers().map(|t| t.name().to_owned()).collect::<Vec<_>>(), vec!["users"]);
+        assert!(schema.get_enum("PostStatus").is_none());
+    }
+
+    #[test]
+    fn retain_tables_keeps_enums_still_referenced_by_a_kept_table() {
+        let mut schema = SqlSchema::default();
+
+        let users_table = schema.push_table("users".to_owned());
+        schema.push_column(users_table, {
+            let mut col = column("role");
+            col.tpe.family = ColumnTypeFamily::Enum("Role".to_owned());
+            col
+        });
+        schema.enums.push(Enum {
+            name: "Role".to_owned(),
+            values: vec!["ADMIN".to_owned(), "USER".to_owned()],
+        });
+
+        schema.retain_tables(|name| name == "users");
+
+        assert!(schema.get_enum("Role").is_some());
+    }
 }
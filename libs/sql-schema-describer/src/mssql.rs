@@ -1,7 +1,8 @@
 use crate::{
-    getters::Getter, ids::*, parsers::Parser, Column, ColumnArity, ColumnType, ColumnTypeFamily, DefaultValue,
-    DescriberError, DescriberErrorKind, DescriberResult, ForeignKey, ForeignKeyAction, Index, IndexColumn, IndexType,
-    PrimaryKey, PrimaryKeyColumn, Procedure, SQLSortOrder, SqlMetadata, SqlSchema, UserDefinedType, View,
+    getters::Getter, ids::*, parsers::Parser, schemas_match, CheckConstraint, Column, ColumnArity, ColumnType,
+    ColumnTypeFamily, DefaultValue, DescribeOptions, DescriberError, DescriberErrorKind, DescriberResult, ForeignKey,
+    ForeignKeyAction, Index, IndexColumn, IndexType, PrimaryKey, PrimaryKeyColumn, Procedure, SQLSortOrder,
+    SqlMetadata, SqlSchema, UserDefinedType, View,
 };
 use indexmap::IndexMap;
 use indoc::indoc;
@@ -13,41 +14,6 @@ use regex::Regex;
 use std::{any::type_name, borrow::Cow, collections::BTreeMap, convert::TryInto};
 use tracing::{debug, trace};
 
-/// Matches a default value in the schema, that is not a string.
-///
-/// Examples:
-///
-/// ```ignore
-/// ((1))
-/// ```
-///
-/// ```ignore
-/// ((1.123))
-/// ```
-///
-/// ```ignore
-/// ((true))
-/// ```
-static DEFAULT_NON_STRING: Lazy<Regex> = Lazy::new(|| Regex::new(r"\(\((.*)\)\)").unwrap());
-
-/// Matches a default value in the schema, that is a string.
-///
-/// Example:
-///
-/// ```ignore
-/// ('this is a test')
-/// ```
-static DEFAULT_STRING: Lazy<Regex> = Lazy::new(|| Regex::new(r"\('([\S\s]*)'\)").unwrap());
-
-/// Matches a database-generated value in the schema.
-///
-/// Example:
-///
-/// ```ignore
-/// (current_timestamp)
-/// ```
-static DEFAULT_DB_GEN: Lazy<Regex> = Lazy::new(|| Regex::new(r"\((.*)\)").unwrap());
-
 /// Matches a shared default constraint (which we will skip).
 ///
 /// example:
@@ -57,19 +23,85 @@ static DEFAULT_DB_GEN: Lazy<Regex> = Lazy::new(|| Regex::new(r"\((.*)\)").unwrap
 /// ```
 static DEFAULT_SHARED_CONSTRAINT: Lazy<Regex> = Lazy::new(|| Regex::new(r"^CREATE DEFAULT (.*)").unwrap());
 
+/// Whether `s` is wrapped in a single pair of parentheses that spans its entire length, e.g.
+/// `(1)` or `(getdate())`, as opposed to e.g. `(1)+(2)` where the leading `(` closes before the
+/// end of the string.
+fn is_fully_parenthesized(s: &str) -> bool {
+    if s.len() < 2 || !s.starts_with('(') || !s.ends_with(')') {
+        return false;
+    }
+
+    let mut depth = 0i32;
+
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return i == s.len() - 1;
+                }
+            }
+            _ => (),
+        }
+    }
+
+    false
+}
+
+/// MSSQL wraps default expressions in parentheses, sometimes more than once (e.g. `((0))`).
+/// Strips all of them, leaving the inner expression bare.
+fn strip_outer_parens(mut s: &str) -> &str {
+    while is_fully_parenthesized(s) {
+        s = &s[1..s.len() - 1];
+    }
+    s
+}
+
+/// A negative numeric default is rendered as unary minus applied to a parenthesized literal
+/// (`-(1)`) rather than a parenthesized negative literal (`(-1)`). Collapse it into a plain `-1`
+/// so the numeric parsers can pick it up.
+fn normalize_unary_minus(s: &str) -> Cow<'_, str> {
+    match s.strip_prefix('-') {
+        Some(rest) if is_fully_parenthesized(rest) => Cow::Owned(format!("-{}", strip_outer_parens(rest))),
+        _ => Cow::Borrowed(s),
+    }
+}
+
+/// If `s` is a (optionally `N`-prefixed) single-quoted string literal, unescape it and return its
+/// contents.
+fn parse_mssql_string_literal(s: &str) -> Option<String> {
+    let s = s.strip_prefix('N').or_else(|| s.strip_prefix('n')).unwrap_or(s);
+    let inner = s.strip_prefix('\'')?.strip_suffix('\'')?;
+
+    Some(inner.replace("''", "'"))
+}
+
 pub struct SqlSchemaDescriber<'a> {
     conn: &'a dyn Queryable,
 }
 
+/// The `IDENTITY(seed, increment)` parameters of an identity column, plus the identity value
+/// that was current at the time the schema was described.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Identity {
+    pub seed: i64,
+    pub increment: i64,
+    pub current_value: Option<i64>,
+}
+
 #[derive(Default)]
 pub struct MssqlSchemaExt {
     pub clustered_indexes: Vec<IndexId>,
     pub nonclustered_primary_keys: Vec<TableId>,
+    /// Identity parameters of the columns that have them, sorted by `ColumnId`.
+    pub identities: Vec<(ColumnId, Identity)>,
 }
 
 const DEFAULT_REF: &MssqlSchemaExt = &MssqlSchemaExt {
     clustered_indexes: Vec::new(),
     nonclustered_primary_keys: Vec::new(),
+    identities: Vec::new(),
 };
 
 impl<'a> Default for &'a MssqlSchemaExt {
@@ -86,6 +118,13 @@ impl MssqlSchemaExt {
     pub fn index_is_clustered(&self, index_id: IndexId) -> bool {
         self.clustered_indexes.binary_search(&index_id).is_ok()
     }
+
+    pub fn get_identity(&self, column_id: ColumnId) -> Option<&Identity> {
+        self.identities
+            .binary_search_by_key(&column_id, |(id, _)| *id)
+            .ok()
+            .map(|idx| &self.identities[idx].1)
+    }
 }
 
 impl std::fmt::Debug for SqlSchemaDescriber<'_> {
@@ -111,7 +150,7 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber<'_> {
         })
     }
 
-    async fn describe(&self, schema: &str) -> DescriberResult<SqlSchema> {
+    async fn describe_with_options(&self, schema: &str, options: &DescribeOptions) -> DescriberResult<SqlSchema> {
         let mut sql_schema = SqlSchema::default();
         let mut mssql_ext = MssqlSchemaExt::default();
 
@@ -120,12 +159,16 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber<'_> {
         sql_schema.columns = self.get_all_columns(&table_names, schema).await?;
         self.get_all_indices(schema, &mut mssql_ext, &table_names, &mut sql_schema)
             .await?;
-        sql_schema.foreign_keys = self.get_foreign_keys(schema, &table_names).await?;
+        self.get_identities(schema, &mut mssql_ext, &sql_schema).await?;
+        sql_schema.foreign_keys = self.get_foreign_keys(schema, &table_names, options).await?;
+        sql_schema.check_constraints = self.get_check_constraints(schema, &table_names).await?;
 
         // Sort the vectors we will use for binary search.
         sql_schema.foreign_keys.sort_by_key(|(table_id, _)| *table_id);
+        sql_schema.check_constraints.sort_by_key(|(table_id, _)| *table_id);
         mssql_ext.clustered_indexes.sort();
         mssql_ext.nonclustered_primary_keys.sort();
+        mssql_ext.identities.sort_by_key(|(id, _)| *id);
 
         sql_schema.views = self.get_views(schema).await?;
         sql_schema.procedures = self.get_procedures(schema).await?;
@@ -134,6 +177,10 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber<'_> {
             data: Some(Box::new(mssql_ext)),
         };
 
+        if options.fail_on_unsupported {
+            sql_schema.error_on_unsupported_columns()?;
+        }
+
         Ok(sql_schema)
     }
 
@@ -315,15 +362,8 @@ impl<'a> SqlSchemaDescriber<'a> {
                     None => None,
                     Some(x) if x == "(NULL)" => None,
                     Some(x) if DEFAULT_SHARED_CONSTRAINT.is_match(&x) => None,
-                    Some(default_string) => {
-                        let default_string = DEFAULT_NON_STRING
-                            .captures_iter(&default_string)
-                            .next()
-                            .or_else(|| DEFAULT_STRING.captures_iter(&default_string).next())
-                            .or_else(|| DEFAULT_DB_GEN.captures_iter(&default_string).next())
-                            .map(|cap| cap[1].to_string())
-                            .ok_or_else(|| format!("Couldn't parse default value: `{}`", default_string))
-                            .unwrap();
+                    Some(raw_default) => {
+                        let default_string = normalize_unary_minus(strip_outer_parens(&raw_default)).into_owned();
 
                         let mut default = match tpe.family {
                             ColumnTypeFamily::Int => match Self::parse_int(&default_string) {
@@ -347,10 +387,13 @@ impl<'a> SqlSchemaDescriber<'a> {
                                 Some(PrismaValue::Int(0)) => DefaultValue::value(PrismaValue::Boolean(false)),
                                 _ => DefaultValue::db_generated(default_string),
                             },
-                            ColumnTypeFamily::String => DefaultValue::value(default_string.replace("''", "'")),
+                            ColumnTypeFamily::String => match parse_mssql_string_literal(&default_string) {
+                                Some(s) => DefaultValue::value(s),
+                                None => DefaultValue::db_generated(default_string),
+                            },
                             //todo check other now() definitions
-                            ColumnTypeFamily::DateTime => match default_string.as_str() {
-                                "getdate()" => DefaultValue::now(),
+                            ColumnTypeFamily::DateTime => match default_string.to_lowercase().as_str() {
+                                "getdate()" | "current_timestamp" | "sysdatetimeoffset()" => DefaultValue::now(),
                                 _ => DefaultValue::db_generated(default_string),
                             },
                             ColumnTypeFamily::Binary => DefaultValue::db_generated(default_string),
@@ -358,6 +401,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                             ColumnTypeFamily::Uuid => DefaultValue::db_generated(default_string),
                             ColumnTypeFamily::Unsupported(_) => DefaultValue::db_generated(default_string),
                             ColumnTypeFamily::Enum(_) => unreachable!("No enums in MSSQL"),
+                            ColumnTypeFamily::Set(_) => unreachable!("No sets in MSSQL"),
                         };
 
                         if let Some(name) = col.get_string("constraint_name") {
@@ -376,6 +420,9 @@ impl<'a> SqlSchemaDescriber<'a> {
                     tpe,
                     default,
                     auto_increment,
+                    is_identity: false,
+                    comment: None,
+                    generated: None,
                 },
             ));
         }
@@ -385,6 +432,62 @@ impl<'a> SqlSchemaDescriber<'a> {
         Ok(columns)
     }
 
+    /// Reads the `IDENTITY(seed, increment)` parameters and the identity value that is current
+    /// at description time, for every identity column. Must run after `sql_schema.columns` has
+    /// been populated, since it resolves columns by name through `find_column`.
+    async fn get_identities(
+        &self,
+        schema: &str,
+        mssql_ext: &mut MssqlSchemaExt,
+        sql_schema: &SqlSchema,
+    ) -> DescriberResult<()> {
+        let sql = indoc! {r#"
+            SELECT
+                t.name          AS table_name,
+                c.name          AS column_name,
+                ic.seed_value   AS seed_value,
+                ic.increment_value AS increment_value,
+                ic.last_value   AS last_value
+            FROM sys.identity_columns ic
+            INNER JOIN sys.tables t ON ic.object_id = t.object_id
+            INNER JOIN sys.columns c ON ic.object_id = c.object_id AND ic.column_id = c.column_id
+            WHERE SCHEMA_NAME(t.schema_id) = @P1
+                AND t.is_ms_shipped = 0
+        "#};
+
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+
+        for row in rows {
+            let table_name = row.get_expect_string("table_name");
+            let column_name = row.get_expect_string("column_name");
+
+            let table_id = match sql_schema.table_walkers().find(|t| t.name() == table_name) {
+                Some(table) => table.id,
+                None => continue,
+            };
+
+            let column_id = match sql_schema.find_column(table_id, &column_name) {
+                Some((column_id, _)) => column_id,
+                None => continue,
+            };
+
+            let seed = row.get_i64("seed_value").unwrap_or(1);
+            let increment = row.get_i64("increment_value").unwrap_or(1);
+            let current_value = row.get_i64("last_value");
+
+            mssql_ext.identities.push((
+                column_id,
+                Identity {
+                    seed,
+                    increment,
+                    current_value,
+                },
+            ));
+        }
+
+        Ok(())
+    }
+
     async fn get_all_indices(
         &self,
         schema: &str,
@@ -505,6 +608,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                         true => IndexType::Unique,
                         false => IndexType::Normal,
                     },
+                    is_autogenerated: false,
                 });
             }
         }
@@ -527,6 +631,7 @@ impl<'a> SqlSchemaDescriber<'a> {
             views.push(View {
                 name: row.get_expect_string("view_name"),
                 definition: row.get_string("view_sql"),
+                is_materialized: false,
             })
         }
 
@@ -597,6 +702,7 @@ impl<'a> SqlSchemaDescriber<'a> {
         &self,
         schema: &str,
         table_ids: &IndexMap<String, TableId>,
+        options: &DescribeOptions,
     ) -> DescriberResult<Vec<(TableId, ForeignKey)>> {
         // Foreign keys covering multiple columns will return multiple rows, which we need to
         // merge.
@@ -649,7 +755,7 @@ impl<'a> SqlSchemaDescriber<'a> {
             let referenced_column = row.get_expect_string("referenced_column_name");
             let referenced_table = row.get_expect_string("referenced_table_name");
 
-            if schema != referenced_schema_name {
+            if !schemas_match(schema, &referenced_schema_name, options) {
                 return Err(DescriberError::from(DescriberErrorKind::CrossSchemaReference {
                     from: format!("{}.{}", schema, table_name),
                     to: format!("{}.{}", referenced_schema_name, referenced_table),
@@ -689,6 +795,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                     referenced_columns: Vec::new(),
                     on_delete_action,
                     on_update_action,
+                    validated: true,
                 });
 
             let pos = ord_pos as usize - 1;
@@ -716,6 +823,44 @@ impl<'a> SqlSchemaDescriber<'a> {
         Ok(fks)
     }
 
+    async fn get_check_constraints(
+        &self,
+        schema: &str,
+        table_ids: &IndexMap<String, TableId>,
+    ) -> DescriberResult<Vec<(TableId, CheckConstraint)>> {
+        let sql = indoc! {r#"
+            SELECT t.name         AS table_name,
+                   cc.name        AS constraint_name,
+                   cc.definition  AS definition
+            FROM sys.check_constraints cc
+                     INNER JOIN sys.tables t ON t.object_id = cc.parent_object_id
+            WHERE SCHEMA_NAME(t.schema_id) = @P1
+            ORDER BY t.name, cc.name;
+        "#};
+
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+        let mut check_constraints = Vec::new();
+
+        for row in rows.into_iter() {
+            let table_name = row.get_expect_string("table_name");
+
+            let table_id = if let Some(id) = table_ids.get(&table_name) {
+                *id
+            } else {
+                continue;
+            };
+
+            let check_constraint = CheckConstraint {
+                name: row.get_expect_string("constraint_name"),
+                expression: normalize_check_constraint_expression(&row.get_expect_string("definition")),
+            };
+
+            check_constraints.push((table_id, check_constraint));
+        }
+
+        Ok(check_constraints)
+    }
+
     fn get_column_type(
         &self,
         data_type: &str,
@@ -805,6 +950,14 @@ impl<'a> SqlSchemaDescriber<'a> {
     }
 }
 
+/// `sys.check_constraints.definition` renders a CHECK constraint's expression already wrapped in
+/// a redundant outer pair of parentheses (e.g. `([age]>=(0))`). Strip every such redundant outer
+/// paren pair, so the stored expression doesn't change based on how many layers SQL Server
+/// happens to print.
+fn normalize_check_constraint_expression(definition: &str) -> String {
+    strip_outer_parens(definition.trim()).to_owned()
+}
+
 fn parse_type_parameter(character_maximum_length: Option<i64>) -> Option<MsSqlTypeParameter> {
     match character_maximum_length {
         Some(-1) => Some(MsSqlTypeParameter::Max),
@@ -812,3 +965,69 @@ fn parse_type_parameter(character_maximum_length: Option<i64>) -> Option<MsSqlTy
         None => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        is_fully_parenthesized, normalize_check_constraint_expression, normalize_unary_minus,
+        parse_mssql_string_literal, strip_outer_parens,
+    };
+
+    #[test]
+    fn strip_outer_parens_removes_arbitrarily_many_layers() {
+        assert_eq!(strip_outer_parens("0"), "0");
+        assert_eq!(strip_outer_parens("(0)"), "0");
+        assert_eq!(strip_outer_parens("((0))"), "0");
+        assert_eq!(strip_outer_parens("(((0)))"), "0");
+        assert_eq!(strip_outer_parens("(getdate())"), "getdate()");
+    }
+
+    #[test]
+    fn strip_outer_parens_does_not_touch_unbalanced_siblings() {
+        assert_eq!(strip_outer_parens("(1)+(2)"), "(1)+(2)");
+    }
+
+    #[test]
+    fn is_fully_parenthesized_rejects_sibling_groups() {
+        assert!(is_fully_parenthesized("(1)"));
+        assert!(is_fully_parenthesized("((1))"));
+        assert!(!is_fully_parenthesized("(1)+(2)"));
+        assert!(!is_fully_parenthesized("1"));
+    }
+
+    #[test]
+    fn normalize_unary_minus_collapses_the_function_call_form() {
+        assert_eq!(normalize_unary_minus("-(1)"), "-1");
+        assert_eq!(normalize_unary_minus("-((1))"), "-1");
+        assert_eq!(normalize_unary_minus("-1"), "-1");
+        assert_eq!(normalize_unary_minus("getdate()"), "getdate()");
+    }
+
+    #[test]
+    fn parse_mssql_string_literal_unescapes_doubled_quotes() {
+        assert_eq!(parse_mssql_string_literal("'hello'").as_deref(), Some("hello"));
+        assert_eq!(parse_mssql_string_literal("'it''s'").as_deref(), Some("it's"));
+    }
+
+    #[test]
+    fn normalize_check_constraint_expression_strips_the_redundant_outer_parens() {
+        assert_eq!(normalize_check_constraint_expression("([age]>=(0))"), "[age]>=(0)");
+        assert_eq!(
+            normalize_check_constraint_expression("([price]>(0) AND [discounted_price]>(0))"),
+            "[price]>(0) AND [discounted_price]>(0)"
+        );
+    }
+
+    #[test]
+    fn parse_mssql_string_literal_handles_the_n_prefix() {
+        assert_eq!(parse_mssql_string_literal("N'hello'").as_deref(), Some("hello"));
+        assert_eq!(parse_mssql_string_literal("N'it''s'").as_deref(), Some("it's"));
+        assert_eq!(parse_mssql_string_literal("n'hello'").as_deref(), Some("hello"));
+    }
+
+    #[test]
+    fn parse_mssql_string_literal_rejects_non_string_literals() {
+        assert_eq!(parse_mssql_string_literal("getdate()"), None);
+        assert_eq!(parse_mssql_string_literal("1"), None);
+    }
+}
@@ -43,6 +43,76 @@ pub struct SqlSchemaDescriber<'a> {
     conn: &'a dyn Queryable,
 }
 
+#[derive(Default, Debug)]
+pub struct MysqlSchemaExt {
+    /// The table-level `AUTO_INCREMENT = <n>` starting value, for tables that have one.
+    pub auto_increment_starting_values: Vec<(TableId, i64)>,
+    /// The storage algorithm of each index, from `information_schema.statistics.index_type`.
+    pub index_algorithms: Vec<(IndexId, MysqlIndexAlgorithm)>,
+}
+
+const DEFAULT_REF: &MysqlSchemaExt = &MysqlSchemaExt {
+    auto_increment_starting_values: Vec::new(),
+    index_algorithms: Vec::new(),
+};
+
+impl<'a> Default for &'a MysqlSchemaExt {
+    fn default() -> Self {
+        DEFAULT_REF
+    }
+}
+
+impl MysqlSchemaExt {
+    pub fn get_auto_increment_starting_value(&self, table_id: TableId) -> Option<i64> {
+        let idx = self
+            .auto_increment_starting_values
+            .binary_search_by_key(&table_id, |(id, _)| *id)
+            .ok()?;
+        Some(self.auto_increment_starting_values[idx].1)
+    }
+
+    #[track_caller]
+    pub fn index_algorithm(&self, index_id: IndexId) -> MysqlIndexAlgorithm {
+        match self.index_algorithms.binary_search_by_key(&index_id, |(id, _)| *id) {
+            Ok(i) => self.index_algorithms[i].1,
+            Err(_) => panic!("No index algorithm stored for {:?}", index_id),
+        }
+    }
+}
+
+/// The storage algorithm backing a MySQL/MariaDB index, from
+/// `information_schema.statistics.index_type`.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum MysqlIndexAlgorithm {
+    BTree,
+    Hash,
+    Fulltext,
+    Spatial,
+}
+
+impl Default for MysqlIndexAlgorithm {
+    fn default() -> Self {
+        Self::BTree
+    }
+}
+
+impl AsRef<str> for MysqlIndexAlgorithm {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::BTree => "BTREE",
+            Self::Hash => "HASH",
+            Self::Fulltext => "FULLTEXT",
+            Self::Spatial => "SPATIAL",
+        }
+    }
+}
+
+impl fmt::Display for MysqlIndexAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
 #[async_trait::async_trait]
 impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber<'_> {
     async fn list_databases(&self) -> DescriberResult<Vec<String>> {
@@ -61,7 +131,7 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber<'_> {
     }
 
     #[tracing::instrument(skip(self))]
-    async fn describe(&self, schema: &str) -> DescriberResult<SqlSchema> {
+    async fn describe_with_options(&self, schema: &str, options: &DescribeOptions) -> DescriberResult<SqlSchema> {
         let mut sql_schema = SqlSchema::default();
         let version = self.conn.version().await.ok().flatten();
         let flavour = version
@@ -73,8 +143,11 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber<'_> {
         sql_schema.tables.reserve(table_names.len());
         sql_schema.columns.reserve(table_names.len());
 
+        let mut mysql_ext = MysqlSchemaExt::default();
+        mysql_ext.auto_increment_starting_values = self.get_auto_increment_starting_values(schema, &table_names).await?;
+
         Self::get_all_columns(&table_names, self.conn, schema, &mut sql_schema, &flavour).await?;
-        let mut indexes = self.get_all_indexes(&table_names, schema).await?;
+        let (mut indexes, index_algorithms) = self.get_all_indexes(&table_names, schema).await?;
         Self::get_foreign_keys(self.conn, schema, &table_names, &mut sql_schema).await?;
 
         // In certain cases we cannot query any columns, but we can still list
@@ -95,9 +168,31 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber<'_> {
             self.get_table(table_name, table_id, &mut indexes, &mut sql_schema);
         }
 
+        for (table_idx, table) in sql_schema.tables.iter().enumerate() {
+            let table_id = TableId(table_idx as u32);
+
+            for (index_pos, index) in table.indices.iter().enumerate() {
+                if let Some(algorithm) = index_algorithms.get(&(table_id, index.name.clone())) {
+                    mysql_ext
+                        .index_algorithms
+                        .push((IndexId(table_id, index_pos as u32), *algorithm));
+                }
+            }
+        }
+
         sql_schema.views = self.get_views(schema).await?;
         sql_schema.procedures = self.get_procedures(schema).await?;
 
+        mysql_ext.auto_increment_starting_values.sort_by_key(|(id, _)| *id);
+        mysql_ext.index_algorithms.sort_by_key(|(id, _)| *id);
+        sql_schema.connector_data = crate::connector_data::ConnectorData {
+            data: Some(Box::new(mysql_ext)),
+        };
+
+        if options.fail_on_unsupported {
+            sql_schema.error_on_unsupported_columns()?;
+        }
+
         Ok(sql_schema)
     }
 
@@ -144,6 +239,7 @@ impl<'a> SqlSchemaDescriber<'a> {
             views.push(View {
                 name: row.get_expect_string("view_name"),
                 definition: row.get_string("view_sql"),
+                is_materialized: false,
             })
         }
 
@@ -207,6 +303,36 @@ impl<'a> SqlSchemaDescriber<'a> {
         Ok(map)
     }
 
+    #[tracing::instrument(skip(self, table_ids))]
+    async fn get_auto_increment_starting_values(
+        &self,
+        schema: &str,
+        table_ids: &IndexMap<String, TableId>,
+    ) -> DescriberResult<Vec<(TableId, i64)>> {
+        let sql = r#"
+            SELECT BINARY table_name AS table_name, auto_increment
+            FROM information_schema.TABLES
+            WHERE table_schema = ?
+                AND auto_increment IS NOT NULL
+        "#;
+
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+        let mut values = Vec::new();
+
+        for row in rows.into_iter() {
+            let table_name = row.get_expect_string("table_name");
+
+            if let (Some(table_id), Some(auto_increment)) = (table_ids.get(&table_name), row.get_i64("auto_increment"))
+            {
+                values.push((*table_id, auto_increment));
+            }
+        }
+
+        trace!("Found auto increment starting values: {:?}", values);
+
+        Ok(values)
+    }
+
     #[tracing::instrument(skip(self))]
     async fn get_size(&self, schema: &str) -> DescriberResult<usize> {
         let sql = r#"
@@ -244,6 +370,8 @@ impl<'a> SqlSchemaDescriber<'a> {
             name,
             indices: indices.into_iter().map(|(_k, v)| v).collect(),
             primary_key,
+            comment: None,
+            row_count_estimate: None,
         };
     }
 
@@ -279,7 +407,7 @@ impl<'a> SqlSchemaDescriber<'a> {
 
         for col in rows {
             trace!("Got column: {:?}", col);
-            let table_name = col.get_expect_string("table_name");
+            let table_name = col.get_result_string("table_name", "MySQL get_columns")?;
             let table_id = if let Some(id) = table_ids.get(table_name.as_str()) {
                 *id
             } else {
@@ -332,11 +460,19 @@ impl<'a> SqlSchemaDescriber<'a> {
                 sql_schema.enums.push(enm);
             }
 
+            // TEXT/BLOB/JSON columns cannot carry a `DEFAULT` at all on MySQL/MariaDB, but some
+            // versions still surface a spurious value for them in `information_schema.columns`.
+            let cannot_have_default = matches!(
+                data_type.as_str(),
+                "text" | "tinytext" | "mediumtext" | "longtext" | "blob" | "tinyblob" | "mediumblob" | "longblob" | "json"
+            );
+
             let default = match default_value {
                 None => None,
+                Some(_) if cannot_have_default => None,
                 Some(param_value) => match param_value.to_string() {
                     None => None,
-                    Some(x) if x == "NULL" => None,
+                    Some(x) if x.eq_ignore_ascii_case("NULL") => None,
                     Some(default_string) => {
                         let default_generated = matches!(extra.as_str(), "default_generated");
                         let maria_db = matches!(flavour, Flavour::MariaDb);
@@ -415,7 +551,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                                     )))
                                 }
                             }
-                            ColumnTypeFamily::Unsupported(_) => match default_expression {
+                            ColumnTypeFamily::Set(_) | ColumnTypeFamily::Unsupported(_) => match default_expression {
                                 true => Self::dbgenerated_expression(&default_string),
                                 false => DefaultValue::db_generated(default_string),
                             },
@@ -429,6 +565,9 @@ impl<'a> SqlSchemaDescriber<'a> {
                 tpe,
                 default,
                 auto_increment,
+                is_identity: false,
+                comment: None,
+                generated: None,
             };
 
             sql_schema.columns.push((table_id, col));
@@ -455,9 +594,13 @@ impl<'a> SqlSchemaDescriber<'a> {
         &self,
         table_ids: &IndexMap<String, TableId>,
         schema_name: &str,
-    ) -> DescriberResult<BTreeMap<TableId, (BTreeMap<String, Index>, Option<PrimaryKey>)>> {
+    ) -> DescriberResult<(
+        BTreeMap<TableId, (BTreeMap<String, Index>, Option<PrimaryKey>)>,
+        BTreeMap<(TableId, String), MysqlIndexAlgorithm>,
+    )> {
         let mut map = BTreeMap::<TableId, _>::new();
         let mut indexes_with_expressions: HashSet<(TableId, String)> = HashSet::new();
+        let mut algorithms: BTreeMap<(TableId, String), MysqlIndexAlgorithm> = BTreeMap::new();
 
         // We alias all the columns because MySQL column names are case-insensitive in queries, but the
         // information schema column names became upper-case in MySQL 8, causing the code fetching
@@ -551,18 +694,29 @@ impl<'a> SqlSchemaDescriber<'a> {
                         column.length = length;
                         column.sort_order = sort_order;
 
-                        let tpe = match (is_unique, row.get_string("index_type").as_deref()) {
+                        let index_type = row.get_string("index_type");
+
+                        let tpe = match (is_unique, index_type.as_deref()) {
                             (true, _) => IndexType::Unique,
                             (_, Some("FULLTEXT")) => IndexType::Fulltext,
                             _ => IndexType::Normal,
                         };
 
+                        let algorithm = match index_type.as_deref() {
+                            Some("FULLTEXT") => MysqlIndexAlgorithm::Fulltext,
+                            Some("SPATIAL") => MysqlIndexAlgorithm::Spatial,
+                            Some("HASH") => MysqlIndexAlgorithm::Hash,
+                            _ => MysqlIndexAlgorithm::BTree,
+                        };
+                        algorithms.insert((*table_id, index_name.clone()), algorithm);
+
                         indexes_map.insert(
                             index_name.clone(),
                             Index {
                                 name: index_name,
                                 columns: vec![column],
                                 tpe,
+                                is_autogenerated: false,
                             },
                         );
                     }
@@ -581,7 +735,7 @@ impl<'a> SqlSchemaDescriber<'a> {
             }
         }
 
-        Ok(map)
+        Ok((map, algorithms))
     }
 
     async fn get_foreign_keys(
@@ -670,6 +824,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                     referenced_columns: Vec::new(),
                     on_delete_action,
                     on_update_action,
+                    validated: true,
                 });
 
             let pos = ord_pos as usize - 1;
@@ -762,7 +917,7 @@ impl<'a> SqlSchemaDescriber<'a> {
             "longtext" => (ColumnTypeFamily::String, Some(MySqlType::LongText)),
             "enum" => (ColumnTypeFamily::Enum(format!("{}_{}", table, column_name)), None),
             "json" => (ColumnTypeFamily::Json, Some(MySqlType::Json)),
-            "set" => (ColumnTypeFamily::String, None),
+            "set" => (ColumnTypeFamily::Set(Self::extract_set_values(&full_data_type)), None),
             //temporal
             "date" => (ColumnTypeFamily::DateTime, Some(MySqlType::Date)),
             "time" => (
@@ -842,6 +997,13 @@ impl<'a> SqlSchemaDescriber<'a> {
         vals.split(',').map(unquote_string).collect()
     }
 
+    // `full_data_type` looks like `set('a','b')`.
+    fn extract_set_values(full_data_type: &&str) -> Vec<String> {
+        let len = &full_data_type.len() - 1;
+        let vals = &full_data_type[4..len];
+        vals.split(',').map(unquote_string).collect()
+    }
+
     // See https://dev.mysql.com/doc/refman/8.0/en/string-literals.html
     //
     // In addition, MariaDB will return string literals with the quotes and extra backslashes around
@@ -873,3 +1035,17 @@ impl<'a> SqlSchemaDescriber<'a> {
         MYSQL_CURRENT_TIMESTAMP_RE.is_match(default_str)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::SqlSchemaDescriber;
+
+    #[test]
+    fn default_is_current_timestamp_is_case_insensitive() {
+        assert!(SqlSchemaDescriber::default_is_current_timestamp("current_timestamp"));
+        assert!(SqlSchemaDescriber::default_is_current_timestamp("CURRENT_TIMESTAMP"));
+        assert!(SqlSchemaDescriber::default_is_current_timestamp("Current_Timestamp"));
+        assert!(SqlSchemaDescriber::default_is_current_timestamp("CURRENT_TIMESTAMP(3)"));
+        assert!(!SqlSchemaDescriber::default_is_current_timestamp("now()"));
+    }
+}
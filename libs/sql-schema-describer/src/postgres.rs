@@ -52,6 +52,33 @@ impl Default for Sequence {
     }
 }
 
+/// A custom PostgreSQL collation (`CREATE COLLATION`), as opposed to a collation merely applied
+/// to a column or index.
+/// https://www.postgresql.org/docs/current/sql-createcollation.html
+#[derive(Debug, PartialEq)]
+pub struct Collation {
+    /// The collation's name.
+    pub name: String,
+    /// The `LC_COLLATE` locale setting the collation was created with. `None` for ICU-provider
+    /// collations, which are configured through `locale` instead.
+    pub lc_collate: Option<String>,
+    /// The `LC_CTYPE` locale setting the collation was created with. `None` for ICU-provider
+    /// collations, which are configured through `locale` instead.
+    pub lc_ctype: Option<String>,
+}
+
+/// An installed PostgreSQL extension (`CREATE EXTENSION`).
+/// https://www.postgresql.org/docs/current/catalog-pg-extension.html
+#[derive(Debug, PartialEq)]
+pub struct Extension {
+    /// The extension's name.
+    pub name: String,
+    /// The extension's installed version, e.g. `"1.4"` for `citext`.
+    pub version: String,
+    /// The name of the schema the extension's objects were installed into.
+    pub schema: String,
+}
+
 #[derive(PartialEq, Debug, Clone, Copy)]
 pub enum SqlIndexAlgorithm {
     BTree,
@@ -93,6 +120,11 @@ impl fmt::Display for SqlIndexAlgorithm {
 pub enum Circumstances {
     Cockroach,
     CockroachWithPostgresNativeTypes, // TODO: this is a temporary workaround
+    /// Map unrecognized column types to `String` instead of `Unsupported(...)`.
+    LenientTypes,
+    /// The `pg_attribute.attgenerated` column used to detect `GENERATED ALWAYS AS (...) STORED`
+    /// columns does not exist before Postgres 12.
+    IsPostgres11OrOlder,
 }
 
 pub struct SqlSchemaDescriber<'a> {
@@ -114,12 +146,37 @@ pub struct PostgresSchemaExt {
     pub indexes: Vec<(IndexId, SqlIndexAlgorithm)>,
     /// The schema's sequences.
     pub sequences: Vec<Sequence>,
+    /// Index fields whose NULLS FIRST/LAST position was explicitly set and diverges from the
+    /// default for their sort order (NULLS LAST for ASC, NULLS FIRST for DESC).
+    pub null_position: Vec<(IndexFieldId, SQLNullPosition)>,
+    /// Unique indexes backed by a `DEFERRABLE` constraint.
+    pub deferrable_unique_indexes: Vec<IndexId>,
+    /// Tables whose primary key constraint is `DEFERRABLE`.
+    pub deferrable_primary_keys: Vec<TableId>,
+    /// The `WHERE` predicate of partial indexes, e.g. `deleted = false` for
+    /// `CREATE INDEX ... WHERE deleted = false`.
+    pub predicates: Vec<(IndexId, String)>,
+    /// Index fields that are `INCLUDE`d in a covering index (`CREATE INDEX ... INCLUDE (a, b)`)
+    /// rather than being part of its key.
+    pub non_key_columns: Vec<IndexFieldId>,
+    /// The schema's custom collations (`CREATE COLLATION`).
+    pub collations: Vec<Collation>,
+    /// The database's installed extensions (`CREATE EXTENSION`). Only populated when
+    /// [`crate::DescribeOptions::include_extensions`] is set.
+    pub extensions: Vec<Extension>,
 }
 
 const DEFAULT_REF: &PostgresSchemaExt = &PostgresSchemaExt {
     opclasses: Vec::new(),
     indexes: Vec::new(),
     sequences: Vec::new(),
+    null_position: Vec::new(),
+    deferrable_unique_indexes: Vec::new(),
+    deferrable_primary_keys: Vec::new(),
+    predicates: Vec::new(),
+    non_key_columns: Vec::new(),
+    collations: Vec::new(),
+    extensions: Vec::new(),
 };
 
 impl<'a> Default for &'a PostgresSchemaExt {
@@ -145,12 +202,51 @@ impl PostgresSchemaExt {
         Some(&self.opclasses[idx].1)
     }
 
+    /// The `WHERE` predicate of `index_id`, if it is a partial index.
+    pub fn index_predicate(&self, index_id: IndexId) -> Option<&str> {
+        let idx = self.predicates.binary_search_by_key(&index_id, |(id, _)| *id).ok()?;
+        Some(self.predicates[idx].1.as_str())
+    }
+
     pub fn get_sequence(&self, name: &str) -> Option<(usize, &Sequence)> {
         self.sequences
             .binary_search_by_key(&name, |s| &s.name)
             .map(|idx| (idx, &self.sequences[idx]))
             .ok()
     }
+
+    /// The NULLS FIRST/LAST position of an index field, if it was explicitly captured because it
+    /// diverges from the default for its sort order.
+    pub fn get_null_position(&self, index_field_id: IndexFieldId) -> Option<SQLNullPosition> {
+        let idx = self
+            .null_position
+            .binary_search_by_key(&index_field_id, |(id, _)| *id)
+            .ok()?;
+        Some(self.null_position[idx].1)
+    }
+
+    /// Whether the unique constraint backing this index is `DEFERRABLE`.
+    pub fn index_is_deferrable(&self, index_id: IndexId) -> bool {
+        self.deferrable_unique_indexes.binary_search(&index_id).is_ok()
+    }
+
+    /// Whether the primary key constraint of this table is `DEFERRABLE`.
+    pub fn pk_is_deferrable(&self, table_id: TableId) -> bool {
+        self.deferrable_primary_keys.binary_search(&table_id).is_ok()
+    }
+
+    /// Whether this index field is `INCLUDE`d in a covering index rather than part of its key.
+    pub fn field_is_included(&self, index_field_id: IndexFieldId) -> bool {
+        self.non_key_columns.binary_search(&index_field_id).is_ok()
+    }
+}
+
+/// The position of NULL values in an index's sort order, when it does not follow the default for
+/// the sort direction (NULLS LAST for ASC, NULLS FIRST for DESC).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SQLNullPosition {
+    First,
+    Last,
 }
 
 #[derive(Clone, Debug)]
@@ -171,6 +267,8 @@ pub enum SQLOperatorClassKind {
     ArrayOps,
     /// SP-GiST + text type
     TextOps,
+    /// GIN + text type, from the `pg_trgm` extension
+    GinTrgmOps,
     /// BRIN + bit
     BitMinMaxOps,
     /// BRIN + varbit
@@ -290,6 +388,7 @@ impl From<&str> for SQLOperatorClassKind {
             "array_ops" => SQLOperatorClassKind::ArrayOps,
             "jsonb_ops" => SQLOperatorClassKind::JsonbOps,
             "text_ops" => SQLOperatorClassKind::TextOps,
+            "gin_trgm_ops" => SQLOperatorClassKind::GinTrgmOps,
             "bit_minmax_ops" => SQLOperatorClassKind::BitMinMaxOps,
             "varbit_minmax_ops" => SQLOperatorClassKind::VarBitMinMaxOps,
             "bpchar_minmax_ops" => SQLOperatorClassKind::BpcharMinMaxOps,
@@ -356,6 +455,7 @@ impl AsRef<str> for SQLOperatorClassKind {
             SQLOperatorClassKind::JsonbPathOps => "jsonb_path_ops",
             SQLOperatorClassKind::ArrayOps => "array_ops",
             SQLOperatorClassKind::TextOps => "text_ops",
+            SQLOperatorClassKind::GinTrgmOps => "gin_trgm_ops",
             SQLOperatorClassKind::BitMinMaxOps => "bit_minmax_ops",
             SQLOperatorClassKind::VarBitMinMaxOps => "varbit_minmax_ops",
             SQLOperatorClassKind::BpcharBloomOps => "bpchar_bloom_ops",
@@ -420,7 +520,10 @@ impl<'a> super::SqlSchemaDescriberBackend for SqlSchemaDescriber<'a> {
 
     async fn get_metadata(&self, schema: &str) -> DescriberResult<SqlMetadata> {
         let mut sql_schema = SqlSchema::default();
-        let table_count = self.get_table_names(schema, &mut sql_schema).await?.len();
+        let table_count = self
+            .get_table_names(&[schema.to_owned(), schema.to_owned()], &mut sql_schema)
+            .await?
+            .len();
         let size_in_bytes = self.get_size(schema).await?;
 
         Ok(SqlMetadata {
@@ -429,33 +532,68 @@ impl<'a> super::SqlSchemaDescriberBackend for SqlSchemaDescriber<'a> {
         })
     }
 
-    async fn describe(&self, schema: &str) -> DescriberResult<SqlSchema> {
+    async fn describe_with_options(&self, schema: &str, options: &DescribeOptions) -> DescriberResult<SqlSchema> {
         let mut sql_schema = SqlSchema::default();
         let mut pg_ext = PostgresSchemaExt::default();
-        let table_names = self.get_table_names(schema, &mut sql_schema).await?;
+        let schemas = self.schemas_to_describe(schema, options).await?;
+        let table_names = self.get_table_names(&schemas, &mut sql_schema).await?;
 
         self.get_sequences(schema, &mut pg_ext).await?;
+
+        if !self.is_cockroach() {
+            self.get_collations(schema, &mut pg_ext).await?;
+        }
+
+        if options.include_extensions {
+            self.get_extensions(&mut pg_ext).await?;
+        }
+
         sql_schema.enums = self.get_enums(schema).await?;
-        self.get_columns(schema, &sql_schema.enums, &table_names, &mut sql_schema.columns)
-            .await?;
-        self.get_foreign_keys(schema, &table_names, &mut sql_schema).await?;
+        self.get_columns(
+            &schemas,
+            &sql_schema.enums,
+            &table_names,
+            &mut sql_schema.columns,
+            &mut sql_schema.raw_unsupported,
+            options,
+        )
+        .await?;
+        self.get_foreign_keys(schema, &table_names, &mut sql_schema, options).await?;
+        self.get_check_constraints(schema, &table_names, &mut sql_schema).await?;
 
         self.get_indices(schema, &table_names, &mut pg_ext, &mut sql_schema)
             .await?;
 
+        self.get_comments(schema, &table_names, &mut sql_schema).await?;
+
         sql_schema.views = self.get_views(schema).await?;
         sql_schema.procedures = self.get_procedures(schema).await?;
 
+        if options.include_row_count_estimates {
+            self.get_row_count_estimates(&schemas, &table_names, &mut sql_schema)
+                .await?;
+        }
+
         // Make sure the vectors we use binary search on are sorted.
         sql_schema.foreign_keys.sort_by_key(|(table_id, _)| *table_id);
+        sql_schema.check_constraints.sort_by_key(|(table_id, _)| *table_id);
         sql_schema.columns.sort_by_key(|(table_id, _)| *table_id);
         pg_ext.indexes.sort_by_key(|(id, _)| *id);
         pg_ext.opclasses.sort_by_key(|(id, _)| *id);
+        pg_ext.null_position.sort_by_key(|(id, _)| *id);
+        pg_ext.deferrable_unique_indexes.sort();
+        pg_ext.deferrable_primary_keys.sort();
+        pg_ext.predicates.sort_by_key(|(id, _)| *id);
+        pg_ext.non_key_columns.sort();
 
         sql_schema.connector_data = crate::connector_data::ConnectorData {
             data: Some(Box::new(pg_ext)),
         };
 
+        if options.fail_on_unsupported {
+            sql_schema.error_on_unsupported_columns()?;
+        }
+
         Ok(sql_schema)
     }
 
@@ -475,7 +613,7 @@ impl<'a> SqlSchemaDescriber<'a> {
 
     async fn get_databases(&self) -> DescriberResult<Vec<String>> {
         let sql = "select schema_name from information_schema.schemata;";
-        let rows = self.conn.query_raw(sql, &[]).await?;
+        let rows = retry_on_serialization_failure(|| self.conn.query_raw(sql, &[])).await?;
         let names = rows
             .into_iter()
             .map(|row| row.get_expect_string("schema_name"))
@@ -502,7 +640,7 @@ impl<'a> SqlSchemaDescriber<'a> {
             WHERE n.nspname = $1
         "#;
 
-        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+        let rows = retry_on_serialization_failure(|| self.conn.query_raw(sql, &[schema.into()])).await?;
         let mut procedures = Vec::with_capacity(rows.len());
 
         for row in rows.into_iter() {
@@ -515,18 +653,41 @@ impl<'a> SqlSchemaDescriber<'a> {
         Ok(procedures)
     }
 
+    /// Resolve the list of schemas to search for tables: just `schema`, unless
+    /// `options.include_temporary_tables` is set and the current session has a temporary schema,
+    /// in which case that schema is appended.
+    async fn schemas_to_describe(&self, schema: &str, options: &DescribeOptions) -> DescriberResult<[String; 2]> {
+        if !options.include_temporary_tables {
+            return Ok([schema.to_owned(), schema.to_owned()]);
+        }
+
+        let sql = "SELECT nspname FROM pg_namespace WHERE oid = pg_my_temp_schema()";
+        let rows = retry_on_serialization_failure(|| self.conn.query_raw(sql, &[])).await?;
+        let temp_schema = rows
+            .into_iter()
+            .next()
+            .and_then(|row| row.get_string("nspname"))
+            .unwrap_or_else(|| schema.to_owned());
+
+        Ok([schema.to_owned(), temp_schema])
+    }
+
     async fn get_table_names(
         &self,
-        schema: &str,
+        schemas: &[String; 2],
         sql_schema: &mut SqlSchema,
     ) -> DescriberResult<IndexMap<String, TableId>> {
         let sql = "
             SELECT table_name as table_name FROM information_schema.tables
-            WHERE table_schema = $1
+            WHERE table_schema IN ($1, $2)
             -- Views are not supported yet
             AND table_type = 'BASE TABLE'
             ORDER BY table_name";
-        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+        let rows = retry_on_serialization_failure(|| {
+            self.conn
+                .query_raw(sql, &[schemas[0].as_str().into(), schemas[1].as_str().into()])
+        })
+        .await?;
         let names = rows.into_iter().map(|row| row.get_expect_string("table_name"));
         let mut map = IndexMap::default();
 
@@ -539,16 +700,53 @@ impl<'a> SqlSchemaDescriber<'a> {
         Ok(map)
     }
 
-    async fn get_size(&self, schema: &str) -> DescriberResult<usize> {
-        if self.circumstances.contains(Circumstances::Cockroach) {
-            return Ok(0); // TODO
+    async fn get_row_count_estimates(
+        &self,
+        schemas: &[String; 2],
+        table_ids: &IndexMap<String, TableId>,
+        sql_schema: &mut SqlSchema,
+    ) -> DescriberResult<()> {
+        let sql = "
+            SELECT c.relname AS table_name, c.reltuples::bigint AS estimate
+            FROM pg_class c
+            INNER JOIN pg_namespace n ON n.oid = c.relnamespace
+            WHERE n.nspname IN ($1, $2) AND c.relkind = 'r'";
+        let rows = retry_on_serialization_failure(|| {
+            self.conn
+                .query_raw(sql, &[schemas[0].as_str().into(), schemas[1].as_str().into()])
+        })
+        .await?;
+
+        for row in rows {
+            let table_name = row.get_expect_string("table_name");
+
+            if let Some(table_id) = table_ids.get(&table_name) {
+                // A table that was never analyzed reports a negative estimate; treat it as unknown.
+                sql_schema[*table_id].row_count_estimate = row.get_i64("estimate").filter(|estimate| *estimate >= 0);
+            }
         }
 
-        let sql =
-            "SELECT SUM(pg_total_relation_size(quote_ident(schemaname) || '.' || quote_ident(tablename)))::BIGINT as size
+        Ok(())
+    }
+
+    async fn get_size(&self, schema: &str) -> DescriberResult<usize> {
+        // CockroachDB doesn't support `pg_total_relation_size` (which also adds in TOAST and index
+        // sizes, neither of which CockroachDB has), but does support `pg_relation_size`, which is
+        // good enough to sum up a table-size estimate per schema.
+        let size_fn = if self.is_cockroach() {
+            "pg_relation_size"
+        } else {
+            "pg_total_relation_size"
+        };
+
+        let sql = format!(
+            "SELECT SUM({size_fn}(quote_ident(schemaname) || '.' || quote_ident(tablename)))::BIGINT as size
              FROM pg_tables
-             WHERE schemaname = $1::text";
-        let mut result_iter = self.conn.query_raw(sql, &[schema.into()]).await?.into_iter();
+             WHERE schemaname = $1::text"
+        );
+        let mut result_iter = retry_on_serialization_failure(|| self.conn.query_raw(sql.as_str(), &[schema.into()]))
+            .await?
+            .into_iter();
         let size: i64 = result_iter.next().and_then(|row| row.get_i64("size")).unwrap_or(0);
 
         trace!("Found db size: {:?}", size);
@@ -563,25 +761,50 @@ impl<'a> SqlSchemaDescriber<'a> {
             WHERE schemaname = $1
         "#};
 
-        let result_set = self.conn.query_raw(sql, &[schema.into()]).await?;
+        let result_set = retry_on_serialization_failure(|| self.conn.query_raw(sql, &[schema.into()])).await?;
         let mut views = Vec::with_capacity(result_set.len());
 
         for row in result_set.into_iter() {
             views.push(View {
                 name: row.get_expect_string("view_name"),
                 definition: row.get_string("view_sql"),
+                is_materialized: false,
             })
         }
 
+        // CockroachDB's materialized view support is too limited (e.g. no pg_matviews catalog
+        // view in older versions) to rely on here, so we only describe materialized views on
+        // genuine Postgres.
+        if !self.is_cockroach() {
+            let matview_sql = indoc! {r#"
+                SELECT matviewname AS view_name, definition AS view_sql
+                FROM pg_catalog.pg_matviews
+                WHERE schemaname = $1
+            "#};
+
+            let result_set =
+                retry_on_serialization_failure(|| self.conn.query_raw(matview_sql, &[schema.into()])).await?;
+
+            for row in result_set.into_iter() {
+                views.push(View {
+                    name: row.get_expect_string("view_name"),
+                    definition: row.get_string("view_sql"),
+                    is_materialized: true,
+                })
+            }
+        }
+
         Ok(views)
     }
 
     async fn get_columns(
         &self,
-        schema: &str,
+        schemas: &[String; 2],
         enums: &[Enum],
         table_ids: &IndexMap<String, TableId>,
         columns: &mut Vec<(TableId, Column)>,
+        raw_unsupported: &mut Vec<RawObject>,
+        options: &DescribeOptions,
     ) -> DescriberResult<()> {
         let is_visible_clause = if self.is_cockroach() {
             " AND info.is_hidden = 'NO'"
@@ -589,6 +812,22 @@ impl<'a> SqlSchemaDescriber<'a> {
             ""
         };
 
+        // CockroachDB exposes computed (`GENERATED ALWAYS AS (...) STORED`) columns through the
+        // standard `is_generated`/`generation_expression` columns.
+        let generated_column_select = if self.is_cockroach() {
+            ", info.is_generated, info.generation_expression"
+        } else {
+            ""
+        };
+
+        // `pg_attribute.attgenerated` (`'s'` for `GENERATED ALWAYS AS (...) STORED`) was added in
+        // Postgres 12, so we can't select it on older versions.
+        let attgenerated_select = if self.circumstances.contains(Circumstances::IsPostgres11OrOlder) {
+            "NULL AS attgenerated"
+        } else {
+            "att.attgenerated"
+        };
+
         let sql = format!(
             r#"
             SELECT
@@ -604,7 +843,8 @@ impl<'a> SqlSchemaDescriber<'a> {
                 pg_get_expr(attdef.adbin, attdef.adrelid) AS column_default,
                 info.is_nullable,
                 info.is_identity,
-                info.character_maximum_length
+                {},
+                info.character_maximum_length{}
             FROM information_schema.columns info
             JOIN pg_attribute att ON att.attname = info.column_name
                 AND att.attrelid = (
@@ -612,20 +852,24 @@ impl<'a> SqlSchemaDescriber<'a> {
                     FROM pg_class
                     JOIN pg_namespace on pg_namespace.oid = pg_class.relnamespace
                     WHERE relname = info.table_name
-                    AND pg_namespace.nspname = $1
+                    AND pg_namespace.nspname IN ($1, $2)
                 )
             LEFT OUTER JOIN pg_attrdef attdef ON attdef.adrelid = att.attrelid AND attdef.adnum = att.attnum
-            WHERE table_schema = $1 {}
+            WHERE table_schema IN ($1, $2) {}
             ORDER BY table_name, ordinal_position;
         "#,
-            is_visible_clause,
+            attgenerated_select, generated_column_select, is_visible_clause,
         );
 
-        let rows = self.conn.query_raw(sql.as_str(), &[schema.into()]).await?;
+        let rows = retry_on_serialization_failure(|| {
+            self.conn
+                .query_raw(sql.as_str(), &[schemas[0].as_str().into(), schemas[1].as_str().into()])
+        })
+        .await?;
 
         for col in rows {
             trace!("Got column: {:?}", col);
-            let table_name = col.get_expect_string("table_name");
+            let table_name = col.get_result_string("table_name", "Postgres get_columns")?;
             let table_id = match table_ids.get(&table_name) {
                 Some(table_id) => table_id,
                 None => continue, // we only care about columns in tables we have access to
@@ -639,33 +883,68 @@ impl<'a> SqlSchemaDescriber<'a> {
                 None => false,
             };
 
+            let lenient_types = self.circumstances.contains(Circumstances::LenientTypes) || options.lenient_types;
+
             let tpe = if self.is_cockroach()
                 && !self
                     .circumstances
                     .contains(Circumstances::CockroachWithPostgresNativeTypes)
             {
-                get_column_type_cockroachdb(&col, enums)
+                get_column_type_cockroachdb(&col, enums, lenient_types)
             } else {
-                get_column_type_postgresql(&col, enums)
+                get_column_type_postgresql(&col, enums, lenient_types)
             };
-            let default = col
+
+            if options.capture_raw_unsupported {
+                if let ColumnTypeFamily::Unsupported(raw_type) = &tpe.family {
+                    raw_unsupported.push(RawObject {
+                        kind: "column".to_owned(),
+                        raw_definition: raw_type.clone(),
+                    });
+                }
+            }
+
+            // On Postgres proper (not Cockroach), a stored generated column's `pg_attrdef` entry
+            // holds its generation expression, not a default: `pg_get_expr(attdef.adbin, ...)`
+            // returns the same text either way, so we must consult `attgenerated` to tell them
+            // apart before treating it as a `DEFAULT`.
+            let is_stored_generated = col.get_string("attgenerated").as_deref() == Some("s");
+
+            let raw_column_default = col
                 .get("column_default")
-                .and_then(|raw_default_value| raw_default_value.to_string())
-                .and_then(|raw_default_value| get_default_value(&raw_default_value, &tpe));
+                .and_then(|raw_default_value| raw_default_value.to_string());
+
+            let default = if is_stored_generated {
+                None
+            } else {
+                raw_column_default
+                    .as_deref()
+                    .and_then(|raw_default_value| get_default_value(raw_default_value, &tpe))
+            };
 
             let auto_increment = is_identity
                 || matches!(default.as_ref().map(|d| d.kind()), Some(DefaultKind::Sequence(_)))
-                || (self.is_cockroach()
-                    && matches!(
-                        default.as_ref().map(|d| d.kind()),
-                        Some(DefaultKind::DbGenerated(s)) if s == "unique_rowid()"
-                    ));
+                || (self.is_cockroach() && matches!(default.as_ref().map(|d| d.kind()), Some(DefaultKind::UniqueRowid)));
+
+            let generated = if self.is_cockroach() {
+                col.get_string("is_generated")
+                    .filter(|is_generated| is_generated.eq_ignore_ascii_case("always"))
+                    .and_then(|_| col.get_string("generation_expression"))
+                    .filter(|expression| !expression.is_empty())
+            } else if is_stored_generated {
+                raw_column_default.filter(|expression| !expression.is_empty())
+            } else {
+                None
+            };
 
             let col = Column {
                 name,
                 tpe,
                 default,
                 auto_increment,
+                is_identity,
+                comment: None,
+                generated,
             };
 
             columns.push((*table_id, col));
@@ -676,6 +955,55 @@ impl<'a> SqlSchemaDescriber<'a> {
         Ok(())
     }
 
+    /// Fetches table and column comments (`COMMENT ON TABLE`/`COMMENT ON COLUMN`) and attaches
+    /// them to the already-described tables and columns.
+    async fn get_comments(
+        &self,
+        schema: &str,
+        table_ids: &IndexMap<String, TableId>,
+        sql_schema: &mut SqlSchema,
+    ) -> DescriberResult<()> {
+        let sql = r#"
+            SELECT
+                class.relname AS table_name,
+                attr.attname AS column_name,
+                descr.description AS comment
+            FROM pg_catalog.pg_description descr
+            JOIN pg_catalog.pg_class class ON class.oid = descr.objoid
+            JOIN pg_catalog.pg_namespace namespace ON namespace.oid = class.relnamespace
+            LEFT JOIN pg_catalog.pg_attribute attr
+                ON attr.attrelid = class.oid AND attr.attnum = descr.objsubid AND descr.objsubid <> 0
+            WHERE namespace.nspname = $1 AND descr.description IS NOT NULL
+        "#;
+
+        let rows = retry_on_serialization_failure(|| self.conn.query_raw(sql, &[schema.into()])).await?;
+
+        for row in rows {
+            let table_name = row.get_expect_string("table_name");
+            let table_id = match table_ids.get(&table_name) {
+                Some(table_id) => *table_id,
+                None => continue,
+            };
+            let comment = row.get_string("comment");
+            let column_name = row.get_string("column_name");
+
+            match column_name {
+                Some(column_name) => {
+                    if let Some((_, column)) = sql_schema
+                        .columns
+                        .iter_mut()
+                        .find(|(id, col)| *id == table_id && col.name == column_name)
+                    {
+                        column.comment = comment;
+                    }
+                }
+                None => sql_schema[table_id].comment = comment,
+            }
+        }
+
+        Ok(())
+    }
+
     fn get_precision(col: &ResultRow) -> Precision {
         let (character_maximum_length, numeric_precision, numeric_scale, time_precision) =
             if matches!(col.get_expect_string("data_type").as_str(), "ARRAY") {
@@ -742,6 +1070,7 @@ impl<'a> SqlSchemaDescriber<'a> {
         schema: &str,
         table_ids: &IndexMap<String, TableId>,
         sql_schema: &mut SqlSchema,
+        options: &DescribeOptions,
     ) -> DescriberResult<()> {
         // The `generate_subscripts` in the inner select is needed because the optimizer is free to reorganize the unnested rows if not explicitly ordered.
         let sql = r#"
@@ -751,6 +1080,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                 att.attname     as "parent_column",
                 con.confdeltype,
                 con.confupdtype,
+                con.convalidated,
                 rel_ns.nspname as "referenced_schema_name",
                 conname         as constraint_name,
                 child,
@@ -766,7 +1096,8 @@ impl<'a> SqlSchemaDescriber<'a> {
                         con1.conrelid,
                         con1.conname,
                         con1.confdeltype,
-                        con1.confupdtype
+                        con1.confupdtype,
+                        con1.convalidated
                 FROM pg_class cl
                         join pg_constraint con1 on con1.conrelid = cl.oid
                         join pg_namespace ns on cl.relnamespace = ns.oid
@@ -786,7 +1117,7 @@ impl<'a> SqlSchemaDescriber<'a> {
         // One foreign key with multiple columns will be represented here as several
         // rows with the same ID, which we will have to combine into corresponding foreign key
         // objects.
-        let result_set = self.conn.query_raw(sql, &[schema.into()]).await?;
+        let result_set = retry_on_serialization_failure(|| self.conn.query_raw(sql, &[schema.into()])).await?;
         let mut intermediate_fks: BTreeMap<i64, (TableId, ForeignKey)> = BTreeMap::new();
         for row in result_set.into_iter() {
             trace!("Got description FK row {:?}", row);
@@ -798,7 +1129,7 @@ impl<'a> SqlSchemaDescriber<'a> {
             let referenced_column = row.get_expect_string("parent_column");
             let referenced_schema_name = row.get_expect_string("referenced_schema_name");
 
-            if schema != referenced_schema_name {
+            if !schemas_match(schema, &referenced_schema_name, options) {
                 return Err(DescriberError::from(DescriberErrorKind::CrossSchemaReference {
                     from: format!("{}.{}", schema, table_name),
                     to: format!("{}.{}", referenced_schema_name, referenced_table),
@@ -840,6 +1171,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                 'd' => ForeignKeyAction::SetDefault,
                 _ => panic!("unrecognized foreign key action (on update) '{}'", confupdtype),
             };
+            let validated = row.get_expect_bool("convalidated");
             match intermediate_fks.get_mut(&id) {
                 Some((_, fk)) => {
                     fk.columns.push(column);
@@ -853,6 +1185,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                         referenced_columns: vec![referenced_column],
                         on_delete_action,
                         on_update_action,
+                        validated,
                     };
                     intermediate_fks.insert(id, (table_id, fk));
                 }
@@ -870,6 +1203,46 @@ impl<'a> SqlSchemaDescriber<'a> {
         Ok(())
     }
 
+    async fn get_check_constraints(
+        &self,
+        schema: &str,
+        table_ids: &IndexMap<String, TableId>,
+        sql_schema: &mut SqlSchema,
+    ) -> DescriberResult<()> {
+        let sql = r#"
+            SELECT con.conname   AS constraint_name,
+                   cl.relname    AS table_name,
+                   pg_get_constraintdef(con.oid) AS definition
+            FROM pg_constraint con
+                     JOIN pg_class cl ON cl.oid = con.conrelid
+                     JOIN pg_namespace ns ON ns.oid = cl.relnamespace
+            WHERE ns.nspname = $1
+              AND con.contype = 'c'
+            ORDER BY cl.relname, con.conname;
+        "#;
+
+        let rows = retry_on_serialization_failure(|| self.conn.query_raw(sql, &[schema.into()])).await?;
+
+        for row in rows.into_iter() {
+            let table_name = row.get_expect_string("table_name");
+
+            let table_id = if let Some(id) = table_ids.get(table_name.as_str()) {
+                *id
+            } else {
+                continue;
+            };
+
+            let check_constraint = CheckConstraint {
+                name: row.get_expect_string("constraint_name"),
+                expression: normalize_check_constraint_expression(&row.get_expect_string("definition")),
+            };
+
+            sql_schema.check_constraints.push((table_id, check_constraint));
+        }
+
+        Ok(())
+    }
+
     /// Returns a map from table name to indexes and (optional) primary key.
     async fn get_indices(
         &self,
@@ -890,10 +1263,18 @@ impl<'a> SqlSchemaDescriber<'a> {
                rawIndex.indkeyidx,
                rawIndex.opclass                            AS opclass,
                rawIndex.opcdefault                         AS opcdefault,
+               rawIndex.predicate                          AS predicate,
+               rawIndex.expression                         AS expression,
+               rawIndex.indnkeyatts                        AS indnkeyatts,
                CASE rawIndex.sort_order & 1
                    WHEN 1 THEN 'DESC'
                    ELSE 'ASC'
-                   END                                     AS column_order
+                   END                                     AS column_order,
+               CASE rawIndex.sort_order & 2
+                   WHEN 2 THEN 'FIRST'
+                   ELSE 'LAST'
+                   END                                     AS column_nulls_order,
+               constraintInfo.condeferrable                AS is_deferrable
         FROM
             -- pg_class stores infos about tables, indices etc: https://www.postgresql.org/docs/current/catalog-pg-class.html
             pg_class tableInfos,
@@ -905,11 +1286,19 @@ impl<'a> SqlSchemaDescriber<'a> {
                        i.indisunique,
                        i.indisprimary,
                        i.indkey,
+                       i.indnkeyatts,
                        opc.opcname opclass,
                        opc.opcdefault opcdefault,
+                       pg_get_expr(i.indpred, i.indrelid) AS predicate,
                        o.OPTION AS sort_order,
                        c.colnum AS sort_order_colnum,
-                       generate_subscripts(i.indkey, 1) AS indkeyidx
+                       generate_subscripts(i.indkey, 1) AS indkeyidx,
+                       -- an indkey entry of 0 means this position in the index is an expression
+                       -- (e.g. `lower(email)`) rather than a plain column reference
+                       CASE c.colnum
+                           WHEN 0 THEN pg_get_indexdef(i.indexrelid, c.ordinality::int, true)
+                           ELSE NULL
+                           END AS expression
                 FROM pg_index i
                          CROSS JOIN LATERAL UNNEST(indkey) WITH ordinality AS c (colnum, ordinality)
                          LEFT JOIN LATERAL UNNEST(indclass) WITH ordinality AS p (opcoid, ordinality)
@@ -917,46 +1306,61 @@ impl<'a> SqlSchemaDescriber<'a> {
                          LEFT JOIN LATERAL UNNEST(indoption) WITH ordinality AS o (OPTION, ordinality)
                                    ON c.ordinality = o.ordinality
                          LEFT JOIN pg_opclass opc ON opc.oid = p.opcoid
-                WHERE i.indpred IS NULL
-                GROUP BY i.indrelid, i.indexrelid, i.indisunique, i.indisprimary, indkeyidx, i.indkey, i.indoption, opc.opcname, sort_order, sort_order_colnum, opc.opcdefault
+                GROUP BY i.indrelid, i.indexrelid, i.indisunique, i.indisprimary, indkeyidx, i.indkey, i.indnkeyatts, i.indoption, opc.opcname, sort_order, sort_order_colnum, opc.opcdefault, i.indpred, c.colnum
                 ORDER BY i.indrelid, i.indexrelid
-            ) rawIndex,
-            -- pg_attribute stores infos about columns: https://www.postgresql.org/docs/current/catalog-pg-attribute.html
-            pg_attribute columnInfos,
+            ) rawIndex
+                -- LEFT JOIN: an expression entry in the index (indkey = 0) has no backing
+                -- pg_attribute row, and must not be dropped from the result set
+                LEFT JOIN pg_attribute columnInfos
+                    ON columnInfos.attrelid = tableInfos.oid
+                   AND columnInfos.attnum = rawIndex.indkey[rawIndex.indkeyidx]
+                   AND rawIndex.sort_order_colnum = columnInfos.attnum,
             -- pg_namespace stores info about the schema
             pg_namespace schemaInfo,
             -- index access methods: https://www.postgresql.org/docs/9.3/catalog-pg-am.html
             pg_am indexAccess
+            -- pg_constraint stores the DEFERRABLE-ness of the PK/unique constraint backing the index, if any
+            LEFT JOIN pg_constraint constraintInfo
+                ON constraintInfo.conindid = rawIndex.indexrelid
+               AND constraintInfo.contype IN ('p', 'u')
         WHERE
           -- find table info for index
             tableInfos.oid = rawIndex.indrelid
           -- find index info
           AND indexInfos.oid = rawIndex.indexrelid
-          -- find table columns
-          AND columnInfos.attrelid = tableInfos.oid
-          AND columnInfos.attnum = rawIndex.indkey[rawIndex.indkeyidx]
           -- we only consider ordinary tables
           AND tableInfos.relkind = 'r'
           -- we only consider stuff out of one specific schema
           AND tableInfos.relnamespace = schemaInfo.oid
           AND schemaInfo.nspname = $1
-          AND rawIndex.sort_order_colnum = columnInfos.attnum
           AND indexAccess.oid = indexInfos.relam
         GROUP BY tableInfos.relname, indexInfos.relname, rawIndex.indisunique, rawIndex.indisprimary, columnInfos.attname,
-                 rawIndex.indkeyidx, column_order, index_algo, opclass, opcdefault
+                 rawIndex.indkeyidx, column_order, column_nulls_order, index_algo, opclass, opcdefault, is_deferrable, predicate,
+                 rawIndex.expression, rawIndex.indnkeyatts
         ORDER BY indexinfos.relname, rawIndex.indkeyidx;
         "#;
 
-        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+        let rows = retry_on_serialization_failure(|| self.conn.query_raw(sql, &[schema.into()])).await?;
 
         for row in rows {
             trace!("Got index: {:?}", row);
             let name = row.get_expect_string("name");
-            let column_name = row.get_expect_string("column_name");
+            // NULL for the entries of an expression index (`CREATE INDEX ON t (lower(col))`),
+            // which have no backing pg_attribute row.
+            let column_name = row.get_string("column_name");
             let is_unique = row.get_expect_bool("is_unique");
             let is_primary_key = row.get_expect_bool("is_primary_key");
             let table_name = row.get_expect_string("table_name");
             let index_algo = row.get_expect_string("index_algo");
+            let is_deferrable = row.get_bool("is_deferrable").unwrap_or(false);
+            let predicate = row.get_string("predicate");
+            // Columns past indnkeyatts are INCLUDEd (covering) columns rather than part of the
+            // index key (`CREATE INDEX ... INCLUDE (a, b)`). indnkeyatts was added in Postgres 11,
+            // which is also the version that introduced covering indexes, so we can rely on it
+            // unconditionally here.
+            let indnkeyatts = row.get_expect_i64("indnkeyatts") as u32;
+            let indkeyidx = row.get_expect_i64("indkeyidx") as u32;
+            let is_included_column = indkeyidx > indnkeyatts;
 
             let table_id: TableId = if let Some(id) = table_ids.get(table_name.as_str()) {
                 *id
@@ -973,6 +1377,20 @@ impl<'a> SqlSchemaDescriber<'a> {
                 ),
             });
 
+            let nulls_order = row.get_string("column_nulls_order").map(|v| match v.as_ref() {
+                "FIRST" => SQLNullPosition::First,
+                "LAST" => SQLNullPosition::Last,
+                misc => panic!("Unexpected nulls order `{}`, expected FIRST or LAST", misc),
+            });
+
+            // NULLS LAST is the implicit default for ASC, NULLS FIRST for DESC. We only need to
+            // remember the position when it diverges from that default.
+            let non_default_nulls_order = match (sort_order.unwrap_or(SQLSortOrder::Asc), nulls_order) {
+                (SQLSortOrder::Asc, Some(SQLNullPosition::First)) => Some(SQLNullPosition::First),
+                (SQLSortOrder::Desc, Some(SQLNullPosition::Last)) => Some(SQLNullPosition::Last),
+                _ => None,
+            };
+
             let algorithm = if self.is_cockroach() {
                 match index_algo.as_str() {
                     "inverted" => SqlIndexAlgorithm::Gin,
@@ -988,15 +1406,26 @@ impl<'a> SqlSchemaDescriber<'a> {
                     "brin" => SqlIndexAlgorithm::Brin,
                     other => {
                         tracing::warn!("Unknown index algorithm on {name}: {other}");
+                        sql_schema.warnings.push(DescriberWarning {
+                            message: format!(
+                                "Index `{name}` uses an unrecognized index algorithm (`{other}`). It was assumed to be a BTree index."
+                            ),
+                        });
                         SqlIndexAlgorithm::BTree
                     }
                 }
             };
 
             if is_primary_key {
+                if is_deferrable {
+                    pg_ext.deferrable_primary_keys.push(table_id);
+                }
+
                 let entry: &mut (Vec<_>, Option<PrimaryKey>) =
                     indexes_map.entry(table_id).or_insert_with(|| (Vec::new(), None));
 
+                let column_name = column_name.expect("Postgres does not support expressions in primary keys");
+
                 match entry.1.as_mut() {
                     Some(pk) => {
                         pk.columns.push(PrimaryKeyColumn::new(column_name));
@@ -1022,7 +1451,13 @@ impl<'a> SqlSchemaDescriber<'a> {
 
                 let entry: &mut (Vec<Index>, _) = indexes_map.entry(table_id).or_insert_with(|| (Vec::new(), None));
 
-                let mut column = IndexColumn::new(column_name);
+                let mut column = match column_name {
+                    Some(column_name) => IndexColumn::new(column_name),
+                    None => IndexColumn {
+                        expression: row.get_string("expression"),
+                        ..Default::default()
+                    },
+                };
                 column.sort_order = sort_order;
 
                 if let Some(index_id) = entry.0.iter_mut().position(|idx| idx.name == name) {
@@ -1031,24 +1466,60 @@ impl<'a> SqlSchemaDescriber<'a> {
 
                     pg_ext.indexes.push((index_id, algorithm));
 
+                    if is_unique && is_deferrable {
+                        pg_ext.deferrable_unique_indexes.push(index_id);
+                    }
+
                     if let Some(opclass) = operator_class {
                         let index_field_id = IndexFieldId(index_id, existing_index.columns.len() as u32);
 
                         pg_ext.opclasses.push((index_field_id, opclass));
                     }
 
+                    if let Some(nulls_order) = non_default_nulls_order {
+                        let index_field_id = IndexFieldId(index_id, existing_index.columns.len() as u32);
+
+                        pg_ext.null_position.push((index_field_id, nulls_order));
+                    }
+
+                    if is_included_column {
+                        let index_field_id = IndexFieldId(index_id, existing_index.columns.len() as u32);
+
+                        pg_ext.non_key_columns.push(index_field_id);
+                    }
+
                     existing_index.columns.push(column);
                 } else {
                     let index_id = IndexId(table_id, entry.0.len() as u32);
 
                     pg_ext.indexes.push((index_id, algorithm));
 
+                    if is_unique && is_deferrable {
+                        pg_ext.deferrable_unique_indexes.push(index_id);
+                    }
+
                     if let Some(opclass) = operator_class {
                         let index_field_id = IndexFieldId(index_id, 0);
 
                         pg_ext.opclasses.push((index_field_id, opclass));
                     }
 
+                    if let Some(nulls_order) = non_default_nulls_order {
+                        let index_field_id = IndexFieldId(index_id, 0);
+
+                        pg_ext.null_position.push((index_field_id, nulls_order));
+                    }
+
+                    if let Some(predicate) = predicate {
+                        pg_ext.predicates.push((index_id, predicate));
+                    }
+
+                    if is_included_column {
+                        let index_field_id = IndexFieldId(index_id, 0);
+
+                        pg_ext.non_key_columns.push(index_field_id);
+                    }
+
                     entry.0.push(Index {
                         name,
                         columns: vec![column],
@@ -1056,6 +1527,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                             true => IndexType::Unique,
                             false => IndexType::Normal,
                         },
+                        is_autogenerated: false,
                     })
                 }
             }
@@ -1110,7 +1582,7 @@ impl<'a> SqlSchemaDescriber<'a> {
             "#
         };
 
-        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+        let rows = retry_on_serialization_failure(|| self.conn.query_raw(sql, &[schema.into()])).await?;
         let sequences = rows.into_iter().map(|seq| Sequence {
             name: seq.get_expect_string("sequence_name"),
             start_value: seq.get_expect_i64("start_value"),
@@ -1126,6 +1598,48 @@ impl<'a> SqlSchemaDescriber<'a> {
         Ok(())
     }
 
+    // CockroachDB does not support `CREATE COLLATION`, so there is nothing to describe there.
+    async fn get_collations(&self, schema: &str, postgres_ext: &mut PostgresSchemaExt) -> DescriberResult<()> {
+        let sql = indoc! {r#"
+            SELECT coll.collname AS name, coll.collcollate AS lc_collate, coll.collctype AS lc_ctype
+            FROM pg_collation coll
+            INNER JOIN pg_namespace ns ON ns.oid = coll.collnamespace
+            WHERE ns.nspname = $1
+            ORDER BY coll.collname
+        "#};
+
+        let rows = retry_on_serialization_failure(|| self.conn.query_raw(sql, &[schema.into()])).await?;
+        let collations = rows.into_iter().map(|coll| Collation {
+            name: coll.get_expect_string("name"),
+            lc_collate: coll.get_string("lc_collate"),
+            lc_ctype: coll.get_string("lc_ctype"),
+        });
+        postgres_ext.collations.extend(collations);
+
+        Ok(())
+    }
+
+    // Extensions are database-wide rather than schema-scoped, so unlike the other `get_*`
+    // methods here this does not take a `schema` argument.
+    async fn get_extensions(&self, postgres_ext: &mut PostgresSchemaExt) -> DescriberResult<()> {
+        let sql = indoc! {r#"
+            SELECT ext.extname AS name, ext.extversion AS version, ns.nspname AS schema
+            FROM pg_extension ext
+            INNER JOIN pg_namespace ns ON ns.oid = ext.extnamespace
+            ORDER BY ext.extname
+        "#};
+
+        let rows = retry_on_serialization_failure(|| self.conn.query_raw(sql, &[])).await?;
+        let extensions = rows.into_iter().map(|ext| Extension {
+            name: ext.get_expect_string("name"),
+            version: ext.get_expect_string("version"),
+            schema: ext.get_expect_string("schema"),
+        });
+        postgres_ext.extensions.extend(extensions);
+
+        Ok(())
+    }
+
     async fn get_enums(&self, schema: &str) -> DescriberResult<Vec<Enum>> {
         let sql = "
             SELECT t.typname as name, e.enumlabel as value
@@ -1135,7 +1649,7 @@ impl<'a> SqlSchemaDescriber<'a> {
             WHERE n.nspname = $1
             ORDER BY e.enumsortorder";
 
-        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+        let rows = retry_on_serialization_failure(|| self.conn.query_raw(sql, &[schema.into()])).await?;
         let mut enum_values: BTreeMap<String, Vec<String>> = BTreeMap::new();
 
         for row in rows.into_iter() {
@@ -1160,7 +1674,49 @@ impl<'a> SqlSchemaDescriber<'a> {
     }
 }
 
-fn get_column_type_postgresql(row: &ResultRow, enums: &[Enum]) -> ColumnType {
+/// `pg_get_constraintdef()` renders a CHECK constraint as `CHECK (<expr>)`, and `<expr>` itself is
+/// often wrapped in one more redundant pair of parentheses (e.g. `CHECK ((age >= 0))`). Strip the
+/// `CHECK ` prefix and every redundant outer paren pair that wraps the whole expression, so the
+/// stored expression doesn't change based on how many layers Postgres happens to print.
+fn normalize_check_constraint_expression(constraintdef: &str) -> String {
+    let without_prefix = constraintdef.trim().strip_prefix("CHECK ").unwrap_or(constraintdef).trim();
+
+    let mut expr = without_prefix;
+    while let Some(inner) = strip_matching_outer_parens(expr) {
+        expr = inner.trim();
+    }
+
+    expr.to_owned()
+}
+
+/// If `s` is entirely wrapped in one pair of matching parentheses (i.e. the first `(` closes at
+/// the very last character), return the inner content. Otherwise, `None`.
+fn strip_matching_outer_parens(s: &str) -> Option<&str> {
+    let bytes = s.as_bytes();
+
+    if bytes.first() != Some(&b'(') || bytes.last() != Some(&b')') {
+        return None;
+    }
+
+    let mut depth = 0i32;
+
+    for (i, b) in bytes.iter().enumerate() {
+        match b {
+            b'(' => depth += 1,
+            b')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return if i == bytes.len() - 1 { Some(&s[1..s.len() - 1]) } else { None };
+                }
+            }
+            _ => (),
+        }
+    }
+
+    None
+}
+
+fn get_column_type_postgresql(row: &ResultRow, enums: &[Enum], lenient_types: bool) -> ColumnType {
     use ColumnTypeFamily::*;
     let data_type = row.get_expect_string("data_type");
     let full_data_type = row.get_expect_string("full_data_type");
@@ -1177,7 +1733,13 @@ fn get_column_type_postgresql(row: &ResultRow, enums: &[Enum]) -> ColumnType {
     };
 
     let precision = SqlSchemaDescriber::get_precision(row);
-    let unsupported_type = || (Unsupported(full_data_type.clone()), None);
+    let unsupported_type = || {
+        if lenient_types {
+            (String, None)
+        } else {
+            (Unsupported(full_data_type.clone()), None)
+        }
+    };
     let enum_exists = |name| enums.iter().any(|e| e.name == name);
 
     let (family, native_type) = match full_data_type.as_str() {
@@ -1234,10 +1796,45 @@ fn get_column_type_postgresql(row: &ResultRow, enums: &[Enum]) -> ColumnType {
         "lseg" | "_lseg" => unsupported_type(),
         "path" | "_path" => unsupported_type(),
         "polygon" | "_polygon" => unsupported_type(),
+        // PostGIS geometry/geography columns have no Prisma scalar type or native type
+        // equivalent either, so they stay Unsupported like the built-in geometric types above.
+        // Their subtype and SRID (e.g. `Point,4326`) live in the typmod, which `full_data_type`
+        // (`udt_name`) doesn't carry (it's just the bare `geometry`/`geography`), so we report
+        // `formatted_type` instead, which `format_type()` renders as `geometry(Point,4326)`.
+        "geometry" | "_geometry" | "geography" | "_geography" => {
+            if lenient_types {
+                (String, None)
+            } else {
+                (Unsupported(row.get_expect_string("formatted_type")), None)
+            }
+        }
+        // Range and multirange types have no Prisma scalar type or native type equivalent, so
+        // they stay Unsupported, but we name them explicitly rather than falling through to the
+        // catch-all so new built-in types don't get silently lumped in with them.
+        "int4range" | "_int4range" => unsupported_type(),
+        "int8range" | "_int8range" => unsupported_type(),
+        "numrange" | "_numrange" => unsupported_type(),
+        "tsrange" | "_tsrange" => unsupported_type(),
+        "tstzrange" | "_tstzrange" => unsupported_type(),
+        "daterange" | "_daterange" => unsupported_type(),
+        "int4multirange" | "_int4multirange" => unsupported_type(),
+        "int8multirange" | "_int8multirange" => unsupported_type(),
+        "nummultirange" | "_nummultirange" => unsupported_type(),
+        "tsmultirange" | "_tsmultirange" => unsupported_type(),
+        "tstzmultirange" | "_tstzmultirange" => unsupported_type(),
+        "datemultirange" | "_datemultirange" => unsupported_type(),
         name if enum_exists(name) => (Enum(name.to_owned()), None),
         _ => unsupported_type(),
     };
 
+    // For geometry/geography columns, prefer the typmod-qualified `formatted_type` (e.g.
+    // `geometry(Point,4326)`) over the bare `full_data_type` (`geometry`) so the subtype and SRID
+    // survive introspection on the `ColumnType` itself, not just in the `Unsupported` family.
+    let full_data_type = match full_data_type.as_str() {
+        "geometry" | "_geometry" | "geography" | "_geography" => row.get_expect_string("formatted_type"),
+        _ => full_data_type,
+    };
+
     ColumnType {
         full_data_type,
         family,
@@ -1247,7 +1844,7 @@ fn get_column_type_postgresql(row: &ResultRow, enums: &[Enum]) -> ColumnType {
 }
 
 // Separate from get_column_type_postgresql because of native types.
-fn get_column_type_cockroachdb(row: &ResultRow, enums: &[Enum]) -> ColumnType {
+fn get_column_type_cockroachdb(row: &ResultRow, enums: &[Enum], lenient_types: bool) -> ColumnType {
     use ColumnTypeFamily::*;
     let data_type = row.get_expect_string("data_type");
     let full_data_type = row.get_expect_string("full_data_type");
@@ -1264,7 +1861,13 @@ fn get_column_type_cockroachdb(row: &ResultRow, enums: &[Enum]) -> ColumnType {
     };
 
     let precision = SqlSchemaDescriber::get_precision(row);
-    let unsupported_type = || (Unsupported(full_data_type.clone()), None);
+    let unsupported_type = || {
+        if lenient_types {
+            (String, None)
+        } else {
+            (Unsupported(full_data_type.clone()), None)
+        }
+    };
     let enum_exists = |name| enums.iter().any(|e| e.name == name);
 
     let (family, native_type) = match full_data_type.as_str() {
@@ -1329,3 +1932,142 @@ fn get_column_type_cockroachdb(row: &ResultRow, enums: &[Enum]) -> ColumnType {
         native_type: native_type.map(|x| x.to_json()),
     }
 }
+
+/// The SQLSTATE CockroachDB and Postgres (under `SERIALIZABLE` isolation) raise when a
+/// transaction's reads conflict with a concurrent transaction and must be retried.
+const SERIALIZATION_FAILURE_SQLSTATE: &str = "40001";
+
+/// How many times a describe query is retried after a serialization failure before giving up.
+const MAX_SERIALIZATION_FAILURE_RETRIES: u32 = 3;
+
+fn is_serialization_failure(err: &quaint::error::Error) -> bool {
+    is_serialization_failure_code(err.original_code())
+}
+
+fn is_serialization_failure_code(code: Option<&str>) -> bool {
+    code == Some(SERIALIZATION_FAILURE_SQLSTATE)
+}
+
+/// Retries `f` up to [`MAX_SERIALIZATION_FAILURE_RETRIES`] times when it fails with a retryable
+/// serialization failure (SQLSTATE `40001`), which CockroachDB and Postgres under `SERIALIZABLE`
+/// isolation can raise on catalog reads that conflict with a concurrent transaction. Any other
+/// error is returned immediately.
+async fn retry_on_serialization_failure<T, F, Fut>(f: F) -> quaint::Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = quaint::Result<T>>,
+{
+    retry_on_failure(is_serialization_failure, f).await
+}
+
+/// Retries `f` up to [`MAX_SERIALIZATION_FAILURE_RETRIES`] times as long as `is_retryable` returns
+/// `true` for the error it failed with. Split out from [`retry_on_serialization_failure`] so the
+/// retry-counting logic itself can be unit tested with a mock error type, independently of
+/// `quaint`'s error type and `Queryable` trait.
+async fn retry_on_failure<T, E, F, Fut>(mut is_retryable: impl FnMut(&E) -> bool, mut f: F) -> Result<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<T, E>>,
+{
+    let mut attempt = 0;
+
+    loop {
+        match f().await {
+            Ok(value) => return Ok(value),
+            Err(err) if attempt < MAX_SERIALIZATION_FAILURE_RETRIES && is_retryable(&err) => {
+                attempt += 1;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_check_constraint_expression_strips_the_check_prefix_and_redundant_parens() {
+        assert_eq!(normalize_check_constraint_expression("CHECK ((age >= 0))"), "age >= 0");
+        assert_eq!(normalize_check_constraint_expression("CHECK (age >= 0)"), "age >= 0");
+        assert_eq!(
+            normalize_check_constraint_expression("CHECK ((price > 0) AND (discounted_price > 0))"),
+            "(price > 0) AND (discounted_price > 0)"
+        );
+    }
+
+    #[test]
+    fn serialization_failure_sqlstate_is_recognized() {
+        assert!(is_serialization_failure_code(Some("40001")));
+    }
+
+    #[test]
+    fn unrelated_error_codes_are_not_treated_as_serialization_failures() {
+        assert!(!is_serialization_failure_code(Some("40P01"))); // deadlock_detected
+        assert!(!is_serialization_failure_code(None));
+    }
+
+    // We don't construct a real `quaint::error::Error`/`Queryable` here (`quaint` is a git
+    // dependency we don't have vendored source for in this environment, so we can't confirm the
+    // shape of its error constructors well enough to fake them). `retry_on_failure` splits the
+    // retry-counting loop out from the SQLSTATE check, so it can be exercised with a mock error
+    // type and a queryable stub instead.
+    #[tokio::test]
+    async fn retry_on_failure_retries_a_retryable_error_and_then_succeeds() {
+        let attempts = std::cell::Cell::new(0u32);
+
+        let result = retry_on_failure(
+            |err: &&str| *err == "retryable",
+            || {
+                let attempt = attempts.get();
+                attempts.set(attempt + 1);
+                async move {
+                    if attempt == 0 {
+                        Err("retryable")
+                    } else {
+                        Ok(attempt)
+                    }
+                }
+            },
+        )
+        .await;
+
+        assert_eq!(result, Ok(1));
+        assert_eq!(attempts.get(), 2);
+    }
+
+    #[tokio::test]
+    async fn retry_on_failure_gives_up_after_max_retries() {
+        let attempts = std::cell::Cell::new(0u32);
+
+        let result: Result<(), &str> = retry_on_failure(
+            |err: &&str| *err == "retryable",
+            || {
+                attempts.set(attempts.get() + 1);
+                async { Err::<(), _>("retryable") }
+            },
+        )
+        .await;
+
+        assert_eq!(result, Err("retryable"));
+        // One initial attempt, plus MAX_SERIALIZATION_FAILURE_RETRIES retries.
+        assert_eq!(attempts.get(), MAX_SERIALIZATION_FAILURE_RETRIES + 1);
+    }
+
+    #[tokio::test]
+    async fn retry_on_failure_does_not_retry_a_non_retryable_error() {
+        let attempts = std::cell::Cell::new(0u32);
+
+        let result: Result<(), &str> = retry_on_failure(
+            |err: &&str| *err == "retryable",
+            || {
+                attempts.set(attempts.get() + 1);
+                async { Err::<(), _>("fatal") }
+            },
+        )
+        .await;
+
+        assert_eq!(result, Err("fatal"));
+        assert_eq!(attempts.get(), 1);
+    }
+}
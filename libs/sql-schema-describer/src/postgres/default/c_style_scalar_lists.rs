@@ -86,6 +86,74 @@ fn parse_literal(s: &str, tpe: &ColumnType) -> Option<PrismaValue> {
         ColumnTypeFamily::DateTime
         | ColumnTypeFamily::Binary
         | ColumnTypeFamily::Uuid
+        | ColumnTypeFamily::Set(_)
         | ColumnTypeFamily::Unsupported(_) => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ColumnArity;
+    use expect_test::expect;
+
+    #[test]
+    fn parse_int_array_literal() {
+        let tpe = ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::List);
+        let out = parse_array_literal("{ 9, 12999, -4, 0, 1249849 }", &tpe);
+
+        let expected = expect![[r#"
+            Some(
+                [
+                    Int(
+                        9,
+                    ),
+                    Int(
+                        12999,
+                    ),
+                    Int(
+                        -4,
+                    ),
+                    Int(
+                        0,
+                    ),
+                    Int(
+                        1249849,
+                    ),
+                ],
+            )
+        "#]];
+
+        expected.assert_debug_eq(&out);
+    }
+
+    #[test]
+    fn parse_empty_array_literal() {
+        let tpe = ColumnType::pure(ColumnTypeFamily::String, ColumnArity::List);
+        let out = parse_array_literal("{}", &tpe);
+
+        assert_eq!(out, Some(Vec::new()));
+    }
+
+    #[test]
+    fn parse_quoted_string_array_literal() {
+        let tpe = ColumnType::pure(ColumnTypeFamily::String, ColumnArity::List);
+        let out = parse_array_literal(r#"{ "abc", "def" }"#, &tpe).unwrap();
+
+        assert_eq!(out, vec![PrismaValue::String("abc".to_owned()), PrismaValue::String("def".to_owned())]);
+    }
+
+    #[test]
+    fn parse_missing_closing_brace_returns_none() {
+        let tpe = ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::List);
+        assert_eq!(parse_array_literal("{ 1, 2", &tpe), None);
+    }
+
+    #[test]
+    fn parse_datetime_array_literal_falls_back_to_none() {
+        // DateTime elements are not parsed into structured values; callers fall back to
+        // `db_generated` for the whole default in that case.
+        let tpe = ColumnType::pure(ColumnTypeFamily::DateTime, ColumnArity::List);
+        assert_eq!(parse_array_literal("{ 2022-09-01T08:00Z }", &tpe), None);
+    }
+}
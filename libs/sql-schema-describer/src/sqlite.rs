@@ -2,16 +2,17 @@
 
 use crate::{
     getters::Getter, ids::*, parsers::Parser, Column, ColumnArity, ColumnType, ColumnTypeFamily, DefaultValue,
-    DescriberResult, ForeignKey, ForeignKeyAction, Index, IndexColumn, IndexType, Lazy, PrimaryKey, PrimaryKeyColumn,
-    PrismaValue, Regex, SQLSortOrder, SqlMetadata, SqlSchema, SqlSchemaDescriberBackend, Table, View,
+    DescribeOptions, DescriberResult, ForeignKey, ForeignKeyAction, Index, IndexColumn, IndexType, Lazy, PrimaryKey,
+    PrimaryKeyColumn, PrismaValue, Regex, SQLSortOrder, SqlMetadata, SqlSchema, SqlSchemaDescriberBackend, Table, View,
 };
 use indexmap::IndexMap;
-use quaint::{ast::Value, prelude::Queryable};
+use quaint::{ast::Value, connector::ResultRow, prelude::Queryable};
 use std::{any::type_name, borrow::Cow, collections::BTreeMap, convert::TryInto, fmt::Debug, path::Path};
 use tracing::trace;
 
 pub struct SqlSchemaDescriber<'a> {
     conn: &'a dyn Queryable,
+    lenient_types: bool,
 }
 
 impl Debug for SqlSchemaDescriber<'_> {
@@ -28,7 +29,10 @@ impl SqlSchemaDescriberBackend for SqlSchemaDescriber<'_> {
 
     async fn get_metadata(&self, _schema: &str) -> DescriberResult<SqlMetadata> {
         let mut sql_schema = SqlSchema::default();
-        let table_count = self.get_table_names(&mut sql_schema).await?.len();
+        let table_count = self
+            .get_table_names(&mut sql_schema, &DescribeOptions::default())
+            .await?
+            .len();
         let size_in_bytes = self.get_size().await?;
 
         Ok(SqlMetadata {
@@ -37,12 +41,17 @@ impl SqlSchemaDescriberBackend for SqlSchemaDescriber<'_> {
         })
     }
 
-    async fn describe(&self, _schema: &str) -> DescriberResult<SqlSchema> {
+    async fn describe_with_options(&self, _schema: &str, options: &DescribeOptions) -> DescriberResult<SqlSchema> {
         let mut schema = SqlSchema::default();
-        let table_ids = self.get_table_names(&mut schema).await?;
+        let table_ids = self.get_table_names(&mut schema, options).await?;
 
-        for (table_name, table_id) in &table_ids {
-            self.get_table(table_name, *table_id, &table_ids, &mut schema).await?
+        if self.supports_table_valued_pragmas().await? {
+            self.get_all_tables(&table_ids, &mut schema, options).await?;
+        } else {
+            for (table_name, table_id) in &table_ids {
+                self.get_table(table_name, *table_id, &table_ids, &mut schema, options)
+                    .await?
+            }
         }
 
         // SQLite allows foreign key definitions without specifying the referenced columns, it then
@@ -73,6 +82,19 @@ impl SqlSchemaDescriberBackend for SqlSchemaDescriber<'_> {
             .foreign_keys
             .sort_by_cached_key(|(id, fk)| (*id, fk.columns.to_owned()));
 
+        // Before any reordering, while `table_ids` still matches the schema's table ids.
+        if options.include_row_count_estimates {
+            self.get_row_count_estimates(&table_ids, &mut schema).await?;
+        }
+
+        if options.dependency_order {
+            schema.sort_tables_by_dependency_order();
+        }
+
+        if options.fail_on_unsupported {
+            schema.error_on_unsupported_columns()?;
+        }
+
         Ok(schema)
     }
 
@@ -86,7 +108,16 @@ impl Parser for SqlSchemaDescriber<'_> {}
 impl<'a> SqlSchemaDescriber<'a> {
     /// Constructor.
     pub fn new(conn: &'a dyn Queryable) -> SqlSchemaDescriber<'a> {
-        SqlSchemaDescriber { conn }
+        SqlSchemaDescriber {
+            conn,
+            lenient_types: false,
+        }
+    }
+
+    /// Map unrecognized column types to `String` instead of `Unsupported(...)`.
+    pub fn with_lenient_types(mut self, lenient_types: bool) -> Self {
+        self.lenient_types = lenient_types;
+        self
     }
 
     async fn get_databases(&self) -> DescriberResult<Vec<String>> {
@@ -111,10 +142,17 @@ impl<'a> SqlSchemaDescriber<'a> {
         Ok(names)
     }
 
-    async fn get_table_names(&self, schema: &mut SqlSchema) -> DescriberResult<IndexMap<String, TableId>> {
-        let sql = r#"SELECT name FROM sqlite_master WHERE type='table' ORDER BY name ASC"#;
+    async fn get_table_names(
+        &self,
+        schema: &mut SqlSchema,
+        options: &DescribeOptions,
+    ) -> DescriberResult<IndexMap<String, TableId>> {
+        let sql = format!(
+            "SELECT name FROM {} WHERE type='table' ORDER BY name ASC",
+            master_tables_source(options.include_temporary_tables)
+        );
 
-        let result_set = self.conn.query_raw(sql, &[]).await?;
+        let result_set = self.conn.query_raw(&sql, &[]).await?;
 
         let names = result_set
             .into_iter()
@@ -132,6 +170,27 @@ impl<'a> SqlSchemaDescriber<'a> {
         Ok(map)
     }
 
+    async fn get_row_count_estimates(
+        &self,
+        table_ids: &IndexMap<String, TableId>,
+        schema: &mut SqlSchema,
+    ) -> DescriberResult<()> {
+        for (table_name, table_id) in table_ids {
+            let sql = format!(r#"SELECT COUNT(*) AS count FROM "{}""#, table_name);
+            let count = self
+                .conn
+                .query_raw(&sql, &[])
+                .await?
+                .into_iter()
+                .next()
+                .and_then(|row| row.get_i64("count"));
+
+            schema[*table_id].row_count_estimate = count;
+        }
+
+        Ok(())
+    }
+
     async fn get_size(&self) -> DescriberResult<usize> {
         let sql = r#"SELECT page_count * page_size as size FROM pragma_page_count(), pragma_page_size();"#;
         let result = self.conn.query_raw(sql, &[]).await?;
@@ -149,14 +208,17 @@ impl<'a> SqlSchemaDescriber<'a> {
         table_id: TableId,
         table_ids: &IndexMap<String, TableId>,
         schema: &mut SqlSchema,
+        options: &DescribeOptions,
     ) -> DescriberResult<()> {
-        let (table_columns, primary_key) = self.get_columns(name).await?;
+        let (table_columns, primary_key) = self.get_columns(name, options).await?;
         let indices = self.get_indices(name).await?;
 
         schema[table_id] = Table {
             name: name.to_owned(),
             indices,
             primary_key,
+            comment: None,
+            row_count_estimate: None,
         };
 
         for col in table_columns {
@@ -177,17 +239,26 @@ impl<'a> SqlSchemaDescriber<'a> {
             views.push(View {
                 name: row.get_expect_string("view_name"),
                 definition: row.get_string("view_sql"),
+                is_materialized: false,
             })
         }
 
         Ok(views)
     }
 
-    async fn get_columns(&self, table: &str) -> DescriberResult<(Vec<Column>, Option<PrimaryKey>)> {
+    async fn get_columns(
+        &self,
+        table: &str,
+        options: &DescribeOptions,
+    ) -> DescriberResult<(Vec<Column>, Option<PrimaryKey>)> {
         let sql = format!(r#"PRAGMA table_info ("{}")"#, table);
         let result_set = self.conn.query_raw(&sql, &[]).await?;
+        Ok(self.columns_from_rows(result_set.into_iter().collect(), options))
+    }
+
+    fn columns_from_rows(&self, rows: Vec<ResultRow>, options: &DescribeOptions) -> (Vec<Column>, Option<PrimaryKey>) {
         let mut pk_cols: BTreeMap<i64, String> = BTreeMap::new();
-        let mut cols: Vec<Column> = result_set
+        let mut cols: Vec<Column> = rows
             .into_iter()
             .map(|row| {
                 trace!("Got column row {:?}", row);
@@ -199,7 +270,11 @@ impl<'a> SqlSchemaDescriber<'a> {
                     ColumnArity::Nullable
                 };
 
-                let tpe = get_column_type(&row.get("type").and_then(|x| x.to_string()).expect("type"), arity);
+                let tpe = get_column_type(
+                    &row.get("type").and_then(|x| x.to_string()).expect("type"),
+                    arity,
+                    self.lenient_types || options.lenient_types,
+                );
 
                 let default = match row.get("dflt_value") {
                     None => None,
@@ -248,6 +323,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                                 ColumnTypeFamily::Json => DefaultValue::db_generated(default_string),
                                 ColumnTypeFamily::Uuid => DefaultValue::db_generated(default_string),
                                 ColumnTypeFamily::Enum(_) => DefaultValue::value(PrismaValue::Enum(default_string)),
+                                ColumnTypeFamily::Set(_) => DefaultValue::db_generated(default_string),
                                 ColumnTypeFamily::Unsupported(_) => DefaultValue::db_generated(default_string),
                             })
                         }
@@ -262,6 +338,9 @@ impl<'a> SqlSchemaDescriber<'a> {
                     tpe,
                     default,
                     auto_increment: false,
+                    is_identity: false,
+                    comment: None,
+                    generated: None,
                 };
 
                 if pk_col > 0 {
@@ -317,7 +396,7 @@ impl<'a> SqlSchemaDescriber<'a> {
             })
         };
 
-        Ok((cols, primary_key))
+        (cols, primary_key)
     }
 
     async fn push_foreign_keys(
@@ -327,6 +406,22 @@ impl<'a> SqlSchemaDescriber<'a> {
         table_ids: &IndexMap<String, TableId>,
         schema: &mut SqlSchema,
     ) -> DescriberResult<()> {
+        let sql = format!(r#"PRAGMA foreign_key_list("{}");"#, table_name);
+        trace!("describing table foreign keys, SQL: '{}'", sql);
+        let result_set = self.conn.query_raw(&sql, &[]).await.expect("querying for foreign keys");
+
+        self.push_foreign_keys_from_rows(result_set.into_iter().collect(), table_id, table_ids, schema);
+
+        Ok(())
+    }
+
+    fn push_foreign_keys_from_rows(
+        &self,
+        rows: Vec<ResultRow>,
+        table_id: TableId,
+        table_ids: &IndexMap<String, TableId>,
+        schema: &mut SqlSchema,
+    ) {
         struct IntermediateForeignKey {
             pub columns: BTreeMap<i64, String>,
             pub referenced_table: TableId,
@@ -335,15 +430,11 @@ impl<'a> SqlSchemaDescriber<'a> {
             pub on_update_action: ForeignKeyAction,
         }
 
-        let sql = format!(r#"PRAGMA foreign_key_list("{}");"#, table_name);
-        trace!("describing table foreign keys, SQL: '{}'", sql);
-        let result_set = self.conn.query_raw(&sql, &[]).await.expect("querying for foreign keys");
-
         // Since one foreign key with multiple columns will be represented here as several
         // rows with the same ID, we have to use an intermediate representation that gets
         // translated into the real foreign keys in another pass
         let mut intermediate_fks: BTreeMap<i64, IntermediateForeignKey> = BTreeMap::new();
-        for row in result_set.into_iter() {
+        for row in rows.into_iter() {
             trace!("got FK description row {:?}", row);
             let id = row.get("id").and_then(|x| x.as_integer()).expect("id");
             let seq = row.get("seq").and_then(|x| x.as_integer()).expect("seq");
@@ -438,20 +529,45 @@ impl<'a> SqlSchemaDescriber<'a> {
                 // Not relevant in SQLite since we cannot ALTER or DROP foreign keys by
                 // constraint name.
                 constraint_name: None,
+
+                // SQLite has no equivalent to Postgres' `NOT VALID` constraints.
+                validated: true,
             };
             schema.foreign_keys.push((table_id, fk));
         }
-
-        Ok(())
     }
 
     async fn get_indices(&self, table: &str) -> DescriberResult<Vec<Index>> {
         let sql = format!(r#"PRAGMA index_list("{}");"#, table);
-        let result_set = self.conn.query_raw(&sql, &[]).await?;
-        trace!("Got indices description results: {:?}", result_set);
+        let index_list_rows: Vec<ResultRow> = self.conn.query_raw(&sql, &[]).await?.into_iter().collect();
+        trace!("Got indices description results: {:?}", index_list_rows);
+
+        let mut info_by_index: BTreeMap<String, Vec<ResultRow>> = BTreeMap::new();
+        let mut xinfo_by_index: BTreeMap<String, Vec<ResultRow>> = BTreeMap::new();
 
+        for row in &index_list_rows {
+            let name = row.get("name").and_then(|x| x.to_string()).expect("get name");
+
+            let sql = format!(r#"PRAGMA index_info("{}");"#, name);
+            let rows = self.conn.query_raw(&sql, &[]).await.expect("querying for index info");
+            info_by_index.insert(name.clone(), rows.into_iter().collect());
+
+            let sql = format!(r#"PRAGMA index_xinfo("{}");"#, name);
+            let rows = self.conn.query_raw(&sql, &[]).await.expect("querying for index info");
+            xinfo_by_index.insert(name, rows.into_iter().collect());
+        }
+
+        Ok(self.indices_from_rows(index_list_rows, &info_by_index, &xinfo_by_index))
+    }
+
+    fn indices_from_rows(
+        &self,
+        index_list_rows: Vec<ResultRow>,
+        info_by_index: &BTreeMap<String, Vec<ResultRow>>,
+        xinfo_by_index: &BTreeMap<String, Vec<ResultRow>>,
+    ) -> Vec<Index> {
         let mut indices = Vec::new();
-        let filtered_rows = result_set
+        let filtered_rows = index_list_rows
             .into_iter()
             // Exclude primary keys, they are inferred separately.
             .filter(|row| row.get("origin").and_then(|origin| origin.as_str()).unwrap() != "pk")
@@ -463,6 +579,9 @@ impl<'a> SqlSchemaDescriber<'a> {
 
             let is_unique = row.get("unique").and_then(|x| x.as_bool()).expect("get unique");
             let name = row.get("name").and_then(|x| x.to_string()).expect("get name");
+            // "u" means the index backs a `UNIQUE` constraint that SQLite created on its own
+            // (`sqlite_autoindex_*`); "c" means it was created explicitly with `CREATE INDEX`.
+            let is_autogenerated = row.get("origin").and_then(|origin| origin.as_str()).unwrap() == "u";
             let mut index = Index {
                 name: name.clone(),
                 tpe: match is_unique {
@@ -470,13 +589,10 @@ impl<'a> SqlSchemaDescriber<'a> {
                     false => IndexType::Normal,
                 },
                 columns: vec![],
+                is_autogenerated,
             };
 
-            let sql = format!(r#"PRAGMA index_info("{}");"#, name);
-            let result_set = self.conn.query_raw(&sql, &[]).await.expect("querying for index info");
-            trace!("Got index description results: {:?}", result_set);
-
-            for row in result_set.into_iter() {
+            for row in info_by_index.get(&name).into_iter().flatten() {
                 //if the index is on a rowid or expression, the name of the column will be null, we ignore these for now
                 match row.get("name").and_then(|x| x.to_string()) {
                     Some(name) => {
@@ -490,11 +606,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                 }
             }
 
-            let sql = format!(r#"PRAGMA index_xinfo("{}");"#, name);
-            let result_set = self.conn.query_raw(&sql, &[]).await.expect("querying for index info");
-            trace!("Got index description results: {:?}", result_set);
-
-            for row in result_set.into_iter() {
+            for row in xinfo_by_index.get(&name).into_iter().flatten() {
                 //if the index is on a rowid or expression, the name of the column will be null, we ignore these for now
                 if row.get("name").and_then(|x| x.to_string()).is_some() {
                     let pos = row.get("seqno").and_then(|x| x.as_integer()).expect("get seqno") as usize;
@@ -513,11 +625,165 @@ impl<'a> SqlSchemaDescriber<'a> {
             }
         }
 
-        Ok(indices)
+        indices
+    }
+
+    /// Returns `true` if the connected SQLite version has table-valued pragma functions
+    /// (`pragma_table_info()` and friends) available, which lets us batch the per-table
+    /// PRAGMA calls into a handful of bulk queries instead of one round trip per table.
+    async fn supports_table_valued_pragmas(&self) -> DescriberResult<bool> {
+        let version = self.conn.version().await?;
+
+        // Table-valued pragma functions have been available since SQLite 3.16.0. If we
+        // can't determine the version, assume a modern SQLite rather than falling back
+        // to the slow path unnecessarily.
+        Ok(match version.as_deref().and_then(Self::parse_sqlite_version) {
+            Some(version) => version >= (3, 16, 0),
+            None => true,
+        })
+    }
+
+    fn parse_sqlite_version(version: &str) -> Option<(u32, u32, u32)> {
+        let mut parts = version.trim().split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next().unwrap_or("0").parse().ok()?;
+        let patch = parts.next().unwrap_or("0").parse().ok()?;
+        Some((major, minor, patch))
+    }
+
+    /// Describes all tables in a handful of bulk queries, joining `sqlite_master` against
+    /// the table-valued pragma functions instead of issuing PRAGMA calls per table.
+    async fn get_all_tables(
+        &self,
+        table_ids: &IndexMap<String, TableId>,
+        schema: &mut SqlSchema,
+        options: &DescribeOptions,
+    ) -> DescriberResult<()> {
+        let mut columns_by_table = self.get_all_columns_grouped(options).await?;
+        let mut fks_by_table = self.get_all_foreign_keys_grouped(options).await?;
+        let (mut index_list_by_table, info_by_index, xinfo_by_index) =
+            self.get_all_indices_grouped(options).await?;
+
+        for (table_name, table_id) in table_ids {
+            let (columns, primary_key) = columns_by_table
+                .remove(table_name)
+                .map(|rows| self.columns_from_rows(rows, options))
+                .unwrap_or_default();
+
+            let indices = index_list_by_table
+                .remove(table_name)
+                .map(|rows| self.indices_from_rows(rows, &info_by_index, &xinfo_by_index))
+                .unwrap_or_default();
+
+            schema[*table_id] = Table {
+                name: table_name.clone(),
+                indices,
+                primary_key,
+                comment: None,
+                row_count_estimate: None,
+            };
+
+            for col in columns {
+                schema.columns.push((*table_id, col));
+            }
+
+            if let Some(rows) = fks_by_table.remove(table_name) {
+                self.push_foreign_keys_from_rows(rows, *table_id, table_ids, schema);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn get_all_columns_grouped(&self, options: &DescribeOptions) -> DescriberResult<BTreeMap<String, Vec<ResultRow>>> {
+        let sql = format!(
+            r#"
+            SELECT sm.name AS table_name, ti.*
+            FROM {} sm, pragma_table_info(sm.name) ti
+            WHERE sm.type = 'table'
+            ORDER BY sm.name, ti.cid
+        "#,
+            master_tables_source(options.include_temporary_tables)
+        );
+        let result_set = self.conn.query_raw(&sql, &[]).await?;
+        Ok(Self::group_rows_by(result_set, "table_name"))
+    }
+
+    async fn get_all_foreign_keys_grouped(
+        &self,
+        options: &DescribeOptions,
+    ) -> DescriberResult<BTreeMap<String, Vec<ResultRow>>> {
+        let sql = format!(
+            r#"
+            SELECT sm.name AS table_name, fk.*
+            FROM {} sm, pragma_foreign_key_list(sm.name) fk
+            WHERE sm.type = 'table'
+        "#,
+            master_tables_source(options.include_temporary_tables)
+        );
+        let result_set = self.conn.query_raw(&sql, &[]).await?;
+        Ok(Self::group_rows_by(result_set, "table_name"))
+    }
+
+    #[allow(clippy::type_complexity)]
+    async fn get_all_indices_grouped(
+        &self,
+        options: &DescribeOptions,
+    ) -> DescriberResult<(
+        BTreeMap<String, Vec<ResultRow>>,
+        BTreeMap<String, Vec<ResultRow>>,
+        BTreeMap<String, Vec<ResultRow>>,
+    )> {
+        let master = master_tables_source(options.include_temporary_tables);
+        let list_sql = format!(
+            r#"
+            SELECT sm.name AS table_name, il.*
+            FROM {} sm, pragma_index_list(sm.name) il
+            WHERE sm.type = 'table'
+        "#,
+            master
+        );
+        let info_sql = format!(
+            r#"
+            SELECT il.name AS index_name, ii.*
+            FROM {} sm, pragma_index_list(sm.name) il, pragma_index_info(il.name) ii
+            WHERE sm.type = 'table'
+        "#,
+            master
+        );
+        let xinfo_sql = format!(
+            r#"
+            SELECT il.name AS index_name, xi.*
+            FROM {} sm, pragma_index_list(sm.name) il, pragma_index_xinfo(il.name) xi
+            WHERE sm.type = 'table'
+        "#,
+            master
+        );
+
+        let list_rows = self.conn.query_raw(&list_sql, &[]).await?;
+        let info_rows = self.conn.query_raw(&info_sql, &[]).await?;
+        let xinfo_rows = self.conn.query_raw(&xinfo_sql, &[]).await?;
+
+        Ok((
+            Self::group_rows_by(list_rows, "table_name"),
+            Self::group_rows_by(info_rows, "index_name"),
+            Self::group_rows_by(xinfo_rows, "index_name"),
+        ))
+    }
+
+    fn group_rows_by(rows: impl IntoIterator<Item = ResultRow>, key: &str) -> BTreeMap<String, Vec<ResultRow>> {
+        let mut map: BTreeMap<String, Vec<ResultRow>> = BTreeMap::new();
+
+        for row in rows {
+            let group = row.get(key).and_then(|x| x.to_string()).expect("group_rows_by key");
+            map.entry(group).or_default().push(row);
+        }
+
+        map
     }
 }
 
-fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
+fn get_column_type(tpe: &str, arity: ColumnArity, lenient_types: bool) -> ColumnType {
     let tpe_lower = tpe.to_lowercase();
 
     let family = match tpe_lower.as_ref() {
@@ -551,6 +817,7 @@ fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
         "text[]" => ColumnTypeFamily::String,
         // NUMERIC type affinity
         data_type if data_type.starts_with("decimal") => ColumnTypeFamily::Decimal,
+        _ if lenient_types => ColumnTypeFamily::String,
         data_type => ColumnTypeFamily::Unsupported(data_type.into()),
     };
     ColumnType {
@@ -583,6 +850,18 @@ fn is_system_table(table_name: &str) -> bool {
         .any(|system_table| table_name == *system_table)
 }
 
+/// The `sqlite_master` table only lists tables in the main (and attached) schemas. Temporary
+/// tables live in the separate, connection-local `sqlite_temp_master` table, so opting into
+/// `DescribeOptions::include_temporary_tables` requires unioning both in every query that lists
+/// tables by joining against `sqlite_master`.
+fn master_tables_source(include_temporary_tables: bool) -> &'static str {
+    if include_temporary_tables {
+        "(SELECT name, type FROM sqlite_master UNION ALL SELECT name, type FROM sqlite_temp_master)"
+    } else {
+        "sqlite_master"
+    }
+}
+
 /// See https://www.sqlite.org/fileformat2.html
 const SQLITE_SYSTEM_TABLES: &[&str] = &[
     "sqlite_sequence",
@@ -3,8 +3,9 @@
 #![deny(missing_docs)]
 
 use crate::{
-    ids::*, Column, ColumnArity, ColumnType, ColumnTypeFamily, DefaultValue, Enum, ForeignKey, ForeignKeyAction, Index,
-    IndexColumn, IndexType, PrimaryKey, PrimaryKeyColumn, SQLSortOrder, SqlSchema, Table, UserDefinedType, View,
+    ids::*, CheckConstraint, Column, ColumnArity, ColumnType, ColumnTypeFamily, DefaultValue, Enum, ForeignKey,
+    ForeignKeyAction, Index, IndexColumn, IndexType, PrimaryKey, PrimaryKeyColumn, SQLSortOrder, SqlSchema, Table,
+    UserDefinedType, View,
 };
 use serde::de::DeserializeOwned;
 use std::ops::Range;
@@ -40,6 +41,9 @@ impl<'a, Id> Walker<'a, Id> {
 /// Traverse a foreign key.
 pub type ForeignKeyWalker<'a> = Walker<'a, ForeignKeyId>;
 
+/// Traverse a CHECK constraint.
+pub type CheckConstraintWalker<'a> = Walker<'a, CheckConstraintId>;
+
 /// Traverse column.
 pub type ColumnWalker<'a> = Walker<'a, ColumnId>;
 
@@ -109,6 +113,11 @@ impl<'a> ColumnWalker<'a> {
         self.column().default.as_ref()
     }
 
+    /// The comment on the column (e.g. `COMMENT ON COLUMN`), if the describer captured one.
+    pub fn comment(&self) -> Option<&'a str> {
+        self.column().comment.as_deref()
+    }
+
     /// The full column type.
     pub fn column_type(self) -> &'a ColumnType {
         &self.column().tpe
@@ -138,6 +147,13 @@ impl<'a> ColumnWalker<'a> {
         table.indexes().any(|idx| idx.contains_column(name))
     }
 
+    /// Is this column part of a unique index?
+    pub fn is_part_of_unique_index(&self) -> bool {
+        let table = self.table();
+        let name = self.name();
+        table.indexes().any(|idx| idx.is_unique() && idx.contains_column(name))
+    }
+
     /// Is this column a part of the table's primary key?
     pub fn is_part_of_primary_key(&self) -> bool {
         match self.table().primary_key() {
@@ -201,6 +217,11 @@ impl<'a> ViewWalker<'a> {
         self.view().definition.as_deref()
     }
 
+    /// True if this is a materialized view (Postgres only).
+    pub fn is_materialized(&self) -> bool {
+        self.view().is_materialized
+    }
+
     /// The index of the view in the schema.
     pub fn view_index(&self) -> usize {
         self.view_index
@@ -289,6 +310,16 @@ impl<'a> TableWalker<'a> {
             .map(move |id| self.jump(ForeignKeyId(id as u32)))
     }
 
+    /// Traverse the CHECK constraints on the table.
+    pub fn check_constraints(self) -> impl Iterator<Item = CheckConstraintWalker<'a>> {
+        self.check_constraints_range()
+            .map(move |id| self.jump(CheckConstraintId(id as u32)))
+    }
+
+    fn check_constraints_range(self) -> Range<usize> {
+        range_for_key(&self.schema.check_constraints, self.id, |(id, _)| *id)
+    }
+
     /// Traverse foreign keys from other tables, referencing current table.
     pub fn referencing_foreign_keys(self) -> impl Iterator<Item = ForeignKeyWalker<'a>> {
         let table_id = self.id;
@@ -304,6 +335,17 @@ impl<'a> TableWalker<'a> {
         &self.table().name
     }
 
+    /// The approximate row count for the table, if it was fetched (see
+    /// [`crate::DescribeOptions::include_row_count_estimates`]).
+    pub fn row_count_estimate(self) -> Option<i64> {
+        self.table().row_count_estimate
+    }
+
+    /// The comment on the table (e.g. `COMMENT ON TABLE`), if the describer captured one.
+    pub fn comment(self) -> Option<&'a str> {
+        self.table().comment.as_deref()
+    }
+
     fn foreign_keys_range(self) -> Range<usize> {
         range_for_key(&self.schema.foreign_keys, self.id, |(id, _)| *id)
     }
@@ -461,6 +503,29 @@ impl<'schema> ForeignKeyWalker<'schema> {
     }
 }
 
+impl<'schema> CheckConstraintWalker<'schema> {
+    /// The underlying CheckConstraint struct.
+    pub fn check_constraint(&self) -> &'schema CheckConstraint {
+        &self.schema[self.id].1
+    }
+
+    /// The name of the CHECK constraint.
+    pub fn name(&self) -> &'schema str {
+        &self.check_constraint().name
+    }
+
+    /// The constraint's expression, normalized to the boolean expression itself, without the
+    /// surrounding `CHECK (...)`.
+    pub fn expression(&self) -> &'schema str {
+        &self.check_constraint().expression
+    }
+
+    /// Traverse to the table the CHECK constraint is defined on.
+    pub fn table(&self) -> TableWalker<'schema> {
+        self.jump(self.schema[self.id].0)
+    }
+}
+
 /// Traverse an index column.
 #[derive(Clone, Copy)]
 pub struct IndexColumnWalker<'a> {
@@ -485,6 +550,18 @@ impl<'a> IndexColumnWalker<'a> {
         self.get().sort_order
     }
 
+    /// True if this entry in the index is an expression (e.g. `lower(email)`) rather than a plain
+    /// column reference. Only possible on Postgres.
+    pub fn is_expression(self) -> bool {
+        self.get().expression.is_some()
+    }
+
+    /// The expression text, if this entry in the index is an expression rather than a plain
+    /// column reference.
+    pub fn expression(self) -> Option<&'a str> {
+        self.get().expression.as_deref()
+    }
+
     /// The table where the column is located.
     pub fn table(&self) -> TableWalker<'a> {
         TableWalker {
@@ -519,6 +596,13 @@ impl<'a> IndexColumnWalker<'a> {
     pub fn index_field_id(&self) -> IndexFieldId {
         IndexFieldId(self.index().id, self.index_column_id as u32)
     }
+
+    /// True if this column is `INCLUDE`d in a covering index rather than part of its key
+    /// (`CREATE INDEX ... INCLUDE (a, b)`). Only possible on Postgres.
+    pub fn is_included(&self) -> bool {
+        let ext: &crate::postgres::PostgresSchemaExt = self.schema.downcast_connector_data().unwrap_or_default();
+        ext.field_is_included(self.index_field_id())
+    }
 }
 
 impl<'a> IndexWalker<'a> {
@@ -545,6 +629,12 @@ impl<'a> IndexWalker<'a> {
         self.get().columns.iter().any(|column| column.name() == column_name)
     }
 
+    /// Traverse the columns that are `INCLUDE`d in this covering index rather than part of its
+    /// key. Only possible on Postgres.
+    pub fn non_key_columns<'b>(&'b self) -> impl Iterator<Item = IndexColumnWalker<'a>> + 'b {
+        self.columns().filter(|column| column.is_included())
+    }
+
     fn get(&self) -> &'a Index {
         &self.table().table().indices[self.id.1 as usize]
     }
@@ -554,6 +644,17 @@ impl<'a> IndexWalker<'a> {
         self.get().tpe
     }
 
+    /// True if the index is a unique index.
+    pub fn is_unique(&self) -> bool {
+        self.index_type().is_unique()
+    }
+
+    /// True if the index was created automatically by the database to back a constraint (e.g. a
+    /// SQLite `sqlite_autoindex_*` index for a `UNIQUE` column) rather than requested explicitly.
+    pub fn is_autogenerated(&self) -> bool {
+        self.get().is_autogenerated
+    }
+
     /// The name of the index.
     pub fn name(&self) -> &'a str {
         &self.get().name
@@ -586,6 +687,9 @@ pub trait SqlSchemaExt {
     /// Find a table by name.
     fn table_walker<'a>(&'a self, name: &str) -> Option<TableWalker<'a>>;
 
+    /// Find a column by table and column name.
+    fn column_walker<'a>(&'a self, table_name: &str, column_name: &str) -> Option<ColumnWalker<'a>>;
+
     /// Find a table by id.
     fn table_walker_at(&self, table_id: TableId) -> TableWalker<'_>;
 
@@ -610,6 +714,10 @@ impl SqlSchemaExt for SqlSchema {
         })
     }
 
+    fn column_walker<'a>(&'a self, table_name: &str, column_name: &str) -> Option<ColumnWalker<'a>> {
+        self.table_walker(table_name)?.column(column_name)
+    }
+
     fn table_walker_at(&self, id: TableId) -> TableWalker<'_> {
         TableWalker { id, schema: self }
     }
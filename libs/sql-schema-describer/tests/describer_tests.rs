@@ -190,3 +190,87 @@ fn composite_primary_keys_must_work(api: TestApi) {
         }
     );
 }
+
+#[test_connector(exclude(Sqlite))]
+fn column_walker_role_flags_must_work(api: TestApi) {
+    let create_city = match api.sql_family() {
+        SqlFamily::Mysql => format!("CREATE TABLE `{}`.`City` (id INTEGER NOT NULL PRIMARY KEY)", api.db_name()),
+        SqlFamily::Mssql => format!("CREATE TABLE [{}].[City] ([id] INT NOT NULL PRIMARY KEY)", api.schema_name()),
+        _ => format!(
+            "CREATE TABLE \"{}\".\"City\" (id INTEGER NOT NULL PRIMARY KEY)",
+            api.schema_name()
+        ),
+    };
+
+    let create_user = match api.sql_family() {
+        SqlFamily::Mysql => format!(
+            "CREATE TABLE `{0}`.`User` (
+                id INTEGER NOT NULL,
+                name VARCHAR(255) NOT NULL,
+                email VARCHAR(255) NOT NULL,
+                city_id INTEGER NOT NULL,
+                bio VARCHAR(255) NOT NULL,
+                PRIMARY KEY (id, name),
+                UNIQUE (email),
+                FOREIGN KEY (city_id) REFERENCES `City`(id)
+            )",
+            api.db_name()
+        ),
+        SqlFamily::Mssql => format!(
+            "CREATE TABLE [{0}].[User] (
+                [id] INT NOT NULL,
+                [name] VARCHAR(255) NOT NULL,
+                [email] VARCHAR(255) NOT NULL,
+                [city_id] INT NOT NULL,
+                [bio] VARCHAR(255) NOT NULL,
+                CONSTRAINT [PK_User] PRIMARY KEY ([id], [name]),
+                CONSTRAINT [UQ_User_email] UNIQUE ([email]),
+                FOREIGN KEY ([city_id]) REFERENCES [{0}].[City]([id])
+            )",
+            api.schema_name()
+        ),
+        _ => format!(
+            "CREATE TABLE \"{0}\".\"User\" (
+                id INTEGER NOT NULL,
+                name VARCHAR(255) NOT NULL,
+                email VARCHAR(255) NOT NULL,
+                city_id INTEGER NOT NULL,
+                bio VARCHAR(255) NOT NULL,
+                PRIMARY KEY (id, name),
+                UNIQUE (email),
+                FOREIGN KEY (city_id) REFERENCES \"{0}\".\"City\"(id)
+            )",
+            api.schema_name()
+        ),
+    };
+
+    api.raw_cmd(&create_city);
+    api.raw_cmd(&create_user);
+
+    let schema = api.describe();
+    let table = schema.table_walkers().find(|t| t.name() == "User").unwrap();
+
+    let column = |name: &str| table.column(name).unwrap();
+
+    // id and name are part of the composite primary key, and nothing else.
+    assert!(column("id").is_part_of_primary_key());
+    assert!(!column("id").is_part_of_foreign_key());
+    assert!(!column("id").is_part_of_unique_index());
+
+    assert!(column("name").is_part_of_primary_key());
+
+    // email is uniquely indexed, but not a key or a foreign key.
+    assert!(column("email").is_part_of_unique_index());
+    assert!(!column("email").is_part_of_primary_key());
+    assert!(!column("email").is_part_of_foreign_key());
+
+    // city_id is a foreign key, but not part of the primary key or a unique index.
+    assert!(column("city_id").is_part_of_foreign_key());
+    assert!(!column("city_id").is_part_of_primary_key());
+    assert!(!column("city_id").is_part_of_unique_index());
+
+    // bio does not participate in any of these.
+    assert!(!column("bio").is_part_of_primary_key());
+    assert!(!column("bio").is_part_of_foreign_key());
+    assert!(!column("bio").is_part_of_unique_index());
+}
@@ -166,6 +166,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                             ),
                         },
                     ),
+                    comment: None,
                 },
             ],
             enums: [],
@@ -188,6 +189,9 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -208,6 +212,9 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -235,6 +242,9 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -255,6 +265,9 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -275,6 +288,9 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -302,6 +318,9 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -322,6 +341,9 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -342,6 +364,9 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -362,6 +387,9 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -382,6 +410,9 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -404,6 +435,9 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -424,6 +458,9 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -444,6 +481,9 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -464,6 +504,9 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -484,6 +527,9 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -504,6 +550,9 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -524,6 +573,9 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -546,6 +598,9 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -570,6 +625,9 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -592,6 +650,9 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -612,6 +673,9 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -636,6 +700,9 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -658,6 +725,9 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -678,6 +748,9 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -700,6 +773,9 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -724,6 +800,9 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -746,6 +825,9 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -766,6 +848,9 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -786,10 +871,14 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
             ],
             foreign_keys: [],
+            check_constraints: [],
             views: [],
             procedures: [],
             user_defined_types: [],
@@ -930,6 +1019,7 @@ fn mssql_foreign_key_on_delete_must_be_handled(api: TestApi) {
                             ),
                         },
                     ),
+                    comment: None,
                 },
                 Table {
                     name: "User",
@@ -950,6 +1040,7 @@ fn mssql_foreign_key_on_delete_must_be_handled(api: TestApi) {
                             ),
                         },
                     ),
+                    comment: None,
                 },
             ],
             enums: [],
@@ -972,6 +1063,9 @@ fn mssql_foreign_key_on_delete_must_be_handled(api: TestApi) {
                         },
                         default: None,
                         auto_increment: true,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -992,6 +1086,9 @@ fn mssql_foreign_key_on_delete_must_be_handled(api: TestApi) {
                         },
                         default: None,
                         auto_increment: true,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1012,6 +1109,9 @@ fn mssql_foreign_key_on_delete_must_be_handled(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1032,6 +1132,9 @@ fn mssql_foreign_key_on_delete_must_be_handled(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
             ],
@@ -1055,6 +1158,7 @@ fn mssql_foreign_key_on_delete_must_be_handled(api: TestApi) {
                         ],
                         on_delete_action: NoAction,
                         on_update_action: NoAction,
+                        validated: true,
                     },
                 ),
                 (
@@ -1076,9 +1180,11 @@ fn mssql_foreign_key_on_delete_must_be_handled(api: TestApi) {
                         ],
                         on_delete_action: Cascade,
                         on_update_action: NoAction,
+                        validated: true,
                     },
                 ),
             ],
+            check_constraints: [],
             views: [],
             procedures: [],
             user_defined_types: [],
@@ -1122,6 +1228,7 @@ fn mssql_multi_field_indexes_must_be_inferred(api: TestApi) {
             name: "age_and_name_index".into(),
             columns,
             tpe: IndexType::Unique,
+            is_autogenerated: false,
         }]
     );
 }
@@ -1171,6 +1278,91 @@ fn mssql_join_table_unique_indexes_must_be_inferred(api: TestApi) {
             name: "cat_and_human_index".into(),
             columns,
             tpe: IndexType::Unique,
+            is_autogenerated: false,
         }]
     );
 }
+
+#[test_connector(tags(Mssql))]
+fn mssql_identity_seed_and_increment_are_described(api: TestApi) {
+    let sql = formatdoc! {r#"
+        CREATE TABLE [{}].[Order] (
+            id INT NOT NULL IDENTITY(100,10),
+            CONSTRAINT [PK__Order] PRIMARY KEY ([id])
+        );
+    "#, api.schema_name()};
+
+    api.raw_cmd(&sql);
+    api.raw_cmd(&format!(
+        "SET IDENTITY_INSERT [{}].[Order] ON; INSERT INTO [{0}].[Order] (id) VALUES (150); SET IDENTITY_INSERT [{0}].[Order] OFF;",
+        api.schema_name()
+    ));
+
+    let schema = api.describe();
+    let mssql_ext: &sql_schema_describer::mssql::MssqlSchemaExt =
+        schema.downcast_connector_data().unwrap_or_default();
+
+    let table_id = schema.table_bang("Order").0;
+    let (column_id, _) = schema.find_column(table_id, "id").unwrap();
+
+    let identity = mssql_ext.get_identity(column_id).unwrap();
+
+    assert_eq!(identity.seed, 100);
+    assert_eq!(identity.increment, 10);
+    assert_eq!(identity.current_value, Some(150));
+}
+
+#[test_connector(tags(Mssql))]
+fn table_level_check_constraints_are_captured(api: TestApi) {
+    let sql = formatdoc! {r#"
+        CREATE TABLE [{schema}].[products] (
+            id INT NOT NULL PRIMARY KEY,
+            price INT NOT NULL,
+            discounted_price INT NOT NULL,
+            CONSTRAINT prices_positive CHECK (price > 0 AND discounted_price > 0),
+            CONSTRAINT discount_is_smaller CHECK (discounted_price < price)
+        );
+    "#, schema = api.schema_name()};
+
+    api.raw_cmd(&sql);
+
+    let schema = api.describe();
+    let table = schema.table_walkers().find(|t| t.name() == "products").unwrap();
+
+    let mut checks: Vec<(&str, &str)> = table
+        .check_constraints()
+        .map(|check| (check.name(), check.expression()))
+        .collect();
+    checks.sort();
+
+    assert_eq!(
+        checks,
+        &[
+            ("discount_is_smaller", "[discounted_price]<[price]"),
+            ("prices_positive", "[price]>(0) AND [discounted_price]>(0)"),
+        ]
+    );
+
+    for check in table.check_constraints() {
+        assert_eq!(check.table().id, table.id);
+    }
+}
+
+#[test_connector(tags(Mssql))]
+fn column_level_check_constraints_are_captured(api: TestApi) {
+    let sql = formatdoc! {r#"
+        CREATE TABLE [{schema}].[users] (
+            id INT NOT NULL PRIMARY KEY,
+            age INT CHECK (age >= 0)
+        );
+    "#, schema = api.schema_name()};
+
+    api.raw_cmd(&sql);
+
+    let schema = api.describe();
+    let table = schema.table_walkers().find(|t| t.name() == "users").unwrap();
+
+    let check = table.check_constraints().next().unwrap();
+
+    assert_eq!(check.expression(), "[age]>=(0)");
+}
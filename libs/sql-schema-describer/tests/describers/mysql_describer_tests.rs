@@ -2,7 +2,10 @@ use crate::test_api::*;
 use barrel::{types, Migration};
 use indoc::indoc;
 use pretty_assertions::assert_eq;
-use sql_schema_describer::*;
+use sql_schema_describer::{
+    mysql::{MysqlIndexAlgorithm, MysqlSchemaExt},
+    *,
+};
 
 #[test_connector(tags(Mysql))]
 fn views_can_be_described(api: TestApi) {
@@ -108,6 +111,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                             constraint_name: None,
                         },
                     ),
+                    comment: None,
                 },
             ],
             enums: [
@@ -138,6 +142,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: true,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -158,6 +165,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -178,6 +188,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -198,6 +211,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -218,6 +234,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -238,6 +257,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -258,6 +280,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -285,6 +310,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -312,6 +340,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -332,6 +363,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -352,6 +386,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -372,6 +409,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -394,6 +434,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -416,6 +459,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -443,6 +489,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -463,6 +512,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -485,6 +537,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -507,6 +562,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -527,6 +585,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -547,6 +608,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -567,6 +631,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -587,6 +654,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -605,6 +675,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -615,12 +688,20 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         name: "set_col",
                         tpe: ColumnType {
                             full_data_type: "set('a','b')",
-                            family: String,
+                            family: Set(
+                                [
+                                    "a",
+                                    "b",
+                                ],
+                            ),
                             arity: Required,
                             native_type: None,
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -643,6 +724,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -665,6 +749,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -685,6 +772,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -705,6 +795,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -725,6 +818,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -745,6 +841,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -763,6 +862,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -781,6 +883,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -799,6 +904,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -817,6 +925,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -835,6 +946,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -853,6 +967,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -871,6 +988,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -889,6 +1009,9 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -909,10 +1032,14 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
             ],
             foreign_keys: [],
+            check_constraints: [],
             views: [],
             procedures: [],
             user_defined_types: [],
@@ -987,6 +1114,7 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                             constraint_name: None,
                         },
                     ),
+                    comment: None,
                 },
             ],
             enums: [
@@ -1017,6 +1145,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: true,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1037,6 +1168,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1057,6 +1191,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1077,6 +1214,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1097,6 +1237,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1117,6 +1260,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1137,6 +1283,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1164,6 +1313,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1191,6 +1343,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1211,6 +1366,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1231,6 +1389,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1251,6 +1412,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1273,6 +1437,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1295,6 +1462,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1322,6 +1492,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1342,6 +1515,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1364,6 +1540,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1386,6 +1565,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1406,6 +1588,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1426,6 +1611,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1446,6 +1634,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1466,6 +1657,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1484,6 +1678,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1494,12 +1691,20 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         name: "set_col",
                         tpe: ColumnType {
                             full_data_type: "set('a','b')",
-                            family: String,
+                            family: Set(
+                                [
+                                    "a",
+                                    "b",
+                                ],
+                            ),
                             arity: Required,
                             native_type: None,
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1522,6 +1727,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1544,6 +1752,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1564,6 +1775,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1584,6 +1798,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1604,6 +1821,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1624,6 +1844,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1642,6 +1865,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1660,6 +1886,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1678,6 +1907,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1696,6 +1928,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1714,6 +1949,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1732,6 +1970,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1750,6 +1991,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1768,6 +2012,9 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1788,10 +2035,14 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
             ],
             foreign_keys: [],
+            check_constraints: [],
             views: [],
             procedures: [],
             user_defined_types: [],
@@ -1866,6 +2117,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                             constraint_name: None,
                         },
                     ),
+                    comment: None,
                 },
             ],
             enums: [
@@ -1896,6 +2148,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: true,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1916,6 +2171,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1936,6 +2194,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1956,6 +2217,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1976,6 +2240,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1996,6 +2263,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2016,6 +2286,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2043,6 +2316,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2070,6 +2346,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2090,6 +2369,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2110,6 +2392,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2130,6 +2415,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2152,6 +2440,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2174,6 +2465,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2196,6 +2490,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2216,6 +2513,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2238,6 +2538,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2260,6 +2563,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2280,6 +2586,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2300,6 +2609,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2320,6 +2632,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2340,6 +2655,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2358,6 +2676,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2368,12 +2689,20 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         name: "set_col",
                         tpe: ColumnType {
                             full_data_type: "set('a','b')",
-                            family: String,
+                            family: Set(
+                                [
+                                    "a",
+                                    "b",
+                                ],
+                            ),
                             arity: Required,
                             native_type: None,
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2396,6 +2725,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2418,6 +2750,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2438,6 +2773,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2458,6 +2796,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2478,6 +2819,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2498,6 +2842,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2516,6 +2863,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2534,6 +2884,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2552,6 +2905,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2570,6 +2926,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2588,6 +2947,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2606,6 +2968,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2624,6 +2989,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2642,6 +3010,9 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2662,10 +3033,14 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
             ],
             foreign_keys: [],
+            check_constraints: [],
             views: [],
             procedures: [],
             user_defined_types: [],
@@ -2721,6 +3096,52 @@ fn mysql_foreign_key_on_delete_must_be_handled(api: TestApi) {
     });
 }
 
+#[test_connector(tags(Mysql))]
+fn mysql_foreign_key_on_update_must_be_handled(api: TestApi) {
+    // NB: We don't test the SET DEFAULT variety since it isn't supported on InnoDB and will
+    // just cause an error
+    let sql = format!(
+        "CREATE TABLE `{0}`.City (id INTEGER NOT NULL AUTO_INCREMENT PRIMARY KEY);
+         CREATE TABLE `{0}`.User (
+            id INTEGER NOT NULL AUTO_INCREMENT PRIMARY KEY,
+            city INTEGER, FOREIGN KEY(city) REFERENCES City (id) ON UPDATE NO ACTION,
+            city_cascade INTEGER, FOREIGN KEY(city_cascade) REFERENCES City (id) ON UPDATE CASCADE,
+            city_restrict INTEGER, FOREIGN KEY(city_restrict) REFERENCES City (id) ON UPDATE RESTRICT,
+            city_set_null INTEGER, FOREIGN KEY(city_set_null) REFERENCES City (id) ON UPDATE SET NULL
+        )",
+        api.db_name()
+    );
+    api.raw_cmd(&sql);
+
+    api.describe().assert_table("User", |t| {
+        t.assert_column("id", |id| id.assert_type_is_int())
+            .assert_column("city", |c| c.assert_type_is_int())
+            .assert_column("city_cascade", |c| c.assert_type_is_int())
+            .assert_column("city_restrict", |c| c.assert_type_is_int())
+            .assert_column("city_set_null", |c| c.assert_type_is_int())
+            .assert_index_on_columns(&["city"], |idx| idx.assert_is_not_unique())
+            .assert_index_on_columns(&["city_cascade"], |idx| idx.assert_is_not_unique())
+            .assert_index_on_columns(&["city_restrict"], |idx| idx.assert_is_not_unique())
+            .assert_index_on_columns(&["city_set_null"], |idx| idx.assert_is_not_unique())
+            .assert_foreign_key_on_columns(&["city"], |fk| {
+                fk.assert_references("City", &["id"])
+                    .assert_on_update(ForeignKeyAction::NoAction)
+            })
+            .assert_foreign_key_on_columns(&["city_cascade"], |fk| {
+                fk.assert_references("City", &["id"])
+                    .assert_on_update(ForeignKeyAction::Cascade)
+            })
+            .assert_foreign_key_on_columns(&["city_restrict"], |fk| {
+                fk.assert_references("City", &["id"])
+                    .assert_on_update(ForeignKeyAction::Restrict)
+            })
+            .assert_foreign_key_on_columns(&["city_set_null"], |fk| {
+                fk.assert_references("City", &["id"])
+                    .assert_on_update(ForeignKeyAction::SetNull)
+            })
+    });
+}
+
 #[test_connector(tags(Mysql8))]
 fn mysql_multi_field_indexes_must_be_inferred(api: TestApi) {
     let mut migration = Migration::new().schema(api.db_name());
@@ -2755,6 +3176,7 @@ fn mysql_multi_field_indexes_must_be_inferred(api: TestApi) {
             name: "age_and_name_index".into(),
             columns,
             tpe: IndexType::Unique,
+            is_autogenerated: false,
         }]
     );
 }
@@ -2793,6 +3215,7 @@ fn old_mysql_multi_field_indexes_must_be_inferred(api: TestApi) {
             name: "age_and_name_index".into(),
             columns,
             tpe: IndexType::Unique,
+            is_autogenerated: false,
         }]
     );
 }
@@ -2883,6 +3306,7 @@ fn constraints_from_other_databases_should_not_be_introspected(api: TestApi) {
                                 },
                             ],
                             tpe: Normal,
+                            is_autogenerated: false,
                         },
                     ],
                     primary_key: Some(
@@ -2897,6 +3321,7 @@ fn constraints_from_other_databases_should_not_be_introspected(api: TestApi) {
                             constraint_name: None,
                         },
                     ),
+                    comment: None,
                 },
                 Table {
                     name: "User",
@@ -2913,6 +3338,7 @@ fn constraints_from_other_databases_should_not_be_introspected(api: TestApi) {
                             constraint_name: None,
                         },
                     ),
+                    comment: None,
                 },
             ],
             enums: [],
@@ -2937,6 +3363,9 @@ fn constraints_from_other_databases_should_not_be_introspected(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2959,6 +3388,9 @@ fn constraints_from_other_databases_should_not_be_introspected(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -2981,6 +3413,9 @@ fn constraints_from_other_databases_should_not_be_introspected(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
             ],
@@ -3004,9 +3439,11 @@ fn constraints_from_other_databases_should_not_be_introspected(api: TestApi) {
                         ],
                         on_delete_action: Restrict,
                         on_update_action: Restrict,
+                        validated: true,
                     },
                 ),
             ],
+            check_constraints: [],
             views: [],
             procedures: [],
             user_defined_types: [],
@@ -3032,6 +3469,7 @@ fn introspected_default_strings_should_be_unescaped(api: TestApi) {
                     name: "User",
                     indices: [],
                     primary_key: None,
+                    comment: None,
                 },
             ],
             enums: [],
@@ -3065,10 +3503,14 @@ fn introspected_default_strings_should_be_unescaped(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
             ],
             foreign_keys: [],
+            check_constraints: [],
             views: [],
             procedures: [],
             user_defined_types: [],
@@ -3095,6 +3537,7 @@ fn escaped_quotes_in_string_defaults_must_be_unescaped(api: TestApi) {
                     name: "string_defaults_test",
                     indices: [],
                     primary_key: None,
+                    comment: None,
                 },
             ],
             enums: [],
@@ -3128,6 +3571,9 @@ fn escaped_quotes_in_string_defaults_must_be_unescaped(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -3159,10 +3605,14 @@ fn escaped_quotes_in_string_defaults_must_be_unescaped(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
             ],
             foreign_keys: [],
+            check_constraints: [],
             views: [],
             procedures: [],
             user_defined_types: [],
@@ -3189,6 +3639,7 @@ fn escaped_backslashes_in_string_literals_must_be_unescaped(api: TestApi) {
                     name: "test",
                     indices: [],
                     primary_key: None,
+                    comment: None,
                 },
             ],
             enums: [],
@@ -3222,10 +3673,14 @@ fn escaped_backslashes_in_string_literals_must_be_unescaped(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
             ],
             foreign_keys: [],
+            check_constraints: [],
             views: [],
             procedures: [],
             user_defined_types: [],
@@ -3263,6 +3718,7 @@ fn function_expression_defaults_are_described_as_dbgenerated(api: TestApi) {
                     name: "game",
                     indices: [],
                     primary_key: None,
+                    comment: None,
                 },
             ],
             enums: [
@@ -3299,6 +3755,9 @@ fn function_expression_defaults_are_described_as_dbgenerated(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -3326,6 +3785,9 @@ fn function_expression_defaults_are_described_as_dbgenerated(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -3353,6 +3815,9 @@ fn function_expression_defaults_are_described_as_dbgenerated(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -3387,6 +3852,9 @@ fn function_expression_defaults_are_described_as_dbgenerated(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -3414,6 +3882,9 @@ fn function_expression_defaults_are_described_as_dbgenerated(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -3443,6 +3914,9 @@ fn function_expression_defaults_are_described_as_dbgenerated(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -3470,6 +3944,9 @@ fn function_expression_defaults_are_described_as_dbgenerated(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -3499,6 +3976,9 @@ fn function_expression_defaults_are_described_as_dbgenerated(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -3528,6 +4008,9 @@ fn function_expression_defaults_are_described_as_dbgenerated(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -3555,6 +4038,9 @@ fn function_expression_defaults_are_described_as_dbgenerated(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -3580,6 +4066,9 @@ fn function_expression_defaults_are_described_as_dbgenerated(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -3590,7 +4079,12 @@ fn function_expression_defaults_are_described_as_dbgenerated(api: TestApi) {
                         name: "unsupported_col",
                         tpe: ColumnType {
                             full_data_type: "set('one','two')",
-                            family: String,
+                            family: Set(
+                                [
+                                    "one",
+                                    "two",
+                                ],
+                            ),
                             arity: Nullable,
                             native_type: None,
                         },
@@ -3603,10 +4097,14 @@ fn function_expression_defaults_are_described_as_dbgenerated(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
             ],
             foreign_keys: [],
+            check_constraints: [],
             views: [],
             procedures: [],
             user_defined_types: [],
@@ -3657,6 +4155,7 @@ fn dangling_foreign_keys_are_filtered_out(api: TestApi) {
                                 },
                             ],
                             tpe: Normal,
+                            is_autogenerated: false,
                         },
                     ],
                     primary_key: Some(
@@ -3671,6 +4170,7 @@ fn dangling_foreign_keys_are_filtered_out(api: TestApi) {
                             constraint_name: None,
                         },
                     ),
+                    comment: None,
                 },
                 Table {
                     name: "platypus",
@@ -3687,6 +4187,7 @@ fn dangling_foreign_keys_are_filtered_out(api: TestApi) {
                             constraint_name: None,
                         },
                     ),
+                    comment: None,
                 },
             ],
             enums: [],
@@ -3709,6 +4210,9 @@ fn dangling_foreign_keys_are_filtered_out(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -3729,6 +4233,9 @@ fn dangling_foreign_keys_are_filtered_out(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -3749,6 +4256,9 @@ fn dangling_foreign_keys_are_filtered_out(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
             ],
@@ -3772,9 +4282,11 @@ fn dangling_foreign_keys_are_filtered_out(api: TestApi) {
                         ],
                         on_delete_action: Restrict,
                         on_update_action: Restrict,
+                        validated: true,
                     },
                 ),
             ],
+            check_constraints: [],
             views: [],
             procedures: [],
             user_defined_types: [],
@@ -3839,3 +4351,106 @@ fn index_length_and_sorting_is_handled(api: TestApi) {
     assert_eq!(Some(10), columns[0].length());
     assert_eq!(Some(20), columns[1].length());
 }
+
+#[test_connector(tags(Mysql))]
+fn enum_columns_capture_their_values(api: TestApi) {
+    let sql = indoc! {r#"
+        CREATE TABLE a (
+            id INT PRIMARY KEY,
+            size ENUM('small', 'medium', 'large') NOT NULL
+        );
+    "#};
+
+    api.raw_cmd(sql);
+
+    let schema = api.describe();
+    let table = schema.table_walkers().next().unwrap();
+    let column = table.column("size").unwrap();
+
+    let enum_name = column.column_type_family().as_enum().unwrap();
+    let enm = schema.get_enum(enum_name).unwrap();
+
+    assert_eq!(vec!["small", "medium", "large"], enm.values);
+}
+
+#[test_connector(tags(Mysql))]
+fn set_columns_capture_their_values(api: TestApi) {
+    let sql = indoc! {r#"
+        CREATE TABLE a (
+            id INT PRIMARY KEY,
+            flags SET('read', 'write', 'delete') NOT NULL
+        );
+    "#};
+
+    api.raw_cmd(sql);
+
+    let schema = api.describe();
+    let table = schema.table_walkers().next().unwrap();
+    let column = table.column("flags").unwrap();
+
+    let values = column.column_type_family().as_set().unwrap().to_vec();
+
+    assert_eq!(vec!["read", "write", "delete"], values);
+}
+
+#[test_connector(tags(Mysql))]
+fn auto_increment_starting_value_is_captured(api: TestApi) {
+    let sql = indoc! {r#"
+        CREATE TABLE a (
+            id INT PRIMARY KEY AUTO_INCREMENT
+        ) AUTO_INCREMENT = 1000;
+    "#};
+
+    api.raw_cmd(sql);
+
+    let schema = api.describe();
+    let table = schema.table_walkers().next().unwrap();
+
+    let mysql_ext: &MysqlSchemaExt = schema.downcast_connector_data().unwrap();
+
+    assert_eq!(Some(1000), mysql_ext.get_auto_increment_starting_value(table.id));
+}
+
+#[test_connector(tags(Mysql), exclude(Mysql56))]
+fn fulltext_indexes_capture_their_algorithm(api: TestApi) {
+    let sql = indoc! {r#"
+        CREATE TABLE a (
+            id INT PRIMARY KEY,
+            body TEXT NOT NULL
+        ) ENGINE = InnoDB;
+
+        CREATE FULLTEXT INDEX idx ON a (body);
+    "#};
+
+    api.raw_cmd(sql);
+
+    let schema = api.describe();
+    let table = schema.table_walkers().next().unwrap();
+    let index = table.indexes().find(|idx| idx.name() == "idx").unwrap();
+
+    let mysql_ext: &MysqlSchemaExt = schema.downcast_connector_data().unwrap();
+
+    assert_eq!(MysqlIndexAlgorithm::Fulltext, mysql_ext.index_algorithm(index.id));
+}
+
+#[test_connector(tags(Mysql), exclude(Mysql56))]
+fn spatial_indexes_capture_their_algorithm(api: TestApi) {
+    let sql = indoc! {r#"
+        CREATE TABLE a (
+            id INT PRIMARY KEY,
+            location GEOMETRY NOT NULL
+        ) ENGINE = InnoDB;
+
+        CREATE SPATIAL INDEX idx ON a (location);
+    "#};
+
+    api.raw_cmd(sql);
+
+    let schema = api.describe();
+    let table = schema.table_walkers().next().unwrap();
+    let index = table.indexes().find(|idx| idx.name() == "idx").unwrap();
+
+    let mysql_ext: &MysqlSchemaExt = schema.downcast_connector_data().unwrap();
+
+    assert_eq!(MysqlIndexAlgorithm::Spatial, mysql_ext.index_algorithm(index.id));
+}
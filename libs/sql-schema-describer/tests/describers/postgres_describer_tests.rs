@@ -5,7 +5,10 @@ use barrel::{types, Migration};
 use indoc::indoc;
 use pretty_assertions::assert_eq;
 use prisma_value::PrismaValue;
-use sql_schema_describer::{postgres::PostgresSchemaExt, *};
+use sql_schema_describer::{
+    postgres::{PostgresSchemaExt, SQLNullPosition, SqlIndexAlgorithm},
+    *,
+};
 
 #[test_connector(tags(Postgres), exclude(CockroachDb))]
 fn views_can_be_described(api: TestApi) {
@@ -23,6 +26,50 @@ fn views_can_be_described(api: TestApi) {
 
     assert_eq!("ab", &view.name);
     assert_eq!(expected_sql, view.definition.unwrap());
+    assert!(!view.is_materialized);
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn materialized_views_can_be_described(api: TestApi) {
+    let full_sql = r#"
+        CREATE TABLE a (a_id int);
+        CREATE VIEW regular_view AS SELECT a_id FROM a;
+        CREATE MATERIALIZED VIEW matview AS SELECT a_id FROM a;
+    "#;
+
+    api.raw_cmd(full_sql);
+    let result = api.describe();
+
+    let regular_view = result.get_view("regular_view").expect("couldn't get regular_view").to_owned();
+    assert!(!regular_view.is_materialized);
+
+    let matview = result.get_view("matview").expect("couldn't get matview").to_owned();
+    let expected_sql = " SELECT a.a_id\n   FROM a;";
+
+    assert_eq!("matview", &matview.name);
+    assert_eq!(expected_sql, matview.definition.unwrap());
+    assert!(matview.is_materialized);
+}
+
+// Materialized views are backed by a table-like relation (`relkind = 'm'`), so an index declared
+// on one must not be picked up by the table index query (which only looks at `relkind = 'r'`) and
+// attributed to a nonexistent table of the same name.
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn indexes_on_materialized_views_are_not_mistaken_for_table_indexes(api: TestApi) {
+    let full_sql = r#"
+        CREATE TABLE a (a_id int);
+        CREATE MATERIALIZED VIEW matview AS SELECT a_id FROM a;
+        CREATE UNIQUE INDEX matview_a_id_idx ON matview (a_id);
+    "#;
+
+    api.raw_cmd(full_sql);
+    let result = api.describe();
+
+    let matview = result.get_view("matview").expect("couldn't get matview").to_owned();
+    assert!(matview.is_materialized);
+
+    // There is no table named `matview` for the index to be (mis)attributed to.
+    assert!(result.table_walkers().all(|table| table.name() != "matview"));
 }
 
 #[test_connector(tags(Postgres), exclude(CockroachDb))]
@@ -95,6 +142,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                                 },
                             ],
                             tpe: Unique,
+                            is_autogenerated: false,
                         },
                     ],
                     primary_key: Some(
@@ -111,6 +159,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                             ),
                         },
                     ),
+                    comment: None,
                 },
             ],
             enums: [],
@@ -133,6 +182,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -153,6 +205,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -173,6 +228,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -193,6 +251,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -213,6 +274,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -233,6 +297,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -253,6 +320,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -275,6 +345,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -295,6 +368,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -322,6 +398,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                             },
                         ),
                         auto_increment: true,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -344,6 +423,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -366,6 +448,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -386,6 +471,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -406,6 +494,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -424,6 +515,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -446,6 +540,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -464,6 +561,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -484,6 +584,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -504,6 +607,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -524,6 +630,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -544,6 +653,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -562,6 +674,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -580,6 +695,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -600,6 +718,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -618,6 +739,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -636,6 +760,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -654,6 +781,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -674,6 +804,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -701,6 +834,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                             },
                         ),
                         auto_increment: true,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -728,6 +864,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                             },
                         ),
                         auto_increment: true,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -755,6 +894,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                             },
                         ),
                         auto_increment: true,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -775,6 +917,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -797,6 +942,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -819,6 +967,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -841,6 +992,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -863,6 +1017,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -885,6 +1042,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -903,6 +1063,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -921,6 +1084,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -939,6 +1105,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -959,6 +1128,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -979,6 +1151,9 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -999,10 +1174,14 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
             ],
             foreign_keys: [],
+            check_constraints: [],
             views: [],
             procedures: [],
             user_defined_types: [],
@@ -1073,6 +1252,13 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                     virtual: false,
                 },
             ],
+            null_position: [],
+            deferrable_unique_indexes: [],
+            deferrable_primary_keys: [],
+            predicates: [],
+            non_key_columns: [],
+            collations: [],
+            extensions: [],
         }
     "#]];
     expected_ext.assert_debug_eq(&ext);
@@ -1156,6 +1342,30 @@ fn postgres_foreign_key_on_delete_must_be_handled(api: TestApi) {
     });
 }
 
+#[test_connector(tags(Postgres))]
+fn not_valid_foreign_keys_must_work(api: TestApi) {
+    let sql = format!(
+        "CREATE TABLE \"{0}\".\"City\" (id INT PRIMARY KEY);
+         CREATE TABLE \"{0}\".\"User\" (
+            id INT PRIMARY KEY,
+            city INT,
+            valid_city INT REFERENCES \"{0}\".\"City\" (id)
+        );
+         ALTER TABLE \"{0}\".\"User\" ADD CONSTRAINT city_fkey FOREIGN KEY (city) REFERENCES \"{0}\".\"City\" (id) NOT VALID;
+        ",
+        api.schema_name()
+    );
+
+    api.raw_cmd(&sql);
+
+    let schema = api.describe();
+
+    schema.assert_table("User", |t| {
+        t.assert_foreign_key_on_columns(&["city"], |fk| fk.assert_is_not_validated())
+            .assert_foreign_key_on_columns(&["valid_city"], |fk| fk.assert_is_validated())
+    });
+}
+
 #[test_connector(tags(Postgres))]
 fn postgres_enums_must_work(api: TestApi) {
     api.raw_cmd(&format!(
@@ -1171,6 +1381,166 @@ fn postgres_enums_must_work(api: TestApi) {
 }
 
 #[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn tsvector_columns_are_unsupported_by_default(api: TestApi) {
+    api.raw_cmd(&format!(
+        "CREATE TABLE \"{}\".\"a\" (id INT PRIMARY KEY, doc tsvector)",
+        api.schema_name()
+    ));
+
+    let schema = api.describe();
+    let table = schema.table_walkers().find(|t| t.name() == "a").unwrap();
+    let column = table.column("doc").unwrap();
+
+    assert!(matches!(column.column_type_family(), ColumnTypeFamily::Unsupported(tpe) if tpe.as_str() == "tsvector"));
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn tsvector_columns_become_string_with_lenient_types(api: TestApi) {
+    api.raw_cmd(&format!(
+        "CREATE TABLE \"{}\".\"a\" (id INT PRIMARY KEY, doc tsvector)",
+        api.schema_name()
+    ));
+
+    let schema = api.describe_lenient();
+    let table = schema.table_walkers().find(|t| t.name() == "a").unwrap();
+    let column = table.column("doc").unwrap();
+
+    assert_eq!(column.column_type_family(), &ColumnTypeFamily::String);
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn tsvector_columns_become_string_with_lenient_types_option(api: TestApi) {
+    api.raw_cmd(&format!(
+        "CREATE TABLE \"{}\".\"a\" (id INT PRIMARY KEY, doc tsvector)",
+        api.schema_name()
+    ));
+
+    let schema = api.describe_with_options(&DescribeOptions {
+        lenient_types: true,
+        ..Default::default()
+    });
+    let table = schema.table_walkers().find(|t| t.name() == "a").unwrap();
+    let column = table.column("doc").unwrap();
+
+    assert_eq!(column.column_type_family(), &ColumnTypeFamily::String);
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn range_columns_are_unsupported(api: TestApi) {
+    api.raw_cmd(&format!(
+        "CREATE TABLE \"{}\".\"a\" (
+            id INT PRIMARY KEY,
+            ints int4range,
+            bigints int8range,
+            numerics numrange,
+            timestamps tsrange,
+            timestamptzs tstzrange,
+            dates daterange
+        )",
+        api.schema_name()
+    ));
+
+    let schema = api.describe();
+    let table = schema.table_walkers().find(|t| t.name() == "a").unwrap();
+
+    for (column_name, expected_type) in [
+        ("ints", "int4range"),
+        ("bigints", "int8range"),
+        ("numerics", "numrange"),
+        ("timestamps", "tsrange"),
+        ("timestamptzs", "tstzrange"),
+        ("dates", "daterange"),
+    ] {
+        let column = table.column(column_name).unwrap();
+        assert!(
+            matches!(column.column_type_family(), ColumnTypeFamily::Unsupported(tpe) if tpe.as_str() == expected_type)
+        );
+    }
+}
+
+#[test_connector(tags(Postgres14, Postgres15))]
+fn multirange_columns_are_unsupported(api: TestApi) {
+    api.raw_cmd(&format!(
+        "CREATE TABLE \"{}\".\"a\" (
+            id INT PRIMARY KEY,
+            ints int4multirange,
+            bigints int8multirange,
+            numerics nummultirange,
+            timestamps tsmultirange,
+            timestamptzs tstzmultirange,
+            dates datemultirange
+        )",
+        api.schema_name()
+    ));
+
+    let schema = api.describe();
+    let table = schema.table_walkers().find(|t| t.name() == "a").unwrap();
+
+    for (column_name, expected_type) in [
+        ("ints", "int4multirange"),
+        ("bigints", "int8multirange"),
+        ("numerics", "nummultirange"),
+        ("timestamps", "tsmultirange"),
+        ("timestamptzs", "tstzmultirange"),
+        ("dates", "datemultirange"),
+    ] {
+        let column = table.column(column_name).unwrap();
+        assert!(
+            matches!(column.column_type_family(), ColumnTypeFamily::Unsupported(tpe) if tpe.as_str() == expected_type)
+        );
+    }
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn geometry_and_geography_columns_are_unsupported(api: TestApi) {
+    api.raw_cmd("CREATE EXTENSION IF NOT EXISTS postgis");
+    api.raw_cmd(&format!(
+        "CREATE TABLE \"{}\".\"a\" (
+            id INT PRIMARY KEY,
+            bare_geometry geometry,
+            point geometry(Point,4326),
+            bare_geography geography,
+            linestring geography(LineString,4326)
+        )",
+        api.schema_name()
+    ));
+
+    let schema = api.describe();
+    let table = schema.table_walkers().find(|t| t.name() == "a").unwrap();
+
+    for (column_name, expected_type) in [
+        ("bare_geometry", "geometry"),
+        ("point", "geometry(Point,4326)"),
+        ("bare_geography", "geography"),
+        ("linestring", "geography(LineString,4326)"),
+    ] {
+        let column = table.column(column_name).unwrap();
+        assert!(
+            matches!(column.column_type_family(), ColumnTypeFamily::Unsupported(tpe) if tpe.as_str() == expected_type),
+            "expected {column_name} to be Unsupported({expected_type}), got {:?}",
+            column.column_type_family()
+        );
+        assert_eq!(column.column_type().full_data_type, expected_type);
+    }
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn postgres_custom_collations_must_work(api: TestApi) {
+    api.raw_cmd(&format!(
+        "CREATE COLLATION \"{}\".\"case_insensitive\" (provider = icu, locale = 'und-u-ks-level2')",
+        api.schema_name()
+    ));
+
+    let schema = api.describe();
+    let ext = extract_ext(&schema);
+
+    assert!(ext.collations.iter().any(|coll| coll.name == "case_insensitive"));
+}
+
+// The first user of `versions(...)`: run this test against both the oldest and the newest
+// supported Postgres versions in a single test run, since sequence introspection reads from
+// `information_schema.sequences` on both and should behave identically.
+#[test_connector(versions(Postgres9, Postgres15))]
 fn postgres_sequences_must_work(api: TestApi) {
     api.raw_cmd(&format!("CREATE SEQUENCE \"{}\".\"test\"", api.schema_name()));
 
@@ -1192,6 +1562,13 @@ fn postgres_sequences_must_work(api: TestApi) {
                     virtual: false,
                 },
             ],
+            null_position: [],
+            deferrable_unique_indexes: [],
+            deferrable_primary_keys: [],
+            predicates: [],
+            non_key_columns: [],
+            collations: [],
+            extensions: [],
         }
     "#]];
     expected_ext.assert_debug_eq(&ext);
@@ -1238,6 +1615,7 @@ fn postgres_multi_field_indexes_must_be_inferred_in_the_right_order(api: TestApi
                                 },
                             ],
                             tpe: Unique,
+                            is_autogenerated: false,
                         },
                         Index {
                             name: "my_idx2",
@@ -1258,6 +1636,7 @@ fn postgres_multi_field_indexes_must_be_inferred_in_the_right_order(api: TestApi
                                 },
                             ],
                             tpe: Normal,
+                            is_autogenerated: false,
                         },
                     ],
                     primary_key: Some(
@@ -1274,6 +1653,7 @@ fn postgres_multi_field_indexes_must_be_inferred_in_the_right_order(api: TestApi
                             ),
                         },
                     ),
+                    comment: None,
                 },
             ],
             enums: [],
@@ -1296,6 +1676,9 @@ fn postgres_multi_field_indexes_must_be_inferred_in_the_right_order(api: TestApi
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1316,6 +1699,9 @@ fn postgres_multi_field_indexes_must_be_inferred_in_the_right_order(api: TestApi
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1336,10 +1722,14 @@ fn postgres_multi_field_indexes_must_be_inferred_in_the_right_order(api: TestApi
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
             ],
             foreign_keys: [],
+            check_constraints: [],
             views: [],
             procedures: [],
             user_defined_types: [],
@@ -1381,6 +1771,7 @@ fn escaped_quotes_in_string_defaults_must_be_unescaped(api: TestApi) {
                             ),
                         },
                     ),
+                    comment: None,
                 },
             ],
             enums: [],
@@ -1403,6 +1794,9 @@ fn escaped_quotes_in_string_defaults_must_be_unescaped(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1432,6 +1826,9 @@ fn escaped_quotes_in_string_defaults_must_be_unescaped(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1461,6 +1858,9 @@ fn escaped_quotes_in_string_defaults_must_be_unescaped(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -1490,10 +1890,14 @@ fn escaped_quotes_in_string_defaults_must_be_unescaped(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
             ],
             foreign_keys: [],
+            check_constraints: [],
             views: [],
             procedures: [],
             user_defined_types: [],
@@ -1520,6 +1924,7 @@ fn seemingly_escaped_backslashes_in_string_literals_must_not_be_unescaped(api: T
                     name: "test",
                     indices: [],
                     primary_key: None,
+                    comment: None,
                 },
             ],
             enums: [],
@@ -1553,10 +1958,14 @@ fn seemingly_escaped_backslashes_in_string_literals_must_not_be_unescaped(api: T
                             },
                         ),
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
             ],
             foreign_keys: [],
+            check_constraints: [],
             views: [],
             procedures: [],
             user_defined_types: [],
@@ -1648,6 +2057,74 @@ fn index_sort_order_composite_type_asc_desc_is_handled(api: TestApi) {
     assert_eq!(Some(SQLSortOrder::Desc), columns[1].sort_order());
 }
 
+#[test_connector(tags(Postgres))]
+fn index_nulls_order_is_only_captured_when_non_default(api: TestApi) {
+    let sql = indoc! {r#"
+        CREATE TABLE A (
+            id INT PRIMARY KEY,
+            a  INT,
+            b  INT,
+            c  INT
+        );
+
+        CREATE INDEX asc_default ON A (a);
+        CREATE INDEX asc_nulls_first ON A (b NULLS FIRST);
+        CREATE INDEX desc_nulls_last ON A (c DESC NULLS LAST);
+    "#};
+
+    api.raw_cmd(sql);
+
+    let schema = api.describe();
+    let ext = extract_ext(&schema);
+    let table = schema.table_walkers().next().unwrap();
+
+    let asc_default = table.indexes().find(|idx| idx.name() == "asc_default").unwrap();
+    let asc_nulls_first = table.indexes().find(|idx| idx.name() == "asc_nulls_first").unwrap();
+    let desc_nulls_last = table.indexes().find(|idx| idx.name() == "desc_nulls_last").unwrap();
+
+    // ASC with the default NULLS LAST is not recorded.
+    assert_eq!(None, ext.get_null_position(IndexFieldId(asc_default.id, 0)));
+
+    // ASC with the non-default NULLS FIRST is recorded.
+    assert_eq!(
+        Some(SQLNullPosition::First),
+        ext.get_null_position(IndexFieldId(asc_nulls_first.id, 0))
+    );
+
+    // DESC with the non-default NULLS LAST is recorded.
+    assert_eq!(
+        Some(SQLNullPosition::Last),
+        ext.get_null_position(IndexFieldId(desc_nulls_last.id, 0))
+    );
+}
+
+#[test_connector(tags(Postgres))]
+fn deferrable_unique_and_primary_key_constraints_are_captured(api: TestApi) {
+    let sql = indoc! {r#"
+        CREATE TABLE A (
+            id INT PRIMARY KEY DEFERRABLE,
+            a  INT NOT NULL,
+            b  INT NOT NULL,
+            CONSTRAINT a_key UNIQUE (a) DEFERRABLE,
+            CONSTRAINT b_key UNIQUE (b)
+        );
+    "#};
+
+    api.raw_cmd(sql);
+
+    let schema = api.describe();
+    let ext = extract_ext(&schema);
+    let table = schema.table_walkers().next().unwrap();
+
+    assert!(ext.pk_is_deferrable(table.id));
+
+    let a_key = table.indexes().find(|idx| idx.name() == "a_key").unwrap();
+    let b_key = table.indexes().find(|idx| idx.name() == "b_key").unwrap();
+
+    assert!(ext.index_is_deferrable(a_key.id));
+    assert!(!ext.index_is_deferrable(b_key.id));
+}
+
 #[test_connector(tags(Postgres), exclude(CockroachDb))]
 fn array_column_defaults(api: TestApi) {
     let schema = r#"
@@ -1807,6 +2284,399 @@ fn int_expressions_in_defaults(api: TestApi) {
     assert!(value.is_db_generated());
 }
 
+// Describing Postgres issues one bulk query per kind of schema object (tables, sequences,
+// enums, columns, foreign keys, indices, comments, views, procedures), regardless of how many
+// tables/columns/indexes are being described. This guards against a regression back to a
+// per-table query loop.
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn describing_does_not_issue_a_query_per_table(api: TestApi) {
+    let sql = indoc! {r#"
+        CREATE TABLE a (
+            id INT PRIMARY KEY,
+            name TEXT NOT NULL
+        );
+
+        CREATE TABLE b (
+            id INT PRIMARY KEY,
+            a_id INT NOT NULL REFERENCES a(id)
+        );
+
+        CREATE UNIQUE INDEX a_name_idx ON a (name);
+    "#};
+
+    api.raw_cmd(sql);
+
+    let (schema, query_count) = api.describe_counting_queries();
+
+    assert_eq!(2, schema.table_walkers().count());
+    assert_eq!(9, query_count);
+}
+
+// Temporary tables live in the session's `pg_temp*` schema, which is invisible to regular
+// `information_schema` queries scoped to the tested schema. They must only show up when
+// `include_temporary_tables` is set, and only their columns are captured (not indices or FKs).
+#[test_connector(tags(Postgres))]
+fn include_temporary_tables_option_captures_temp_tables(api: TestApi) {
+    api.raw_cmd("CREATE TEMP TABLE scratch (id INT PRIMARY KEY, val TEXT NOT NULL)");
+
+    let schema = api.describe();
+    assert!(schema.table_walkers().all(|t| t.name() != "scratch"));
+
+    let schema = api.describe_with_options(&DescribeOptions {
+        include_temporary_tables: true,
+        ..Default::default()
+    });
+
+    let table = schema
+        .table_walkers()
+        .find(|t| t.name() == "scratch")
+        .expect("temp table `scratch` should be present when `include_temporary_tables` is set");
+
+    let column_names: Vec<&str> = table.columns().map(|c| c.name()).collect();
+    assert_eq!(vec!["id", "val"], column_names);
+}
+
+// `pg_class.reltuples` is only refreshed by `ANALYZE` (or autovacuum), so we run it explicitly
+// before asserting on the estimate.
+#[test_connector(tags(Postgres))]
+fn include_row_count_estimates_option_reports_a_plausible_count(api: TestApi) {
+    api.raw_cmd("CREATE TABLE users (id INT PRIMARY KEY, name TEXT NOT NULL)");
+    api.raw_cmd("INSERT INTO users (id, name) VALUES (1, 'Alice'), (2, 'Bob'), (3, 'Carol')");
+    api.raw_cmd("ANALYZE users");
+
+    let schema = api.describe();
+    let table = schema.table_walkers().find(|t| t.name() == "users").unwrap();
+    assert_eq!(None, table.row_count_estimate());
+
+    let schema = api.describe_with_options(&DescribeOptions {
+        include_row_count_estimates: true,
+        ..Default::default()
+    });
+
+    let table = schema.table_walkers().find(|t| t.name() == "users").unwrap();
+    assert_eq!(Some(3), table.row_count_estimate());
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn include_extensions_option_captures_installed_extensions(api: TestApi) {
+    api.raw_cmd("CREATE EXTENSION IF NOT EXISTS citext");
+
+    let schema = api.describe();
+    assert!(extract_ext(&schema).extensions.is_empty());
+
+    let schema = api.describe_with_options(&DescribeOptions {
+        include_extensions: true,
+        ..Default::default()
+    });
+
+    let ext = extract_ext(&schema);
+    let citext = ext
+        .extensions
+        .iter()
+        .find(|e| e.name == "citext")
+        .expect("citext extension should be captured when `include_extensions` is set");
+    assert!(!citext.version.is_empty());
+    assert!(!citext.schema.is_empty());
+}
+
+#[test_connector(tags(Postgres))]
+fn partial_indexes_capture_their_predicate(api: TestApi) {
+    let sql = indoc! {r#"
+        CREATE TABLE A (
+            id      INT PRIMARY KEY,
+            deleted BOOLEAN NOT NULL,
+            a       INT NOT NULL,
+            b       INT NOT NULL
+        );
+
+        CREATE INDEX full_index ON A (a);
+        CREATE INDEX single_column_partial ON A (a) WHERE deleted = false;
+        CREATE INDEX multi_column_partial ON A (a, b) WHERE deleted = false;
+    "#};
+
+    api.raw_cmd(sql);
+
+    let schema = api.describe();
+    let ext = extract_ext(&schema);
+    let table = schema.table_walkers().next().unwrap();
+
+    let full_index = table.indexes().find(|idx| idx.name() == "full_index").unwrap();
+    let single_column_partial = table.indexes().find(|idx| idx.name() == "single_column_partial").unwrap();
+    let multi_column_partial = table.indexes().find(|idx| idx.name() == "multi_column_partial").unwrap();
+
+    // A regular index has no predicate.
+    assert_eq!(None, ext.index_predicate(full_index.id));
+
+    // The predicate text round-trips for both single- and multi-column partial indexes.
+    assert_eq!(Some("(deleted = false)"), ext.index_predicate(single_column_partial.id));
+    assert_eq!(Some("(deleted = false)"), ext.index_predicate(multi_column_partial.id));
+
+    // The multi-column partial index still has both of its columns.
+    assert_eq!(2, multi_column_partial.columns().len());
+}
+
+#[test_connector(tags(Postgres))]
+fn expression_indexes_capture_their_expression(api: TestApi) {
+    let sql = indoc! {r#"
+        CREATE TABLE A (
+            id    INT PRIMARY KEY,
+            email TEXT NOT NULL,
+            a     INT NOT NULL,
+            b     INT NOT NULL
+        );
+
+        CREATE INDEX idx ON A (lower(email));
+        CREATE INDEX mixed_idx ON A (a, lower(email));
+        CREATE INDEX plain_idx ON A (b);
+    "#};
+
+    api.raw_cmd(sql);
+
+    let schema = api.describe();
+    let table = schema.table_walkers().next().unwrap();
+
+    let idx = table.indexes().find(|idx| idx.name() == "idx").unwrap();
+    let mixed_idx = table.indexes().find(|idx| idx.name() == "mixed_idx").unwrap();
+    let plain_idx = table.indexes().find(|idx| idx.name() == "plain_idx").unwrap();
+
+    let expression_column = idx.columns().next().unwrap();
+    assert!(expression_column.is_expression());
+    assert_eq!(Some("lower(email)"), expression_column.expression());
+
+    // The plain column of a mixed index is unaffected, and only the expression entry is flagged.
+    let mixed_columns: Vec<_> = mixed_idx.columns().collect();
+    assert!(!mixed_columns[0].is_expression());
+    assert_eq!(None, mixed_columns[0].expression());
+    assert!(mixed_columns[1].is_expression());
+    assert_eq!(Some("lower(email)"), mixed_columns[1].expression());
+
+    // A plain index is unaffected.
+    let plain_column = plain_idx.columns().next().unwrap();
+    assert!(!plain_column.is_expression());
+    assert_eq!(None, plain_column.expression());
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn covering_index_include_columns_are_not_part_of_the_key(api: TestApi) {
+    let sql = indoc! {r#"
+        CREATE TABLE A (id INT PRIMARY KEY, a INT NOT NULL, b INT NOT NULL, c INT NOT NULL);
+        CREATE INDEX idx ON A USING BTREE (a, b) INCLUDE (c);
+    "#};
+
+    api.raw_cmd(sql);
+
+    let schema = api.describe();
+    let table = schema.table_walkers().find(|t| t.name() == "a").unwrap();
+    let index = table.indexes().find(|idx| idx.name() == "idx").unwrap();
+
+    let columns: Vec<_> = index.columns().collect();
+    assert_eq!(3, columns.len());
+    assert!(!columns[0].is_included());
+    assert!(!columns[1].is_included());
+    assert!(columns[2].is_included());
+
+    let non_key_columns: Vec<_> = index.non_key_columns().map(|c| c.get().name().to_owned()).collect();
+    assert_eq!(vec!["c".to_owned()], non_key_columns);
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn unknown_index_algorithms_produce_a_describer_warning(api: TestApi) {
+    // Register a new access method reusing the builtin btree handler, so we get a working index
+    // under a name the describer does not recognize, simulating an access method it doesn't know
+    // about yet (e.g. from a newer Postgres version or an extension).
+    let sql = indoc! {r#"
+        CREATE TABLE A (id INT PRIMARY KEY, a INT NOT NULL);
+        CREATE ACCESS METHOD fake_am TYPE INDEX HANDLER bthandler;
+        CREATE INDEX idx ON A USING fake_am (a);
+    "#};
+
+    api.raw_cmd(sql);
+
+    let schema = api.describe();
+
+    assert!(schema
+        .warnings
+        .iter()
+        .any(|w| w.message.contains("idx") && w.message.contains("fake_am")));
+
+    let ext = extract_ext(&schema);
+    let table = schema.table_walkers().find(|t| t.name() == "a").unwrap();
+    let index = table.indexes().find(|idx| idx.name() == "idx").unwrap();
+
+    assert_eq!(SqlIndexAlgorithm::BTree, ext.index_algorithm(index.id));
+}
+
+#[test_connector(tags(Postgres))]
+fn timestamp_and_time_precisions_are_captured(api: TestApi) {
+    let sql = indoc! {r#"
+        CREATE TABLE A (
+            id                  INT PRIMARY KEY,
+            timestamp_zero      TIMESTAMP(0) NOT NULL,
+            timestamp_default   TIMESTAMP NOT NULL,
+            time_six            TIME(6) NOT NULL
+        );
+    "#};
+
+    api.raw_cmd(sql);
+
+    let schema = api.describe();
+    let table = schema.table_walkers().next().unwrap();
+
+    let native_type = |column_name: &str| table.column(column_name).unwrap().column_type().native_type.clone();
+
+    assert_eq!(
+        Some(serde_json::json!({ "Timestamp": 0 })),
+        native_type("timestamp_zero")
+    );
+
+    // A bare `timestamp` has an implicit precision of 6, same as `timestamp(6)`.
+    assert_eq!(
+        Some(serde_json::json!({ "Timestamp": 6 })),
+        native_type("timestamp_default")
+    );
+
+    assert_eq!(Some(serde_json::json!({ "Time": 6 })), native_type("time_six"));
+}
+
 fn extract_ext(schema: &SqlSchema) -> &PostgresSchemaExt {
     schema.downcast_connector_data().unwrap_or_default()
 }
+
+#[test_connector(tags(Postgres))]
+fn table_level_check_constraints_are_captured(api: TestApi) {
+    let sql = indoc! {r#"
+        CREATE TABLE "products" (
+            id INT PRIMARY KEY,
+            price INT NOT NULL,
+            discounted_price INT NOT NULL,
+            CONSTRAINT prices_positive CHECK (price > 0 AND discounted_price > 0),
+            CONSTRAINT discount_is_smaller CHECK (discounted_price < price)
+        );
+    "#};
+
+    api.raw_cmd(sql);
+
+    let schema = api.describe();
+    let table = schema.table_walkers().next().unwrap();
+
+    let mut checks: Vec<(&str, &str)> = table
+        .check_constraints()
+        .map(|check| (check.name(), check.expression()))
+        .collect();
+    checks.sort();
+
+    assert_eq!(
+        checks,
+        &[
+            ("discount_is_smaller", "discounted_price < price"),
+            ("prices_positive", "(price > 0) AND (discounted_price > 0)"),
+        ]
+    );
+
+    for check in table.check_constraints() {
+        assert_eq!(check.table().id, table.id);
+    }
+}
+
+#[test_connector(tags(Postgres))]
+fn column_level_check_constraints_are_captured(api: TestApi) {
+    let sql = indoc! {r#"
+        CREATE TABLE "users" (
+            id INT PRIMARY KEY,
+            age INT CHECK (age >= 0)
+        );
+    "#};
+
+    api.raw_cmd(sql);
+
+    let schema = api.describe();
+    let table = schema.table_walkers().next().unwrap();
+
+    let check = table.check_constraints().next().unwrap();
+
+    assert_eq!(check.expression(), "age >= 0");
+
+    // Postgres names an inline, unnamed column check `<table>_<column>_check`.
+    assert_eq!(check.name(), "users_age_check");
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn generated_stored_columns_are_captured(api: TestApi) {
+    // `GENERATED ALWAYS AS (...) STORED` columns were added in Postgres 12.
+    if api.connector_tags().contains(Tags::Postgres9) {
+        return;
+    }
+
+    let sql = indoc! {r#"
+        CREATE TABLE "products" (
+            id INT PRIMARY KEY,
+            price INT NOT NULL,
+            quantity INT NOT NULL,
+            total INT GENERATED ALWAYS AS (price * quantity) STORED
+        );
+    "#};
+
+    api.raw_cmd(sql);
+
+    let schema = api.describe();
+    let table = schema.table_walkers().next().unwrap();
+
+    let total = table.column("total").unwrap();
+    let generated = total.column().generated.as_deref().expect("expected a generated expression");
+    assert!(generated.contains("price"), "unexpected generation expression: {}", generated);
+    assert!(generated.contains("quantity"), "unexpected generation expression: {}", generated);
+    assert_eq!(total.column().default, None, "a generated column must not also have a default");
+
+    let price = table.column("price").unwrap();
+    assert_eq!(price.column().generated, None);
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn capture_raw_unsupported_captures_the_raw_type_of_unsupported_columns(api: TestApi) {
+    let sql = indoc! {r#"
+        CREATE TABLE "documents" (
+            id INT PRIMARY KEY,
+            body TEXT NOT NULL,
+            search TSVECTOR
+        );
+    "#};
+
+    api.raw_cmd(sql);
+
+    let schema = api.describe_with_options(&DescribeOptions {
+        capture_raw_unsupported: true,
+        ..Default::default()
+    });
+
+    assert_eq!(
+        schema.raw_unsupported,
+        &[RawObject {
+            kind: "column".to_owned(),
+            raw_definition: "tsvector".to_owned(),
+        }]
+    );
+
+    // Off by default: no raw objects are captured without opting in.
+    let schema = api.describe();
+    assert!(schema.raw_unsupported.is_empty());
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn table_and_column_comments_round_trip_verbatim(api: TestApi) {
+    let sql = indoc! {r#"
+        CREATE TABLE "products" (
+            id INT PRIMARY KEY,
+            name TEXT NOT NULL
+        );
+        COMMENT ON TABLE "products" IS $$A "products" café ☕ table.$$;
+        COMMENT ON COLUMN "products".name IS $$Say "hi" — 你好$$;
+    "#};
+
+    api.raw_cmd(sql);
+
+    let schema = api.describe();
+    let table = schema.table_walkers().next().unwrap();
+
+    assert_eq!(table.comment(), Some(r#"A "products" café ☕ table."#));
+    assert_eq!(table.column("name").unwrap().comment(), Some("Say \"hi\" — 你好"));
+}
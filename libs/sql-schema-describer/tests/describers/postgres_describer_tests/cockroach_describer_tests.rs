@@ -1,6 +1,6 @@
 use crate::test_api::*;
 use prisma_value::PrismaValue;
-use sql_schema_describer::{postgres::PostgresSchemaExt, ColumnTypeFamily};
+use sql_schema_describer::{postgres::PostgresSchemaExt, ColumnTypeFamily, DefaultKind};
 
 #[test_connector(tags(CockroachDb))]
 fn views_can_be_described(api: TestApi) {
@@ -255,6 +255,7 @@ fn multi_field_indexes_must_be_inferred_in_the_right_order(api: TestApi) {
                                 },
                             ],
                             tpe: Unique,
+                            is_autogenerated: false,
                         },
                         Index {
                             name: "my_idx2",
@@ -275,6 +276,7 @@ fn multi_field_indexes_must_be_inferred_in_the_right_order(api: TestApi) {
                                 },
                             ],
                             tpe: Normal,
+                            is_autogenerated: false,
                         },
                     ],
                     primary_key: Some(
@@ -291,6 +293,7 @@ fn multi_field_indexes_must_be_inferred_in_the_right_order(api: TestApi) {
                             ),
                         },
                     ),
+                    comment: None,
                 },
             ],
             enums: [],
@@ -313,6 +316,9 @@ fn multi_field_indexes_must_be_inferred_in_the_right_order(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -333,6 +339,9 @@ fn multi_field_indexes_must_be_inferred_in_the_right_order(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -353,10 +362,14 @@ fn multi_field_indexes_must_be_inferred_in_the_right_order(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
             ],
             foreign_keys: [],
+            check_constraints: [],
             views: [],
             procedures: [],
             user_defined_types: [],
@@ -449,6 +462,13 @@ fn cockroachdb_sequences_must_work(api: TestApi) {
                     virtual: false,
                 },
             ],
+            null_position: [],
+            deferrable_unique_indexes: [],
+            deferrable_primary_keys: [],
+            predicates: [],
+            non_key_columns: [],
+            collations: [],
+            extensions: [],
         }
     "#]];
     expected_ext.assert_debug_eq(&ext);
@@ -470,6 +490,24 @@ fn int_expressions_in_defaults(api: TestApi) {
     assert!(matches!(value, PrismaValue::Int(37)));
 }
 
+#[test_connector(tags(CockroachDb))]
+fn unique_rowid_default_is_a_structured_autoincrement_kind(api: TestApi) {
+    let schema = r#"
+        CREATE TABLE "defaults" (
+            id INT8 NOT NULL DEFAULT unique_rowid(),
+            name TEXT NOT NULL
+        );
+    "#;
+
+    api.raw_cmd(schema);
+    let schema = api.describe();
+    let table = schema.table_walkers().next().unwrap();
+    let col = table.column("id").unwrap();
+
+    assert!(matches!(col.default().unwrap().kind(), DefaultKind::UniqueRowid));
+    assert!(col.is_autoincrement());
+}
+
 #[test_connector(tags(CockroachDb))]
 fn array_column_defaults(api: TestApi) {
     let schema = r#"
@@ -537,3 +575,47 @@ fn array_column_defaults(api: TestApi) {
         ],
     );
 }
+
+#[test_connector(tags(CockroachDb))]
+fn computed_columns_are_captured(api: TestApi) {
+    let schema = r#"
+        CREATE TABLE "Product" (
+            id INT4 PRIMARY KEY,
+            price INT4 NOT NULL,
+            quantity INT4 NOT NULL,
+            total INT4 AS (price * quantity) STORED
+        );
+    "#;
+
+    api.raw_cmd(schema);
+    let schema = api.describe();
+    let table = schema.table_walkers().next().unwrap();
+
+    let total = table.column("total").unwrap();
+    let generated = total.column().generated.as_deref().expect("expected a generated expression");
+    assert!(generated.contains("price"), "unexpected generation expression: {}", generated);
+    assert!(generated.contains("quantity"), "unexpected generation expression: {}", generated);
+
+    let price = table.column("price").unwrap();
+    assert_eq!(price.column().generated, None);
+}
+
+#[test_connector(tags(CockroachDb))]
+fn get_metadata_reports_a_nonzero_size(api: TestApi) {
+    api.raw_cmd(
+        r#"
+        CREATE TABLE "big_table" (id INT4 PRIMARY KEY, payload TEXT NOT NULL);
+        INSERT INTO "big_table" (id, payload)
+        SELECT g, repeat('a', 1000) FROM generate_series(1, 1000) g;
+        "#,
+    );
+
+    let metadata = api.get_metadata();
+
+    assert_eq!(metadata.table_count, 1);
+    assert!(
+        metadata.size_in_bytes > 0,
+        "expected a non-zero size, got {}",
+        metadata.size_in_bytes
+    );
+}
@@ -89,6 +89,7 @@ fn sqlite_column_types_must_work(api: TestApi) {
                             constraint_name: None,
                         },
                     ),
+                    comment: None,
                 },
             ],
             enums: [],
@@ -107,6 +108,9 @@ fn sqlite_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -123,6 +127,9 @@ fn sqlite_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -139,6 +146,9 @@ fn sqlite_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -155,6 +165,9 @@ fn sqlite_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -171,6 +184,9 @@ fn sqlite_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: true,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -187,10 +203,14 @@ fn sqlite_column_types_must_work(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
             ],
             foreign_keys: [],
+            check_constraints: [],
             views: [],
             procedures: [],
             user_defined_types: [],
@@ -232,6 +252,7 @@ fn sqlite_foreign_key_on_delete_must_be_handled(api: TestApi) {
                             constraint_name: None,
                         },
                     ),
+                    comment: None,
                 },
                 Table {
                     name: "User",
@@ -248,6 +269,7 @@ fn sqlite_foreign_key_on_delete_must_be_handled(api: TestApi) {
                             constraint_name: None,
                         },
                     ),
+                    comment: None,
                 },
             ],
             enums: [],
@@ -266,6 +288,9 @@ fn sqlite_foreign_key_on_delete_must_be_handled(api: TestApi) {
                         },
                         default: None,
                         auto_increment: true,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -282,6 +307,9 @@ fn sqlite_foreign_key_on_delete_must_be_handled(api: TestApi) {
                         },
                         default: None,
                         auto_increment: true,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -298,6 +326,9 @@ fn sqlite_foreign_key_on_delete_must_be_handled(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -314,6 +345,9 @@ fn sqlite_foreign_key_on_delete_must_be_handled(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -330,6 +364,9 @@ fn sqlite_foreign_key_on_delete_must_be_handled(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -346,6 +383,9 @@ fn sqlite_foreign_key_on_delete_must_be_handled(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -362,6 +402,9 @@ fn sqlite_foreign_key_on_delete_must_be_handled(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
             ],
@@ -383,6 +426,7 @@ fn sqlite_foreign_key_on_delete_must_be_handled(api: TestApi) {
                         ],
                         on_delete_action: NoAction,
                         on_update_action: NoAction,
+                        validated: true,
                     },
                 ),
                 (
@@ -402,6 +446,7 @@ fn sqlite_foreign_key_on_delete_must_be_handled(api: TestApi) {
                         ],
                         on_delete_action: Cascade,
                         on_update_action: NoAction,
+                        validated: true,
                     },
                 ),
                 (
@@ -421,6 +466,7 @@ fn sqlite_foreign_key_on_delete_must_be_handled(api: TestApi) {
                         ],
                         on_delete_action: Restrict,
                         on_update_action: NoAction,
+                        validated: true,
                     },
                 ),
                 (
@@ -440,6 +486,7 @@ fn sqlite_foreign_key_on_delete_must_be_handled(api: TestApi) {
                         ],
                         on_delete_action: SetDefault,
                         on_update_action: NoAction,
+                        validated: true,
                     },
                 ),
                 (
@@ -459,9 +506,11 @@ fn sqlite_foreign_key_on_delete_must_be_handled(api: TestApi) {
                         ],
                         on_delete_action: SetNull,
                         on_update_action: NoAction,
+                        validated: true,
                     },
                 ),
             ],
+            check_constraints: [],
             views: [],
             procedures: [],
             user_defined_types: [],
@@ -519,6 +568,7 @@ fn escaped_quotes_in_string_defaults_must_be_unescaped(api: TestApi) {
                     name: "string_defaults_test",
                     indices: [],
                     primary_key: None,
+                    comment: None,
                 },
             ],
             enums: [],
@@ -546,6 +596,9 @@ fn escaped_quotes_in_string_defaults_must_be_unescaped(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -571,10 +624,14 @@ fn escaped_quotes_in_string_defaults_must_be_unescaped(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
             ],
             foreign_keys: [],
+            check_constraints: [],
             views: [],
             procedures: [],
             user_defined_types: [],
@@ -601,6 +658,7 @@ fn backslashes_in_string_literals(api: TestApi) {
                     name: "test",
                     indices: [],
                     primary_key: None,
+                    comment: None,
                 },
             ],
             enums: [],
@@ -628,10 +686,14 @@ fn backslashes_in_string_literals(api: TestApi) {
                             },
                         ),
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
             ],
             foreign_keys: [],
+            check_constraints: [],
             views: [],
             procedures: [],
             user_defined_types: [],
@@ -681,6 +743,7 @@ fn broken_relations_are_filtered_out(api: TestApi) {
                             constraint_name: None,
                         },
                     ),
+                    comment: None,
                 },
                 Table {
                     name: "platypus",
@@ -697,6 +760,7 @@ fn broken_relations_are_filtered_out(api: TestApi) {
                             constraint_name: None,
                         },
                     ),
+                    comment: None,
                 },
             ],
             enums: [],
@@ -715,6 +779,9 @@ fn broken_relations_are_filtered_out(api: TestApi) {
                         },
                         default: None,
                         auto_increment: true,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -731,6 +798,9 @@ fn broken_relations_are_filtered_out(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -747,6 +817,9 @@ fn broken_relations_are_filtered_out(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -763,6 +836,9 @@ fn broken_relations_are_filtered_out(api: TestApi) {
                         },
                         default: None,
                         auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
                 (
@@ -779,6 +855,9 @@ fn broken_relations_are_filtered_out(api: TestApi) {
                         },
                         default: None,
                         auto_increment: true,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
                     },
                 ),
             ],
@@ -800,9 +879,11 @@ fn broken_relations_are_filtered_out(api: TestApi) {
                         ],
                         on_delete_action: NoAction,
                         on_update_action: NoAction,
+                        validated: true,
                     },
                 ),
             ],
+            check_constraints: [],
             views: [],
             procedures: [],
             user_defined_types: [],
@@ -812,6 +893,53 @@ fn broken_relations_are_filtered_out(api: TestApi) {
     api.expect_schema(expectation);
 }
 
+#[test_connector(tags(Sqlite))]
+fn unrecognized_column_types_are_unsupported_by_default(api: TestApi) {
+    api.raw_cmd("CREATE TABLE a (id INTEGER PRIMARY KEY, doc tsvector)");
+
+    let schema = api.describe();
+    let table = schema.table_walkers().next().unwrap();
+    let column = table.column("doc").unwrap();
+
+    assert!(matches!(column.column_type_family(), ColumnTypeFamily::Unsupported(tpe) if tpe == "tsvector"));
+}
+
+#[test_connector(tags(Sqlite))]
+fn unrecognized_column_types_become_string_with_lenient_types(api: TestApi) {
+    api.raw_cmd("CREATE TABLE a (id INTEGER PRIMARY KEY, doc tsvector)");
+
+    let schema = api.describe_lenient();
+    let table = schema.table_walkers().next().unwrap();
+    let column = table.column("doc").unwrap();
+
+    assert_eq!(column.column_type_family(), &ColumnTypeFamily::String);
+}
+
+#[test_connector(tags(Sqlite))]
+fn unrecognized_column_types_are_unsupported_with_default_options(api: TestApi) {
+    api.raw_cmd("CREATE TABLE a (id INTEGER PRIMARY KEY, doc tsvector)");
+
+    let schema = api.describe_with_options(&DescribeOptions::default());
+    let table = schema.table_walkers().next().unwrap();
+    let column = table.column("doc").unwrap();
+
+    assert!(matches!(column.column_type_family(), ColumnTypeFamily::Unsupported(tpe) if tpe == "tsvector"));
+}
+
+#[test_connector(tags(Sqlite))]
+fn unrecognized_column_types_become_string_with_lenient_types_option(api: TestApi) {
+    api.raw_cmd("CREATE TABLE a (id INTEGER PRIMARY KEY, doc tsvector)");
+
+    let schema = api.describe_with_options(&DescribeOptions {
+        lenient_types: true,
+        ..Default::default()
+    });
+    let table = schema.table_walkers().next().unwrap();
+    let column = table.column("doc").unwrap();
+
+    assert_eq!(column.column_type_family(), &ColumnTypeFamily::String);
+}
+
 #[test_connector(tags(Sqlite))]
 fn index_sort_order_is_handled(api: TestApi) {
     let sql = indoc! {r#"
@@ -840,3 +968,196 @@ fn index_sort_order_is_handled(api: TestApi) {
     assert_eq!(Some(SQLSortOrder::Desc), columns[0].sort_order());
     assert_eq!(Some(SQLSortOrder::Asc), columns[1].sort_order());
 }
+
+// Describing a schema with several tables goes through the bulk, table-valued-pragma based
+// path (see `SqlSchemaDescriber::get_all_tables`) rather than the legacy per-table one. This
+// exercises columns, indexes and foreign keys across multiple tables at once, to make sure the
+// bulk path produces the exact same shape as describing a single table would.
+#[test_connector(tags(Sqlite))]
+fn many_tables_are_described_correctly_through_the_batched_path(api: TestApi) {
+    let sql = indoc! {r#"
+        CREATE TABLE a (
+            id INTEGER PRIMARY KEY,
+            name TEXT NOT NULL
+        );
+
+        CREATE TABLE b (
+            id INTEGER PRIMARY KEY,
+            a_id INTEGER NOT NULL,
+            FOREIGN KEY (a_id) REFERENCES a(id)
+        );
+
+        CREATE TABLE c (
+            id INTEGER PRIMARY KEY,
+            b_id INTEGER NOT NULL,
+            label TEXT NOT NULL,
+            FOREIGN KEY (b_id) REFERENCES b(id)
+        );
+
+        CREATE UNIQUE INDEX a_name_idx ON a (name);
+        CREATE INDEX c_label_idx ON c (label DESC);
+    "#};
+
+    api.raw_cmd(sql);
+
+    let schema = api.describe();
+
+    assert_eq!(3, schema.table_walkers().count());
+
+    let a = schema.table_walkers().find(|t| t.name() == "a").unwrap();
+    a.column("name").unwrap();
+    let a_index = a.indexes().next().unwrap();
+    assert_eq!("a_name_idx", a_index.name());
+    assert!(a_index.index_type().is_unique());
+
+    let b = schema.table_walkers().find(|t| t.name() == "b").unwrap();
+    let b_fk = b.foreign_keys().next().unwrap();
+    assert_eq!("a", b_fk.referenced_table().name());
+    assert!(b_fk.constrained_column_names() == ["a_id"]);
+
+    let c = schema.table_walkers().find(|t| t.name() == "c").unwrap();
+    let c_fk = c.foreign_keys().next().unwrap();
+    assert_eq!("b", c_fk.referenced_table().name());
+
+    let c_index = c.indexes().next().unwrap();
+    assert_eq!("c_label_idx", c_index.name());
+    let c_index_columns = c_index.columns().collect::<Vec<_>>();
+    assert_eq!("label", c_index_columns[0].as_column().name());
+    assert_eq!(Some(SQLSortOrder::Desc), c_index_columns[0].sort_order());
+}
+
+// Describing SQLite issues a fixed number of queries (`get_table_names`, `version`, plus the
+// bulk column/foreign-key/index queries in `get_all_tables`) regardless of how many tables or
+// indexes are being described. This guards against a regression back to per-table or per-index
+// PRAGMA calls.
+#[test_connector(tags(Sqlite))]
+fn describing_does_not_issue_a_query_per_table_or_index(api: TestApi) {
+    let sql = indoc! {r#"
+        CREATE TABLE a (
+            id INTEGER PRIMARY KEY,
+            name TEXT NOT NULL
+        );
+
+        CREATE TABLE b (
+            id INTEGER PRIMARY KEY,
+            a_id INTEGER NOT NULL,
+            FOREIGN KEY (a_id) REFERENCES a(id)
+        );
+
+        CREATE TABLE c (
+            id INTEGER PRIMARY KEY,
+            b_id INTEGER NOT NULL,
+            label TEXT NOT NULL,
+            FOREIGN KEY (b_id) REFERENCES b(id)
+        );
+
+        CREATE UNIQUE INDEX a_name_idx ON a (name);
+        CREATE INDEX c_label_idx ON c (label DESC);
+    "#};
+
+    api.raw_cmd(sql);
+
+    let (schema, query_count) = api.describe_counting_queries();
+
+    assert_eq!(3, schema.table_walkers().count());
+    assert_eq!(8, query_count);
+}
+
+// The batched columns/foreign-keys/indices queries in get_all_tables() issue the same handful of
+// queries whether we're describing 3 tables or 50: they join sqlite_master against the
+// table-valued pragma functions once, instead of looping over the tables. This pins that down so
+// a per-table (or per-index) PRAGMA loop can't sneak back in.
+#[test_connector(tags(Sqlite))]
+fn describing_fifty_tables_does_not_scale_the_query_count(api: TestApi) {
+    let mut sql = String::new();
+
+    for i in 0..50 {
+        sql.push_str(&format!(
+            "CREATE TABLE table_{i} (id INTEGER PRIMARY KEY, val TEXT NOT NULL);\n\
+             CREATE INDEX table_{i}_val_idx ON table_{i} (val);\n"
+        ));
+    }
+
+    api.raw_cmd(&sql);
+
+    let (schema, query_count) = api.describe_counting_queries();
+
+    assert_eq!(50, schema.table_walkers().count());
+    assert_eq!(8, query_count);
+}
+
+// Table names are otherwise returned in alphabetical order (`A`, `B`, `C`), which is the reverse
+// of the dependency chain `A -> B -> C` set up below. With `dependency_order` set, `C` (which has
+// no foreign keys) must come first, then `B`, then `A`.
+#[test_connector(tags(Sqlite))]
+fn dependency_order_option_orders_tables_by_foreign_key(api: TestApi) {
+    let sql = indoc! {r#"
+        CREATE TABLE C (
+            id INTEGER PRIMARY KEY
+        );
+
+        CREATE TABLE B (
+            id INTEGER PRIMARY KEY,
+            c_id INTEGER NOT NULL,
+            FOREIGN KEY (c_id) REFERENCES C(id)
+        );
+
+        CREATE TABLE A (
+            id INTEGER PRIMARY KEY,
+            b_id INTEGER NOT NULL,
+            FOREIGN KEY (b_id) REFERENCES B(id)
+        );
+    "#};
+
+    api.raw_cmd(sql);
+
+    let schema = api.describe_with_options(&DescribeOptions {
+        dependency_order: true,
+        ..Default::default()
+    });
+
+    let table_names: Vec<&str> = schema.table_walkers().map(|t| t.name()).collect();
+
+    assert_eq!(vec!["C", "B", "A"], table_names);
+}
+
+// Temporary tables live in the connection-local `sqlite_temp_master` catalog, which is invisible
+// to `sqlite_master`-based queries. They must only show up when `include_temporary_tables` is set.
+#[test_connector(tags(Sqlite))]
+fn include_temporary_tables_option_captures_temp_tables(api: TestApi) {
+    api.raw_cmd("CREATE TEMP TABLE scratch (id INTEGER PRIMARY KEY, val TEXT NOT NULL)");
+
+    let schema = api.describe();
+    assert!(schema.table_walkers().all(|t| t.name() != "scratch"));
+
+    let schema = api.describe_with_options(&DescribeOptions {
+        include_temporary_tables: true,
+        ..Default::default()
+    });
+
+    let table = schema
+        .table_walkers()
+        .find(|t| t.name() == "scratch")
+        .expect("temp table `scratch` should be present when `include_temporary_tables` is set");
+
+    let column_names: Vec<&str> = table.columns().map(|c| c.name()).collect();
+    assert_eq!(vec!["id", "val"], column_names);
+}
+
+#[test_connector(tags(Sqlite))]
+fn include_row_count_estimates_option_counts_rows(api: TestApi) {
+    api.raw_cmd("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL)");
+    api.raw_cmd("INSERT INTO users (name) VALUES ('Alice'), ('Bob'), ('Carol')");
+
+    let schema = api.describe();
+    let table = schema.table_walkers().find(|t| t.name() == "users").unwrap();
+    assert_eq!(None, table.row_count_estimate());
+
+    let schema = api.describe_with_options(&DescribeOptions {
+        include_row_count_estimates: true,
+        ..Default::default()
+    });
+
+    let table = schema.table_walkers().find(|t| t.name() == "users").unwrap();
+    assert_eq!(Some(3), table.row_count_estimate());
+}
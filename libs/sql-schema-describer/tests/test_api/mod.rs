@@ -4,15 +4,105 @@ pub use test_macros::test_connector;
 pub use test_setup::{runtime::run_with_thread_local_runtime as tok, BitFlags, Capabilities, Tags};
 
 use barrel::Migration;
-use quaint::prelude::SqlFamily;
+use quaint::{
+    ast::Query,
+    connector::{ResultSet, TransactionCapable},
+    prelude::SqlFamily,
+    Value,
+};
 use sql_schema_describer::{
     postgres::Circumstances,
     walkers::{ColumnWalker, ForeignKeyWalker, IndexWalker, SqlSchemaExt, TableWalker},
-    ColumnTypeFamily, DescriberError, ForeignKeyAction, SqlSchema, SqlSchemaDescriberBackend,
+    ColumnTypeFamily, DescribeOptions, DescriberError, ForeignKeyAction, SqlMetadata, SqlSchema,
+    SqlSchemaDescriberBackend,
+};
+use std::{
+    future::Future,
+    sync::atomic::{AtomicUsize, Ordering},
 };
-use std::future::Future;
 use test_setup::*;
 
+/// A `Queryable` wrapper that counts the number of round trips made through it, so tests can
+/// assert that a describer doesn't regress into issuing more queries than it needs to (e.g. an
+/// accidental N+1 loop).
+pub struct CountingConnection<'a> {
+    inner: &'a dyn Queryable,
+    count: AtomicUsize,
+}
+
+impl<'a> CountingConnection<'a> {
+    fn new(inner: &'a dyn Queryable) -> Self {
+        CountingConnection {
+            inner,
+            count: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn query_count(&self) -> usize {
+        self.count.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait::async_trait]
+impl Queryable for CountingConnection<'_> {
+    async fn query(&self, q: Query<'_>) -> quaint::Result<ResultSet> {
+        self.count.fetch_add(1, Ordering::SeqCst);
+        self.inner.query(q).await
+    }
+
+    async fn query_raw(&self, sql: &str, params: &[Value<'_>]) -> quaint::Result<ResultSet> {
+        self.count.fetch_add(1, Ordering::SeqCst);
+        self.inner.query_raw(sql, params).await
+    }
+
+    async fn query_raw_typed(&self, sql: &str, params: &[Value<'_>]) -> quaint::Result<ResultSet> {
+        self.count.fetch_add(1, Ordering::SeqCst);
+        self.inner.query_raw_typed(sql, params).await
+    }
+
+    async fn execute(&self, q: Query<'_>) -> quaint::Result<u64> {
+        self.count.fetch_add(1, Ordering::SeqCst);
+        self.inner.execute(q).await
+    }
+
+    async fn execute_raw(&self, sql: &str, params: &[Value<'_>]) -> quaint::Result<u64> {
+        self.count.fetch_add(1, Ordering::SeqCst);
+        self.inner.execute_raw(sql, params).await
+    }
+
+    async fn execute_raw_typed(&self, sql: &str, params: &[Value<'_>]) -> quaint::Result<u64> {
+        self.count.fetch_add(1, Ordering::SeqCst);
+        self.inner.execute_raw_typed(sql, params).await
+    }
+
+    async fn raw_cmd(&self, cmd: &str) -> quaint::Result<()> {
+        self.count.fetch_add(1, Ordering::SeqCst);
+        self.inner.raw_cmd(cmd).await
+    }
+
+    async fn version(&self) -> quaint::Result<Option<String>> {
+        self.inner.version().await
+    }
+
+    fn is_healthy(&self) -> bool {
+        self.inner.is_healthy()
+    }
+
+    async fn server_reset_query(&self, connection: &dyn Queryable) -> quaint::Result<()> {
+        self.inner.server_reset_query(connection).await
+    }
+
+    fn begin_statement(&self) -> &'static str {
+        self.inner.begin_statement()
+    }
+
+    fn requires_isolation_first(&self) -> bool {
+        self.inner.requires_isolation_first()
+    }
+}
+
+impl TransactionCapable for CountingConnection<'_> {}
+
 pub struct TestApi {
     db_name: &'static str,
     database: Quaint,
@@ -74,10 +164,34 @@ impl TestApi {
         tok(self.describer(&self.database).describe(schema)).unwrap()
     }
 
+    pub(crate) fn describe_lenient(&self) -> SqlSchema {
+        tok(self.lenient_describer(&self.database).describe(self.schema_name())).unwrap()
+    }
+
+    pub(crate) fn describe_with_options(&self, options: &DescribeOptions) -> SqlSchema {
+        tok(self
+            .describer(&self.database)
+            .describe_with_options(self.schema_name(), options))
+        .unwrap()
+    }
+
+    pub(crate) fn get_metadata(&self) -> SqlMetadata {
+        tok(self.describer(&self.database).get_metadata(self.schema_name())).unwrap()
+    }
+
     pub(crate) fn describe_error(&self) -> DescriberError {
         tok(self.describer(&self.database).describe(self.schema_name())).unwrap_err()
     }
 
+    /// Like [`TestApi::describe`], but also returns the number of queries the describer issued
+    /// to produce it, so tests can guard against accidental N+1 regressions.
+    pub(crate) fn describe_counting_queries(&self) -> (SqlSchema, usize) {
+        let counting_connection = CountingConnection::new(&self.database);
+        let schema = tok(self.describer(&counting_connection).describe(self.schema_name())).unwrap();
+
+        (schema, counting_connection.query_count())
+    }
+
     fn describer<'a>(&self, connection: &'a dyn Queryable) -> Box<dyn SqlSchemaDescriberBackend + 'a> {
         match self.sql_family() {
             SqlFamily::Postgres => Box::new(sql_schema_describer::postgres::SqlSchemaDescriber::new(
@@ -94,6 +208,29 @@ impl TestApi {
         }
     }
 
+    /// Like [`TestApi::describer`], but with `lenient_types` turned on.
+    fn lenient_describer<'a>(&self, connection: &'a dyn Queryable) -> Box<dyn SqlSchemaDescriberBackend + 'a> {
+        match self.sql_family() {
+            SqlFamily::Postgres => {
+                let mut circumstances = if self.tags.contains(Tags::CockroachDb) {
+                    Circumstances::Cockroach.into()
+                } else {
+                    BitFlags::default()
+                };
+                circumstances |= Circumstances::LenientTypes;
+
+                Box::new(sql_schema_describer::postgres::SqlSchemaDescriber::new(
+                    connection,
+                    circumstances,
+                ))
+            }
+            SqlFamily::Sqlite => Box::new(
+                sql_schema_describer::sqlite::SqlSchemaDescriber::new(connection).with_lenient_types(true),
+            ),
+            _ => self.describer(connection),
+        }
+    }
+
     pub(crate) fn db_name(&self) -> &'static str {
         self.db_name
     }
@@ -325,4 +462,19 @@ impl<'a> ForeignKeyAssertion<'a> {
         assert_eq!(self.fk.on_delete_action(), &expected);
         self
     }
+
+    pub fn assert_on_update(&self, expected: ForeignKeyAction) -> &Self {
+        assert_eq!(self.fk.on_update_action(), &expected);
+        self
+    }
+
+    pub fn assert_is_validated(&self) -> &Self {
+        assert!(self.fk.inner().validated);
+        self
+    }
+
+    pub fn assert_is_not_validated(&self) -> &Self {
+        assert!(!self.fk.inner().validated);
+        self
+    }
 }
@@ -214,6 +214,8 @@ async fn main() -> anyhow::Result<()> {
                 schema,
                 false,
                 CompositeTypeDepth::from(composite_type_depth.unwrap_or(0)),
+                false,
+                Default::default(),
             )
             .await
             .map_err(|err| anyhow::anyhow!("{:?}", err.data))?;
@@ -322,10 +324,15 @@ async fn generate_dmmf(cmd: &DmmfCommand) -> anyhow::Result<()> {
         if let Some(url) = cmd.url.as_ref() {
             let skeleton = minimal_schema_from_url(url)?;
             //todo make this configurable
-            let introspected =
-                introspection_core::RpcImpl::introspect_internal(skeleton, false, CompositeTypeDepth::Infinite)
-                    .await
-                    .map_err(|err| anyhow::anyhow!("{:?}", err.data))?;
+            let introspected = introspection_core::RpcImpl::introspect_internal(
+                skeleton,
+                false,
+                CompositeTypeDepth::Infinite,
+                false,
+                Default::default(),
+            )
+            .await
+            .map_err(|err| anyhow::anyhow!("{:?}", err.data))?;
 
             eprintln!("{}", "Schema was successfully introspected from database URL".green());
 
@@ -371,6 +378,7 @@ async fn schema_push(cmd: &SchemaPush) -> anyhow::Result<()> {
         .schema_push(SchemaPushInput {
             schema,
             force: cmd.force,
+            models: None,
         })
         .await?;
 
@@ -64,6 +64,71 @@ pub fn test_connector(attr: TokenStream, input: TokenStream) -> TokenStream {
     };
     let ignore_attr = attrs.ignore_reason.map(|reason| quote!(#[ignore = #reason]));
 
+    if !attrs.versions.is_empty() {
+        let generated = attrs.versions.iter().map(|version| {
+            let versioned_fn_name = quote::format_ident!(
+                "{}_{}",
+                test_function_name,
+                version.get_ident().unwrap().to_string().to_lowercase()
+            );
+
+            let setup = quote! {
+                let args = match test_setup::TestApiArgs::for_version(
+                    #test_function_name_lit,
+                    &[#(#preview_features,)*],
+                    Tags::#version,
+                ) {
+                    Some(args) => args,
+                    None => return,
+                };
+
+                if test_setup::should_skip_test(
+                    &args,
+                    BitFlags::empty(),
+                    BitFlags::empty() #(| Tags::#exclude_tagged)*,
+                    BitFlags::empty() #(| Capabilities::#capabilities)*,
+                ) { return }
+            };
+
+            if sig.asyncness.is_some() {
+                let (return_ty, unwrap) = match sig.output {
+                    syn::ReturnType::Default => (quote!(()), quote!()),
+                    syn::ReturnType::Type(_, ref ty) => (quote!(#ty), quote!(.unwrap())),
+                };
+
+                quote! {
+                    #[test]
+                    #ignore_attr
+                    fn #versioned_fn_name() {
+                        #setup
+
+                        test_setup::runtime::run_with_tokio::<#return_ty, _>(async {
+                            let #arg_name = &#arg_type::new(args).await;
+
+                            #body
+
+                        })#unwrap;
+                    }
+                }
+            } else {
+                quote! {
+                    #[test]
+                    #ignore_attr
+                    fn #versioned_fn_name() {
+                        #setup
+
+                        #[allow(all)]
+                        let mut #arg_name = #arg_type::new(args);
+
+                        #body
+                    }
+                }
+            }
+        });
+
+        return quote! { #(#generated)* }.into();
+    }
+
     let tokens = if sig.asyncness.is_some() {
         let (return_ty, unwrap) = match sig.output {
             syn::ReturnType::Default => (quote!(()), quote!()),
@@ -121,6 +186,7 @@ struct TestConnectorAttrs {
     include_tagged: Vec<syn::Path>,
     exclude_tagged: Vec<syn::Path>,
     capabilities: Vec<syn::Path>,
+    versions: Vec<syn::Path>,
     preview_features: Vec<syn::LitStr>,
     ignore_reason: Option<LitStr>,
 }
@@ -131,6 +197,7 @@ impl TestConnectorAttrs {
             p if p.is_ident("tags") => &mut self.include_tagged,
             p if p.is_ident("exclude") => &mut self.exclude_tagged,
             p if p.is_ident("capabilities") => &mut self.capabilities,
+            p if p.is_ident("versions") => &mut self.versions,
             p if p.is_ident("preview_features") => {
                 self.preview_features.reserve(list.nested.len());
 
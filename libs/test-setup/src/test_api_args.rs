@@ -30,13 +30,22 @@ static DB_UNDER_TEST: Lazy<Result<DbUnderTest, String>> = Lazy::new(|| {
         std::env::var("TEST_DATABASE_URL").map_err(|_| MISSING_TEST_DATABASE_URL_MSG.to_owned())?
     };
     let shadow_database_url = std::env::var("TEST_SHADOW_DATABASE_URL").ok();
+
+    logging::init_logger();
+
+    db_under_test_from_url(database_url, shadow_database_url)
+});
+
+/// Resolve a `DbUnderTest` from an arbitrary connection string, the same way the main
+/// `TEST_DATABASE_URL`-driven one is resolved. Used both for the default, process-wide database
+/// under test, and for the extra, version-specific databases a test can opt into with
+/// `#[test_connector(versions(...))]`.
+fn db_under_test_from_url(database_url: String, shadow_database_url: Option<String>) -> Result<DbUnderTest, String> {
     let prefix = database_url
         .find(':')
         .map(|prefix_end| &database_url[..prefix_end])
         .unwrap_or_else(|| database_url.as_str());
 
-    logging::init_logger();
-
     match prefix {
         "file" | "sqlite" => Ok(DbUnderTest {
             database_url,
@@ -90,7 +99,7 @@ static DB_UNDER_TEST: Lazy<Result<DbUnderTest, String>> = Lazy::new(|| {
         }),
         _ => Err("Unknown database URL".into()),
     }
-});
+}
 
 /// Crate-public interface to the global test database state.
 pub(crate) fn db_under_test() -> &'static DbUnderTest {
@@ -108,6 +117,23 @@ pub(crate) fn db_under_test() -> &'static DbUnderTest {
     }
 }
 
+/// The environment variable a specific version tag's connection string is read from, for tests
+/// that opt into running against more than one server version with `versions(...)`.
+fn env_var_for_version_tag(tag: Tags) -> Option<&'static str> {
+    match tag {
+        Tags::Postgres9 => Some("TEST_DATABASE_URL_POSTGRES_9"),
+        Tags::Postgres12 => Some("TEST_DATABASE_URL_POSTGRES_12"),
+        Tags::Postgres14 => Some("TEST_DATABASE_URL_POSTGRES_14"),
+        Tags::Postgres15 => Some("TEST_DATABASE_URL_POSTGRES_15"),
+        Tags::Mysql56 => Some("TEST_DATABASE_URL_MYSQL_5_6"),
+        Tags::Mysql57 => Some("TEST_DATABASE_URL_MYSQL_5_7"),
+        Tags::Mysql8 => Some("TEST_DATABASE_URL_MYSQL_8"),
+        Tags::Mssql2017 => Some("TEST_DATABASE_URL_MSSQL_2017"),
+        Tags::Mssql2019 => Some("TEST_DATABASE_URL_MSSQL_2019"),
+        _ => None,
+    }
+}
+
 /// Context for test initialization.
 #[derive(Debug)]
 pub struct TestApiArgs {
@@ -127,6 +153,42 @@ impl TestApiArgs {
         }
     }
 
+    /// Build test args pinned to a specific server version tag (e.g. `Tags::Postgres9`), reading
+    /// its connection string from a dedicated environment variable instead of the default
+    /// `TEST_DATABASE_URL`. Returns `None`, after logging why, when that version isn't configured
+    /// in the current environment, so the caller can skip the test gracefully.
+    pub fn for_version(
+        test_function_name: &'static str,
+        preview_features: &'static [&'static str],
+        tag: Tags,
+    ) -> Option<Self> {
+        let env_var = env_var_for_version_tag(tag).unwrap_or_else(|| panic!("{:?} is not a version tag", tag));
+
+        let database_url = match std::env::var(env_var) {
+            Ok(url) => url,
+            Err(_) => {
+                println!("Test skipped: {} is not set, skipping the {:?} run", env_var, tag);
+                return None;
+            }
+        };
+
+        let shadow_database_url = std::env::var("TEST_SHADOW_DATABASE_URL").ok();
+
+        let db = match db_under_test_from_url(database_url, shadow_database_url) {
+            Ok(db) => Box::leak(Box::new(db)),
+            Err(explanation) => {
+                println!("Test skipped: could not use {}: {}", env_var, explanation);
+                return None;
+            }
+        };
+
+        Some(TestApiArgs {
+            test_function_name,
+            preview_features,
+            db,
+        })
+    }
+
     pub fn preview_features(&self) -> &'static [&'static str] {
         self.preview_features
     }
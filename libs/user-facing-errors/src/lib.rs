@@ -66,6 +66,11 @@ pub struct Error {
     is_panic: bool,
     #[serde(flatten)]
     inner: ErrorType,
+    /// Context frames pushed by intermediate layers (e.g. a describer, a connector flavour) as
+    /// this error propagated, oldest (deepest) first, if any were attached. See
+    /// `migration_connector::ConnectorError::with_context`, the main producer of these.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    context_chain: Option<serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
@@ -98,6 +103,7 @@ impl Error {
                 backtrace: Some(format!("{:?}", backtrace::Backtrace::new())),
             }),
             is_panic: false,
+            context_chain: None,
         }
     }
 
@@ -108,6 +114,7 @@ impl Error {
                 backtrace: None,
             }),
             is_panic: false,
+            context_chain: None,
         }
     }
 
@@ -133,6 +140,7 @@ impl Error {
                 backtrace,
             }),
             is_panic: true,
+            context_chain: None,
         }
     }
 
@@ -141,6 +149,7 @@ impl Error {
         Error {
             inner: ErrorType::Known(err),
             is_panic: false,
+            context_chain: None,
         }
     }
 
@@ -153,6 +162,7 @@ impl Error {
                 backtrace: None,
             }),
             is_panic: true,
+            context_chain: None,
         }
     }
 
@@ -170,6 +180,15 @@ impl Error {
             err @ ErrorType::Unknown(_) => panic!("Expected known error, got {:?}", err),
         }
     }
+
+    /// Attach a chain of context frames pushed by intermediate layers before this error reached
+    /// its final, user-facing form. Serialized as a top-level `context_chain` field, alongside
+    /// `message`/`meta`, so it survives even though it isn't part of any specific known error's
+    /// metadata.
+    pub fn with_context_chain(mut self, context_chain: serde_json::Value) -> Self {
+        self.context_chain = Some(context_chain);
+        self
+    }
 }
 
 pub fn new_backtrace() -> backtrace::Backtrace {
@@ -181,6 +200,7 @@ impl From<UnknownError> for Error {
         Error {
             inner: ErrorType::Unknown(unknown_error),
             is_panic: false,
+            context_chain: None,
         }
     }
 }
@@ -190,6 +210,7 @@ impl From<KnownError> for Error {
         Error {
             is_panic: false,
             inner: ErrorType::Known(known_error),
+            context_chain: None,
         }
     }
 }
@@ -54,6 +54,12 @@ pub struct DatabaseSchemaNotEmpty;
 #[derive(Debug, Serialize)]
 pub struct MigrationDoesNotApplyCleanly {
     pub migration_name: String,
+    /// 1-based position of the failing migration in the migrations history.
+    pub migration_index: usize,
+    /// Total number of migrations in the history that was being replayed.
+    pub migration_count: usize,
+    /// The first non-comment line of the failing migration script, if one could be extracted.
+    pub first_statement_excerpt: Option<String>,
     pub inner_error: crate::Error,
 }
 
@@ -70,11 +76,16 @@ impl crate::UserFacingError for MigrationDoesNotApplyCleanly {
             crate::ErrorType::Unknown(_) => String::new(),
         };
 
+        let first_statement_excerpt = self.first_statement_excerpt.as_deref().unwrap_or("(not available)");
+
         format!(
-            "Migration `{migration_name}` failed to apply cleanly to the shadow database. \n{error_code}Error:\n{inner_error}",
+            "Migration `{migration_name}` failed to apply cleanly to the shadow database. \n{error_code}Error:\n{inner_error}\n\nThis is migration {migration_index} of {migration_count} in your migration history. The first statement of the failing migration was:\n{first_statement_excerpt}\n\nThe migration was only applied to the disposable shadow database used to compute the diff. Your main database has not been modified.",
             migration_name = self.migration_name,
             inner_error = self.inner_error.message(),
-            error_code = error_code
+            error_code = error_code,
+            migration_index = self.migration_index,
+            migration_count = self.migration_count,
+            first_statement_excerpt = first_statement_excerpt,
         )
     }
 }
@@ -267,6 +278,46 @@ pub struct ForeignKeyCreationNotAllowed;
 )]
 pub struct DirectDdlNotAllowed;
 
+#[derive(Debug, Serialize, UserFacingError)]
+#[user_facing(
+    code = "P3023",
+    message = "The index cannot be created because it exceeds the maximum key length for this database: max key length is {max_key_length_bytes} bytes. Hint: add an explicit length to the indexed column(s), for example `@db.VarChar(191)`, so the index fits within the limit."
+)]
+pub struct MysqlKeyTooLong {
+    /// The maximum key length in bytes, as reported by the database.
+    pub max_key_length_bytes: String,
+}
+
+#[derive(Debug, Serialize, UserFacingError)]
+#[user_facing(
+    code = "P3024",
+    message = "The relation between the column `{referencing_column}` and the referenced column `{referenced_column}` cannot be created because their types are incompatible. Hint: make sure both sides of the relation use the same native type, for example by adding matching `@db` type attributes to both columns."
+)]
+pub struct MysqlIncompatibleForeignKeyColumnTypes {
+    pub referencing_column: String,
+    pub referenced_column: String,
+}
+
+#[derive(Debug, Serialize, UserFacingError)]
+#[user_facing(
+    code = "P3025",
+    message = "The `_prisma_migrations` table is missing required columns that cannot be added automatically: {details}. Please repair or recreate the table before running migrate commands again. Read more: https://pris.ly/d/migrate-baseline"
+)]
+pub struct MigrationsTableIncompatible {
+    /// A description of the incompatibilities found on the migrations table.
+    pub details: String,
+}
+
+#[derive(Debug, Serialize, UserFacingError)]
+#[user_facing(
+    code = "P3026",
+    message = "The database could not be reached after retrying for {seconds_waited} seconds. Azure SQL serverless databases automatically pause when idle and can take up to a minute to resume after the first connection attempt following a pause. Please try running the command again."
+)]
+pub struct AzureMssqlDatabasePaused {
+    /// How long, in seconds, we kept retrying the connection before giving up.
+    pub seconds_waited: u64,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
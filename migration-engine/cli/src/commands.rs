@@ -1,54 +1,223 @@
 use crate::logger::log_error_and_exit;
-use migration_connector::ConnectorError;
-use migration_core::json_rpc::types::{DatasourceParam, UrlContainer};
+use migration_connector::{BoxFuture, ConnectorError, ConnectorHost, ConnectorResult};
+use migration_core::json_rpc::types::{DatasourceParam, DiffParams, DiffTarget, SchemaContainer, UrlContainer};
+use serde::Serialize;
+use std::sync::{Arc, Mutex};
 use structopt::StructOpt;
 use user_facing_errors::common::SchemaParserError;
 
 #[derive(Debug, StructOpt)]
 pub(crate) struct Cli {
-    /// The connection string to the database
+    /// The connection string to the database. Required by every command except `diff`, which
+    /// never reads it.
     #[structopt(long, short = "d", parse(try_from_str = parse_base64_string))]
-    datasource: String,
+    datasource: Option<String>,
+    /// Print the result as a single JSON object to stdout, instead of a human-readable message on
+    /// stderr. Errors are still reported with a non-zero exit code.
+    #[structopt(long)]
+    json: bool,
     #[structopt(subcommand)]
     command: CliCommand,
 }
 
 impl Cli {
     pub(crate) async fn run(self) {
+        let json = self.json;
+
         match self.run_inner().await {
-            Ok(msg) => {
-                tracing::info!("{}", msg);
+            Ok(outcome) => {
+                if json {
+                    println!("{}", serde_json::to_string(&outcome.into_json()).unwrap());
+                } else {
+                    tracing::info!("{}", outcome.into_message());
+                }
+            }
+            Err(error) if json => {
+                println!("{}", serde_json::to_string(&CliJsonFailure::from(&error)).unwrap());
+                std::process::exit(1);
             }
             Err(error) => log_error_and_exit(error),
         }
     }
 
-    pub(crate) async fn run_inner(self) -> Result<String, ConnectorError> {
-        let api = migration_core::migration_api(None, None)?;
+    pub(crate) async fn run_inner(self) -> Result<CliOutcome, ConnectorError> {
+        let host = Arc::new(CapturingHost::default());
+        let api = migration_core::migration_api(None, Some(host.clone()))?;
         match self.command {
-            CliCommand::CreateDatabase => {
+            CliCommand::CreateDatabase { print: false } => {
                 let migration_core::json_rpc::types::CreateDatabaseResult { database_name } = api
                     .create_database(migration_core::json_rpc::types::CreateDatabaseParams {
                         datasource: DatasourceParam::ConnectionString(UrlContainer {
-                            url: self.datasource.clone(),
+                            url: self.datasource()?.to_owned(),
+                        }),
+                    })
+                    .await?;
+                Ok(CliOutcome::CreateDatabase { database_name })
+            }
+            CliCommand::CreateDatabase { print: true } => {
+                let plan = api
+                    .create_database_plan(migration_core::json_rpc::types::CreateDatabaseParams {
+                        datasource: DatasourceParam::ConnectionString(UrlContainer {
+                            url: self.datasource()?.to_owned(),
                         }),
                     })
                     .await?;
-                Ok(format!("Database '{}' was successfully created.", database_name))
+                Ok(CliOutcome::Plan(render_plan(&plan)))
             }
             CliCommand::CanConnectToDatabase => {
                 api.ensure_connection_validity(migration_core::json_rpc::types::EnsureConnectionValidityParams {
                     datasource: DatasourceParam::ConnectionString(UrlContainer {
-                        url: self.datasource.clone(),
+                        url: self.datasource()?.to_owned(),
                     }),
                 })
                 .await?;
-                Ok("Connection successful".to_owned())
+                Ok(CliOutcome::CanConnectToDatabase)
+            }
+            CliCommand::DropDatabase { print: false } => {
+                api.drop_database(self.datasource()?.to_owned()).await?;
+                Ok(CliOutcome::DropDatabase)
             }
-            CliCommand::DropDatabase => {
-                api.drop_database(self.datasource.clone()).await?;
-                Ok("The database was successfully dropped.".to_owned())
+            CliCommand::DropDatabase { print: true } => {
+                let plan = api.drop_database_plan(self.datasource()?.to_owned()).await?;
+                Ok(CliOutcome::Plan(render_plan(&plan)))
             }
+            CliCommand::Diff {
+                from_schema,
+                from_empty,
+                to_schema,
+            } => {
+                let from = match (from_schema, from_empty) {
+                    (Some(schema), false) => DiffTarget::SchemaDatamodel(SchemaContainer { schema }),
+                    (None, true) => DiffTarget::Empty,
+                    (Some(_), true) => {
+                        return Err(ConnectorError::from_msg(
+                            "`--from-schema` and `--from-empty` are mutually exclusive.".to_owned(),
+                        ))
+                    }
+                    (None, false) => {
+                        return Err(ConnectorError::from_msg(
+                            "Pass either `--from-schema <FILE>` or `--from-empty`.".to_owned(),
+                        ))
+                    }
+                };
+
+                let result = api
+                    .diff(DiffParams {
+                        from,
+                        to: DiffTarget::SchemaDatamodel(SchemaContainer { schema: to_schema }),
+                        shadow_database_url: None,
+                        script: true,
+                        exit_code: Some(true),
+                    })
+                    .await?;
+
+                // With `exit_code` set, the engine reports 0 only when the diff is empty. The
+                // rendered script is then just a comment, which is not reported.
+                let script = host.take();
+                Ok(CliOutcome::Diff(if result.exit_code == 0 { String::new() } else { script }))
+            }
+        }
+    }
+
+    /// The connection string passed with `-d`/`--datasource`, or a user-facing error if the
+    /// current command needs one and it wasn't provided.
+    fn datasource(&self) -> Result<&str, ConnectorError> {
+        self.datasource
+            .as_deref()
+            .ok_or_else(|| ConnectorError::from_msg("This command requires the `-d`/`--datasource` flag.".to_owned()))
+    }
+}
+
+/// A [`ConnectorHost`] that buffers printed text instead of writing it anywhere, so `run_inner`
+/// can hand it back as part of a [`CliOutcome`], the same way every other command in this CLI
+/// returns its result as data instead of writing to the terminal directly.
+#[derive(Debug, Default)]
+struct CapturingHost(Mutex<String>);
+
+impl CapturingHost {
+    fn take(&self) -> String {
+        std::mem::take(&mut self.0.lock().unwrap())
+    }
+}
+
+impl ConnectorHost for CapturingHost {
+    fn print<'a>(&'a self, text: &'a str) -> BoxFuture<'a, ConnectorResult<()>> {
+        Box::pin(async move {
+            self.0.lock().unwrap().push_str(text);
+            Ok(())
+        })
+    }
+}
+
+/// The result of running a [`Cli`] command, in a form that can be rendered either as a
+/// human-readable message or as a JSON payload.
+pub(crate) enum CliOutcome {
+    CreateDatabase { database_name: String },
+    CanConnectToDatabase,
+    DropDatabase,
+    /// The rendered SQL and connection summary produced by a `--print` command. These are meant
+    /// to be read by a human, so they are not given a JSON representation.
+    Plan(String),
+    /// The rendered migration script produced by `diff`. Empty if the two schemas are identical.
+    Diff(String),
+}
+
+impl CliOutcome {
+    fn into_message(self) -> String {
+        match self {
+            CliOutcome::CreateDatabase { database_name } => {
+                format!("Database '{}' was successfully created.", database_name)
+            }
+            CliOutcome::CanConnectToDatabase => "Connection successful".to_owned(),
+            CliOutcome::DropDatabase => "The database was successfully dropped.".to_owned(),
+            CliOutcome::Plan(plan) => plan,
+            CliOutcome::Diff(script) => script,
+        }
+    }
+
+    fn into_json(self) -> CliJsonSuccess {
+        match self {
+            CliOutcome::CreateDatabase { database_name } => CliJsonSuccess::CreateDatabase { ok: true, database_name },
+            CliOutcome::CanConnectToDatabase => CliJsonSuccess::CanConnectToDatabase { ok: true },
+            CliOutcome::DropDatabase => CliJsonSuccess::DropDatabase { ok: true },
+            CliOutcome::Plan(plan) => CliJsonSuccess::Plan { ok: true, plan },
+            CliOutcome::Diff(script) => CliJsonSuccess::Diff { ok: true, script },
+        }
+    }
+}
+
+/// The `--json` success payload for a [`Cli`] command, e.g.
+/// `{"command":"create-database","ok":true,"database_name":"foo"}`.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(tag = "command", rename_all = "kebab-case")]
+pub(crate) enum CliJsonSuccess {
+    CreateDatabase { ok: bool, database_name: String },
+    CanConnectToDatabase { ok: bool },
+    DropDatabase { ok: bool },
+    Plan { ok: bool, plan: String },
+    Diff { ok: bool, script: String },
+}
+
+/// The `--json` failure payload for a [`Cli`] command, e.g.
+/// `{"ok":false,"error_code":"P1003","message":"..."}`.
+#[derive(Debug, PartialEq, Serialize)]
+pub(crate) struct CliJsonFailure {
+    ok: bool,
+    error_code: Option<String>,
+    message: String,
+}
+
+impl From<&ConnectorError> for CliJsonFailure {
+    fn from(error: &ConnectorError) -> Self {
+        let message = match error.known_error() {
+            Some(known_error) => known_error.message.clone(),
+            None => error.to_string(),
+        };
+
+        CliJsonFailure {
+            ok: false,
+            error_code: error.error_code().map(ToOwned::to_owned),
+            message,
         }
     }
 }
@@ -57,11 +226,49 @@ impl Cli {
 #[allow(clippy::enum_variant_names)] // disagee
 enum CliCommand {
     /// Create an empty database defined in the configuration string.
-    CreateDatabase,
+    CreateDatabase {
+        /// Print the SQL that would be executed and the target connection, instead of running it.
+        #[structopt(long)]
+        print: bool,
+    },
     /// Does the database connection string work?
     CanConnectToDatabase,
     /// Drop the database.
-    DropDatabase,
+    DropDatabase {
+        /// Print the SQL that would be executed and the target connection, instead of running it.
+        #[structopt(long)]
+        print: bool,
+    },
+    /// Compare two Prisma schemas and print the migration script needed to go from one to the
+    /// other. Does not require `-d`/`--datasource`, and never opens a database connection: both
+    /// schemas are read and diffed as static files.
+    Diff {
+        /// Path to the Prisma schema to diff from.
+        #[structopt(long)]
+        from_schema: Option<String>,
+        /// Diff from an empty schema, instead of `--from-schema`.
+        #[structopt(long)]
+        from_empty: bool,
+        /// Path to the Prisma schema to diff to.
+        #[structopt(long)]
+        to_schema: String,
+    },
+}
+
+/// Render a [`migration_connector::DatabasePlan`] the way `--print` reports it.
+fn render_plan(plan: &migration_connector::DatabasePlan) -> String {
+    let mut rendered = format!("-- Connection: {}\n", plan.connection_summary);
+
+    if plan.statements.is_empty() {
+        rendered.push_str("-- No SQL statements would be executed.\n");
+    }
+
+    for statement in &plan.statements {
+        rendered.push_str(statement);
+        rendered.push('\n');
+    }
+
+    rendered
 }
 
 fn parse_base64_string(s: &str) -> Result<String, ConnectorError> {
@@ -75,3 +282,87 @@ fn parse_base64_string(s: &str) -> Result<String, ConnectorError> {
         Err(_) => Ok(String::from(s)),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use user_facing_errors::UserFacingError;
+
+    #[test]
+    fn create_database_success_is_serialized_correctly() {
+        let outcome = CliOutcome::CreateDatabase {
+            database_name: "foo".to_owned(),
+        };
+
+        assert_eq!(
+            serde_json::to_string(&outcome.into_json()).unwrap(),
+            r#"{"command":"create-database","ok":true,"database_name":"foo"}"#
+        );
+    }
+
+    #[test]
+    fn can_connect_to_database_success_is_serialized_correctly() {
+        let outcome = CliOutcome::CanConnectToDatabase;
+
+        assert_eq!(
+            serde_json::to_string(&outcome.into_json()).unwrap(),
+            r#"{"command":"can-connect-to-database","ok":true}"#
+        );
+    }
+
+    #[test]
+    fn drop_database_success_is_serialized_correctly() {
+        let outcome = CliOutcome::DropDatabase;
+
+        assert_eq!(
+            serde_json::to_string(&outcome.into_json()).unwrap(),
+            r#"{"command":"drop-database","ok":true}"#
+        );
+    }
+
+    #[test]
+    fn diff_success_is_serialized_correctly() {
+        let outcome = CliOutcome::Diff("-- This is an empty migration.".to_owned());
+
+        assert_eq!(
+            serde_json::to_string(&outcome.into_json()).unwrap(),
+            r#"{"command":"diff","ok":true,"script":"-- This is an empty migration."}"#
+        );
+    }
+
+    #[test]
+    fn known_error_failure_is_serialized_correctly() {
+        let error = ConnectorError::user_facing(SchemaParserError {
+            full_error: "boom".to_owned(),
+        });
+
+        let failure = CliJsonFailure::from(&error);
+
+        assert_eq!(failure.ok, false);
+        assert_eq!(failure.error_code.as_deref(), Some(SchemaParserError::ERROR_CODE));
+        assert_eq!(
+            serde_json::to_string(&failure).unwrap(),
+            format!(
+                r#"{{"ok":false,"error_code":"{}","message":"{}"}}"#,
+                SchemaParserError::ERROR_CODE,
+                failure.message
+            )
+        );
+    }
+
+    #[test]
+    fn unknown_error_failure_is_serialized_correctly() {
+        let error = ConnectorError::from_msg("something went wrong".to_owned());
+
+        let failure = CliJsonFailure::from(&error);
+
+        assert_eq!(failure.ok, false);
+        assert_eq!(failure.error_code, None);
+        assert!(failure.message.starts_with("something went wrong"));
+
+        // `error_code` must still be a literal JSON null, not an omitted field.
+        let json: serde_json::Value = serde_json::from_str(&serde_json::to_string(&failure).unwrap()).unwrap();
+        assert_eq!(json["ok"], false);
+        assert_eq!(json["error_code"], serde_json::Value::Null);
+    }
+}
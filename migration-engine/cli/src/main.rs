@@ -5,9 +5,19 @@ mod logger;
 
 use migration_connector::{BoxFuture, ConnectorHost, ConnectorResult};
 use migration_core::rpc_api;
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 use structopt::StructOpt;
 
+/// How long we wait, after receiving a shutdown signal, for the RPC call in flight to finish
+/// before exiting anyway. Configurable through `PRISMA_MIGRATE_SHUTDOWN_GRACE_PERIOD_MS` mostly
+/// for the benefit of tests, which do not want to wait 10 seconds.
+const DEFAULT_SHUTDOWN_GRACE_PERIOD: Duration = Duration::from_secs(10);
+
+/// Exit code used whenever the RPC server stops, which only happens after a shutdown signal,
+/// whether the operations in flight finished within the grace period or not. Distinct from the
+/// default `0` so orchestrators can tell a signal-driven shutdown apart from a normal exit.
+const SIGNAL_SHUTDOWN_EXIT_CODE: i32 = 143; // 128 + SIGTERM(15), following the usual shell convention.
+
 /// When no subcommand is specified, the migration engine will default to starting as a JSON-RPC
 /// server over stdio.
 #[derive(Debug, StructOpt)]
@@ -113,7 +123,61 @@ async fn start_engine(datamodel_location: Option<&str>) {
     let (client, adapter) = json_rpc_stdio::new_client();
     let host = JsonRpcHost { client };
 
-    let api = rpc_api(datamodel, Arc::new(host));
-    // Block the thread and handle IO in async until EOF.
-    json_rpc_stdio::run_with_client(&api, adapter).await.unwrap();
+    let (io_handler, generic_api) = rpc_api(datamodel, Arc::new(host));
+    let grace_period = shutdown_grace_period();
+
+    // Block the thread and handle IO in async until we are asked to shut down. Reaching EOF on
+    // stdin does not end this loop.
+    let outcome = json_rpc_stdio::run_with_client_until_shutdown(&io_handler, adapter, shutdown_signal(), grace_period)
+        .await
+        .unwrap();
+
+    match outcome {
+        json_rpc_stdio::ShutdownOutcome::Graceful => {
+            tracing::info!("Shut down gracefully.");
+        }
+        json_rpc_stdio::ShutdownOutcome::TimedOut { in_flight_methods } => {
+            tracing::warn!(
+                ?in_flight_methods,
+                grace_period_ms = grace_period.as_millis() as u64,
+                "Shutdown grace period elapsed with operations still running, exiting anyway."
+            );
+        }
+    }
+
+    generic_api.release_all_locks().await;
+
+    std::process::exit(SIGNAL_SHUTDOWN_EXIT_CODE);
+}
+
+fn shutdown_grace_period() -> Duration {
+    std::env::var("PRISMA_MIGRATE_SHUTDOWN_GRACE_PERIOD_MS")
+        .ok()
+        .and_then(|value| value.parse::<u64>().ok())
+        .map(Duration::from_millis)
+        .unwrap_or(DEFAULT_SHUTDOWN_GRACE_PERIOD)
+}
+
+/// Resolves when the process receives SIGTERM (Unix only) or SIGINT (Ctrl+C, all platforms).
+async fn shutdown_signal() {
+    #[cfg(unix)]
+    {
+        let mut sigterm = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("failed to install SIGTERM handler");
+
+        tokio::select! {
+            _ = sigterm.recv() => {
+                tracing::info!("Received SIGTERM.");
+            }
+            _ = tokio::signal::ctrl_c() => {
+                tracing::info!("Received SIGINT.");
+            }
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        tokio::signal::ctrl_c().await.expect("failed to install Ctrl+C handler");
+        tracing::info!("Received Ctrl+C.");
+    }
 }
@@ -4,12 +4,12 @@
 
 /// Compute the checksum for a new migration script, and render it formatted to
 /// a human readable string.
-pub(crate) fn render_checksum(script: &str) -> String {
+pub fn render_checksum(script: &str) -> String {
     compute_checksum(script).format_checksum()
 }
 
 /// Returns whether a migration script matches an existing checksum.
-pub(crate) fn script_matches_checksum(script: &str, checksum: &str) -> bool {
+pub fn script_matches_checksum(script: &str, checksum: &str) -> bool {
     use std::iter::{once, once_with};
 
     // Checksum with potentially different line endings, so checksums will match
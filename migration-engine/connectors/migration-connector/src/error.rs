@@ -19,6 +19,20 @@ pub struct ConnectorError(Box<ConnectorErrorImpl>);
 /// variant is a [ConnectorError](/error/enum.ConnectorError.html).
 pub type ConnectorResult<T> = Result<T, ConnectorError>;
 
+/// A single layer of context attached to a [`ConnectorError`] as it propagates from where it
+/// originated (e.g. a raw quaint error) up through the describer, the flavour and finally the RPC
+/// boundary. Each layer that has useful information but isn't the right place to render a final
+/// user-facing message pushes a frame instead of discarding what it knew, so the original
+/// SQLSTATE, failing query, etc. all survive to the top.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ErrorContextFrame {
+    /// A short, human-readable description of what this layer was doing when the error occurred.
+    pub message: String,
+    /// Structured details specific to this layer (e.g. `{ "sqlstate": "23505" }`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<serde_json::Value>,
+}
+
 #[derive(Debug, Clone)]
 struct ConnectorErrorImpl {
     /// An optional error already rendered for users in case the migration core does not handle it.
@@ -29,6 +43,9 @@ struct ConnectorErrorImpl {
     source: Option<Arc<(dyn StdError + Send + Sync + 'static)>>,
     /// See the tracing-error docs.
     context: SpanTrace,
+    /// Context frames pushed by intermediate layers, oldest (deepest) first. See
+    /// [`ErrorContextFrame`].
+    context_chain: Vec<ErrorContextFrame>,
 }
 
 impl Debug for ConnectorError {
@@ -82,6 +99,7 @@ impl ConnectorError {
             context: SpanTrace::capture(),
             message: Some(msg.into_boxed_str()),
             source: None,
+            context_chain: Vec::new(),
         }))
     }
 
@@ -92,6 +110,7 @@ impl ConnectorError {
             message: Some(context),
             source: Some(Arc::new(source)),
             context: SpanTrace::capture(),
+            context_chain: Vec::new(),
         }))
     }
 
@@ -102,14 +121,54 @@ impl ConnectorError {
             message: Some(context.into()),
             source: Some(Arc::new(source)),
             context: SpanTrace::capture(),
+            context_chain: Vec::new(),
         }))
     }
 
+    /// The chain of context frames pushed by intermediate layers (describer, flavour, ...) as
+    /// this error propagated, oldest (deepest) first. See [`ErrorContextFrame`].
+    pub fn context_chain(&self) -> &[ErrorContextFrame] {
+        &self.0.context_chain
+    }
+
+    /// Attach a context frame describing what the current layer was doing when this error
+    /// occurred, without discarding the message, source or context frames already carried by the
+    /// error. Use this instead of building a fresh `ConnectorError` when a layer (the describer,
+    /// a flavour's quaint error mapping, ...) has useful context but isn't the right place to
+    /// render the final user-facing message.
+    pub fn with_context(self, message: impl Into<String>) -> Self {
+        self.with_context_and_fields(message, None)
+    }
+
+    /// Like [`ConnectorError::with_context`], with structured fields specific to this layer (e.g.
+    /// `{ "sqlstate": "23505" }`).
+    pub fn with_context_and_fields(mut self, message: impl Into<String>, fields: Option<serde_json::Value>) -> Self {
+        self.0.context_chain.push(ErrorContextFrame {
+            message: message.into(),
+            fields,
+        });
+        self
+    }
+
     /// Turn the error into a nested, user-facing MigrationDoesNotApplyCleanly error.
-    pub fn into_migration_does_not_apply_cleanly(self, migration_name: String) -> Self {
+    ///
+    /// `migration_index` and `migration_count` locate the failing migration in the history (1-based,
+    /// e.g. migration 2 of 5), and `script` is the raw content of the failing migration, used to
+    /// extract a short excerpt of the statement that caused the failure.
+    pub fn into_migration_does_not_apply_cleanly(
+        self,
+        migration_name: String,
+        migration_index: usize,
+        migration_count: usize,
+        script: &str,
+    ) -> Self {
         let context = self.0.context.clone();
+        let context_chain = self.0.context_chain.clone();
         let user_facing_error = user_facing_errors::migration_engine::MigrationDoesNotApplyCleanly {
             migration_name,
+            migration_index,
+            migration_count,
+            first_statement_excerpt: first_non_comment_line(script),
             inner_error: self.to_user_facing(),
         };
 
@@ -117,13 +176,18 @@ impl ConnectorError {
             user_facing_error: Some(KnownError::new(user_facing_error)),
             source: Some(Arc::new(self)),
             context,
+            context_chain,
             message: None,
         }))
     }
 
-    /// Turn the error into a nested, user-facing ShadowDbCreationError.
+    /// Turn the error into a nested, user-facing ShadowDbCreationError. Keeps the wrapped error's
+    /// context chain (rather than starting a fresh one) and adds a frame for this layer, so the
+    /// original failure (e.g. a permissions error creating the shadow database) stays visible
+    /// alongside the generic "shadow database creation failed" message.
     pub fn into_shadow_db_creation_error(self) -> Self {
         let context = self.0.context.clone();
+        let context_chain = self.0.context_chain.clone();
         let user_facing_error = user_facing_errors::migration_engine::ShadowDbCreationError {
             inner_error: self.to_user_facing(),
         };
@@ -133,12 +197,15 @@ impl ConnectorError {
             message: None,
             context,
             source: Some(Arc::new(self)),
+            context_chain,
         }))
+        .with_context("Failed to create the shadow database")
     }
 
     /// Turn the error into a nested, user-facing SoftResetFailed error.
     pub fn into_soft_reset_failed_error(self) -> Self {
         let context = self.0.context.clone();
+        let context_chain = self.0.context_chain.clone();
         let user_facing_error = user_facing_errors::migration_engine::SoftResetFailed {
             inner_error: self.to_user_facing(),
         };
@@ -146,6 +213,7 @@ impl ConnectorError {
         ConnectorError(Box::new(ConnectorErrorImpl {
             user_facing_error: Some(KnownError::new(user_facing_error)),
             context,
+            context_chain,
             message: None,
             source: Some(Arc::new(self)),
         }))
@@ -168,10 +236,24 @@ impl ConnectorError {
 
     /// Render to a user_facing_error::Error
     pub fn to_user_facing(&self) -> user_facing_errors::Error {
-        match &self.0.user_facing_error {
+        /// How many of the most recent context frames to keep in the serialized error, so a
+        /// pathologically deep chain of wrapping doesn't blow up the payload size.
+        const MAX_CONTEXT_FRAMES: usize = 10;
+
+        let user_facing_error = match &self.0.user_facing_error {
             Some(known_error) => known_error.clone().into(),
             None => user_facing_errors::Error::from_dyn_error(self),
+        };
+
+        if self.0.context_chain.is_empty() {
+            return user_facing_error;
         }
+
+        let skip = self.0.context_chain.len().saturating_sub(MAX_CONTEXT_FRAMES);
+        let context_chain = serde_json::to_value(&self.0.context_chain[skip..])
+            .unwrap_or_else(|_| serde_json::Value::Array(Vec::new()));
+
+        user_facing_error.with_context_chain(context_chain)
     }
 
     /// Construct a GenericError with an associated user facing error.
@@ -181,6 +263,7 @@ impl ConnectorError {
             user_facing_error: Some(KnownError::new(err)),
             source: None,
             context: SpanTrace::capture(),
+            context_chain: Vec::new(),
         }))
     }
 
@@ -199,6 +282,7 @@ impl From<KnownError> for ConnectorError {
             user_facing_error: Some(err),
             source: None,
             context: SpanTrace::capture(),
+            context_chain: Vec::new(),
         }))
     }
 }
@@ -214,6 +298,7 @@ impl From<ReadMigrationScriptError> for ConnectorError {
             context,
             message: None,
             source: Some(Arc::new(err)),
+            context_chain: Vec::new(),
         }))
     }
 }
@@ -230,12 +315,81 @@ fn invalid_connection_string_description(error_details: impl Display) -> String
     format! {r#"{} in database URL. Please refer to the documentation in {} for constructing a correct connection string. In some cases, certain characters must be escaped. Please check the string for any illegal characters."#, error_details, docs}
 }
 
+/// The first non-blank, non-comment line of a migration script, used to give users a pointer to
+/// the statement that is likely to have caused a shadow database replay failure. This is a
+/// best-effort, connector-agnostic heuristic: it does not understand multi-line block comments or
+/// per-connector quoting rules.
+fn first_non_comment_line(script: &str) -> Option<String> {
+    script
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with("--") && !line.starts_with("/*"))
+        .map(ToOwned::to_owned)
+}
+
 #[cfg(test)]
 mod tests {
+    use super::first_non_comment_line;
     use crate::ConnectorError;
 
     #[test]
     fn connector_error_has_the_expected_size() {
         assert_eq!(std::mem::size_of::<ConnectorError>(), std::mem::size_of::<*mut ()>());
     }
+
+    #[test]
+    fn first_non_comment_line_skips_blank_lines_and_comments() {
+        let script = "\n-- a comment\n\n/* also a comment */\nCREATE TABLE a (id INT);\nSELECT 1;\n";
+
+        assert_eq!(first_non_comment_line(script).as_deref(), Some("CREATE TABLE a (id INT);"));
+    }
+
+    #[test]
+    fn first_non_comment_line_returns_none_for_only_comments() {
+        let script = "-- one\n-- two\n";
+
+        assert_eq!(first_non_comment_line(script), None);
+    }
+
+    #[test]
+    fn context_frames_survive_from_the_original_error_to_the_user_facing_rendering() {
+        // Simulate a failure nested through three layers: a raw quaint-style error, the describer,
+        // and finally the flavour/RPC boundary wrapping it into a user-facing error.
+        let err = ConnectorError::from_msg("connection reset by peer".to_owned())
+            .with_context("Failed to execute a database query")
+            .with_context("Failed to introspect the database schema")
+            .into_shadow_db_creation_error();
+
+        let frames = err.context_chain();
+        assert_eq!(frames[0].message, "Failed to execute a database query");
+        assert_eq!(frames[1].message, "Failed to introspect the database schema");
+        assert_eq!(frames[2].message, "Failed to create the shadow database");
+
+        let user_facing = err.to_user_facing();
+        let serialized = serde_json::to_value(&user_facing).unwrap();
+        let context_chain = serialized["context_chain"].as_array().unwrap();
+
+        assert_eq!(context_chain.len(), 3);
+        assert_eq!(context_chain[0]["message"], "Failed to execute a database query");
+        assert_eq!(context_chain[1]["message"], "Failed to introspect the database schema");
+        assert_eq!(context_chain[2]["message"], "Failed to create the shadow database");
+    }
+
+    #[test]
+    fn to_user_facing_only_keeps_the_most_recent_context_frames() {
+        let mut err = ConnectorError::from_msg("boom".to_owned());
+
+        for i in 0..20 {
+            err = err.with_context(format!("layer {}", i));
+        }
+
+        let user_facing = err.to_user_facing();
+        let serialized = serde_json::to_value(&user_facing).unwrap();
+        let context_chain = serialized["context_chain"].as_array().unwrap();
+
+        // Only the 10 most recent frames are kept, not the 10 oldest.
+        assert_eq!(context_chain.len(), 10);
+        assert_eq!(context_chain[0]["message"], "layer 10");
+        assert_eq!(context_chain[9]["message"], "layer 19");
+    }
 }
@@ -2,13 +2,13 @@
 
 //! This crate defines the API exposed by the connectors to the migration engine core. The entry point for this API is the [MigrationConnector](trait.MigrationConnector.html) trait.
 
-mod checksum;
 mod connector_params;
 mod destructive_change_checker;
 mod diff;
 mod error;
 mod migration_persistence;
 
+pub mod checksum;
 pub mod migrations_directory;
 
 pub use connector_params::ConnectorParams;
@@ -16,7 +16,7 @@ pub use destructive_change_checker::{
     DestructiveChangeChecker, DestructiveChangeDiagnostics, MigrationWarning, UnexecutableMigration,
 };
 pub use diff::DiffTarget;
-pub use error::{ConnectorError, ConnectorResult};
+pub use error::{ConnectorError, ConnectorResult, ErrorContextFrame};
 pub use migration_persistence::{MigrationPersistence, MigrationRecord, PersistenceNotInitializedError, Timestamp};
 
 use datamodel::ValidatedSchema;
@@ -58,11 +58,30 @@ impl DatabaseSchema {
     }
 }
 
+/// The result of planning a `create_database` or `drop_database` operation without executing it:
+/// the statements that would be run, and a human-readable, credential-free summary of the
+/// connection they would run against. Backs `--print` in the migration engine CLI.
+pub struct DatabasePlan {
+    /// The statements that would be executed, in the order they would be executed.
+    pub statements: Vec<String>,
+    /// A description of the target connection (host, database, user), with the password and other
+    /// secrets omitted.
+    pub connection_summary: String,
+}
+
 /// An abstract host for a migration connector. It exposes IO that is not directly performed by the
 /// connectors.
 pub trait ConnectorHost: Sync + Send + 'static {
     /// Print to the console.
     fn print<'a>(&'a self, text: &'a str) -> BoxFuture<'a, ConnectorResult<()>>;
+
+    /// Ask the host to confirm a destructive action (e.g. a step that could lead to data loss)
+    /// before it is executed. Hosts that are not interactive, or do not need confirmation, can
+    /// rely on the default implementation, which always answers yes.
+    fn confirm<'a>(&'a self, prompt: &'a str) -> BoxFuture<'a, ConnectorResult<bool>> {
+        let _ = prompt;
+        Box::pin(std::future::ready(Ok(true)))
+    }
 }
 
 /// A no-op ConnectorHost.
@@ -94,6 +113,10 @@ pub trait MigrationConnector: Send + Sync + 'static {
     /// If possible on the target connector, acquire an advisory lock, so multiple instances of migrate do not run concurrently.
     fn acquire_lock(&mut self) -> BoxFuture<'_, ConnectorResult<()>>;
 
+    /// Release the advisory lock previously acquired by `acquire_lock`, if any. This is a no-op
+    /// on connectors that do not support advisory locking, or if no lock is currently held.
+    fn release_lock(&mut self) -> BoxFuture<'_, ConnectorResult<()>>;
+
     /// Applies the migration to the database. Returns the number of executed steps.
     fn apply_migration<'a>(&'a mut self, migration: &'a Migration) -> BoxFuture<'a, ConnectorResult<u32>>;
 
@@ -111,6 +134,14 @@ pub trait MigrationConnector: Send + Sync + 'static {
     /// Create the database referenced by Prisma schema that was used to initialize the connector.
     fn create_database(&mut self) -> BoxFuture<'_, ConnectorResult<String>>;
 
+    /// Compute what `create_database` would do, without doing it. Defaults to an error for
+    /// connectors that have no notion of database-creation statements to print.
+    fn create_database_plan(&mut self) -> ConnectorResult<DatabasePlan> {
+        Err(ConnectorError::from_msg(
+            "`--print` is not supported by this connector.".to_owned(),
+        ))
+    }
+
     /// Send a command to the database directly.
     fn db_execute(&mut self, script: String) -> BoxFuture<'_, ConnectorResult<()>>;
 
@@ -121,6 +152,13 @@ pub trait MigrationConnector: Send + Sync + 'static {
     /// Drop the database referenced by Prisma schema that was used to initialize the connector.
     fn drop_database(&mut self) -> BoxFuture<'_, ConnectorResult<()>>;
 
+    /// Same as [`MigrationConnector::create_database_plan`], for `drop_database`.
+    fn drop_database_plan(&mut self) -> ConnectorResult<DatabasePlan> {
+        Err(ConnectorError::from_msg(
+            "`--print` is not supported by this connector.".to_owned(),
+        ))
+    }
+
     /// An empty database schema (for diffing).
     fn empty_database_schema(&self) -> DatabaseSchema;
 
@@ -195,4 +233,31 @@ pub trait MigrationConnector: Send + Sync + 'static {
         &'a mut self,
         _migrations: &'a [MigrationDirectory],
     ) -> BoxFuture<'a, ConnectorResult<()>>;
+
+    /// Restrict `from` and `to` to the tables backing `models`, for `schemaPush`'s `models`
+    /// filter. The default implementation errors out, since only connectors with a notion of
+    /// tables (i.e. the SQL connectors) can support scoping a push to a subset of models.
+    fn scope_schemas_to_models(
+        &self,
+        _from: DatabaseSchema,
+        _to: DatabaseSchema,
+        _datamodel: &ValidatedSchema,
+        _models: &[String],
+    ) -> ConnectorResult<(DatabaseSchema, DatabaseSchema)> {
+        Err(ConnectorError::from_msg(
+            "The `models` filter of `schemaPush` is not supported by this connector.".to_owned(),
+        ))
+    }
+
+    /// Run a seed script against the database, unless one with the same `name` and script
+    /// contents was already applied and `force` is `false`. Returns whether the seed was skipped.
+    /// The default implementation errors out, since only connectors with bookkeeping tables (i.e.
+    /// the SQL connectors) can support skipping already-applied seeds.
+    fn seed(&mut self, _name: String, _script: String, _force: bool) -> BoxFuture<'_, ConnectorResult<bool>> {
+        Box::pin(async move {
+            Err(ConnectorError::from_msg(
+                "`seed` is not supported by this connector.".to_owned(),
+            ))
+        })
+    }
 }
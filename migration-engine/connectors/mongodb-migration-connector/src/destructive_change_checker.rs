@@ -1,17 +1,59 @@
-use crate::MongoDbMigrationConnector;
+use crate::{
+    migration::{MongoDbMigration, MongoDbMigrationStep},
+    MongoDbMigrationConnector,
+};
 use migration_connector::{
-    BoxFuture, ConnectorResult, DestructiveChangeChecker, DestructiveChangeDiagnostics, Migration,
+    BoxFuture, ConnectorResult, DestructiveChangeChecker, DestructiveChangeDiagnostics, Migration, MigrationWarning,
 };
+use mongodb_schema_describer::CollectionOptions;
 
 impl DestructiveChangeChecker for MongoDbMigrationConnector {
     fn check<'a>(
         &'a mut self,
-        _database_migration: &'a Migration,
+        database_migration: &'a Migration,
     ) -> BoxFuture<'a, ConnectorResult<DestructiveChangeDiagnostics>> {
-        Box::pin(std::future::ready(Ok(DestructiveChangeDiagnostics::new())))
+        Box::pin(std::future::ready(Ok(self.pure_check(database_migration))))
+    }
+
+    fn pure_check(&self, database_migration: &Migration) -> DestructiveChangeDiagnostics {
+        let migration: &MongoDbMigration = database_migration.downcast_ref();
+        let mut diagnostics = DestructiveChangeDiagnostics::new();
+
+        for (step_index, step) in migration.steps.iter().enumerate() {
+            let index_id = match step {
+                MongoDbMigrationStep::CreateIndex(index_id) => index_id,
+                MongoDbMigrationStep::DropIndex(_) | MongoDbMigrationStep::CreateCollection(_) => continue,
+            };
+
+            let collection = migration.next.walk_index(*index_id).collection();
+            let options = collection.options();
+
+            if options.has_unsupported_options() {
+                diagnostics.warnings.push(MigrationWarning {
+                    description: format!(
+                        "Collection `{}` has options that Prisma does not manage ({}). The index change will be applied, but the collection options themselves are left untouched; use the database driver directly to change them.",
+                        collection.name(),
+                        describe_unsupported_options(options),
+                    ),
+                    step_index,
+                });
+            }
+        }
+
+        diagnostics
+    }
+}
+
+fn describe_unsupported_options(options: CollectionOptions) -> String {
+    let mut parts = Vec::new();
+
+    if options.capped {
+        parts.push("capped collection".to_owned());
     }
 
-    fn pure_check(&self, _database_migration: &Migration) -> DestructiveChangeDiagnostics {
-        DestructiveChangeDiagnostics::new()
+    if options.has_validator {
+        parts.push("schema validator".to_owned());
     }
+
+    parts.join(", ")
 }
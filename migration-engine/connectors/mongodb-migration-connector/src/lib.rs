@@ -159,6 +159,10 @@ impl MigrationConnector for MongoDbMigrationConnector {
         Box::pin(future::ready(Ok(())))
     }
 
+    fn release_lock(&mut self) -> BoxFuture<'_, ConnectorResult<()>> {
+        Box::pin(future::ready(Ok(())))
+    }
+
     fn render_script(
         &self,
         _migration: &Migration,
@@ -3,7 +3,7 @@ use datamodel::{
     parser_database::{IndexType, SortOrder},
     ValidatedSchema,
 };
-use mongodb_schema_describer::{IndexField, IndexFieldProperty, MongoSchema};
+use mongodb_schema_describer::{CollectionOptions, IndexField, IndexFieldProperty, MongoSchema};
 
 /// Datamodel -> MongoSchema
 pub(crate) fn calculate(datamodel: &ValidatedSchema) -> MongoSchema {
@@ -11,7 +11,7 @@ pub(crate) fn calculate(datamodel: &ValidatedSchema) -> MongoSchema {
     let connector = mongodb_datamodel_connector::MongoDbDatamodelConnector;
 
     for model in datamodel.db.walk_models() {
-        let collection_id = schema.push_collection(model.database_name().to_owned());
+        let collection_id = schema.push_collection(model.database_name().to_owned(), CollectionOptions::default());
 
         for index in model.indexes() {
             let name = index.constraint_name(&connector);
@@ -0,0 +1,223 @@
+//! Benchmarks for diffing and rendering synthetic schemas, entirely offline (no database
+//! connection). The schemas built here are not meant to be valid SQL, only structurally
+//! representative: enough tables, columns and indexes to exercise the differ and renderer at
+//! realistic scale.
+//!
+//! `SqlSchema` does not implement `Clone`, and `diff_schemas` consumes its inputs by value, so
+//! every benchmark rebuilds fresh schemas in `iter_batched`'s untimed setup step rather than
+//! cloning a shared one.
+
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use migration_connector::{DestructiveChangeDiagnostics, DiffTarget, MigrationConnector};
+use sql_migration_connector::SqlMigrationConnector;
+use sql_schema_describer::{
+    Column, ColumnArity, ColumnType, ColumnTypeFamily, Index, IndexColumn, IndexType, SqlSchema, TableId,
+};
+
+/// Build a synthetic schema with `tables` tables and `columns_per_table` columns each, with one
+/// index per table for every `1 / index_density` columns (`0.0` disables indexes entirely).
+fn build_schema(tables: usize, columns_per_table: usize, index_density: f64) -> SqlSchema {
+    let mut schema = SqlSchema::default();
+
+    for table_index in 0..tables {
+        let table_id = schema.push_table(format!("table_{table_index}"));
+        let mut column_names = Vec::with_capacity(columns_per_table);
+
+        for column_index in 0..columns_per_table {
+            let name = format!("column_{column_index}");
+            schema.push_column(
+                table_id,
+                Column {
+                    name: name.clone(),
+                    tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+                    default: None,
+                    auto_increment: false,
+                    is_identity: false,
+                    comment: None,
+                    generated: None,
+                },
+            );
+            column_names.push(name);
+        }
+
+        if index_density > 0.0 {
+            let step = (1.0 / index_density).max(1.0) as usize;
+
+            for (index_index, chunk_start) in (0..columns_per_table).step_by(step).enumerate() {
+                schema[table_id].indices.push(Index {
+                    name: format!("index_{table_index}_{index_index}"),
+                    columns: vec![IndexColumn::new(column_names[chunk_start].clone())],
+                    tpe: IndexType::Normal,
+                    is_autogenerated: false,
+                });
+            }
+        }
+    }
+
+    schema
+}
+
+/// A schema built the same way as `schema`, except every 300th table is renamed and every 10th
+/// table gets an extra column, so that roughly 1% of it differs.
+fn build_schema_with_small_diff(tables: usize, columns_per_table: usize, index_density: f64) -> SqlSchema {
+    let mut schema = build_schema(tables, columns_per_table, index_density);
+
+    for table_index in 0..tables {
+        let table_id = TableId(table_index as u32);
+
+        if table_index % 300 == 0 {
+            schema[table_id].name = format!("renamed_table_{table_index}");
+        }
+
+        if table_index % 10 == 0 {
+            schema.push_column(
+                table_id,
+                Column {
+                    name: "extra_column".to_owned(),
+                    tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Nullable),
+                    default: None,
+                    auto_increment: false,
+                    is_identity: false,
+                    comment: None,
+                    generated: None,
+                },
+            );
+        }
+    }
+
+    schema
+}
+
+fn diff_identical_schemas(c: &mut Criterion) {
+    let mut group = c.benchmark_group("diff_identical_schemas");
+    let connector = SqlMigrationConnector::new_postgres();
+
+    for &table_count in &[10usize, 100, 1_000] {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(table_count),
+            &table_count,
+            |b, &table_count| {
+                b.iter_batched(
+                    || (build_schema(table_count, 10, 0.3), build_schema(table_count, 10, 0.3)),
+                    |(previous, next)| connector.diff_schemas(previous, next),
+                    BatchSize::LargeInput,
+                );
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn diff_with_small_changes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("diff_with_1pct_changes");
+    let connector = SqlMigrationConnector::new_postgres();
+
+    for &table_count in &[10usize, 100, 1_000] {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(table_count),
+            &table_count,
+            |b, &table_count| {
+                b.iter_batched(
+                    || {
+                        (
+                            build_schema(table_count, 10, 0.3),
+                            build_schema_with_small_diff(table_count, 10, 0.3),
+                        )
+                    },
+                    |(previous, next)| connector.diff_schemas(previous, next),
+                    BatchSize::LargeInput,
+                );
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn render_creation_script(c: &mut Criterion) {
+    let mut group = c.benchmark_group("render_creation_script");
+    let connector = SqlMigrationConnector::new_postgres();
+
+    for &table_count in &[10usize, 100, 1_000] {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(table_count),
+            &table_count,
+            |b, &table_count| {
+                b.iter_batched(
+                    || build_schema(table_count, 10, 0.3),
+                    |schema| connector.diff_schemas(SqlSchema::default(), schema),
+                    BatchSize::LargeInput,
+                );
+            },
+        );
+    }
+
+    group.finish();
+}
+
+/// A minimal datamodel string with `models` models, used to benchmark the datamodel-to-schema
+/// path (parsing plus `calculate_sql_schema`) that a real `schemaPush`/`migrate dev` goes through
+/// before ever diffing anything.
+fn synthetic_datamodel(models: usize) -> String {
+    let mut out = String::from("datasource db {\n  provider = \"postgresql\"\n  url      = \"postgresql://\"\n}\n\n");
+
+    for model_index in 0..models {
+        out.push_str(&format!(
+            "model Model{model_index} {{\n  id Int @id\n  name String\n}}\n\n"
+        ));
+    }
+
+    out
+}
+
+fn calculate_schema_from_datamodel(c: &mut Criterion) {
+    let mut group = c.benchmark_group("calculate_schema_from_datamodel");
+    let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+
+    for &model_count in &[10usize, 100, 1_000] {
+        let datamodel = synthetic_datamodel(model_count);
+
+        group.bench_with_input(BenchmarkId::from_parameter(model_count), &datamodel, |b, datamodel| {
+            b.iter(|| {
+                let mut connector = SqlMigrationConnector::new_postgres();
+                runtime.block_on(connector.database_schema_from_diff_target(DiffTarget::Datamodel(datamodel), None))
+            });
+        });
+    }
+
+    group.finish();
+}
+
+fn render_full_migration_script(c: &mut Criterion) {
+    let mut group = c.benchmark_group("render_full_migration_script");
+    let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+
+    for &model_count in &[10usize, 100, 1_000] {
+        let datamodel = synthetic_datamodel(model_count);
+
+        group.bench_with_input(BenchmarkId::from_parameter(model_count), &datamodel, |b, datamodel| {
+            b.iter(|| {
+                let mut connector = SqlMigrationConnector::new_postgres();
+                let from = connector.empty_database_schema();
+                let to = runtime
+                    .block_on(connector.database_schema_from_diff_target(DiffTarget::Datamodel(datamodel), None))
+                    .unwrap();
+                let migration = connector.diff(from, to).unwrap();
+                connector.render_script(&migration, &DestructiveChangeDiagnostics::new())
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    diff_identical_schemas,
+    diff_with_small_changes,
+    render_creation_script,
+    calculate_schema_from_datamodel,
+    render_full_migration_script
+);
+criterion_main!(benches);
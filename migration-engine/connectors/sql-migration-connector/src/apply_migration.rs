@@ -1,28 +1,63 @@
 use crate::{
     pair::Pair,
-    sql_migration::{SqlMigration, SqlMigrationStep},
+    sql_migration::{SqlMigration, SqlMigrationStep, TableChange},
     SqlFlavour, SqlMigrationConnector,
 };
-use migration_connector::{ConnectorResult, DestructiveChangeDiagnostics, Migration};
-use sql_schema_describer::{walkers::SqlSchemaExt, SqlSchema};
+use migration_connector::{ConnectorHost, ConnectorResult, DestructiveChangeDiagnostics, Migration};
+use sql_schema_describer::{postgres::PostgresSchemaExt, walkers::SqlSchemaExt, SqlSchema};
 
-#[tracing::instrument(skip(flavour, migration))]
+#[tracing::instrument(skip(flavour, migration, host))]
 pub(crate) async fn apply_migration(
     migration: &Migration,
     flavour: &mut (dyn SqlFlavour + Send + Sync),
+    host: &dyn ConnectorHost,
 ) -> ConnectorResult<u32> {
     let migration: &SqlMigration = migration.downcast_ref();
     tracing::debug!("{} steps to execute", migration.steps.len());
 
+    let mut executed_steps: u32 = 0;
+
     for (index, step) in migration.steps.iter().enumerate() {
+        if is_destructive(step) {
+            let prompt = format!("Apply the destructive step `{}`?", step.description());
+
+            if !host.confirm(&prompt).await? {
+                tracing::debug!(index, "Skipping destructive step because the host denied it.");
+                continue;
+            }
+        }
+
         for sql_string in render_raw_sql(step, flavour, Pair::new(&migration.before, &migration.after)) {
             assert!(!sql_string.is_empty());
             tracing::debug!(index, %sql_string);
             flavour.run_query_script(&sql_string).await?;
         }
+
+        executed_steps += 1;
     }
 
-    Ok(migration.steps.len() as u32)
+    Ok(executed_steps)
+}
+
+/// Whether a step could lead to data loss, and should therefore be confirmed by the host before
+/// being executed.
+fn is_destructive(step: &SqlMigrationStep) -> bool {
+    match step {
+        SqlMigrationStep::DropTable { .. }
+        | SqlMigrationStep::DropView(_)
+        | SqlMigrationStep::DropUserDefinedType(_)
+        | SqlMigrationStep::DropEnum(_) => true,
+        SqlMigrationStep::RedefineTables(redefine_tables) => {
+            redefine_tables.iter().any(|table| !table.dropped_columns.is_empty())
+        }
+        SqlMigrationStep::AlterTable(alter_table) => alter_table.changes.iter().any(|change| {
+            matches!(
+                change,
+                TableChange::DropColumn { .. } | TableChange::DropAndRecreateColumn { .. }
+            )
+        }),
+        _ => false,
+    }
 }
 
 #[tracing::instrument(skip(migration, flavour))]
@@ -115,7 +150,7 @@ pub(crate) async fn apply_script(
     connector.flavour.apply_migration_script(migration_name, script).await
 }
 
-fn render_raw_sql(
+pub(crate) fn render_raw_sql(
     step: &SqlMigrationStep,
     renderer: &(dyn SqlFlavour + Send + Sync),
     schemas: Pair<&SqlSchema>,
@@ -124,6 +159,14 @@ fn render_raw_sql(
         SqlMigrationStep::AlterSequence(sequence_ids, changes) => {
             renderer.render_alter_sequence(*sequence_ids, *changes, schemas)
         }
+        SqlMigrationStep::CreateSequence(sequence_idx) => {
+            let ext: &PostgresSchemaExt = schemas.next.downcast_connector_data().unwrap_or_default();
+            vec![renderer.render_create_sequence(&ext.sequences[*sequence_idx as usize])]
+        }
+        SqlMigrationStep::DropSequence(sequence_idx) => {
+            let ext: &PostgresSchemaExt = schemas.previous.downcast_connector_data().unwrap_or_default();
+            vec![renderer.render_drop_sequence(&ext.sequences[*sequence_idx as usize])]
+        }
         SqlMigrationStep::AlterPrimaryKey(table_id) => renderer.render_alter_primary_key(schemas.tables(*table_id)),
         SqlMigrationStep::AlterEnum(alter_enum) => renderer.render_alter_enum(alter_enum, schemas),
         SqlMigrationStep::RedefineTables(redefine_tables) => renderer.render_redefine_tables(redefine_tables, schemas),
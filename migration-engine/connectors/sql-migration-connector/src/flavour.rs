@@ -7,6 +7,8 @@ mod mysql;
 mod postgres;
 mod sqlite;
 
+pub(crate) mod migrations_table;
+
 pub(crate) use mssql::MssqlFlavour;
 pub(crate) use mysql::MysqlFlavour;
 pub(crate) use postgres::PostgresFlavour;
@@ -19,11 +21,14 @@ use crate::{
 use datamodel::{common::preview_features::PreviewFeature, ValidatedSchema};
 use enumflags2::BitFlags;
 use migration_connector::{
-    migrations_directory::MigrationDirectory, BoxFuture, ConnectorError, ConnectorParams, ConnectorResult,
+    migrations_directory::MigrationDirectory, BoxFuture, ConnectorError, ConnectorHost, ConnectorParams,
+    ConnectorResult, DatabasePlan,
 };
+use once_cell::sync::Lazy;
 use quaint::prelude::{ConnectionInfo, Table};
+use regex::Regex;
 use sql_schema_describer::SqlSchema;
-use std::fmt::Debug;
+use std::{fmt::Debug, sync::Arc};
 
 /// P is the params, C is a connection.
 pub(crate) enum State<P, C> {
@@ -104,6 +109,12 @@ pub(crate) trait SqlFlavour:
 {
     fn acquire_lock(&mut self) -> BoxFuture<'_, ConnectorResult<()>>;
 
+    /// Release the advisory lock acquired by `acquire_lock`, if any. Defaults to a no-op for
+    /// flavours that do not hold a session-scoped lock outside of the connection itself.
+    fn release_lock(&mut self) -> BoxFuture<'_, ConnectorResult<()>> {
+        Box::pin(std::future::ready(Ok(())))
+    }
+
     fn apply_migration_script<'a>(
         &'a mut self,
         migration_name: &'a str,
@@ -117,6 +128,10 @@ pub(crate) trait SqlFlavour:
         None
     }
 
+    /// Accept the ConnectorHost, so the flavour can print progress messages while connecting.
+    /// Defaults to a no-op for flavours that never need to do so.
+    fn set_host(&mut self, _host: Arc<dyn ConnectorHost>) {}
+
     /// The connection string received in set_params().
     fn connection_string(&self) -> Option<&str>;
 
@@ -126,9 +141,21 @@ pub(crate) trait SqlFlavour:
     /// Create a database for the given URL on the server, if applicable.
     fn create_database(&mut self) -> BoxFuture<'_, ConnectorResult<String>>;
 
+    /// Compute the statements `create_database` would execute, and a summary of the connection it
+    /// would use, without executing anything.
+    fn create_database_plan(&mut self) -> ConnectorResult<DatabasePlan>;
+
     /// Initialize the `_prisma_migrations` table.
     fn create_migrations_table(&mut self) -> BoxFuture<'_, ConnectorResult<()>>;
 
+    /// Initialize the `_prisma_seeds` table.
+    fn create_seeds_table(&mut self) -> BoxFuture<'_, ConnectorResult<()>>;
+
+    /// Render the `ALTER TABLE` statement that additively brings a pre-existing migrations table
+    /// up to shape by adding the given column. The column must be one of the "healable" columns
+    /// from [`migrations_table::diff`], so this always succeeds even on a table with existing rows.
+    fn sql_for_healing_migrations_table_column(&self, column: &'static str) -> String;
+
     /// The datamodel connector corresponding to the flavour
     fn datamodel_connector(&self) -> &'static dyn datamodel::datamodel_connector::Connector;
 
@@ -137,6 +164,9 @@ pub(crate) trait SqlFlavour:
     /// Drop the database.
     fn drop_database(&mut self) -> BoxFuture<'_, ConnectorResult<()>>;
 
+    /// Same as [`SqlFlavour::create_database_plan`], for `drop_database`.
+    fn drop_database_plan(&mut self) -> ConnectorResult<DatabasePlan>;
+
     /// Drop the migrations table
     fn drop_migrations_table(&mut self) -> BoxFuture<'_, ConnectorResult<()>>;
 
@@ -189,9 +219,91 @@ pub(crate) trait SqlFlavour:
         self.migrations_table_name().into()
     }
 
+    /// Table to store applied seeds, the name part.
+    fn seeds_table_name(&self) -> &'static str {
+        "_prisma_seeds"
+    }
+
+    /// Table to store applied seeds.
+    fn seeds_table(&self) -> Table<'static> {
+        self.seeds_table_name().into()
+    }
+
+    /// Optionally reject a seed script that looks like it was written for a different SQL
+    /// dialect, before executing it. Defaults to accepting everything.
+    fn validate_seed_script(&self, _script: &str) -> ConnectorResult<()> {
+        Ok(())
+    }
+
     fn version(&mut self) -> BoxFuture<'_, ConnectorResult<Option<String>>>;
 }
 
+/// Matches an MSSQL bracket-quoted identifier: a `[` preceded by whitespace, `(`, `,` or the start
+/// of the line (never by a word character, which rules out a Postgres array type suffix like
+/// `int[]`), containing only identifier-shaped text (letters, digits, underscore, spaces), and
+/// starting with a letter or underscore (which rules out array literals like `ARRAY[1,2,3]` or a
+/// JSON array embedded in a string like `["a","b"]`).
+static BRACKET_IDENTIFIER_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?:^|[\s(,])\[[A-Za-z_][A-Za-z0-9_ ]*\]").unwrap());
+
+/// Reject scripts that contain bracket-quoted identifiers (e.g. `[User]`), a syntax specific to
+/// Microsoft SQL Server. Used by flavours that do not support that syntax, to catch seed scripts
+/// that were written for the wrong dialect early, with a clear error, rather than failing deep
+/// inside the SQL parser of the target database.
+fn reject_bracket_identifiers(script: &str) -> ConnectorResult<()> {
+    let looks_like_bracket_identifier = script.lines().any(|line| {
+        let line = line.trim_start();
+        !line.starts_with("--") && BRACKET_IDENTIFIER_RE.is_match(line)
+    });
+
+    if looks_like_bracket_identifier {
+        Err(ConnectorError::from_msg(
+            "The seed script appears to use `[bracket]`-quoted identifiers, which are specific to Microsoft SQL Server and are not supported by this connector.".to_owned(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod reject_bracket_identifiers_tests {
+    use super::reject_bracket_identifiers;
+
+    #[test]
+    fn rejects_a_bracket_quoted_table_name() {
+        assert!(reject_bracket_identifiers("SELECT * FROM [User]").is_err());
+    }
+
+    #[test]
+    fn rejects_a_bracket_quoted_identifier_with_spaces() {
+        assert!(reject_bracket_identifiers("INSERT INTO [My Table] (id) VALUES (1)").is_err());
+    }
+
+    #[test]
+    fn accepts_a_postgres_array_type_suffix() {
+        assert!(reject_bracket_identifiers("CREATE TABLE t (tags int[])").is_ok());
+    }
+
+    #[test]
+    fn accepts_a_postgres_array_literal() {
+        assert!(reject_bracket_identifiers("INSERT INTO t (tags) VALUES (ARRAY[1,2,3])").is_ok());
+    }
+
+    #[test]
+    fn accepts_a_cast_array_literal() {
+        assert!(reject_bracket_identifiers("INSERT INTO t (tags) VALUES ('{1,2,3}'::int[])").is_ok());
+    }
+
+    #[test]
+    fn accepts_a_json_array_embedded_in_a_string_literal() {
+        assert!(reject_bracket_identifiers(r#"INSERT INTO t (data) VALUES ('{"tags": ["a","b"]}')"#).is_ok());
+    }
+
+    #[test]
+    fn ignores_comment_lines() {
+        assert!(reject_bracket_identifiers("-- SELECT * FROM [User]").is_ok());
+    }
+}
+
 // Utility function shared by multiple flavours to compare shadow database and main connection.
 fn validate_connection_infos_do_not_match(previous: &str, next: &str) -> ConnectorResult<()> {
     if previous == next {
@@ -201,9 +313,10 @@ fn validate_connection_infos_do_not_match(previous: &str, next: &str) -> Connect
     }
 }
 
-/// Remove all usage of non-enabled preview feature elements from the SqlSchema.
-fn normalize_sql_schema(sql_schema: &mut SqlSchema, preview_features: BitFlags<PreviewFeature>) {
-    use sql_schema_describer::IndexType;
+/// Remove all usage of non-enabled preview feature elements from the SqlSchema, and normalize
+/// away database-specific artifacts that should not surface as schema differences.
+fn normalize_sql_schema(sql_schema: &mut SqlSchema, preview_features: BitFlags<PreviewFeature>, is_cockroach: bool) {
+    use sql_schema_describer::{DefaultKind, IndexType, TableId};
 
     fn filter_fulltext_capabilities(schema: &mut SqlSchema) {
         let indices = schema
@@ -215,14 +328,178 @@ fn normalize_sql_schema(sql_schema: &mut SqlSchema, preview_features: BitFlags<P
         }
     }
 
+    // CockroachDB auto-generates foreign key constraint names like
+    // `crdb_internal_fk_posts_ref_users` when none is requested. They are not stable across
+    // describes of an otherwise unchanged schema, so treat them as anonymous rather than as a
+    // constraint name a migration could need to rename.
+    fn strip_crdb_internal_constraint_names(schema: &mut SqlSchema) {
+        for (_, fk) in schema.foreign_keys.iter_mut() {
+            if fk.constraint_name.as_deref().unwrap_or("").starts_with("crdb_internal_") {
+                fk.constraint_name = None;
+            }
+        }
+    }
+
+    // CockroachDB adds a hidden `rowid` column (and uses it as the primary key) on any table
+    // that has no explicit primary key. Prisma never declares that column and cannot manage it,
+    // so it must not show up as something the migration needs to add or drop. This only affects
+    // the schema used for diffing here; the underlying database still has the column, and
+    // introspection describes it separately.
+    fn hide_implicit_rowid_column(schema: &mut SqlSchema) {
+        let implicit_rowid_tables: Vec<TableId> = schema
+            .table_walkers()
+            .filter(|table| {
+                table
+                    .column("rowid")
+                    .map(|column| {
+                        column.is_single_primary_key()
+                            && matches!(column.default().map(|d| d.kind()), Some(DefaultKind::UniqueRowid))
+                    })
+                    .unwrap_or(false)
+            })
+            .map(|table| table.id)
+            .collect();
+
+        for table_id in &implicit_rowid_tables {
+            schema[*table_id].primary_key = None;
+        }
+
+        schema.remove_columns(|table_id, column| column.name == "rowid" && implicit_rowid_tables.contains(&table_id));
+    }
+
     // Remove this when the feature is GA
     if !preview_features.contains(PreviewFeature::FullTextIndex) {
         filter_fulltext_capabilities(sql_schema);
     }
+
+    if is_cockroach {
+        strip_crdb_internal_constraint_names(sql_schema);
+        hide_implicit_rowid_column(sql_schema);
+    }
+}
+
+#[cfg(test)]
+mod normalize_sql_schema_tests {
+    use super::normalize_sql_schema;
+    use sql_schema_describer::{ForeignKeyAction, SqlSchema};
+
+    fn schema_with_fk_named(constraint_name: &str) -> SqlSchema {
+        let mut schema = SqlSchema::default();
+        let referencing = schema.push_table("Post".to_owned());
+        let referenced = schema.push_table("User".to_owned());
+        schema.foreign_keys.push((
+            referencing,
+            sql_schema_describer::ForeignKey {
+                constraint_name: Some(constraint_name.to_owned()),
+                columns: vec!["userId".to_owned()],
+                referenced_table: referenced,
+                referenced_columns: vec!["id".to_owned()],
+                on_delete_action: ForeignKeyAction::Cascade,
+                on_update_action: ForeignKeyAction::Cascade,
+                validated: true,
+            },
+        ));
+        schema
+    }
+
+    #[test]
+    fn strips_crdb_internal_constraint_names_on_cockroach() {
+        let mut schema = schema_with_fk_named("crdb_internal_fk_post_ref_user");
+
+        normalize_sql_schema(&mut schema, Default::default(), true);
+
+        assert_eq!(schema.foreign_keys[0].1.constraint_name, None);
+    }
+
+    #[test]
+    fn leaves_other_constraint_names_alone_on_cockroach() {
+        let mut schema = schema_with_fk_named("Post_userId_fkey");
+
+        normalize_sql_schema(&mut schema, Default::default(), true);
+
+        assert_eq!(
+            schema.foreign_keys[0].1.constraint_name.as_deref(),
+            Some("Post_userId_fkey")
+        );
+    }
+
+    #[test]
+    fn does_not_strip_crdb_internal_constraint_names_off_cockroach() {
+        let mut schema = schema_with_fk_named("crdb_internal_fk_post_ref_user");
+
+        normalize_sql_schema(&mut schema, Default::default(), false);
+
+        assert_eq!(
+            schema.foreign_keys[0].1.constraint_name.as_deref(),
+            Some("crdb_internal_fk_post_ref_user")
+        );
+    }
+
+    fn schema_with_implicit_rowid() -> SqlSchema {
+        use sql_schema_describer::{
+            Column, ColumnArity, ColumnType, ColumnTypeFamily, DefaultValue, PrimaryKey, PrimaryKeyColumn,
+        };
+
+        let mut schema = SqlSchema::default();
+        let table_id = schema.push_table("Cat".to_owned());
+        schema.push_column(
+            table_id,
+            Column {
+                name: "rowid".to_owned(),
+                tpe: ColumnType::pure(ColumnTypeFamily::BigInt, ColumnArity::Required),
+                default: Some(DefaultValue::unique_rowid()),
+                auto_increment: true,
+                is_identity: false,
+                comment: None,
+                generated: None,
+            },
+        );
+        schema.push_column(
+            table_id,
+            Column {
+                name: "name".to_owned(),
+                tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Required),
+                default: None,
+                auto_increment: false,
+                is_identity: false,
+                comment: None,
+                generated: None,
+            },
+        );
+        schema[table_id].primary_key = Some(PrimaryKey {
+            columns: vec![PrimaryKeyColumn::new("rowid")],
+            constraint_name: None,
+        });
+
+        schema
+    }
+
+    #[test]
+    fn hides_the_implicit_rowid_column_on_cockroach() {
+        let mut schema = schema_with_implicit_rowid();
+
+        normalize_sql_schema(&mut schema, Default::default(), true);
+
+        let table = schema.table_walkers().next().unwrap();
+        assert!(table.column("rowid").is_none());
+        assert!(table.primary_key().is_none());
+        assert!(table.column("name").is_some());
+    }
+
+    #[test]
+    fn does_not_hide_the_rowid_column_off_cockroach() {
+        let mut schema = schema_with_implicit_rowid();
+
+        normalize_sql_schema(&mut schema, Default::default(), false);
+
+        let table = schema.table_walkers().next().unwrap();
+        assert!(table.column("rowid").is_some());
+        assert!(table.primary_key().is_some());
+    }
 }
 
 fn quaint_error_to_connector_error(error: quaint::error::Error, connection_info: &ConnectionInfo) -> ConnectorError {
-    match user_facing_errors::quaint::render_quaint_error(error.kind(), connection_info) {
+    let connector_error = match user_facing_errors::quaint::render_quaint_error(error.kind(), connection_info) {
         Some(user_facing_error) => user_facing_error.into(),
         None => {
             let msg = error
@@ -231,5 +508,10 @@ fn quaint_error_to_connector_error(error: quaint::error::Error, connection_info:
                 .unwrap_or_else(|| error.to_string());
             ConnectorError::from_msg(msg)
         }
-    }
+    };
+
+    // Keep the original quaint error message around as a context frame, even once it's been
+    // mapped to a user-facing error above, so it isn't lost if a higher layer (the describer, the
+    // flavour, the RPC boundary) wraps this error again.
+    connector_error.with_context(error.to_string())
 }
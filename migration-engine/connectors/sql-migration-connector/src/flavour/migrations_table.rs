@@ -0,0 +1,78 @@
+//! Comparing an existing `_prisma_migrations` table against the shape the engine expects, shared
+//! by all flavours. Users upgrading across many versions of the engine can end up with a
+//! migrations table that predates a column we now rely on; we want to add those additively
+//! rather than fail with a raw SQL error on the next `INSERT`.
+
+use sql_schema_describer::walkers::TableWalker;
+
+/// A column the engine expects to find on the migrations table.
+struct ExpectedColumn {
+    name: &'static str,
+    /// Whether a missing column can be added to a table that may already contain rows, without
+    /// data loss or a constraint violation (it's nullable, or every flavour gives it a default).
+    healable: bool,
+}
+
+const EXPECTED_COLUMNS: &[ExpectedColumn] = &[
+    ExpectedColumn {
+        name: "id",
+        healable: false,
+    },
+    ExpectedColumn {
+        name: "checksum",
+        healable: false,
+    },
+    ExpectedColumn {
+        name: "finished_at",
+        healable: true,
+    },
+    ExpectedColumn {
+        name: "migration_name",
+        healable: false,
+    },
+    ExpectedColumn {
+        name: "logs",
+        healable: true,
+    },
+    ExpectedColumn {
+        name: "rolled_back_at",
+        healable: true,
+    },
+    ExpectedColumn {
+        name: "started_at",
+        healable: true,
+    },
+    ExpectedColumn {
+        name: "applied_steps_count",
+        healable: true,
+    },
+];
+
+/// The result of comparing an existing migrations table against [`EXPECTED_COLUMNS`].
+#[derive(Debug, Default)]
+pub(crate) struct MigrationsTableDiff {
+    /// Columns that are missing, and can be added with an additive, non-destructive `ALTER TABLE`.
+    pub(crate) healable: Vec<&'static str>,
+    /// Columns that are missing, but cannot be added safely (e.g. a `NOT NULL` column with no
+    /// universal default we could backfill existing rows with).
+    pub(crate) unhealable: Vec<&'static str>,
+}
+
+/// Compare the columns on an existing migrations table against what the engine expects.
+pub(crate) fn diff(table: TableWalker<'_>) -> MigrationsTableDiff {
+    let mut diff = MigrationsTableDiff::default();
+
+    for column in EXPECTED_COLUMNS {
+        if table.column(column.name).is_some() {
+            continue;
+        }
+
+        if column.healable {
+            diff.healable.push(column.name);
+        } else {
+            diff.unhealable.push(column.name);
+        }
+    }
+
+    diff
+}
@@ -4,16 +4,19 @@ use crate::{flavour::normalize_sql_schema, SqlFlavour};
 use connection_string::JdbcString;
 use indoc::formatdoc;
 use migration_connector::{
-    migrations_directory::MigrationDirectory, BoxFuture, ConnectorError, ConnectorParams, ConnectorResult,
+    migrations_directory::MigrationDirectory, BoxFuture, ConnectorError, ConnectorHost, ConnectorParams,
+    ConnectorResult, DatabasePlan, EmptyHost,
 };
 use quaint::{
     connector::{Mssql as Connection, MssqlUrl},
     prelude::{Queryable, Table},
 };
 use sql_schema_describer::SqlSchema;
-use std::{future, str::FromStr};
+use std::{future, str::FromStr, sync::Arc, time::Duration};
 use user_facing_errors::{
-    introspection_engine::DatabaseSchemaInconsistent, migration_engine::ApplyMigrationError, KnownError,
+    introspection_engine::DatabaseSchemaInconsistent,
+    migration_engine::{ApplyMigrationError, AzureMssqlDatabasePaused},
+    KnownError,
 };
 
 type State = super::State<Params, Connection>;
@@ -31,11 +34,15 @@ impl Params {
 
 pub(crate) struct MssqlFlavour {
     pub(crate) state: State,
+    host: Arc<dyn ConnectorHost>,
 }
 
 impl Default for MssqlFlavour {
     fn default() -> Self {
-        MssqlFlavour { state: State::Initial }
+        MssqlFlavour {
+            state: State::Initial,
+            host: Arc::new(EmptyHost),
+        }
     }
 }
 
@@ -72,12 +79,18 @@ impl SqlFlavour for MssqlFlavour {
         )
     }
 
+    fn release_lock(&mut self) -> BoxFuture<'_, ConnectorResult<()>> {
+        // see
+        // https://docs.microsoft.com/en-us/sql/relational-databases/system-stored-procedures/sp-releaseapplock-transact-sql?view=sql-server-ver15
+        Box::pin(self.raw_cmd("sp_releaseapplock @Resource = 'prisma_migrate', @LockOwner = 'Session'"))
+    }
+
     fn apply_migration_script<'a>(
         &'a mut self,
         migration_name: &'a str,
         script: &'a str,
     ) -> BoxFuture<'a, ConnectorResult<()>> {
-        with_connection(&mut self.state, move |_, connection| {
+        with_connection(&mut self.state, self.host.clone(), move |_, connection| {
             generic_apply_migration_script(migration_name, script, connection)
         })
     }
@@ -88,7 +101,7 @@ impl SqlFlavour for MssqlFlavour {
 
     fn describe_schema(&mut self) -> BoxFuture<'_, ConnectorResult<SqlSchema>> {
         use sql_schema_describer::{mssql as describer, DescriberErrorKind, SqlSchemaDescriberBackend};
-        with_connection(&mut self.state, |params, connection| async move {
+        with_connection(&mut self.state, self.host.clone(), |params, connection| async move {
             let mut schema = describer::SqlSchemaDescriber::new(connection)
                 .describe(params.url.schema())
                 .await
@@ -99,11 +112,11 @@ impl SqlFlavour for MssqlFlavour {
                             explanation: e.to_string(),
                         });
 
-                        ConnectorError::from(err)
+                        ConnectorError::from(err).with_context("Failed to introspect the database schema")
                     }
                 })?;
 
-            normalize_sql_schema(&mut schema, params.connector_params.preview_features);
+            normalize_sql_schema(&mut schema, params.connector_params.preview_features, false);
 
             Ok(schema)
         })
@@ -113,6 +126,10 @@ impl SqlFlavour for MssqlFlavour {
         (self.schema_name().to_owned(), self.migrations_table_name().to_owned()).into()
     }
 
+    fn seeds_table(&self) -> Table<'static> {
+        (self.schema_name().to_owned(), self.seeds_table_name().to_owned()).into()
+    }
+
     fn connection_string(&self) -> Option<&str> {
         self.state
             .params()
@@ -123,17 +140,21 @@ impl SqlFlavour for MssqlFlavour {
         "mssql"
     }
 
+    fn set_host(&mut self, host: Arc<dyn ConnectorHost>) {
+        self.host = host;
+    }
+
     fn create_database(&mut self) -> BoxFuture<'_, ConnectorResult<String>> {
         Box::pin(async {
             let params = self.state.get_unwrapped_params();
             let connection_string = &params.connector_params.connection_string;
             let (db_name, master_uri) = Self::master_url(connection_string)?;
-            let conn = connect(&master_uri).await?;
+            let conn = connect(&master_uri, self.host.clone()).await?;
 
             let query = format!("CREATE DATABASE [{}]", db_name);
             raw_cmd(&query, &conn, &MssqlUrl::new(&master_uri).unwrap()).await?;
 
-            let conn = connect(&params.connector_params.connection_string).await?;
+            let conn = connect(&params.connector_params.connection_string, self.host.clone()).await?;
 
             // dbo is created automatically
             if params.url.schema() != "dbo" {
@@ -145,6 +166,30 @@ impl SqlFlavour for MssqlFlavour {
         })
     }
 
+    fn create_database_plan(&mut self) -> ConnectorResult<DatabasePlan> {
+        let params = self.state.get_unwrapped_params();
+        let (db_name, _) = Self::master_url(&params.connector_params.connection_string)?;
+        let schema_name = params.url.schema();
+
+        let mut statements = vec![format!("CREATE DATABASE [{}]", db_name)];
+
+        // dbo is created automatically
+        if schema_name != "dbo" {
+            statements.push(format!("CREATE SCHEMA {}", schema_name));
+        }
+
+        Ok(DatabasePlan {
+            statements,
+            connection_summary: format!(
+                "sqlserver://{}@{}:{}/{}",
+                params.url.username(),
+                params.url.host(),
+                params.url.port(),
+                db_name
+            ),
+        })
+    }
+
     fn create_migrations_table(&mut self) -> BoxFuture<'_, ConnectorResult<()>> {
         let sql = formatdoc! { r#"
             CREATE TABLE [{}].[{}] (
@@ -162,6 +207,36 @@ impl SqlFlavour for MssqlFlavour {
         Box::pin(async move { self.raw_cmd(&sql).await })
     }
 
+    fn create_seeds_table(&mut self) -> BoxFuture<'_, ConnectorResult<()>> {
+        let sql = formatdoc! { r#"
+            CREATE TABLE [{}].[{}] (
+                name        NVARCHAR(255) PRIMARY KEY NOT NULL,
+                checksum    VARCHAR(64) NOT NULL,
+                applied_at  DATETIMEOFFSET NOT NULL DEFAULT CURRENT_TIMESTAMP
+            );
+        "#, self.schema_name(), self.seeds_table_name()};
+
+        Box::pin(async move { self.raw_cmd(&sql).await })
+    }
+
+    fn sql_for_healing_migrations_table_column(&self, column: &'static str) -> String {
+        let column_definition = match column {
+            "finished_at" => "[finished_at] DATETIMEOFFSET",
+            "logs" => "[logs] NVARCHAR(MAX) NULL",
+            "rolled_back_at" => "[rolled_back_at] DATETIMEOFFSET",
+            "started_at" => "[started_at] DATETIMEOFFSET NOT NULL DEFAULT CURRENT_TIMESTAMP",
+            "applied_steps_count" => "[applied_steps_count] INT NOT NULL DEFAULT 0",
+            other => unreachable!("Unhealable migrations table column: {}", other),
+        };
+
+        format!(
+            "ALTER TABLE [{}].[{}] ADD {}",
+            self.schema_name(),
+            self.migrations_table_name(),
+            column_definition
+        )
+    }
+
     fn drop_database(&mut self) -> BoxFuture<'_, ConnectorResult<()>> {
         Box::pin(async {
             let params = self.state.get_unwrapped_params();
@@ -181,7 +256,7 @@ impl SqlFlavour for MssqlFlavour {
             }
 
             let (db_name, master_uri) = Self::master_url(&params.connector_params.connection_string)?;
-            let conn = connect(&master_uri.to_string()).await?;
+            let conn = connect(&master_uri.to_string(), self.host.clone()).await?;
 
             let query = format!("DROP DATABASE IF EXISTS [{}]", db_name);
             raw_cmd(&query, &conn, &MssqlUrl::new(&master_uri).unwrap()).await?;
@@ -190,6 +265,22 @@ impl SqlFlavour for MssqlFlavour {
         })
     }
 
+    fn drop_database_plan(&mut self) -> ConnectorResult<DatabasePlan> {
+        let params = self.state.get_unwrapped_params();
+        let (db_name, _) = Self::master_url(&params.connector_params.connection_string)?;
+
+        Ok(DatabasePlan {
+            statements: vec![format!("DROP DATABASE IF EXISTS [{}]", db_name)],
+            connection_summary: format!(
+                "sqlserver://{}@{}:{}/{}",
+                params.url.username(),
+                params.url.host(),
+                params.url.port(),
+                db_name
+            ),
+        })
+    }
+
     fn drop_migrations_table(&mut self) -> BoxFuture<'_, ConnectorResult<()>> {
         let sql = format!("DROP TABLE [{}].[{}]", self.schema_name(), self.migrations_table_name());
         Box::pin(async move { self.raw_cmd(&sql).await })
@@ -199,7 +290,7 @@ impl SqlFlavour for MssqlFlavour {
         &'a mut self,
         query: quaint::ast::Query<'a>,
     ) -> BoxFuture<'a, ConnectorResult<quaint::prelude::ResultSet>> {
-        with_connection(&mut self.state, move |params, conn| async move {
+        with_connection(&mut self.state, self.host.clone(), move |params, conn| async move {
             conn.query(query).await.map_err(quaint_err(params))
         })
     }
@@ -209,7 +300,7 @@ impl SqlFlavour for MssqlFlavour {
         sql: &'a str,
         params: &'a [quaint::Value<'a>],
     ) -> BoxFuture<'a, ConnectorResult<quaint::prelude::ResultSet>> {
-        with_connection(&mut self.state, move |conn_params, conn| async move {
+        with_connection(&mut self.state, self.host.clone(), move |conn_params, conn| async move {
             conn.query_raw(sql, params).await.map_err(quaint_err(conn_params))
         })
     }
@@ -219,7 +310,7 @@ impl SqlFlavour for MssqlFlavour {
     }
 
     fn reset(&mut self) -> BoxFuture<'_, ConnectorResult<()>> {
-        with_connection(&mut self.state, move |params, connection| async move {
+        with_connection(&mut self.state, self.host.clone(), move |params, connection| async move {
             let schema_name = params.url.schema();
 
             let drop_procedures = format!(
@@ -341,7 +432,7 @@ impl SqlFlavour for MssqlFlavour {
     }
 
     fn raw_cmd<'a>(&'a mut self, sql: &'a str) -> BoxFuture<'a, ConnectorResult<()>> {
-        with_connection(&mut self.state, move |params, conn| raw_cmd(sql, conn, &params.url))
+        with_connection(&mut self.state, self.host.clone(), move |params, conn| raw_cmd(sql, conn, &params.url))
     }
 
     fn set_params(&mut self, connector_params: ConnectorParams) -> ConnectorResult<()> {
@@ -400,7 +491,7 @@ impl SqlFlavour for MssqlFlavour {
                 shadow_db::sql_schema_from_migrations_history(migrations, shadow_database).await
             })
         } else {
-            with_connection(&mut self.state, move |params, main_connection| async move {
+            with_connection(&mut self.state, self.host.clone(), move |params, main_connection| async move {
                 let shadow_database_name = crate::new_shadow_database_name();
                 // See https://github.com/prisma/prisma/issues/6371 for the rationale on
                 // this conditional.
@@ -452,13 +543,17 @@ impl SqlFlavour for MssqlFlavour {
     }
 
     fn version(&mut self) -> BoxFuture<'_, ConnectorResult<Option<String>>> {
-        with_connection(&mut self.state, |params, connection| async {
+        with_connection(&mut self.state, self.host.clone(), |params, connection| async {
             connection.version().await.map_err(quaint_err(params))
         })
     }
 }
 
-fn with_connection<'a, O, F, C>(state: &'a mut State, f: C) -> BoxFuture<'a, ConnectorResult<O>>
+fn with_connection<'a, O, F, C>(
+    state: &'a mut State,
+    host: Arc<dyn ConnectorHost>,
+    f: C,
+) -> BoxFuture<'a, ConnectorResult<O>>
 where
     O: 'a,
     F: future::Future<Output = ConnectorResult<O>> + Send + 'a,
@@ -468,10 +563,13 @@ where
         super::State::Initial => panic!("logic error: Initial"),
         super::State::Connected(p, c) => Box::pin(f(p, c)),
         state @ super::State::WithParams(_) => Box::pin(async move {
+            let host_for_connect = host.clone();
             state
-                .try_connect(|params| Box::pin(connect(&params.connector_params.connection_string)))
+                .try_connect(move |params| {
+                    Box::pin(connect(&params.connector_params.connection_string, host_for_connect))
+                })
                 .await?;
-            with_connection(state, f).await
+            with_connection(state, host, f).await
         }),
     }
 }
@@ -496,6 +594,12 @@ async fn clean_up_shadow_database(
 }
 
 async fn generic_apply_migration_script(migration_name: &str, script: &str, conn: &Connection) -> ConnectorResult<()> {
+    tracing::debug!(
+        "Applying migration `{}` with session options: {}",
+        migration_name,
+        REQUIRED_SESSION_OPTIONS
+    );
+
     conn.raw_cmd(script).await.map_err(|sql_error| {
         ConnectorError::user_facing(ApplyMigrationError {
             migration_name: migration_name.to_owned(),
@@ -512,13 +616,75 @@ async fn raw_cmd(sql: &str, conn: &Connection, url: &MssqlUrl) -> ConnectorResul
     conn.raw_cmd(sql).await.map_err(quaint_err_url(url))
 }
 
-async fn connect(connection_str: &str) -> ConnectorResult<Connection> {
+/// Migrations touching filtered indexes or computed columns require `QUOTED_IDENTIFIER` and
+/// `ANSI_NULLS` to be `ON`. Depending on how the driver configures the connection, the defaults
+/// for these can differ between the shadow database connection and the main connection, which
+/// surfaces at apply time as SQL Server error 1934 ("SET options have incorrect settings")
+/// instead of at migration-authoring time. We pin both to `ON` on every connection this flavour
+/// opens so applying a migration doesn't depend on how the caller's client configured its
+/// session.
+const REQUIRED_SESSION_OPTIONS: &str = "SET QUOTED_IDENTIFIER ON; SET ANSI_NULLS ON;";
+
+async fn connect(connection_str: &str, host: Arc<dyn ConnectorHost>) -> ConnectorResult<Connection> {
     let url = MssqlUrl::new(connection_str).map_err(|err| {
         ConnectorError::user_facing(user_facing_errors::common::InvalidConnectionString {
             details: err.to_string(),
         })
     })?;
-    Connection::new(url.clone()).await.map_err(quaint_err_url(&url))
+    let connection = connect_with_azure_retry(&url, host.as_ref()).await?;
+
+    tracing::debug!("Setting required MSSQL session options: {}", REQUIRED_SESSION_OPTIONS);
+    raw_cmd(REQUIRED_SESSION_OPTIONS, &connection, &url).await?;
+
+    Ok(connection)
+}
+
+/// SQL Server error codes returned while an Azure SQL serverless database is paused and in the
+/// process of resuming. See
+/// https://docs.microsoft.com/en-us/azure/azure-sql/database/troubleshoot-common-errors-issues
+const AZURE_AUTO_PAUSE_ERROR_CODES: &[i32] = &[40613, 40197, 40501];
+
+/// How long we keep retrying a connection to an auto-paused Azure SQL serverless database before
+/// giving up. Resuming from pause commonly takes 30-60 seconds.
+const AZURE_AUTO_PAUSE_RETRY_WINDOW: Duration = Duration::from_secs(60);
+
+const AZURE_AUTO_PAUSE_RETRY_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Open a connection, retrying at a fixed interval when the target is an Azure SQL serverless database
+/// that is waking up from auto-pause. Non-Azure hosts, and Azure errors unrelated to auto-pause,
+/// fail immediately.
+async fn connect_with_azure_retry(url: &MssqlUrl, host: &dyn ConnectorHost) -> ConnectorResult<Connection> {
+    let is_azure_sql = url.host().contains(".database.windows.net");
+    let deadline = std::time::Instant::now() + AZURE_AUTO_PAUSE_RETRY_WINDOW;
+
+    loop {
+        match Connection::new(url.clone()).await {
+            Ok(connection) => return Ok(connection),
+            Err(err) if is_azure_sql && is_azure_auto_pause_error(&err) => {
+                if std::time::Instant::now() >= deadline {
+                    return Err(ConnectorError::user_facing(AzureMssqlDatabasePaused {
+                        seconds_waited: AZURE_AUTO_PAUSE_RETRY_WINDOW.as_secs(),
+                    }));
+                }
+
+                let _ = host.print("Azure SQL database is resuming, retrying...\n").await;
+                tokio::time::sleep(AZURE_AUTO_PAUSE_RETRY_INTERVAL).await;
+            }
+            Err(err) => return Err(quaint_err_url(url)(err)),
+        }
+    }
+}
+
+fn is_azure_auto_pause_error(err: &quaint::error::Error) -> bool {
+    is_azure_auto_pause_error_code(err.original_code())
+}
+
+/// Pulled out of [`is_azure_auto_pause_error`] so the SQL Server error code classification can be
+/// unit-tested without having to construct a real `quaint::error::Error`.
+fn is_azure_auto_pause_error_code(code: Option<&str>) -> bool {
+    code.and_then(|code| code.parse::<i32>().ok())
+        .map(|code| AZURE_AUTO_PAUSE_ERROR_CODES.contains(&code))
+        .unwrap_or(false)
 }
 
 #[cfg(test)]
@@ -545,4 +711,30 @@ mod tests {
             assert!(!debugged.contains(word));
         }
     }
+
+    #[test]
+    fn azure_auto_pause_error_codes_are_recognized() {
+        // 40613: database not currently available, 40197/40501: transient/throttling errors that
+        // also show up while a serverless database is resuming.
+        for code in &["40613", "40197", "40501"] {
+            assert!(is_azure_auto_pause_error_code(Some(code)));
+        }
+    }
+
+    #[test]
+    fn unrelated_error_codes_are_not_treated_as_auto_pause() {
+        assert!(!is_azure_auto_pause_error_code(Some("18456"))); // login failed
+        assert!(!is_azure_auto_pause_error_code(None));
+        assert!(!is_azure_auto_pause_error_code(Some("not-a-number")));
+    }
+
+    #[test]
+    fn azure_mssql_database_paused_error_message_mentions_wait_time_and_auto_pause() {
+        let known_error = user_facing_errors::KnownError::new(AzureMssqlDatabasePaused { seconds_waited: 60 });
+        let message = known_error.message;
+
+        assert!(message.contains("60 seconds"));
+        assert!(message.contains("auto"));
+        assert!(message.contains("pause") || message.contains("paused"));
+    }
 }
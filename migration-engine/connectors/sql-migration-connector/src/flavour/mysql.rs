@@ -9,6 +9,7 @@ use enumflags2::BitFlags;
 use indoc::indoc;
 use migration_connector::{
     migrations_directory::MigrationDirectory, BoxFuture, ConnectorError, ConnectorParams, ConnectorResult,
+    DatabasePlan,
 };
 use once_cell::sync::Lazy;
 use quaint::{
@@ -23,12 +24,19 @@ use sql_schema_describer::SqlSchema;
 use std::future;
 use url::Url;
 use user_facing_errors::{
-    migration_engine::{ApplyMigrationError, DirectDdlNotAllowed, ForeignKeyCreationNotAllowed},
+    migration_engine::{
+        ApplyMigrationError, DirectDdlNotAllowed, ForeignKeyCreationNotAllowed, MysqlIncompatibleForeignKeyColumnTypes,
+        MysqlKeyTooLong,
+    },
     KnownError,
 };
 
 const ADVISORY_LOCK_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
 static QUALIFIED_NAME_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"`[^ ]+`\.`[^ ]+`"#).unwrap());
+static KEY_TOO_LONG_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"max key length is (\d+) bytes").unwrap());
+static INCOMPATIBLE_FK_COLUMNS_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"Referencing column '([^']+)' and referenced column '([^']+)' are incompatible").unwrap()
+});
 
 type State = super::State<Params, (BitFlags<Circumstances>, Connection)>;
 
@@ -94,6 +102,17 @@ impl SqlFlavour for MysqlFlavour {
         })
     }
 
+    fn release_lock(&mut self) -> BoxFuture<'_, ConnectorResult<()>> {
+        with_connection(&mut self.state, |params, _, connection| async move {
+            if is_planetscale(&params.connector_params.connection_string) {
+                return Ok(());
+            }
+
+            // https://dev.mysql.com/doc/refman/8.0/en/locking-functions.html
+            raw_cmd("SELECT RELEASE_LOCK('prisma_migrate')", connection, &params.url).await
+        })
+    }
+
     fn connector_type(&self) -> &'static str {
         "mysql"
     }
@@ -115,7 +134,7 @@ impl SqlFlavour for MysqlFlavour {
                     }
                 })?;
 
-            normalize_sql_schema(&mut schema, params.connector_params.preview_features);
+            normalize_sql_schema(&mut schema, params.connector_params.preview_features, false);
             Ok(schema)
         })
     }
@@ -265,6 +284,25 @@ impl SqlFlavour for MysqlFlavour {
         })
     }
 
+    fn create_database_plan(&mut self) -> ConnectorResult<DatabasePlan> {
+        let params = self.state.get_unwrapped_params();
+        let db_name = params.url.dbname();
+
+        Ok(DatabasePlan {
+            statements: vec![format!(
+                "CREATE DATABASE `{}` CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci;",
+                db_name
+            )],
+            connection_summary: format!(
+                "mysql://{}@{}:{}/{}",
+                params.url.username(),
+                params.url.host(),
+                params.url.port(),
+                db_name
+            ),
+        })
+    }
+
     fn create_migrations_table(&mut self) -> BoxFuture<'_, ConnectorResult<()>> {
         let sql = indoc! {r#"
             CREATE TABLE _prisma_migrations (
@@ -282,6 +320,37 @@ impl SqlFlavour for MysqlFlavour {
         self.run_query_script(sql)
     }
 
+    fn create_seeds_table(&mut self) -> BoxFuture<'_, ConnectorResult<()>> {
+        let sql = indoc! {r#"
+            CREATE TABLE _prisma_seeds (
+                name        VARCHAR(255) PRIMARY KEY NOT NULL,
+                checksum    VARCHAR(64) NOT NULL,
+                applied_at  DATETIME(3) NOT NULL DEFAULT CURRENT_TIMESTAMP(3)
+            ) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci;
+        "#};
+
+        self.run_query_script(sql)
+    }
+
+    fn validate_seed_script(&self, script: &str) -> ConnectorResult<()> {
+        super::reject_bracket_identifiers(script)
+    }
+
+    fn sql_for_healing_migrations_table_column(&self, column: &'static str) -> String {
+        let table = self.migrations_table_name();
+
+        let column_definition = match column {
+            "finished_at" => "finished_at DATETIME(3)",
+            "logs" => "logs TEXT",
+            "rolled_back_at" => "rolled_back_at DATETIME(3)",
+            "started_at" => "started_at DATETIME(3) NOT NULL DEFAULT CURRENT_TIMESTAMP(3)",
+            "applied_steps_count" => "applied_steps_count INTEGER UNSIGNED NOT NULL DEFAULT 0",
+            other => unreachable!("Unhealable migrations table column: {}", other),
+        };
+
+        format!("ALTER TABLE {} ADD COLUMN {}", table, column_definition)
+    }
+
     fn drop_database(&mut self) -> BoxFuture<'_, ConnectorResult<()>> {
         Box::pin(async {
             let params = self.state.get_unwrapped_params();
@@ -296,6 +365,22 @@ impl SqlFlavour for MysqlFlavour {
         })
     }
 
+    fn drop_database_plan(&mut self) -> ConnectorResult<DatabasePlan> {
+        let params = self.state.get_unwrapped_params();
+        let db_name = params.url.dbname();
+
+        Ok(DatabasePlan {
+            statements: vec![format!("DROP DATABASE `{}`", db_name)],
+            connection_summary: format!(
+                "mysql://{}@{}:{}/{}",
+                params.url.username(),
+                params.url.host(),
+                params.url.port(),
+                db_name
+            ),
+        })
+    }
+
     fn drop_migrations_table(&mut self) -> BoxFuture<'_, ConnectorResult<()>> {
         self.raw_cmd("DROP TABLE _prisma_migrations")
     }
@@ -513,6 +598,44 @@ mod tests {
         );
         assert!(!QUALIFIED_NAME_RE.is_match(should_not_match));
     }
+
+    fn server_error(code: u16, message: &str) -> my::Error {
+        my::Error::Server(my::ServerError {
+            code,
+            message: message.to_owned(),
+            state: "HY000".to_owned(),
+        })
+    }
+
+    #[test]
+    fn convert_server_error_maps_key_too_long() {
+        let error = server_error(1071, "Specified key was too long; max key length is 3072 bytes");
+        let known_error = convert_server_error(BitFlags::empty(), &error).unwrap();
+
+        assert_eq!(known_error.error_code, "P3023");
+        assert!(known_error.message.contains("3072 bytes"));
+        assert!(known_error.message.contains("@db.VarChar(191)"));
+    }
+
+    #[test]
+    fn convert_server_error_maps_incompatible_fk_columns() {
+        let error = server_error(
+            3780,
+            "Referencing column 'author_id' and referenced column 'id' are incompatible.",
+        );
+        let known_error = convert_server_error(BitFlags::empty(), &error).unwrap();
+
+        assert_eq!(known_error.error_code, "P3024");
+        assert!(known_error.message.contains("author_id"));
+        assert!(known_error.message.contains("`id`"));
+    }
+
+    #[test]
+    fn convert_server_error_returns_none_for_unrelated_errors() {
+        let error = server_error(1054, "Unknown column 'foo' in 'field list'");
+
+        assert!(convert_server_error(BitFlags::empty(), &error).is_none());
+    }
 }
 
 fn with_connection<'a, O, F, C>(state: &'a mut State, f: C) -> BoxFuture<'a, ConnectorResult<O>>
@@ -610,8 +733,28 @@ fn scan_migration_script_impl(script: &str) {
 }
 
 fn convert_server_error(circumstances: BitFlags<Circumstances>, error: &my::Error) -> Option<KnownError> {
-    if circumstances.contains(Circumstances::IsVitess) {
-        match error {
+    match error {
+        // ER_TOO_LONG_KEY: the combined length of the indexed column(s) exceeds what the storage
+        // engine can fit in a key. The message itself never names the offending index, but it does
+        // report the limit, which is the actionable part of the hint.
+        my::Error::Server(se) if se.code == 1071 => Some(KnownError::new(MysqlKeyTooLong {
+            max_key_length_bytes: KEY_TOO_LONG_RE
+                .captures(&se.message)
+                .and_then(|captures| captures.get(1))
+                .map(|m| m.as_str().to_owned())
+                .unwrap_or_else(|| "unknown".to_owned()),
+        })),
+        // ER_FK_INCOMPATIBLE_COLUMNS: the referencing and referenced columns of a foreign key have
+        // incompatible types, typically because their native types (length, charset, signedness) differ.
+        my::Error::Server(se) if se.code == 3780 => {
+            let captures = INCOMPATIBLE_FK_COLUMNS_RE.captures(&se.message)?;
+
+            Some(KnownError::new(MysqlIncompatibleForeignKeyColumnTypes {
+                referencing_column: captures.get(1)?.as_str().to_owned(),
+                referenced_column: captures.get(2)?.as_str().to_owned(),
+            }))
+        }
+        _ if circumstances.contains(Circumstances::IsVitess) => match error {
             my::Error::Server(se) if se.code == 1317 => Some(KnownError::new(ForeignKeyCreationNotAllowed)),
             // sigh, this code is for unknown error, so we have the ddl
             // error and other stuff, such as typos in the same category...
@@ -619,9 +762,8 @@ fn convert_server_error(circumstances: BitFlags<Circumstances>, error: &my::Erro
                 Some(KnownError::new(DirectDdlNotAllowed))
             }
             _ => None,
-        }
-    } else {
-        None
+        },
+        _ => None,
     }
 }
 
@@ -6,7 +6,7 @@ pub(super) async fn sql_schema_from_migrations_history(
     migrations: &[MigrationDirectory],
     mut shadow_db: MysqlFlavour,
 ) -> ConnectorResult<SqlSchema> {
-    for migration in migrations {
+    for (migration_index, migration) in migrations.iter().enumerate() {
         let script = migration.read_migration_script()?;
 
         tracing::debug!(
@@ -20,7 +20,12 @@ pub(super) async fn sql_schema_from_migrations_history(
             .apply_migration_script(migration.migration_name(), &script)
             .await
             .map_err(|connector_error| {
-                connector_error.into_migration_does_not_apply_cleanly(migration.migration_name().to_owned())
+                connector_error.into_migration_does_not_apply_cleanly(
+                    migration.migration_name().to_owned(),
+                    migration_index + 1,
+                    migrations.len(),
+                    &script,
+                )
             })?;
     }
 
@@ -7,6 +7,7 @@ use enumflags2::BitFlags;
 use indoc::indoc;
 use migration_connector::{
     migrations_directory::MigrationDirectory, BoxFuture, ConnectorError, ConnectorParams, ConnectorResult,
+    DatabasePlan,
 };
 use quaint::{
     connector::{tokio_postgres::error::ErrorPosition, PostgreSql as Connection, PostgresUrl},
@@ -115,6 +116,17 @@ impl SqlFlavour for PostgresFlavour {
         })
     }
 
+    fn release_lock(&mut self) -> BoxFuture<'_, ConnectorResult<()>> {
+        with_connection(self, move |params, circumstances, connection| async move {
+            if circumstances.contains(Circumstances::IsCockroachDb) {
+                return Ok(());
+            }
+
+            // https://www.postgresql.org/docs/current/explicit-locking.html#ADVISORY-LOCKS
+            raw_cmd("SELECT pg_advisory_unlock(72707369)", connection, &params.url).await
+        })
+    }
+
     fn connector_type(&self) -> &'static str {
         if self.is_cockroach {
             "cockroachdb"
@@ -138,6 +150,9 @@ impl SqlFlavour for PostgresFlavour {
             if circumstances.contains(Circumstances::IsCockroachDb) {
                 describer_circumstances |= describer::Circumstances::Cockroach;
             }
+            if circumstances.contains(Circumstances::IsPostgres11OrOlder) {
+                describer_circumstances |= describer::Circumstances::IsPostgres11OrOlder;
+            }
 
             let mut schema = sql_schema_describer::postgres::SqlSchemaDescriber::new(conn, describer_circumstances)
                 .describe(params.url.schema())
@@ -148,11 +163,15 @@ impl SqlFlavour for PostgresFlavour {
                         let err = DatabaseSchemaInconsistent {
                             explanation: e.to_string(),
                         };
-                        ConnectorError::user_facing(err)
+                        ConnectorError::user_facing(err).with_context("Failed to introspect the database schema")
                     }
                 })?;
 
-            super::normalize_sql_schema(&mut schema, params.connector_params.preview_features);
+            super::normalize_sql_schema(
+                &mut schema,
+                params.connector_params.preview_features,
+                circumstances.contains(Circumstances::IsCockroachDb),
+            );
 
             Ok(schema)
         })
@@ -184,7 +203,7 @@ impl SqlFlavour for PostgresFlavour {
         migration_name: &'a str,
         script: &'a str,
     ) -> BoxFuture<'a, ConnectorResult<()>> {
-        with_connection(self, move |_params, _circumstances, connection| async move {
+        with_connection(self, move |_params, circumstances, connection| async move {
             let client = connection.client();
 
             match client.simple_query(script).await {
@@ -242,7 +261,19 @@ impl SqlFlavour for PostgresFlavour {
                                 String::new()
                             };
 
-                            let database_error = format!("{}{}\n\n{:?}", db_error, position, db_error);
+                            let mut database_error = format!("{}{}\n\n{:?}", db_error, position, db_error);
+
+                            if circumstances.contains(Circumstances::IsPostgres11OrOlder)
+                                && is_add_enum_value_in_transaction_error(&database_error)
+                            {
+                                database_error.push_str(
+                                    "\n\nAdding a value to an enum type with ALTER TYPE ... ADD VALUE cannot run \
+                                     inside a transaction block on PostgreSQL versions older than 12, and every \
+                                     migration script is executed as a single transaction. Move the ALTER TYPE ... \
+                                     ADD VALUE statement into its own migration, applied before any statement that \
+                                     uses the new value.",
+                                );
+                            }
 
                             (Some(db_error.code().code()), database_error)
                         } else {
@@ -310,6 +341,26 @@ impl SqlFlavour for PostgresFlavour {
         })
     }
 
+    fn create_database_plan(&mut self) -> ConnectorResult<DatabasePlan> {
+        let params = self.state.get_unwrapped_params();
+        let db_name = params.url.dbname();
+        let schema_name = params.url.schema();
+
+        Ok(DatabasePlan {
+            statements: vec![
+                format!("CREATE DATABASE \"{}\"", db_name),
+                format!("CREATE SCHEMA IF NOT EXISTS \"{}\";", schema_name),
+            ],
+            connection_summary: format!(
+                "postgresql://{}@{}:{}/{}",
+                params.url.username(),
+                params.url.host(),
+                params.url.port(),
+                db_name
+            ),
+        })
+    }
+
     fn create_migrations_table(&mut self) -> BoxFuture<'_, ConnectorResult<()>> {
         let sql = indoc! {r#"
             CREATE TABLE _prisma_migrations (
@@ -327,6 +378,37 @@ impl SqlFlavour for PostgresFlavour {
         self.raw_cmd(sql)
     }
 
+    fn create_seeds_table(&mut self) -> BoxFuture<'_, ConnectorResult<()>> {
+        let sql = indoc! {r#"
+            CREATE TABLE _prisma_seeds (
+                name        VARCHAR(255) PRIMARY KEY NOT NULL,
+                checksum    VARCHAR(64) NOT NULL,
+                applied_at  TIMESTAMPTZ NOT NULL DEFAULT now()
+            );
+        "#};
+
+        self.raw_cmd(sql)
+    }
+
+    fn validate_seed_script(&self, script: &str) -> ConnectorResult<()> {
+        super::reject_bracket_identifiers(script)
+    }
+
+    fn sql_for_healing_migrations_table_column(&self, column: &'static str) -> String {
+        let table = self.migrations_table_name();
+
+        let column_definition = match column {
+            "finished_at" => "finished_at TIMESTAMPTZ",
+            "logs" => "logs TEXT",
+            "rolled_back_at" => "rolled_back_at TIMESTAMPTZ",
+            "started_at" => "started_at TIMESTAMPTZ NOT NULL DEFAULT now()",
+            "applied_steps_count" => "applied_steps_count INTEGER NOT NULL DEFAULT 0",
+            other => unreachable!("Unhealable migrations table column: {}", other),
+        };
+
+        format!("ALTER TABLE {} ADD COLUMN {}", table, column_definition)
+    }
+
     fn drop_database(&mut self) -> BoxFuture<'_, ConnectorResult<()>> {
         Box::pin(async move {
             let params = self.state.get_unwrapped_params();
@@ -344,6 +426,22 @@ impl SqlFlavour for PostgresFlavour {
         })
     }
 
+    fn drop_database_plan(&mut self) -> ConnectorResult<DatabasePlan> {
+        let params = self.state.get_unwrapped_params();
+        let db_name = params.url.dbname();
+
+        Ok(DatabasePlan {
+            statements: vec![format!("DROP DATABASE \"{}\"", db_name)],
+            connection_summary: format!(
+                "postgresql://{}@{}:{}/{}",
+                params.url.username(),
+                params.url.host(),
+                params.url.port(),
+                db_name
+            ),
+        })
+    }
+
     fn drop_migrations_table(&mut self) -> BoxFuture<'_, ConnectorResult<()>> {
         Box::pin(self.raw_cmd("DROP TABLE _prisma_migrations"))
     }
@@ -528,6 +626,29 @@ async fn create_postgres_admin_conn(mut url: Url) -> ConnectorResult<(Connection
 #[repr(u8)]
 pub(crate) enum Circumstances {
     IsCockroachDb,
+    /// The database is a genuine PostgreSQL server (not CockroachDB) older than version 12,
+    /// where `ALTER TYPE ... ADD VALUE` cannot run inside a transaction block under any
+    /// circumstances, including the implicit transaction wrapping every migration script.
+    IsPostgres11OrOlder,
+}
+
+/// Parse the `(major, minor)` version out of a PostgreSQL `version()` string, e.g.
+/// `"PostgreSQL 11.4 on x86_64-pc-linux-gnu, compiled by ..."` -> `Some((11, 4))`. Returns `None`
+/// for version strings that don't match the expected `PostgreSQL <major>.<minor>` shape, notably
+/// CockroachDB's `version()` output.
+fn parse_postgres_version(version: &str) -> Option<(u32, u32)> {
+    let rest = version.strip_prefix("PostgreSQL ")?;
+    let mut parts = rest.split(|c: char| c == '.' || c.is_whitespace());
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().unwrap_or(0);
+
+    Some((major, minor))
+}
+
+/// Whether a PostgreSQL error message is the "ALTER TYPE ... ADD VALUE cannot run inside a
+/// transaction block" error, which only ever occurs on PostgreSQL < 12.
+fn is_add_enum_value_in_transaction_error(database_error: &str) -> bool {
+    database_error.contains("ADD VALUE") && database_error.contains("cannot run inside a transaction block")
 }
 
 #[allow(clippy::needless_collect)] // clippy is wrong
@@ -604,6 +725,8 @@ where
                             } else if db_is_cockroach {
                                 circumstances |= Circumstances::IsCockroachDb;
                                 raw_cmd(COCKROACHDB_PRELUDE, &mut connection, &params.url).await?;
+                            } else if matches!(parse_postgres_version(&version), Some((major, _)) if major < 12) {
+                                circumstances |= Circumstances::IsPostgres11OrOlder;
                             }
                         }
                         None => {
@@ -680,4 +803,29 @@ mod tests {
             assert!(!debugged.contains(word));
         }
     }
+
+    #[test]
+    fn parse_postgres_version_works() {
+        assert_eq!(
+            parse_postgres_version("PostgreSQL 11.4 on x86_64-pc-linux-gnu, compiled by gcc"),
+            Some((11, 4))
+        );
+        assert_eq!(parse_postgres_version("PostgreSQL 12.0"), Some((12, 0)));
+        assert_eq!(parse_postgres_version("PostgreSQL 9.6.20 on x86_64"), Some((9, 6)));
+        assert_eq!(
+            parse_postgres_version("CockroachDB CCL v22.1.0 (x86_64-pc-linux-gnu, built ...)"),
+            None
+        );
+        assert_eq!(parse_postgres_version("not a version string"), None);
+    }
+
+    #[test]
+    fn is_add_enum_value_in_transaction_error_matches_the_expected_message() {
+        assert!(is_add_enum_value_in_transaction_error(
+            "ERROR: ALTER TYPE ... ADD VALUE cannot run inside a transaction block"
+        ));
+        assert!(!is_add_enum_value_in_transaction_error(
+            "ERROR: syntax error at or near \"CREAT\""
+        ));
+    }
 }
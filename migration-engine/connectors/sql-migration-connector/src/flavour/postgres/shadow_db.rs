@@ -6,7 +6,7 @@ pub(super) async fn sql_schema_from_migrations_history(
     migrations: &[MigrationDirectory],
     mut shadow_db: PostgresFlavour,
 ) -> ConnectorResult<SqlSchema> {
-    for migration in migrations {
+    for (migration_index, migration) in migrations.iter().enumerate() {
         let script = migration.read_migration_script()?;
 
         tracing::debug!(
@@ -19,7 +19,12 @@ pub(super) async fn sql_schema_from_migrations_history(
             .await
             .map_err(ConnectorError::from)
             .map_err(|connector_error| {
-                connector_error.into_migration_does_not_apply_cleanly(migration.migration_name().to_owned())
+                connector_error.into_migration_does_not_apply_cleanly(
+                    migration.migration_name().to_owned(),
+                    migration_index + 1,
+                    migrations.len(),
+                    &script,
+                )
             })?;
     }
 
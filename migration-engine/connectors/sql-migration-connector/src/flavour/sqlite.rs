@@ -2,6 +2,7 @@ use crate::flavour::SqlFlavour;
 use indoc::indoc;
 use migration_connector::{
     migrations_directory::MigrationDirectory, BoxFuture, ConnectorError, ConnectorParams, ConnectorResult,
+    DatabasePlan,
 };
 use quaint::{
     connector::Sqlite as Connection,
@@ -86,6 +87,17 @@ impl SqlFlavour for SqliteFlavour {
         })
     }
 
+    fn create_database_plan(&mut self) -> ConnectorResult<DatabasePlan> {
+        let params = self.state.get_unwrapped_params();
+
+        Ok(DatabasePlan {
+            // SQLite databases are created by opening a connection to the file, there is no SQL
+            // statement involved.
+            statements: Vec::new(),
+            connection_summary: format!("sqlite file at `{}`", params.file_path),
+        })
+    }
+
     fn create_migrations_table(&mut self) -> BoxFuture<'_, ConnectorResult<()>> {
         let sql = indoc! {r#"
             CREATE TABLE "_prisma_migrations" (
@@ -103,6 +115,37 @@ impl SqlFlavour for SqliteFlavour {
         self.raw_cmd(sql)
     }
 
+    fn create_seeds_table(&mut self) -> BoxFuture<'_, ConnectorResult<()>> {
+        let sql = indoc! {r#"
+            CREATE TABLE "_prisma_seeds" (
+                "name"          TEXT PRIMARY KEY NOT NULL,
+                "checksum"      TEXT NOT NULL,
+                "applied_at"    DATETIME NOT NULL DEFAULT current_timestamp
+            );
+        "#};
+
+        self.raw_cmd(sql)
+    }
+
+    fn validate_seed_script(&self, script: &str) -> ConnectorResult<()> {
+        super::reject_bracket_identifiers(script)
+    }
+
+    fn sql_for_healing_migrations_table_column(&self, column: &'static str) -> String {
+        let table = self.migrations_table_name();
+
+        let column_definition = match column {
+            "finished_at" => "\"finished_at\" DATETIME",
+            "logs" => "\"logs\" TEXT",
+            "rolled_back_at" => "\"rolled_back_at\" DATETIME",
+            "started_at" => "\"started_at\" DATETIME NOT NULL DEFAULT current_timestamp",
+            "applied_steps_count" => "\"applied_steps_count\" INTEGER UNSIGNED NOT NULL DEFAULT 0",
+            other => unreachable!("Unhealable migrations table column: {}", other),
+        };
+
+        format!("ALTER TABLE \"{}\" ADD COLUMN {}", table, column_definition)
+    }
+
     fn datamodel_connector(&self) -> &'static dyn datamodel::datamodel_connector::Connector {
         sql_datamodel_connector::SQLITE
     }
@@ -131,6 +174,17 @@ impl SqlFlavour for SqliteFlavour {
         Box::pin(std::future::ready(ret))
     }
 
+    fn drop_database_plan(&mut self) -> ConnectorResult<DatabasePlan> {
+        let params = self.state.get_unwrapped_params();
+
+        Ok(DatabasePlan {
+            // Dropping a SQLite database deletes the file directly, there is no SQL statement
+            // involved.
+            statements: Vec::new(),
+            connection_summary: format!("sqlite file at `{}`", params.file_path),
+        })
+    }
+
     fn drop_migrations_table(&mut self) -> BoxFuture<'_, ConnectorResult<()>> {
         self.raw_cmd("DROP TABLE _prisma_migrations")
     }
@@ -226,7 +280,7 @@ impl SqlFlavour for SqliteFlavour {
                 db_name: "<in_memory>".into(),
             };
 
-            for migration in migrations {
+            for (migration_index, migration) in migrations.iter().enumerate() {
                 let script = migration.read_migration_script()?;
 
                 tracing::debug!(
@@ -239,7 +293,12 @@ impl SqlFlavour for SqliteFlavour {
                     .map_err(|err| super::quaint_error_to_connector_error(err, &conn_info))
                     .map_err(ConnectorError::from)
                     .map_err(|connector_error| {
-                        connector_error.into_migration_does_not_apply_cleanly(migration.migration_name().to_owned())
+                        connector_error.into_migration_does_not_apply_cleanly(
+                            migration.migration_name().to_owned(),
+                            migration_index + 1,
+                            migrations.len(),
+                            &script,
+                        )
                     })?;
             }
 
@@ -9,6 +9,7 @@ mod apply_migration;
 mod database_schema;
 mod error;
 mod flavour;
+mod model_filter;
 mod pair;
 mod sql_destructive_change_checker;
 mod sql_migration;
@@ -104,11 +105,75 @@ impl SqlMigrationConnector {
         self.flavour.raw_cmd(sql).await
     }
 
+    /// Look up the checksum of the seed with the given name, if it was already applied. Returns
+    /// `None` both when the seed was never applied and when the `_prisma_seeds` table does not
+    /// exist yet.
+    async fn existing_seed_checksum(&mut self, name: &str) -> ConnectorResult<Option<String>> {
+        use quaint::ast::*;
+
+        let select = Select::from_table(self.flavour().seeds_table())
+            .column("checksum")
+            .so_that(Column::from("name").equals(name));
+
+        let rows = match self.flavour.query(select.into()).await {
+            Ok(rows) => rows,
+            Err(err) if err.is_user_facing_error::<user_facing_errors::query_engine::TableDoesNotExist>() => {
+                self.flavour.create_seeds_table().await?;
+                return Ok(None);
+            }
+            err @ Err(_) => err?,
+        };
+
+        Ok(rows
+            .into_iter()
+            .next()
+            .and_then(|row| row.get("checksum").and_then(|v| v.to_string())))
+    }
+
+    /// Record that the seed with the given name and checksum was (re)applied. `already_recorded`
+    /// should be `true` if a row for this seed name already exists in `_prisma_seeds`.
+    async fn record_seed(&mut self, name: &str, checksum: &str, already_recorded: bool) -> ConnectorResult<()> {
+        use quaint::ast::*;
+
+        if already_recorded {
+            let update = Update::table(self.flavour().seeds_table())
+                .so_that(Column::from("name").equals(name))
+                .set("checksum", checksum)
+                .set("applied_at", chrono::Utc::now());
+
+            self.flavour.query(update.into()).await?;
+        } else {
+            let insert = Insert::single_into(self.flavour().seeds_table())
+                .value("name", name)
+                .value("checksum", checksum)
+                .value("applied_at", chrono::Utc::now());
+
+            self.flavour.query(insert.into()).await?;
+        }
+
+        Ok(())
+    }
+
     /// Prepare the connector to connect.
     pub fn set_params(&mut self, params: ConnectorParams) -> ConnectorResult<()> {
         self.flavour.set_params(params)
     }
 
+    /// Diff two previously described schemas and render the DDL statements to get from the
+    /// first to the second, entirely offline: no database connection is required, only the
+    /// `SqlSchema`s (e.g. obtained from `describe_schema`) and the flavour to render for.
+    pub fn diff_schemas(&self, from: describer::SqlSchema, to: describer::SqlSchema) -> Vec<String> {
+        let previous = SqlDatabaseSchema::from(from);
+        let next = SqlDatabaseSchema::from(to);
+        let steps = sql_schema_differ::calculate_steps(Pair::new(&previous, &next), self.flavour.as_ref());
+        let schemas = Pair::new(&previous.describer_schema, &next.describer_schema);
+
+        steps
+            .iter()
+            .flat_map(|step| apply_migration::render_raw_sql(step, self.flavour.as_ref(), schemas))
+            .collect()
+    }
+
     async fn db_schema_from_diff_target(
         &mut self,
         target: &DiffTarget<'_>,
@@ -118,10 +183,7 @@ impl SqlMigrationConnector {
             DiffTarget::Datamodel(schema) => {
                 let schema =
                     datamodel::parse_schema_parserdb(schema).map_err(ConnectorError::new_schema_parser_error)?;
-                Ok(sql_schema_calculator::calculate_sql_schema(
-                    &schema,
-                    self.flavour.as_ref(),
-                ))
+                sql_schema_calculator::calculate_sql_schema(&schema, self.flavour.as_ref())
             }
             DiffTarget::Migrations(migrations) => self
                 .flavour
@@ -136,6 +198,7 @@ impl SqlMigrationConnector {
 
 impl MigrationConnector for SqlMigrationConnector {
     fn set_host(&mut self, host: Arc<dyn migration_connector::ConnectorHost>) {
+        self.flavour.set_host(host.clone());
         self.host = host;
     }
 
@@ -155,8 +218,16 @@ impl MigrationConnector for SqlMigrationConnector {
         Box::pin(self.flavour.acquire_lock())
     }
 
+    fn release_lock(&mut self) -> BoxFuture<'_, ConnectorResult<()>> {
+        Box::pin(self.flavour.release_lock())
+    }
+
     fn apply_migration<'a>(&'a mut self, migration: &'a Migration) -> BoxFuture<'a, ConnectorResult<u32>> {
-        Box::pin(apply_migration::apply_migration(migration, self.flavour.as_mut()))
+        Box::pin(apply_migration::apply_migration(
+            migration,
+            self.flavour.as_mut(),
+            self.host.as_ref(),
+        ))
     }
 
     fn apply_script<'a>(&'a mut self, migration_name: &'a str, script: &'a str) -> BoxFuture<'a, ConnectorResult<()>> {
@@ -188,6 +259,10 @@ impl MigrationConnector for SqlMigrationConnector {
         self.flavour.create_database()
     }
 
+    fn create_database_plan(&mut self) -> ConnectorResult<DatabasePlan> {
+        self.flavour.create_database_plan()
+    }
+
     fn database_schema_from_diff_target<'a>(
         &'a mut self,
         diff_target: DiffTarget<'a>,
@@ -220,6 +295,10 @@ impl MigrationConnector for SqlMigrationConnector {
         self.flavour.drop_database()
     }
 
+    fn drop_database_plan(&mut self) -> ConnectorResult<DatabasePlan> {
+        self.flavour.drop_database_plan()
+    }
+
     fn migration_file_extension(&self) -> &'static str {
         "sql"
     }
@@ -277,6 +356,42 @@ impl MigrationConnector for SqlMigrationConnector {
             Ok(())
         })
     }
+
+    fn scope_schemas_to_models(
+        &self,
+        from: DatabaseSchema,
+        to: DatabaseSchema,
+        datamodel: &ValidatedSchema,
+        models: &[String],
+    ) -> ConnectorResult<(DatabaseSchema, DatabaseSchema)> {
+        self.scope_schemas_to_models_impl(from, to, datamodel, models)
+    }
+
+    fn seed(&mut self, name: String, script: String, force: bool) -> BoxFuture<'_, ConnectorResult<bool>> {
+        Box::pin(async move {
+            self.flavour.validate_seed_script(&script)?;
+
+            let existing_checksum = self.existing_seed_checksum(&name).await?;
+
+            if !force {
+                if let Some(checksum) = &existing_checksum {
+                    if migration_connector::checksum::script_matches_checksum(&script, checksum) {
+                        return Ok(true);
+                    }
+                }
+            }
+
+            self.flavour.raw_cmd(&script).await?;
+            self.record_seed(
+                &name,
+                &migration_connector::checksum::render_checksum(&script),
+                existing_checksum.is_some(),
+            )
+            .await?;
+
+            Ok(false)
+        })
+    }
 }
 
 fn new_shadow_database_name() -> String {
@@ -346,3 +461,163 @@ async fn best_effort_reset_impl(flavour: &mut (dyn SqlFlavour + Send + Sync)) ->
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use describer::{Column, ColumnArity, ColumnType, ColumnTypeFamily, Index, IndexColumn, IndexType};
+
+    #[test]
+    fn diff_schemas_renders_ddl_without_a_connection() {
+        let mut previous = describer::SqlSchema::default();
+        let table_id = previous.push_table("A".to_owned());
+        previous.push_column(
+            table_id,
+            Column {
+                name: "id".to_owned(),
+                tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+                default: None,
+                auto_increment: false,
+                is_identity: false,
+                comment: None,
+                generated: None,
+            },
+        );
+        previous[table_id].indices.push(Index {
+            name: "A_id_idx".to_owned(),
+            columns: vec![IndexColumn::new("id")],
+            tpe: IndexType::Normal,
+            is_autogenerated: false,
+        });
+
+        let mut next = describer::SqlSchema::default();
+        let table_id = next.push_table("A".to_owned());
+        next.push_column(
+            table_id,
+            Column {
+                name: "id".to_owned(),
+                tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+                default: None,
+                auto_increment: false,
+                is_identity: false,
+                comment: None,
+                generated: None,
+            },
+        );
+        next.push_column(
+            table_id,
+            Column {
+                name: "name".to_owned(),
+                tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Nullable),
+                default: None,
+                auto_increment: false,
+                is_identity: false,
+                comment: None,
+                generated: None,
+            },
+        );
+
+        let connector = SqlMigrationConnector::new_postgres();
+        let statements = connector.diff_schemas(previous, next);
+
+        assert!(statements.iter().any(|s| s.contains("DROP INDEX") && s.contains("A_id_idx")));
+        assert!(statements
+            .iter()
+            .any(|s| s.contains("ADD COLUMN") && s.contains("\"name\"")));
+    }
+
+    #[test]
+    fn cockroach_diffing_accepts_the_inverted_to_gin_index_mapping() {
+        use sql_schema_describer::postgres::{PostgresSchemaExt, SqlIndexAlgorithm};
+        use sql_schema_describer::IndexId;
+
+        fn schema_with_gin_index(algorithm: SqlIndexAlgorithm) -> describer::SqlSchema {
+            let mut schema = describer::SqlSchema::default();
+            let table_id = schema.push_table("Cat".to_owned());
+            schema.push_column(
+                table_id,
+                Column {
+                    name: "tags".to_owned(),
+                    tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::List),
+                    default: None,
+                    auto_increment: false,
+                    is_identity: false,
+                    comment: None,
+                    generated: None,
+                },
+            );
+            schema[table_id].indices.push(Index {
+                name: "Cat_tags_idx".to_owned(),
+                columns: vec![IndexColumn::new("tags")],
+                tpe: IndexType::Normal,
+                is_autogenerated: false,
+            });
+
+            schema
+                .downcast_connector_data_mut::<PostgresSchemaExt>()
+                .indexes
+                .push((IndexId(table_id, 0), algorithm));
+
+            schema
+        }
+
+        // CockroachDB always picks GIN ("inverted") for this kind of index; the calculated
+        // schema has no way to request it explicitly and defaults to BTree.
+        let previous = schema_with_gin_index(SqlIndexAlgorithm::Gin);
+        let next = schema_with_gin_index(SqlIndexAlgorithm::BTree);
+
+        let connector = SqlMigrationConnector::new_cockroach();
+        let statements = connector.diff_schemas(previous, next);
+
+        assert!(
+            statements.is_empty(),
+            "expected no migration steps, got: {:?}",
+            statements
+        );
+    }
+
+    // Not a benchmark: a coarse assertion that the no-op diff of a large schema stays roughly
+    // linear rather than accidentally quadratic (or worse), so CI catches order-of-magnitude
+    // regressions without relying on flaky precise timing. See `benches/diff_and_render.rs` for
+    // actual performance measurements.
+    #[test]
+    fn diffing_a_large_schema_stays_fast() {
+        fn schema_with_2k_tables() -> describer::SqlSchema {
+            let mut schema = describer::SqlSchema::default();
+
+            for table_index in 0..2_000 {
+                let table_id = schema.push_table(format!("table_{table_index}"));
+                schema.push_column(
+                    table_id,
+                    Column {
+                        name: "id".to_owned(),
+                        tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+                        default: None,
+                        auto_increment: false,
+                        is_identity: false,
+                        comment: None,
+                        generated: None,
+                    },
+                );
+            }
+
+            schema
+        }
+
+        let connector = SqlMigrationConnector::new_postgres();
+        let started_at = std::time::Instant::now();
+        let statements = connector.diff_schemas(schema_with_2k_tables(), schema_with_2k_tables());
+        let elapsed = started_at.elapsed();
+
+        assert!(
+            statements.is_empty(),
+            "expected no migration steps, got: {:?}",
+            statements
+        );
+        assert!(
+            elapsed < std::time::Duration::from_secs(10),
+            "diffing a 2k-table no-op schema took {:?}, expected well under 10s",
+            elapsed
+        );
+    }
+}
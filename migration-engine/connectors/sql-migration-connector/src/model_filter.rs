@@ -0,0 +1,117 @@
+//! Support for `schemaPush`'s `models` filter: restrict a push to the tables backing a subset of
+//! models.
+
+use crate::{database_schema::SqlDatabaseSchema, SqlMigrationConnector};
+use datamodel::ValidatedSchema;
+use migration_connector::{ConnectorError, ConnectorResult, DatabaseSchema};
+use sql_schema_describer::{walkers::SqlSchemaExt, SqlSchema};
+use std::collections::HashSet;
+
+impl SqlMigrationConnector {
+    pub(crate) fn scope_schemas_to_models_impl(
+        &self,
+        from: DatabaseSchema,
+        to: DatabaseSchema,
+        datamodel: &ValidatedSchema,
+        models: &[String],
+    ) -> ConnectorResult<(DatabaseSchema, DatabaseSchema)> {
+        let mut from = SqlDatabaseSchema::from_erased(from);
+        let mut to = SqlDatabaseSchema::from_erased(to);
+
+        let mut requested_tables = HashSet::new();
+
+        for name in models {
+            let model = datamodel
+                .db
+                .walk_models()
+                .find(|model| model.name() == name)
+                .ok_or_else(|| {
+                    ConnectorError::from_msg(format!(
+                        "The `models` filter of `schemaPush` references an unknown model `{}`.",
+                        name
+                    ))
+                })?;
+
+            requested_tables.insert(model.database_name().to_owned());
+        }
+
+        let kept = compute_kept_tables(&to.describer_schema, &from.describer_schema, requested_tables)?;
+
+        let new_to_column_ids = to.describer_schema.retain_tables(|name| kept.contains(name));
+        to.prisma_level_defaults = to
+            .prisma_level_defaults
+            .iter()
+            .filter_map(|id| new_to_column_ids[id.0 as usize])
+            .collect();
+
+        from.describer_schema.retain_tables(|name| kept.contains(name));
+
+        Ok((DatabaseSchema::from(*from), DatabaseSchema::from(*to)))
+    }
+}
+
+/// Starting from the tables backing the requested models, pull in whatever else is needed for the
+/// push to be self-consistent: implicit many-to-many join tables between two requested models, and
+/// tables referenced by a foreign key that are not part of the push but already exist in the
+/// database (so the constraint stays valid without those tables needing to be created).
+fn compute_kept_tables(
+    to: &SqlSchema,
+    from: &SqlSchema,
+    mut kept: HashSet<String>,
+) -> ConnectorResult<HashSet<String>> {
+    loop {
+        let mut changed = false;
+
+        // Implicit join tables: every foreign key on the table points at an already-kept table.
+        for table in to.table_walkers() {
+            if kept.contains(table.name()) {
+                continue;
+            }
+
+            let fks: Vec<_> = table.foreign_keys().collect();
+
+            if !fks.is_empty() && fks.iter().all(|fk| kept.contains(fk.referenced_table().name())) {
+                kept.insert(table.name().to_owned());
+                changed = true;
+            }
+        }
+
+        // Foreign keys from a kept table to a table outside the selection: allowed only if that
+        // table already exists in the database, in which case it is carried over unchanged.
+        let mut externally_referenced = Vec::new();
+
+        for table in to.table_walkers() {
+            if !kept.contains(table.name()) {
+                continue;
+            }
+
+            for fk in table.foreign_keys() {
+                let referenced = fk.referenced_table().name();
+
+                if kept.contains(referenced) {
+                    continue;
+                }
+
+                if from.table_walker(referenced).is_some() {
+                    externally_referenced.push(referenced.to_owned());
+                } else {
+                    return Err(ConnectorError::from_msg(format!(
+                        "Cannot restrict `schemaPush` to the requested models: `{}` has a foreign key to `{}`, which is not part of the requested models and does not already exist in the database.",
+                        table.name(),
+                        referenced,
+                    )));
+                }
+            }
+        }
+
+        for name in externally_referenced {
+            if kept.insert(name) {
+                changed = true;
+            }
+        }
+
+        if !changed {
+            return Ok(kept);
+        }
+    }
+}
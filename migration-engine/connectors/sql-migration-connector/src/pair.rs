@@ -1,5 +1,6 @@
 use crate::SqlDatabaseSchema;
 use sql_schema_describer::{
+    postgres::{PostgresSchemaExt, Sequence},
     walkers::{ColumnWalker, EnumWalker, ForeignKeyWalker, IndexWalker, SqlSchemaExt, TableWalker},
     ColumnId, EnumId, ForeignKeyId, IndexId, SqlSchema, TableId,
 };
@@ -109,6 +110,12 @@ impl<'a> Pair<TableWalker<'a>> {
     }
 }
 
+impl<'a> Pair<&'a PostgresSchemaExt> {
+    pub(crate) fn sequences(self, sequence_idx: Pair<usize>) -> Pair<&'a Sequence> {
+        self.zip(sequence_idx).map(|(ext, idx)| &ext.sequences[idx])
+    }
+}
+
 impl<T> From<(T, T)> for Pair<T> {
     fn from((previous, next): (T, T)) -> Self {
         Pair { previous, next }
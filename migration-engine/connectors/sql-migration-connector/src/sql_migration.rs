@@ -60,6 +60,8 @@ impl SqlMigration {
             let idx = idx as u32;
             match step {
                 SqlMigrationStep::AlterSequence(_, _) => (),
+                SqlMigrationStep::CreateSequence(_) => (),
+                SqlMigrationStep::DropSequence(_) => (),
                 SqlMigrationStep::DropView(drop_view) => {
                     drift_items.insert((
                         DriftType::RemovedView,
@@ -202,6 +204,8 @@ impl SqlMigration {
 
             match &self.steps[*step_idx as usize] {
                 SqlMigrationStep::AlterSequence(_, _) => {}
+                SqlMigrationStep::CreateSequence(_) => {}
+                SqlMigrationStep::DropSequence(_) => {}
                 SqlMigrationStep::DropView(_) => {}
                 SqlMigrationStep::DropUserDefinedType(_) => {}
                 SqlMigrationStep::CreateEnum(enum_id) => {
@@ -420,6 +424,10 @@ fn render_column_changes(columns: Pair<ColumnWalker<'_>>, changes: &ColumnChange
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub(crate) enum SqlMigrationStep {
     AlterSequence(Pair<u32>, SequenceChanges),
+    // Order matters: a renamed sequence is a drop of the old one followed by a create of the
+    // new one, so the drop must sort before the create.
+    DropSequence(u32),
+    CreateSequence(u32),
     DropView(DropView),
     DropUserDefinedType(DropUserDefinedType),
     CreateEnum(sql_schema_describer::EnumId),
@@ -485,8 +493,10 @@ impl SqlMigrationStep {
             SqlMigrationStep::AlterTable(_) => "AlterTable",
             SqlMigrationStep::CreateEnum(_) => "CreateEnum",
             SqlMigrationStep::CreateIndex { .. } => "CreateIndex",
+            SqlMigrationStep::CreateSequence(_) => "CreateSequence",
             SqlMigrationStep::CreateTable { .. } => "CreateTable",
             SqlMigrationStep::DropEnum(_) => "DropEnum",
+            SqlMigrationStep::DropSequence(_) => "DropSequence",
             SqlMigrationStep::DropForeignKey { .. } => "DropForeignKey",
             SqlMigrationStep::DropIndex { .. } => "DropIndex",
             SqlMigrationStep::DropTable { .. } => "DropTable",
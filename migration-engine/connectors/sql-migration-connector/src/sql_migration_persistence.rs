@@ -1,4 +1,4 @@
-use crate::SqlMigrationConnector;
+use crate::{flavour::migrations_table, SqlMigrationConnector};
 use migration_connector::{
     BoxFuture, ConnectorError, ConnectorResult, MigrationPersistence, MigrationRecord, PersistenceNotInitializedError,
 };
@@ -14,10 +14,32 @@ impl MigrationPersistence for SqlMigrationConnector {
         Box::pin(async move {
             let schema = self.flavour.describe_schema().await?;
 
-            if schema
+            let existing_migrations_table = schema
                 .table_walkers()
-                .any(|table| table.name() == self.flavour().migrations_table_name())
-            {
+                .find(|table| table.name() == self.flavour().migrations_table_name());
+
+            if let Some(table) = existing_migrations_table {
+                // Users upgrading across many versions of the engine can be left with a
+                // migrations table that predates a column we now rely on. Bring it up to shape
+                // additively rather than fail later with a raw SQL error on insert.
+                let diff = migrations_table::diff(table);
+
+                if !diff.unhealable.is_empty() {
+                    return Err(ConnectorError::user_facing(
+                        user_facing_errors::migration_engine::MigrationsTableIncompatible {
+                            details: format!(
+                                "missing required column(s) that cannot be added without a destructive change: {}",
+                                diff.unhealable.join(", ")
+                            ),
+                        },
+                    ));
+                }
+
+                for column in diff.healable {
+                    let sql = self.flavour().sql_for_healing_migrations_table_column(column);
+                    self.flavour.raw_cmd(&sql).await?;
+                }
+
                 return Ok(());
             }
 
@@ -22,6 +22,7 @@ use crate::{
 };
 use common::Quoted;
 use sql_schema_describer::{
+    postgres::Sequence,
     walkers::{EnumWalker, ForeignKeyWalker, IndexWalker, TableWalker, UserDefinedTypeWalker, ViewWalker},
     SqlSchema,
 };
@@ -41,6 +42,16 @@ pub(crate) trait SqlRenderer {
         unreachable!("unreachable render_alter_sequence");
     }
 
+    /// Render a `CreateSequence` step.
+    fn render_create_sequence(&self, _sequence: &Sequence) -> String {
+        unreachable!("unreachable render_create_sequence");
+    }
+
+    /// Render a `DropSequence` step.
+    fn render_drop_sequence(&self, _sequence: &Sequence) -> String {
+        unreachable!("unreachable render_drop_sequence");
+    }
+
     fn render_rename_index(&self, _indexes: Pair<IndexWalker<'_>>) -> Vec<String> {
         unreachable!("unreachable render_alter_index")
     }
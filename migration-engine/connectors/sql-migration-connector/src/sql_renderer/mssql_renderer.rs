@@ -48,7 +48,12 @@ impl MssqlFlavour {
         let nullability = render_nullability(column);
 
         let default = if column.is_autoincrement() {
-            Cow::Borrowed(" IDENTITY(1,1)")
+            let mssql_ext: &MssqlSchemaExt = column.schema.downcast_connector_data().unwrap_or_default();
+
+            match mssql_ext.get_identity(column.id) {
+                Some(identity) => Cow::Owned(format!(" IDENTITY({},{})", identity.seed, identity.increment)),
+                None => Cow::Borrowed(" IDENTITY(1,1)"),
+            }
         } else {
             column
                 .default()
@@ -349,6 +354,32 @@ impl SqlRenderer for MssqlFlavour {
             // Rename the temporary table with the name defined in the migration.
             result.push(self.render_rename_table(&temporary_table_name, tables.next.name()));
 
+            // Copying rows with IDENTITY_INSERT ON does not reliably leave the identity's
+            // current value where it was on the old table (e.g. if the highest generated value
+            // had already been deleted), so reseed explicitly from what we observed when we
+            // described the previous schema.
+            if needs_autoincrement {
+                let previous_ext: &MssqlSchemaExt = schemas.previous.downcast_connector_data().unwrap_or_default();
+
+                for (column_indexes, _, _) in &redefine_table.column_pairs {
+                    let previous_column = schemas.columns(*column_indexes).previous;
+
+                    if !previous_column.is_autoincrement() {
+                        continue;
+                    }
+
+                    if let Some(identity) = previous_ext.get_identity(column_indexes.previous) {
+                        if let Some(current_value) = identity.current_value {
+                            result.push(format!(
+                                "DBCC CHECKIDENT ('{}', RESEED, {})",
+                                tables.next.name(),
+                                current_value
+                            ));
+                        }
+                    }
+                }
+            }
+
             // Recreate the indexes.
             for index in tables.next.indexes().filter(|i| !i.index_type().is_unique()) {
                 result.push(self.render_create_index(index));
@@ -9,7 +9,7 @@ use datamodel::dml::PrismaValue;
 use native_types::{CockroachType, PostgresType};
 use sql_ddl::{postgres as ddl, IndexColumn, SortOrder};
 use sql_schema_describer::{
-    postgres::{PostgresSchemaExt, SqlIndexAlgorithm},
+    postgres::{PostgresSchemaExt, Sequence, SqlIndexAlgorithm},
     walkers::*,
     ColumnArity, ColumnTypeFamily, DefaultKind, DefaultValue, ForeignKeyAction, SQLSortOrder, SqlSchema,
 };
@@ -41,10 +41,7 @@ impl SqlRenderer for PostgresFlavour {
         schemas: Pair<&SqlSchema>,
     ) -> Vec<String> {
         let exts: Pair<&PostgresSchemaExt> = schemas.map(|schema| schema.downcast_connector_data().unwrap_or_default());
-        let (prev_seq, next_seq) = exts
-            .combine(sequence_idx)
-            .map(|(ext, idx)| &ext.sequences[idx as usize])
-            .into_tuple();
+        let (prev_seq, next_seq) = exts.sequences(sequence_idx.map(|idx| idx as usize)).into_tuple();
         render_step(&mut |step| {
             step.render_statement(&mut |stmt| {
                 stmt.push_str("ALTER SEQUENCE ");
@@ -78,6 +75,23 @@ impl SqlRenderer for PostgresFlavour {
         })
     }
 
+    fn render_create_sequence(&self, sequence: &Sequence) -> String {
+        format!(
+            "CREATE SEQUENCE {name} INCREMENT BY {increment} MINVALUE {min} MAXVALUE {max} START {start} CACHE {cache}{cycle}",
+            name = Quoted::postgres_ident(&sequence.name),
+            increment = sequence.increment_by,
+            min = sequence.min_value,
+            max = sequence.max_value,
+            start = sequence.start_value,
+            cache = sequence.cache_size,
+            cycle = if sequence.cycle { " CYCLE" } else { "" },
+        )
+    }
+
+    fn render_drop_sequence(&self, sequence: &Sequence) -> String {
+        format!("DROP SEQUENCE {}", Quoted::postgres_ident(&sequence.name))
+    }
+
     fn quote<'a>(&self, name: &'a str) -> Quoted<&'a str> {
         Quoted::postgres_ident(name)
     }
@@ -383,6 +397,7 @@ impl SqlRenderer for PostgresFlavour {
     fn render_drop_view(&self, view: ViewWalker<'_>) -> String {
         ddl::DropView {
             view_name: view.name().into(),
+            is_materialized: view.is_materialized(),
         }
         .to_string()
     }
@@ -740,6 +755,10 @@ enum PostgresAlterColumn {
 fn render_default<'a>(default: &'a DefaultValue, full_data_type: &str) -> Cow<'a, str> {
     fn render_constant_default<'a>(value: &'a PrismaValue, full_data_type: &str) -> Cow<'a, str> {
         match value {
+            // MONEY has no numeric literal syntax of its own: a bare `12.34` is parsed as
+            // `numeric`, which Postgres will only assign to a money column through an implicit
+            // cast in some contexts but not in a column default. Quote and cast explicitly.
+            PrismaValue::Float(val) if full_data_type == "MONEY" => format!("'{}'::MONEY", val).into(),
             PrismaValue::String(val) | PrismaValue::Enum(val) => format!("'{}'", escape_string_literal(val)).into(),
             PrismaValue::Json(json_value) => {
                 let mut out = String::with_capacity(json_value.len() + 2);
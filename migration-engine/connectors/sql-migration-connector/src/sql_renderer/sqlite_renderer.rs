@@ -249,6 +249,7 @@ fn render_column_type(t: &ColumnType) -> &str {
         ColumnTypeFamily::Binary => "BLOB",
         ColumnTypeFamily::Json => unreachable!("ColumnTypeFamily::Json on SQLite"),
         ColumnTypeFamily::Enum(_) => unreachable!("ColumnTypeFamily::Enum on SQLite"),
+        ColumnTypeFamily::Set(_) => unreachable!("ColumnTypeFamily::Set on SQLite"),
         ColumnTypeFamily::Uuid => unimplemented!("ColumnTypeFamily::Uuid on SQLite"),
         ColumnTypeFamily::Unsupported(x) => x.as_ref(),
     }
@@ -10,11 +10,15 @@ use datamodel::{
     schema_ast::ast::{self, FieldArity},
     ValidatedSchema,
 };
+use migration_connector::{ConnectorError, ConnectorResult};
 use sql::walkers::SqlSchemaExt;
 use sql_schema_describer as sql;
 use std::collections::HashMap;
 
-pub(crate) fn calculate_sql_schema(datamodel: &ValidatedSchema, flavour: &dyn SqlFlavour) -> SqlDatabaseSchema {
+pub(crate) fn calculate_sql_schema(
+    datamodel: &ValidatedSchema,
+    flavour: &dyn SqlFlavour,
+) -> ConnectorResult<SqlDatabaseSchema> {
     let mut schema = SqlDatabaseSchema::default();
 
     schema.describer_schema.enums = flavour.calculate_enums(datamodel);
@@ -36,7 +40,77 @@ pub(crate) fn calculate_sql_schema(datamodel: &ValidatedSchema, flavour: &dyn Sq
     push_relation_tables(&mut context);
     flavour.push_connector_data(&mut context);
 
-    schema
+    validate_no_name_collisions(&schema, flavour)?;
+
+    Ok(schema)
+}
+
+/// The kind of schema element that generated a given database identifier, for the collision error
+/// message.
+#[derive(Clone, Copy)]
+enum SchemaElementKind {
+    Model,
+    Enum,
+}
+
+impl SchemaElementKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            SchemaElementKind::Model => "model",
+            SchemaElementKind::Enum => "enum",
+        }
+    }
+}
+
+/// Check that no two tables, and no table and enum, generated from the datamodel would collide
+/// under the connector's identifier rules. Composite types are not checked here: on every
+/// connector this calculator supports, they are inlined as JSON columns rather than materialized
+/// as their own database object, so they cannot collide with anything at this level.
+///
+/// Connectors that do not render enums as separate database objects (MySQL inlines them into the
+/// column type, MSSQL into a `CHECK` constraint) never populate `describer_schema.enums` with a
+/// name that could plausibly collide with a table, so the enum side of this check only bites in
+/// practice on connectors like Postgres, where enums are their own named type.
+fn validate_no_name_collisions(schema: &SqlDatabaseSchema, flavour: &dyn SqlFlavour) -> ConnectorResult<()> {
+    let case_sensitive = flavour.identifiers_are_case_sensitive();
+    let normalize = |name: &str| {
+        if case_sensitive {
+            name.to_owned()
+        } else {
+            name.to_lowercase()
+        }
+    };
+
+    let mut seen: HashMap<String, (&str, SchemaElementKind)> =
+        HashMap::with_capacity(schema.describer_schema.tables_count() + schema.describer_schema.enums.len());
+
+    let elements = schema
+        .describer_schema
+        .table_walkers()
+        .map(|table| (table.name(), SchemaElementKind::Model))
+        .chain(
+            schema
+                .describer_schema
+                .enums
+                .iter()
+                .map(|r#enum| (r#enum.name.as_str(), SchemaElementKind::Enum)),
+        );
+
+    for (name, kind) in elements {
+        if let Some((other_name, other_kind)) = seen.insert(normalize(name), (name, kind)) {
+            return Err(ConnectorError::from_msg(format!(
+                "The {} `{}` and the {} `{}` would both be generated as the database identifier `{}`, which {} does not allow.",
+                other_kind.as_str(),
+                other_name,
+                kind.as_str(),
+                name,
+                name,
+                flavour.connector_type(),
+            )));
+        }
+    }
+
+    Ok(())
 }
 
 fn push_model_tables(ctx: &mut Context<'_>) {
@@ -74,6 +148,7 @@ fn push_model_tables(ctx: &mut Context<'_>) {
                             SortOrder::Desc => sql::SQLSortOrder::Desc,
                         }),
                         length: sf.length(),
+                        ..Default::default()
                     })
                     .collect();
 
@@ -87,6 +162,7 @@ fn push_model_tables(ctx: &mut Context<'_>) {
                     name: index.constraint_name(ctx.flavour.datamodel_connector()).into_owned(),
                     columns,
                     tpe: index_type,
+                    is_autogenerated: false,
                 }
             })
             .collect();
@@ -125,11 +201,18 @@ fn push_inline_relations(ctx: &mut Context<'_>) {
                     .expect("Expected references to be defined on relation field")
                     .map(|f| f.database_name().to_owned())
                     .collect(),
-                on_update_action: relation_field
-                    .explicit_on_update()
-                    .map(convert_referential_action)
-                    .unwrap_or_else(|| sql::ForeignKeyAction::Cascade),
-                on_delete_action: convert_referential_action(on_delete_action),
+                on_update_action: clamp_to_supported_action(
+                    relation_field
+                        .explicit_on_update()
+                        .map(convert_referential_action)
+                        .unwrap_or(sql::ForeignKeyAction::Cascade),
+                    ctx.flavour.referential_actions_supported(),
+                ),
+                on_delete_action: clamp_to_supported_action(
+                    convert_referential_action(on_delete_action),
+                    ctx.flavour.referential_actions_supported(),
+                ),
+                validated: true,
             },
         ));
     }
@@ -186,6 +269,7 @@ fn push_relation_tables(ctx: &mut Context<'_>) {
                         sql::IndexColumn::new(model_b_column),
                     ],
                     tpe: sql::IndexType::Unique,
+                    is_autogenerated: false,
                 },
                 sql::Index {
                     name: format!(
@@ -194,6 +278,7 @@ fn push_relation_tables(ctx: &mut Context<'_>) {
                     ),
                     columns: vec![sql::IndexColumn::new(model_b_column)],
                     tpe: sql::IndexType::Normal,
+                    is_autogenerated: false,
                 },
             ];
         }
@@ -208,6 +293,7 @@ fn push_relation_tables(ctx: &mut Context<'_>) {
                     referenced_columns: vec![model_a_id.database_name().into()],
                     on_update_action: flavour.m2m_foreign_key_action(model_a, model_b),
                     on_delete_action: flavour.m2m_foreign_key_action(model_a, model_b),
+                    validated: true,
                 },
             ));
 
@@ -220,6 +306,7 @@ fn push_relation_tables(ctx: &mut Context<'_>) {
                     referenced_columns: vec![model_b_id.database_name().into()],
                     on_update_action: flavour.m2m_foreign_key_action(model_a, model_b),
                     on_delete_action: flavour.m2m_foreign_key_action(model_a, model_b),
+                    validated: true,
                 },
             ));
         }
@@ -241,6 +328,9 @@ fn push_relation_tables(ctx: &mut Context<'_>) {
                         .clone(),
                     default: None,
                     auto_increment: false,
+                    is_identity: false,
+                    comment: None,
+                    generated: None,
                 },
             );
         }
@@ -305,6 +395,9 @@ fn push_column_for_model_enum_scalar_field(
             tpe: ctx.flavour.enum_column_type(field, r#enum.database_name()),
             default,
             auto_increment: false,
+            is_identity: false,
+            comment: None,
+            generated: None,
         },
     );
 }
@@ -334,6 +427,9 @@ fn push_column_for_model_unsupported_scalar_field(
                 }
             }),
             auto_increment: false,
+            is_identity: false,
+            comment: None,
+            generated: None,
         },
     );
 }
@@ -374,6 +470,9 @@ fn push_column_for_builtin_scalar_type(
                 native_type: Some(native_type),
             },
             auto_increment: field.is_autoincrement() || ctx.flavour.field_is_implicit_autoincrement_primary_key(field),
+            is_identity: false,
+            comment: None,
+            generated: None,
         },
     );
 
@@ -460,6 +559,20 @@ pub(crate) struct Context<'a> {
     model_id_to_table_id: HashMap<ast::ModelId, sql::TableId>,
 }
 
+/// Falls back to `NoAction` when the connector cannot enforce `action` — validation at the
+/// datamodel level should have already rejected this case, so this is a last-resort safety net.
+fn clamp_to_supported_action(
+    action: sql::ForeignKeyAction,
+    supported: enumflags2::BitFlags<sql::ForeignKeyAction>,
+) -> sql::ForeignKeyAction {
+    if supported.contains(action) {
+        action
+    } else {
+        tracing::warn!(?action, "Referential action is not supported by the connector, falling back to NoAction");
+        sql::ForeignKeyAction::NoAction
+    }
+}
+
 fn convert_referential_action(action: ReferentialAction) -> sql::ForeignKeyAction {
     match action {
         ReferentialAction::Cascade => sql::ForeignKeyAction::Cascade,
@@ -479,3 +592,175 @@ fn unwrap_dbgenerated(expr: &ast::Expression) -> Option<String> {
         .get(0)
         .map(|arg| arg.value.as_string_value().unwrap().0.to_owned())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::calculate_sql_schema;
+    use crate::flavour::{MssqlFlavour, MysqlFlavour, PostgresFlavour, SqlFlavour, SqliteFlavour};
+
+    fn parse(schema: &str) -> datamodel::ValidatedSchema {
+        datamodel::parse_schema_parserdb(schema).unwrap()
+    }
+
+    #[test]
+    fn postgres_rejects_an_enum_colliding_with_a_model_table_name() {
+        // Declared names must be distinct (`model Status` and `enum Status` would be a duplicate
+        // top-level name error), but each can be mapped to the same database identifier.
+        let schema = parse(
+            r#"
+            datasource db {
+              provider = "postgresql"
+              url      = "postgresql://"
+            }
+
+            model StatusModel {
+              id Int @id
+
+              @@map("Status")
+            }
+
+            enum StatusEnum {
+              ACTIVE
+              INACTIVE
+
+              @@map("Status")
+            }
+            "#,
+        );
+
+        let err = calculate_sql_schema(&schema, &PostgresFlavour::default() as &dyn SqlFlavour).unwrap_err();
+
+        assert!(err.to_string().contains("Status"), "{}", err);
+    }
+
+    #[test]
+    fn postgres_is_case_sensitive_about_the_collision() {
+        let schema = parse(
+            r#"
+            datasource db {
+              provider = "postgresql"
+              url      = "postgresql://"
+            }
+
+            model StatusModel {
+              id Int @id
+
+              @@map("status")
+            }
+
+            enum StatusEnum {
+              ACTIVE
+              INACTIVE
+
+              @@map("Status")
+            }
+            "#,
+        );
+
+        calculate_sql_schema(&schema, &PostgresFlavour::default() as &dyn SqlFlavour).unwrap();
+    }
+
+    #[test]
+    fn mssql_rejects_two_mapped_model_tables_colliding_only_by_case() {
+        // MSSQL's default collation is case-insensitive, so two models mapped to table names that
+        // only differ by casing would collide, even though Postgres or SQLite would accept them.
+        let schema = parse(
+            r#"
+            datasource db {
+              provider = "sqlserver"
+              url      = "sqlserver://"
+            }
+
+            model Status {
+              id Int @id
+
+              @@map("status")
+            }
+
+            model OtherStatus {
+              id Int @id
+
+              @@map("Status")
+            }
+            "#,
+        );
+
+        let err = calculate_sql_schema(&schema, &MssqlFlavour::default() as &dyn SqlFlavour).unwrap_err();
+
+        assert!(err.to_string().contains("Status"), "{}", err);
+    }
+
+    #[test]
+    fn postgres_allows_two_mapped_model_tables_differing_only_by_case() {
+        let schema = parse(
+            r#"
+            datasource db {
+              provider = "postgresql"
+              url      = "postgresql://"
+            }
+
+            model Status {
+              id Int @id
+
+              @@map("status")
+            }
+
+            model OtherStatus {
+              id Int @id
+
+              @@map("Status")
+            }
+            "#,
+        );
+
+        calculate_sql_schema(&schema, &PostgresFlavour::default() as &dyn SqlFlavour).unwrap();
+    }
+
+    #[test]
+    fn mysql_inlined_enums_never_collide_with_model_table_names() {
+        // MySQL enums are rendered inline as the column's type (`ENUM(...)`), so
+        // `calculate_enums` names the generated enum `{model}_{field}` regardless of the name
+        // mapped in the datamodel — it can't collide with the `Status` model table, even though
+        // the enum is explicitly mapped to that very identifier.
+        let schema = parse(
+            r#"
+            datasource db {
+              provider = "mysql"
+              url      = "mysql://"
+            }
+
+            model Status {
+              id     Int        @id
+              status StatusEnum
+            }
+
+            enum StatusEnum {
+              ACTIVE
+              INACTIVE
+
+              @@map("Status")
+            }
+            "#,
+        );
+
+        calculate_sql_schema(&schema, &MysqlFlavour::default() as &dyn SqlFlavour).unwrap();
+    }
+
+    #[test]
+    fn sqlite_has_no_enums_to_collide() {
+        let schema = parse(
+            r#"
+            datasource db {
+              provider = "sqlite"
+              url      = "file:dev.db"
+            }
+
+            model Status {
+              id Int @id
+            }
+            "#,
+        );
+
+        calculate_sql_schema(&schema, &SqliteFlavour::default() as &dyn SqlFlavour).unwrap();
+    }
+}
@@ -6,7 +6,8 @@ mod sqlite;
 use datamodel::{
     datamodel_connector::ScalarType, parser_database::walkers::*, schema_ast::ast::FieldArity, ValidatedSchema,
 };
-use sql_schema_describer::{self as sql, ColumnArity, ColumnType, ColumnTypeFamily};
+use enumflags2::BitFlags;
+use sql_schema_describer::{self as sql, ColumnArity, ColumnType, ColumnTypeFamily, ForeignKeyAction};
 
 pub(crate) trait SqlSchemaCalculatorFlavour {
     fn calculate_enums(&self, _datamodel: &ValidatedSchema) -> Vec<sql::Enum> {
@@ -44,9 +45,85 @@ pub(crate) trait SqlSchemaCalculatorFlavour {
         false
     }
 
+    /// Whether two database identifiers that only differ by casing refer to distinct objects on
+    /// this connector. Used to detect collisions between generated table and enum names.
+    fn identifiers_are_case_sensitive(&self) -> bool {
+        true
+    }
+
     fn m2m_foreign_key_action(&self, _model_a: ModelWalker<'_>, _model_b: ModelWalker<'_>) -> sql::ForeignKeyAction {
         sql::ForeignKeyAction::Cascade
     }
 
     fn push_connector_data(&self, _context: &mut super::Context<'_>) {}
+
+    /// The set of `ON DELETE`/`ON UPDATE` actions this connector's database can actually
+    /// enforce. All five actions are supported everywhere by default; flavours override this to
+    /// report narrower support.
+    fn referential_actions_supported(&self) -> BitFlags<ForeignKeyAction> {
+        ForeignKeyAction::NoAction
+            | ForeignKeyAction::Restrict
+            | ForeignKeyAction::Cascade
+            | ForeignKeyAction::SetNull
+            | ForeignKeyAction::SetDefault
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flavour::{MssqlFlavour, MysqlFlavour, PostgresFlavour, SqliteFlavour};
+
+    fn all_actions() -> BitFlags<ForeignKeyAction> {
+        ForeignKeyAction::NoAction
+            | ForeignKeyAction::Restrict
+            | ForeignKeyAction::Cascade
+            | ForeignKeyAction::SetNull
+            | ForeignKeyAction::SetDefault
+    }
+
+    #[test]
+    fn mysql_supports_all_referential_actions() {
+        assert_eq!(MysqlFlavour::default().referential_actions_supported(), all_actions());
+    }
+
+    #[test]
+    fn postgres_supports_all_referential_actions() {
+        assert_eq!(PostgresFlavour::default().referential_actions_supported(), all_actions());
+    }
+
+    #[test]
+    fn sqlite_supports_all_referential_actions() {
+        assert_eq!(SqliteFlavour::default().referential_actions_supported(), all_actions());
+    }
+
+    #[test]
+    fn mssql_does_not_support_restrict() {
+        let supported = MssqlFlavour::default().referential_actions_supported();
+
+        assert!(!supported.contains(ForeignKeyAction::Restrict));
+        assert!(supported.contains(
+            ForeignKeyAction::NoAction | ForeignKeyAction::Cascade | ForeignKeyAction::SetNull | ForeignKeyAction::SetDefault
+        ));
+    }
+
+    #[test]
+    fn mysql_identifiers_are_case_sensitive() {
+        assert!(MysqlFlavour::default().identifiers_are_case_sensitive());
+    }
+
+    #[test]
+    fn postgres_identifiers_are_case_sensitive() {
+        assert!(PostgresFlavour::default().identifiers_are_case_sensitive());
+    }
+
+    #[test]
+    fn sqlite_identifiers_are_case_sensitive() {
+        assert!(SqliteFlavour::default().identifiers_are_case_sensitive());
+    }
+
+    #[test]
+    fn mssql_identifiers_are_not_case_sensitive() {
+        assert!(!MssqlFlavour::default().identifiers_are_case_sensitive());
+    }
 }
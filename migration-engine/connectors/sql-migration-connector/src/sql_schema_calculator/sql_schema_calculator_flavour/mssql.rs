@@ -4,6 +4,7 @@ use datamodel::{
     datamodel_connector::walker_ext_traits::DefaultValueExt,
     parser_database::{walkers::*, ScalarType},
 };
+use enumflags2::BitFlags;
 use sql_schema_describer::{mssql::MssqlSchemaExt, ForeignKeyAction};
 
 impl SqlSchemaCalculatorFlavour for MssqlFlavour {
@@ -15,6 +16,13 @@ impl SqlSchemaCalculatorFlavour for MssqlFlavour {
         sql_datamodel_connector::MSSQL.default_native_type_for_scalar_type(scalar_type)
     }
 
+    // SQL Server's default collation (`SQL_Latin1_General_CP1_CI_AS`) is case-insensitive, so
+    // `Status` and `status` name the same object unless the database was set up with a
+    // case-sensitive collation.
+    fn identifiers_are_case_sensitive(&self) -> bool {
+        false
+    }
+
     fn m2m_foreign_key_action(&self, model_a: ModelWalker<'_>, model_b: ModelWalker<'_>) -> ForeignKeyAction {
         // MSSQL will crash when creating a cyclic cascade
         if model_a.name() == model_b.name() {
@@ -24,6 +32,12 @@ impl SqlSchemaCalculatorFlavour for MssqlFlavour {
         }
     }
 
+    fn referential_actions_supported(&self) -> BitFlags<ForeignKeyAction> {
+        // T-SQL has no `RESTRICT` action for foreign keys, only `NO ACTION`, `CASCADE`,
+        // `SET NULL` and `SET DEFAULT`.
+        ForeignKeyAction::NoAction | ForeignKeyAction::Cascade | ForeignKeyAction::SetNull | ForeignKeyAction::SetDefault
+    }
+
     fn push_connector_data(&self, context: &mut super::super::Context<'_>) {
         let mut data = MssqlSchemaExt::default();
 
@@ -68,8 +68,12 @@ impl SqlSchemaCalculatorFlavour for PostgresFlavour {
 
                         let opclass = match opclass.get() {
                             Either::Left(class) => convert_opclass(class, index.algorithm()),
+                            // A raw ops name might still be one of the named operator classes
+                            // (e.g. `raw("gin_trgm_ops")`), so it needs to go through the same
+                            // string-to-variant mapping introspection uses, or the calculated
+                            // schema and the introspected one would disagree on the opclass kind.
                             Either::Right(s) => sql::postgres::SQLOperatorClass {
-                                kind: sql::postgres::SQLOperatorClassKind::Raw(s.to_owned()),
+                                kind: sql::postgres::SQLOperatorClassKind::from(s),
                                 is_default: false,
                             },
                         };
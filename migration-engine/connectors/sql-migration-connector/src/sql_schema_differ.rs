@@ -67,8 +67,15 @@ fn push_created_table_steps(steps: &mut Vec<SqlMigrationStep>, db: &DifferDataba
     }
 }
 
-// We drop the foreign keys of dropped tables first, so we can drop tables in whatever order we
-// please later.
+// We push a `DropForeignKey` for every foreign key of a dropped table (in whatever order we
+// encounter dropped tables and their foreign keys), and don't try to order those against the
+// `DropTable` steps here: `SqlMigrationStep`'s `Ord` impl sorts *all* `DropForeignKey` steps ahead
+// of *all* `DropTable` steps regardless of push order, so this is correct even for two tables that
+// reference each other, or a table with a self-referential foreign key. SQLite is the exception:
+// it can't drop a foreign key independently of the table (`should_drop_foreign_keys_from_dropped_tables`
+// is `false` there), since foreign keys are baked into `CREATE TABLE` and disappear with the table
+// itself, or are handled through the redefine-table (create-copy-drop) path when only some of a
+// table's columns or constraints change.
 fn push_dropped_table_steps(steps: &mut Vec<SqlMigrationStep>, db: &DifferDatabase<'_>) {
     for dropped_table in db.dropped_tables() {
         steps.push(SqlMigrationStep::DropTable {
@@ -502,6 +509,21 @@ fn push_foreign_key_pair_changes(
             return;
         }
 
+        // Some databases generate their own constraint names (e.g. CockroachDB's
+        // `crdb_internal_...` foreign keys) that are not stable across describes and were never
+        // requested by the user, so a difference there should not trigger a rename.
+        if fk
+            .map(|fk| fk.constraint_name())
+            .transpose()
+            .map(|names| {
+                db.flavour.constraint_name_is_generated(names.previous)
+                    || db.flavour.constraint_name_is_generated(names.next)
+            })
+            .unwrap_or(false)
+        {
+            return;
+        }
+
         if db.flavour.can_rename_foreign_key() {
             steps.push(SqlMigrationStep::RenameForeignKey {
                 foreign_key_id: fk.map(|fk| fk.id),
@@ -87,6 +87,13 @@ fn defaults_match(cols: Pair<ColumnWalker<'_>>, flavour: &dyn SqlFlavour) -> boo
         (Some(DefaultKind::Now), None) => false,
         (Some(DefaultKind::Now), Some(DefaultKind::Value(_))) => false,
 
+        // An explicit `DEFAULT NULL` is equivalent to having no default at all.
+        (Some(DefaultKind::DbGenerated(expr)), None) | (None, Some(DefaultKind::DbGenerated(expr)))
+            if expr.trim_matches(|c| c == '(' || c == ')').eq_ignore_ascii_case("null") =>
+        {
+            true
+        }
+
         (Some(DefaultKind::DbGenerated(_)), Some(DefaultKind::Value(_))) => false,
         (Some(DefaultKind::DbGenerated(_)), Some(DefaultKind::Now)) => false,
         (Some(DefaultKind::DbGenerated(_)), None) => false,
@@ -103,7 +110,7 @@ fn defaults_match(cols: Pair<ColumnWalker<'_>>, flavour: &dyn SqlFlavour) -> boo
         (None, Some(DefaultKind::Now)) => false,
 
         (Some(DefaultKind::DbGenerated(prev)), Some(DefaultKind::DbGenerated(next))) => {
-            (prev.eq_ignore_ascii_case(next)) && names_match
+            dbgenerated_defaults_match(prev, next) && names_match
         }
         (_, Some(DefaultKind::DbGenerated(_))) => false,
         (_, Some(DefaultKind::Sequence(_))) => true,
@@ -117,6 +124,44 @@ fn json_defaults_match(previous: &str, next: &str) -> bool {
         .unwrap_or(true)
 }
 
+/// Compare two `dbgenerated()` expressions loosely: databases often echo back an expression we
+/// wrote with different whitespace, casing, or an added type cast (e.g. Postgres turning `'utc'`
+/// into `'utc'::text`), and none of that should be treated as drift.
+fn dbgenerated_defaults_match(previous: &str, next: &str) -> bool {
+    normalize_dbgenerated_expression(previous) == normalize_dbgenerated_expression(next)
+}
+
+/// Lowercase, collapse runs of whitespace to a single space, and strip `::type` casts, so
+/// [`dbgenerated_defaults_match`] does not flag cosmetic differences as drift.
+fn normalize_dbgenerated_expression(expr: &str) -> String {
+    let mut normalized = String::with_capacity(expr.len());
+    let mut chars = expr.trim().chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == ':' && chars.peek() == Some(&':') {
+            chars.next(); // the second `:`
+
+            while matches!(chars.peek(), Some(c) if c.is_alphanumeric() || matches!(c, '_' | '.')) {
+                chars.next();
+            }
+
+            continue;
+        }
+
+        if c.is_whitespace() {
+            if !normalized.ends_with(' ') {
+                normalized.push(' ');
+            }
+
+            continue;
+        }
+
+        normalized.push(c.to_ascii_lowercase());
+    }
+
+    normalized.trim_end().to_owned()
+}
+
 fn list_defaults_match(prev: &[PrismaValue], next: &[PrismaValue], flavour: &dyn SqlFlavour) -> bool {
     if prev.len() != next.len() {
         return false;
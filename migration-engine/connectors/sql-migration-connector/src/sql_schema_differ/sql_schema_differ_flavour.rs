@@ -36,6 +36,13 @@ pub(crate) trait SqlSchemaDifferFlavour {
     /// Controls whether we will generate `RenameForeignKey` steps for this flavour.
     fn can_rename_foreign_key(&self) -> bool;
 
+    /// Whether a constraint name was generated by the database itself rather than requested by
+    /// the user or Prisma (e.g. CockroachDB's `crdb_internal_...` foreign key names). A
+    /// difference purely in such a name should not produce a rename step.
+    fn constraint_name_is_generated(&self, _name: &str) -> bool {
+        false
+    }
+
     /// This method must return whether a column became or ceased to be autoincrementing.
     fn column_autoincrement_changed(&self, columns: Pair<ColumnWalker<'_>>) -> bool {
         columns.previous.is_autoincrement() != columns.next.is_autoincrement()
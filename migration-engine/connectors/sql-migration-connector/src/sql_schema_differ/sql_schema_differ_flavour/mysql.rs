@@ -2,6 +2,7 @@ use super::SqlSchemaDifferFlavour;
 use crate::{flavour::MysqlFlavour, pair::Pair, sql_schema_differ::ColumnTypeChange};
 use native_types::MySqlType;
 use sql_schema_describer::{
+    mysql::MysqlSchemaExt,
     walkers::{ColumnWalker, IndexWalker},
     ColumnTypeFamily,
 };
@@ -69,6 +70,13 @@ impl SqlSchemaDifferFlavour for MysqlFlavour {
         None
     }
 
+    fn indexes_match(&self, a: IndexWalker<'_>, b: IndexWalker<'_>) -> bool {
+        let previous_ext: &MysqlSchemaExt = a.schema.downcast_connector_data().unwrap_or_default();
+        let next_ext: &MysqlSchemaExt = b.schema.downcast_connector_data().unwrap_or_default();
+
+        previous_ext.index_algorithm(a.id) == next_ext.index_algorithm(b.id)
+    }
+
     fn index_should_be_renamed(&self, indexes: Pair<IndexWalker<'_>>) -> bool {
         // Implements correct comparison for truncated index names.
         let (previous_name, next_name) = indexes.as_ref().map(|idx| idx.name()).into_tuple();
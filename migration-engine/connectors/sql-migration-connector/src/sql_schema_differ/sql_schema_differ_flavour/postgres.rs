@@ -37,6 +37,10 @@ impl SqlSchemaDifferFlavour for PostgresFlavour {
         true
     }
 
+    fn constraint_name_is_generated(&self, name: &str) -> bool {
+        self.is_cockroachdb() && name.starts_with("crdb_internal_")
+    }
+
     fn column_autoincrement_changed(&self, columns: Pair<ColumnWalker<'_>>) -> bool {
         if self.is_cockroachdb() {
             return false;
@@ -88,10 +92,6 @@ impl SqlSchemaDifferFlavour for PostgresFlavour {
     }
 
     fn push_alter_sequence_steps(&self, steps: &mut Vec<SqlMigrationStep>, db: &DifferDatabase<'_>) {
-        if !self.is_cockroachdb() {
-            return;
-        }
-
         let schemas: Pair<(&SqlDatabaseSchema, &PostgresSchemaExt)> = db.schemas().map(|schema| {
             (
                 schema,
@@ -118,6 +118,17 @@ impl SqlSchemaDifferFlavour for PostgresFlavour {
         for pair in sequence_pairs {
             let prev = pair.previous.1;
             let next = pair.next.1;
+
+            // The two sides were looked up by name, so a name mismatch here means the column's
+            // default now points at a differently-named sequence. Treat it as a rename: recreate
+            // the sequence under its new name rather than diffing fields that may not even be
+            // comparable between two different objects.
+            if prev.name != next.name {
+                steps.push(SqlMigrationStep::DropSequence(pair.previous.0 as u32));
+                steps.push(SqlMigrationStep::CreateSequence(pair.next.0 as u32));
+                continue;
+            }
+
             let mut changes: BitFlags<SequenceChange> = BitFlags::default();
 
             if prev.min_value != next.min_value {
@@ -159,8 +170,13 @@ impl SqlSchemaDifferFlavour for PostgresFlavour {
         let previous_algo = pg_ext_previous.index_algorithm(a.id);
         let next_algo = pg_ext_next.index_algorithm(b.id);
 
+        // CockroachDB picks GIN ("inverted") indexes on its own for some column types, and
+        // there is no way to request a different algorithm from the datamodel, so a mismatch
+        // here is not a real difference.
+        let algorithms_match = previous_algo == next_algo || self.is_cockroachdb();
+
         columns_previous.len() == columns_next.len()
-            && previous_algo == next_algo
+            && algorithms_match
             && columns_previous.zip(columns_next).all(|(col_a, col_b)| {
                 let a_class = pg_ext_previous.get_opclass(col_a.index_field_id());
                 let b_class = pg_ext_next.get_opclass(col_b.index_field_id());
@@ -316,6 +332,13 @@ fn postgres_native_type_change_riskyness(previous: PostgresType, next: PostgresT
     use native_types::PostgresType::*;
     use ColumnTypeChange::*;
 
+    // A field with no explicit `@db.Decimal(p, s)` lowers to the connector's default, unconstrained
+    // `Decimal(None)`. That is not a precision the user asked for, so it is compatible with
+    // whatever precision the database column already has, and does not warrant a migration step.
+    if let (Decimal(Some(_)), Decimal(None)) = (previous, next) {
+        return None;
+    }
+
     // varchar / varbit without param=> unlimited length
     // char / bit without param => length is 1
     let next_is_char = || matches!(next, Char(_));
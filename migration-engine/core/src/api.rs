@@ -1,6 +1,7 @@
 //! The external facing programmatic API to the migration engine.
 
 use crate::{commands, json_rpc::types::*, CoreResult};
+use migration_connector::DatabasePlan;
 
 /// The programmatic, generic, fantastic migration engine API.
 #[async_trait::async_trait]
@@ -14,6 +15,9 @@ pub trait GenericApi: Send + Sync + 'static {
     /// Create the database referenced by Prisma schema that was used to initialize the connector.
     async fn create_database(&self, params: CreateDatabaseParams) -> CoreResult<CreateDatabaseResult>;
 
+    /// Compute what `create_database` would do, without doing it. Used by `--print` in the CLI.
+    async fn create_database_plan(&self, params: CreateDatabaseParams) -> CoreResult<DatabasePlan>;
+
     /// Generate a new migration, based on the provided schema and existing migrations history.
     async fn create_migration(&self, input: CreateMigrationInput) -> CoreResult<CreateMigrationOutput>;
 
@@ -23,6 +27,10 @@ pub trait GenericApi: Send + Sync + 'static {
     /// Debugging method that only panics, for CLI tests.
     async fn debug_panic(&self) -> CoreResult<()>;
 
+    /// Debugging method that sleeps for the requested duration before responding, for CLI tests
+    /// that exercise behavior around long-running operations (e.g. graceful shutdown).
+    async fn debug_sleep(&self, input: DebugSleepInput) -> CoreResult<()>;
+
     /// Tells the CLI what to do in `migrate dev`.
     async fn dev_diagnostic(&self, input: DevDiagnosticInput) -> CoreResult<DevDiagnosticOutput>;
 
@@ -32,6 +40,9 @@ pub trait GenericApi: Send + Sync + 'static {
     /// Drop the database referenced by Prisma schema that was used to initialize the connector.
     async fn drop_database(&self, url: String) -> CoreResult<()>;
 
+    /// Same as [`GenericApi::create_database_plan`], for `drop_database`.
+    async fn drop_database_plan(&self, url: String) -> CoreResult<DatabasePlan>;
+
     /// Looks at the migrations folder and the database, and returns a bunch of useful information.
     async fn diagnose_migration_history(
         &self,
@@ -49,6 +60,11 @@ pub trait GenericApi: Send + Sync + 'static {
     /// Evaluate the consequences of running the next migration we would generate, given the current state of a Prisma schema.
     async fn evaluate_data_loss(&self, input: EvaluateDataLossInput) -> CoreResult<EvaluateDataLossOutput>;
 
+    /// Return a snapshot of this engine instance's metrics (schema describe calls, diff steps and
+    /// applied migration statements, and failures by error code), as a JSON object or in
+    /// Prometheus text exposition format.
+    async fn get_metrics(&self, params: GetMetricsParams) -> CoreResult<GetMetricsResult>;
+
     /// List the migration directories.
     async fn list_migration_directories(
         &self,
@@ -64,9 +80,27 @@ pub trait GenericApi: Send + Sync + 'static {
         input: MarkMigrationRolledBackInput,
     ) -> CoreResult<MarkMigrationRolledBackOutput>;
 
+    /// Look at the migrations directory and the migrations table, and answer the three questions
+    /// a deploy pipeline usually needs in one round trip: which directory migrations are
+    /// unapplied, which applied migrations have no matching directory entry, and (only if
+    /// `checkDrift` is set, since it requires a shadow database) whether the live schema has
+    /// drifted from the replayed history.
+    async fn migration_status(
+        &self,
+        input: commands::MigrationStatusInput,
+    ) -> CoreResult<commands::MigrationStatusOutput>;
+
     /// Reset a database to an empty state (no data, no schema).
     async fn reset(&self) -> CoreResult<()>;
 
+    /// Release any advisory locks held by connectors that are currently connected. Intended to be
+    /// called during graceful shutdown, so no lock outlives the process.
+    async fn release_all_locks(&self);
+
     /// The command behind `prisma db push`.
     async fn schema_push(&self, input: SchemaPushInput) -> CoreResult<SchemaPushOutput>;
+
+    /// Run a seed script against the database, skipping it if it was already applied with the
+    /// same checksum, unless `force` is set.
+    async fn seed(&self, params: SeedParams) -> CoreResult<SeedResult>;
 }
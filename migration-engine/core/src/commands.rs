@@ -9,11 +9,13 @@ mod diff;
 mod evaluate_data_loss;
 mod mark_migration_applied;
 mod mark_migration_rolled_back;
+mod migration_status;
 mod schema_push;
 
 pub use diagnose_migration_history::{
     DiagnoseMigrationHistoryInput, DiagnoseMigrationHistoryOutput, DriftDiagnostic, HistoryDiagnostic,
 };
+pub use migration_status::{DriftStatus, MigrationStatusEntry, MigrationStatusInput, MigrationStatusOutput};
 
 pub use apply_migrations::apply_migrations;
 pub use create_migration::create_migration;
@@ -23,4 +25,5 @@ pub use diff::diff;
 pub use evaluate_data_loss::evaluate_data_loss;
 pub use mark_migration_applied::mark_migration_applied;
 pub use mark_migration_rolled_back::mark_migration_rolled_back;
+pub use migration_status::migration_status;
 pub use schema_push::schema_push;
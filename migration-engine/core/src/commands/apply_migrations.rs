@@ -44,6 +44,7 @@ pub async fn apply_migrations(
     tracing::info!(analysis_duration_ms, "Analysis run in {}ms", analysis_duration_ms,);
 
     let mut applied_migration_names: Vec<String> = Vec::with_capacity(unapplied_migrations.len());
+    let mut migrations: Vec<AppliedMigration> = Vec::with_capacity(unapplied_migrations.len());
 
     for unapplied_migration in unapplied_migrations {
         let fut = async {
@@ -72,6 +73,11 @@ pub async fn apply_migrations(
                     p.record_successful_step(&migration_id).await?;
                     p.record_migration_finished(&migration_id).await?;
                     applied_migration_names.push(unapplied_migration.migration_name().to_owned());
+                    migrations.push(AppliedMigration {
+                        name: unapplied_migration.migration_name().to_owned(),
+                        steps_count: count_steps(&script),
+                        warnings: extract_warnings(&script),
+                    });
                     Ok(())
                 }
                 Err(err) => {
@@ -97,9 +103,43 @@ pub async fn apply_migrations(
 
     Ok(ApplyMigrationsOutput {
         applied_migration_names,
+        migrations,
     })
 }
 
+/// Count the migration steps in a rendered migration script, based on the `-- <step
+/// description>` comments that `migrate dev` renders one per step (see
+/// `sql_migration_connector::apply_migration::render_script`). This is a best-effort count: a
+/// hand-edited migration script may not match it exactly.
+fn count_steps(script: &str) -> u32 {
+    script
+        .lines()
+        .filter(|line| line.trim_start().starts_with("-- "))
+        .count() as u32
+}
+
+/// Extract the destructive change warnings embedded in a rendered migration script's `/*
+/// Warnings: ... */` header, if any (see `sql_migration_connector::apply_migration::render_script`).
+fn extract_warnings(script: &str) -> Vec<String> {
+    let mut warnings = Vec::new();
+    let mut in_warnings_comment = false;
+
+    for line in script.lines() {
+        match line.trim() {
+            "/*" => in_warnings_comment = true,
+            "*/" => in_warnings_comment = false,
+            trimmed if in_warnings_comment => {
+                if let Some(warning) = trimmed.strip_prefix("- ") {
+                    warnings.push(warning.to_owned());
+                }
+            }
+            _ => (),
+        }
+    }
+
+    warnings
+}
+
 fn detect_failed_migrations(migrations_from_database: &[MigrationRecord]) -> CoreResult<()> {
     use std::fmt::Write as _;
 
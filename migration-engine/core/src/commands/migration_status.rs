@@ -0,0 +1,161 @@
+use crate::CoreResult;
+use migration_connector::{
+    migrations_directory::*, DiffTarget, MigrationConnector, MigrationRecord, PersistenceNotInitializedError,
+    Timestamp,
+};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// The input to the `MigrationStatus` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrationStatusInput {
+    /// The location of the migrations directory.
+    pub migrations_directory_path: String,
+    /// Whether to also replay the migration history in a shadow database and compare it to the
+    /// live database schema. This is significantly more expensive than the rest of the command,
+    /// so it is opt-in.
+    #[serde(default)]
+    pub check_drift: bool,
+}
+
+/// The output of the `MigrationStatus` command.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrationStatusOutput {
+    /// Migrations present in the migrations directory, but not yet applied to the database.
+    pub unapplied_migrations: Vec<MigrationStatusEntry>,
+    /// Migrations recorded as applied in the database, but with no corresponding entry in the
+    /// migrations directory (e.g. deleted or renamed after being applied).
+    pub orphaned_migrations: Vec<MigrationStatusEntry>,
+    /// Whether the live database schema matches what the migration history would produce. `None`
+    /// if `checkDrift` was not set on the input.
+    pub drift: Option<DriftStatus>,
+}
+
+/// One migration mentioned in a [`MigrationStatusOutput`] section.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrationStatusEntry {
+    /// The name of the migration directory.
+    pub migration_name: String,
+    /// Whether the checksum of the migration script on disk matches the checksum recorded when
+    /// it was applied. No comparison is made, so this is always `true`: unapplied migrations have
+    /// no recorded checksum, and orphaned migrations have no script on disk.
+    pub checksum_matches: bool,
+    /// When the migration was applied, if it was.
+    pub applied_at: Option<Timestamp>,
+}
+
+/// The result of the optional drift check.
+#[derive(Serialize, Debug)]
+#[serde(tag = "status", rename_all = "camelCase")]
+pub enum DriftStatus {
+    /// The live database schema matches the replayed migration history.
+    InSync,
+    /// The live database schema does not match the replayed migration history.
+    #[serde(rename_all = "camelCase")]
+    Drifted {
+        /// A one-line, human-readable description of the drift.
+        summary: String,
+    },
+}
+
+/// A consolidated view of the migration history, combining the checks from
+/// [`super::diagnose_migration_history`] into the three questions a deploy pipeline typically
+/// needs answered in one round trip: which migrations are unapplied, which applied migrations
+/// have no matching directory entry, and (optionally, since it requires a shadow database)
+/// whether the live schema has drifted from the replayed history.
+pub async fn migration_status(
+    input: MigrationStatusInput,
+    connector: &mut dyn MigrationConnector,
+) -> CoreResult<MigrationStatusOutput> {
+    tracing::debug!("Computing migration status");
+
+    let migrations_from_filesystem = list_migrations(Path::new(&input.migrations_directory_path))?;
+
+    let (migrations_from_database, _has_migrations_table) =
+        match connector.migration_persistence().list_migrations().await? {
+            Ok(migrations) => (migrations, true),
+            Err(PersistenceNotInitializedError {}) => (vec![], false),
+        };
+
+    let unapplied_migrations: Vec<_> = migrations_from_filesystem
+        .iter()
+        .filter(|fs_migration| {
+            !migrations_from_database
+                .iter()
+                .any(|db_migration| db_migration.migration_name == fs_migration.migration_name())
+        })
+        .map(|fs_migration| MigrationStatusEntry {
+            migration_name: fs_migration.migration_name().to_owned(),
+            checksum_matches: true,
+            applied_at: None,
+        })
+        .collect();
+
+    let orphaned_migrations: Vec<_> = migrations_from_database
+        .iter()
+        .filter(|db_migration| {
+            !migrations_from_filesystem
+                .iter()
+                .any(|fs_migration| fs_migration.migration_name() == db_migration.migration_name)
+        })
+        .map(MigrationStatusEntry::from_db_migration)
+        .collect();
+
+    let drift = if input.check_drift {
+        Some(check_drift(connector, &migrations_from_filesystem, &migrations_from_database).await?)
+    } else {
+        None
+    };
+
+    Ok(MigrationStatusOutput {
+        unapplied_migrations,
+        orphaned_migrations,
+        drift,
+    })
+}
+
+impl MigrationStatusEntry {
+    fn from_db_migration(db_migration: &MigrationRecord) -> Self {
+        MigrationStatusEntry {
+            migration_name: db_migration.migration_name.clone(),
+            checksum_matches: true,
+            applied_at: db_migration.finished_at,
+        }
+    }
+}
+
+async fn check_drift(
+    connector: &mut dyn MigrationConnector,
+    migrations_from_filesystem: &[MigrationDirectory],
+    migrations_from_database: &[MigrationRecord],
+) -> CoreResult<DriftStatus> {
+    let applied_migrations: Vec<_> = migrations_from_filesystem
+        .iter()
+        .filter(|fs_migration| {
+            migrations_from_database
+                .iter()
+                .filter(|db_migration| db_migration.finished_at.is_some() && db_migration.rolled_back_at.is_none())
+                .any(|db_migration| db_migration.migration_name == fs_migration.migration_name())
+        })
+        .cloned()
+        .collect();
+
+    let from = connector
+        .database_schema_from_diff_target(DiffTarget::Migrations(&applied_migrations), None)
+        .await?;
+    let to = connector
+        .database_schema_from_diff_target(DiffTarget::Database, None)
+        .await?;
+    let migration = connector.diff(from, to)?;
+
+    if connector.migration_is_empty(&migration) {
+        Ok(DriftStatus::InSync)
+    } else {
+        Ok(DriftStatus::Drifted {
+            summary: connector.migration_summary(&migration),
+        })
+    }
+}
@@ -1,32 +1,83 @@
-use crate::{json_rpc::types::*, parse_schema, CoreResult};
+use crate::{json_rpc::types::*, metrics::Metrics, parse_schema, CoreResult};
 use migration_connector::{ConnectorError, DiffTarget, MigrationConnector};
+use std::{sync::Arc, time::Instant};
 
 /// Command to bring the local database in sync with the prisma schema, without
 /// interacting with the migrations directory nor the migrations table.
 pub async fn schema_push(
     input: SchemaPushInput,
     connector: &mut dyn MigrationConnector,
+    metrics: Arc<Metrics>,
 ) -> CoreResult<SchemaPushOutput> {
-    let datamodel = parse_schema(&input.schema)?;
+    let datamodel = match parse_schema(&input.schema) {
+        Ok(datamodel) => datamodel,
+        Err(err) => {
+            metrics.record_failure(err.error_code());
+            return Err(err);
+        }
+    };
 
     if let Some(err) = connector.check_database_version_compatibility(&datamodel) {
-        return Err(ConnectorError::user_facing(err));
+        let err = ConnectorError::user_facing(err);
+        metrics.record_failure(err.error_code());
+        return Err(err);
     };
 
-    let from = connector
+    let describe_started_at = Instant::now();
+    let from = match connector
         .database_schema_from_diff_target(DiffTarget::Database, None)
-        .await?;
-    let to = connector
+        .await
+    {
+        Ok(from) => from,
+        Err(err) => {
+            metrics.record_failure(err.error_code());
+            return Err(err);
+        }
+    };
+    metrics.record_describe(describe_started_at.elapsed());
+
+    let describe_started_at = Instant::now();
+    let to = match connector
         .database_schema_from_diff_target(DiffTarget::Datamodel(&input.schema), None)
-        .await?;
-    let database_migration = connector.diff(from, to)?;
+        .await
+    {
+        Ok(to) => to,
+        Err(err) => {
+            metrics.record_failure(err.error_code());
+            return Err(err);
+        }
+    };
+    metrics.record_describe(describe_started_at.elapsed());
+
+    let (from, to) = match &input.models {
+        Some(models) => match connector.scope_schemas_to_models(from, to, &datamodel, models) {
+            Ok(scoped) => scoped,
+            Err(err) => {
+                metrics.record_failure(err.error_code());
+                return Err(err);
+            }
+        },
+        None => (from, to),
+    };
+
+    let database_migration = match connector.diff(from, to) {
+        Ok(database_migration) => database_migration,
+        Err(err) => {
+            metrics.record_failure(err.error_code());
+            return Err(err);
+        }
+    };
+    metrics.record_diff_steps(connector.migration_len(&database_migration) as u64);
 
     tracing::debug!(migration = connector.migration_summary(&database_migration).as_str());
 
-    let checks = connector
-        .destructive_change_checker()
-        .check(&database_migration)
-        .await?;
+    let checks = match connector.destructive_change_checker().check(&database_migration).await {
+        Ok(checks) => checks,
+        Err(err) => {
+            metrics.record_failure(err.error_code());
+            return Err(err);
+        }
+    };
 
     let executed_steps = match (checks.unexecutable_migrations.len(), checks.warnings.len(), input.force) {
         (unexecutable, _, _) if unexecutable > 0 => {
@@ -34,7 +85,18 @@ pub async fn schema_push(
 
             0
         }
-        (0, 0, _) | (0, _, true) => connector.apply_migration(&database_migration).await?,
+        (0, 0, _) | (0, _, true) => {
+            let executed_steps = match connector.apply_migration(&database_migration).await {
+                Ok(executed_steps) => executed_steps,
+                Err(err) => {
+                    metrics.record_failure(err.error_code());
+                    return Err(err);
+                }
+            };
+            metrics.record_statements_applied(executed_steps as u64);
+
+            executed_steps
+        }
         _ => {
             tracing::info!(
                 "The migration was not applied because it triggered warnings and the force flag was not passed."
@@ -8,6 +8,8 @@ include!(concat!(env!("OUT_DIR"), "/methods.rs"));
 // exposed for tests
 #[doc(hidden)]
 pub mod commands;
+#[doc(hidden)]
+pub mod metrics;
 
 mod api;
 mod core_error;
@@ -0,0 +1,129 @@
+//! A lightweight registry of counters describing what a connector has done, exposed through the
+//! `getMetrics` RPC.
+//!
+//! The registry lives on [`crate::state::EngineState`] itself rather than anywhere global, so two
+//! engine instances in the same process never share counters.
+
+use std::{
+    collections::HashMap,
+    fmt::Write as _,
+    sync::atomic::{AtomicU64, Ordering},
+    sync::Mutex,
+    time::Duration,
+};
+
+/// Counters updated at the natural points of a describe+diff+apply cycle, such as the one
+/// [`crate::commands::schema_push`] runs.
+///
+/// `DatabaseSchema` and `Migration` are opaque, connector-specific types from the migration core's
+/// point of view (they're `Box<dyn Any>`), so counting e.g. how many tables were described isn't
+/// possible generically here. `describe_calls_total` is a proxy for that: it counts how many times
+/// a schema was described, rather than how many tables were in it.
+#[derive(Debug, Default)]
+pub struct Metrics {
+    describe_calls_total: AtomicU64,
+    describe_duration_ms_total: AtomicU64,
+    diff_steps_total: AtomicU64,
+    statements_applied_total: AtomicU64,
+    failures_by_error_code: Mutex<HashMap<String, u64>>,
+}
+
+impl Metrics {
+    /// Number of times a schema was described.
+    pub fn describe_calls_total(&self) -> u64 {
+        self.describe_calls_total.load(Ordering::Relaxed)
+    }
+
+    /// Number of steps produced by the differ, summed across every diff.
+    pub fn diff_steps_total(&self) -> u64 {
+        self.diff_steps_total.load(Ordering::Relaxed)
+    }
+
+    /// Number of migration statements applied to the database, summed across every apply.
+    pub fn statements_applied_total(&self) -> u64 {
+        self.statements_applied_total.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn record_describe(&self, duration: Duration) {
+        self.describe_calls_total.fetch_add(1, Ordering::Relaxed);
+        self.describe_duration_ms_total
+            .fetch_add(duration.as_millis() as u64, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_diff_steps(&self, steps: u64) {
+        self.diff_steps_total.fetch_add(steps, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_statements_applied(&self, count: u64) {
+        self.statements_applied_total.fetch_add(count, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_failure(&self, error_code: Option<&str>) {
+        let key = error_code.unwrap_or("unknown").to_owned();
+        *self.failures_by_error_code.lock().unwrap().entry(key).or_insert(0) += 1;
+    }
+
+    /// Render the current counters as a JSON object.
+    pub fn to_json(&self) -> serde_json::Value {
+        let failures: serde_json::Map<String, serde_json::Value> = self
+            .failures_by_error_code
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(code, count)| (code.clone(), serde_json::Value::from(*count)))
+            .collect();
+
+        serde_json::json!({
+            "describe_calls_total": self.describe_calls_total.load(Ordering::Relaxed),
+            "describe_duration_ms_total": self.describe_duration_ms_total.load(Ordering::Relaxed),
+            "diff_steps_total": self.diff_steps_total.load(Ordering::Relaxed),
+            "statements_applied_total": self.statements_applied_total.load(Ordering::Relaxed),
+            "failures_by_error_code": failures,
+        })
+    }
+
+    /// Render the current counters in Prometheus text exposition format.
+    pub fn to_prometheus_text(&self) -> String {
+        let mut out = String::new();
+
+        write_counter(
+            &mut out,
+            "prisma_migrate_describe_calls_total",
+            "Number of schema describe calls.",
+            self.describe_calls_total.load(Ordering::Relaxed),
+        );
+        write_counter(
+            &mut out,
+            "prisma_migrate_describe_duration_ms_total",
+            "Cumulative time spent describing schemas, in milliseconds.",
+            self.describe_duration_ms_total.load(Ordering::Relaxed),
+        );
+        write_counter(
+            &mut out,
+            "prisma_migrate_diff_steps_total",
+            "Number of migration steps produced by the differ.",
+            self.diff_steps_total.load(Ordering::Relaxed),
+        );
+        write_counter(
+            &mut out,
+            "prisma_migrate_statements_applied_total",
+            "Number of migration statements applied to the database.",
+            self.statements_applied_total.load(Ordering::Relaxed),
+        );
+
+        let failures = self.failures_by_error_code.lock().unwrap();
+        writeln!(out, "# HELP prisma_migrate_failures_total Number of connector failures, by user-facing error code.").ok();
+        writeln!(out, "# TYPE prisma_migrate_failures_total counter").ok();
+        for (code, count) in failures.iter() {
+            writeln!(out, "prisma_migrate_failures_total{{error_code=\"{}\"}} {}", code, count).ok();
+        }
+
+        out
+    }
+}
+
+fn write_counter(out: &mut String, name: &str, help: &str, value: u64) {
+    writeln!(out, "# HELP {} {}", name, help).ok();
+    writeln!(out, "# TYPE {} counter", name).ok();
+    writeln!(out, "{} {}", name, value).ok();
+}
@@ -2,10 +2,15 @@ use crate::{json_rpc::method_names::*, CoreError, CoreResult, GenericApi};
 use jsonrpc_core::{types::error::Error as JsonRpcError, IoHandler, Params};
 use std::sync::Arc;
 
-/// Initialize a JSON-RPC ready migration engine API.
-pub fn rpc_api(datamodel: Option<String>, host: Arc<dyn migration_connector::ConnectorHost>) -> IoHandler {
+/// Initialize a JSON-RPC ready migration engine API. Also returns a handle to the underlying
+/// [`GenericApi`], so callers can perform out-of-band operations such as releasing advisory
+/// locks during a graceful shutdown.
+pub fn rpc_api(
+    datamodel: Option<String>,
+    host: Arc<dyn migration_connector::ConnectorHost>,
+) -> (IoHandler, Arc<dyn GenericApi>) {
     let mut io_handler = IoHandler::default();
-    let api = Arc::new(crate::state::EngineState::new(datamodel, Some(host)));
+    let api: Arc<dyn GenericApi> = Arc::new(crate::state::EngineState::new(datamodel, Some(host)));
 
     for cmd in METHOD_NAMES {
         let api = api.clone();
@@ -14,7 +19,7 @@ pub fn rpc_api(datamodel: Option<String>, host: Arc<dyn migration_connector::Con
         });
     }
 
-    io_handler
+    (io_handler, api)
 }
 
 #[allow(clippy::redundant_allocation)]
@@ -32,15 +37,19 @@ async fn run_command(
         DEV_DIAGNOSTIC => render(executor.dev_diagnostic(params.parse()?).await),
         DIFF => render(executor.diff(params.parse()?).await),
         DEBUG_PANIC => render(executor.debug_panic().await),
+        DEBUG_SLEEP => render(executor.debug_sleep(params.parse()?).await),
         DIAGNOSE_MIGRATION_HISTORY => render(executor.diagnose_migration_history(params.parse()?).await),
         ENSURE_CONNECTION_VALIDITY => render(executor.ensure_connection_validity(params.parse()?).await),
         EVALUATE_DATA_LOSS => render(executor.evaluate_data_loss(params.parse()?).await),
         GET_DATABASE_VERSION => render(executor.version().await),
+        GET_METRICS => render(executor.get_metrics(params.parse()?).await),
         LIST_MIGRATION_DIRECTORIES => render(executor.list_migration_directories(params.parse()?).await),
         MARK_MIGRATION_APPLIED => render(executor.mark_migration_applied(params.parse()?).await),
         MARK_MIGRATION_ROLLED_BACK => render(executor.mark_migration_rolled_back(params.parse()?).await),
+        MIGRATION_STATUS => render(executor.migration_status(params.parse()?).await),
         RESET => render(executor.reset().await),
         SCHEMA_PUSH => render(executor.schema_push(params.parse()?).await),
+        SEED => render(executor.seed(params.parse()?).await),
         other => unreachable!("Unknown command {}", other),
     }
 }
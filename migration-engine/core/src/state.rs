@@ -3,9 +3,9 @@
 //! Why this rather than using connectors directly? We must be able to use the migration engine
 //! without a valid schema or database connection for commands like createDatabase and diff.
 
-use crate::{api::GenericApi, commands, json_rpc::types::*, CoreResult};
+use crate::{api::GenericApi, commands, json_rpc::types::*, metrics::Metrics, CoreResult};
 use enumflags2::BitFlags;
-use migration_connector::{ConnectorError, ConnectorHost, MigrationConnector};
+use migration_connector::{ConnectorError, ConnectorHost, DatabasePlan, MigrationConnector};
 use std::{collections::HashMap, future::Future, path::Path, pin::Pin, sync::Arc};
 use tokio::sync::{mpsc, Mutex};
 use tracing_futures::Instrument;
@@ -20,6 +20,7 @@ use tracing_futures::Instrument;
 pub(crate) struct EngineState {
     initial_datamodel: Option<String>,
     host: Arc<dyn ConnectorHost>,
+    metrics: Arc<Metrics>,
     // A map from either:
     //
     // - a connection string / url
@@ -47,6 +48,7 @@ impl EngineState {
         EngineState {
             initial_datamodel,
             host: host.unwrap_or_else(|| Arc::new(migration_connector::EmptyHost)),
+            metrics: Arc::new(Metrics::default()),
             connectors: Default::default(),
         }
     }
@@ -171,6 +173,30 @@ impl EngineState {
 
         self.with_connector_for_schema(schema, None, f).await
     }
+
+    /// Release the advisory locks (if any) held by every connector that is currently connected.
+    /// This is best-effort: errors releasing a lock on one connector do not prevent releasing the
+    /// others. Used when the engine is shutting down, so no connection is left holding a lock
+    /// after the process exits.
+    pub(crate) async fn release_all_locks(&self) {
+        let senders: Vec<_> = self.connectors.lock().await.values().cloned().collect();
+
+        for sender in senders {
+            let (response_sender, response_receiver) = tokio::sync::oneshot::channel::<()>();
+            let erased: ErasedConnectorRequest = Box::new(move |connector| {
+                Box::pin(async move {
+                    if let Err(err) = connector.release_lock().await {
+                        tracing::warn!(error = %err, "Failed to release advisory lock during shutdown.");
+                    }
+                    let _ = response_sender.send(());
+                })
+            });
+
+            if sender.send(erased).await.is_ok() {
+                let _ = response_receiver.await;
+            }
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -200,6 +226,14 @@ impl GenericApi for EngineState {
         .await
     }
 
+    async fn create_database_plan(&self, params: CreateDatabaseParams) -> CoreResult<DatabasePlan> {
+        self.with_connector_from_datasource_param(
+            &params.datasource,
+            Box::new(|connector| Box::pin(async move { Ok(MigrationConnector::create_database_plan(connector)?) })),
+        )
+        .await
+    }
+
     async fn create_migration(&self, input: CreateMigrationInput) -> CoreResult<CreateMigrationOutput> {
         self.with_default_connector(Box::new(move |connector| {
             let span = tracing::info_span!(
@@ -245,6 +279,11 @@ impl GenericApi for EngineState {
         panic!("This is the debugPanic artificial panic")
     }
 
+    async fn debug_sleep(&self, input: DebugSleepInput) -> CoreResult<()> {
+        tokio::time::sleep(std::time::Duration::from_millis(input.duration_ms.into())).await;
+        Ok(())
+    }
+
     async fn dev_diagnostic(&self, input: DevDiagnosticInput) -> CoreResult<DevDiagnosticOutput> {
         self.with_default_connector(Box::new(|connector| {
             Box::pin(commands::dev_diagnostic(input, connector).instrument(tracing::info_span!("DevDiagnostic")))
@@ -261,6 +300,14 @@ impl GenericApi for EngineState {
             .await
     }
 
+    async fn drop_database_plan(&self, url: String) -> CoreResult<DatabasePlan> {
+        self.with_connector_for_url(
+            url,
+            Box::new(|connector| Box::pin(async move { Ok(MigrationConnector::drop_database_plan(connector)?) })),
+        )
+        .await
+    }
+
     async fn diagnose_migration_history(
         &self,
         input: commands::DiagnoseMigrationHistoryInput,
@@ -297,6 +344,15 @@ impl GenericApi for EngineState {
         .await
     }
 
+    async fn get_metrics(&self, params: GetMetricsParams) -> CoreResult<GetMetricsResult> {
+        let content = match params.format.as_deref() {
+            Some("prometheus") => self.metrics.to_prometheus_text(),
+            _ => self.metrics.to_json().to_string(),
+        };
+
+        Ok(GetMetricsResult { content })
+    }
+
     async fn list_migration_directories(
         &self,
         input: ListMigrationDirectoriesInput,
@@ -334,6 +390,16 @@ impl GenericApi for EngineState {
         .await
     }
 
+    async fn migration_status(
+        &self,
+        input: commands::MigrationStatusInput,
+    ) -> CoreResult<commands::MigrationStatusOutput> {
+        self.with_default_connector(Box::new(|connector| {
+            Box::pin(commands::migration_status(input, connector).instrument(tracing::info_span!("MigrationStatus")))
+        }))
+        .await
+    }
+
     async fn reset(&self) -> CoreResult<()> {
         tracing::debug!("Resetting the database.");
 
@@ -344,10 +410,50 @@ impl GenericApi for EngineState {
         Ok(())
     }
 
+    async fn release_all_locks(&self) {
+        EngineState::release_all_locks(self).await
+    }
+
     async fn schema_push(&self, input: SchemaPushInput) -> CoreResult<SchemaPushOutput> {
+        let metrics = self.metrics.clone();
         self.with_default_connector(Box::new(move |connector| {
-            Box::pin(commands::schema_push(input, connector).instrument(tracing::info_span!("SchemaPush")))
+            Box::pin(commands::schema_push(input, connector, metrics).instrument(tracing::info_span!("SchemaPush")))
         }))
         .await
     }
+
+    async fn seed(&self, params: SeedParams) -> CoreResult<SeedResult> {
+        use std::io::Read;
+
+        let url: String = match &params.datasource_type {
+            DbExecuteDatasourceType::Url(UrlContainer { url }) => url.clone(),
+            DbExecuteDatasourceType::Schema(SchemaContainer { schema: file_path }) => {
+                let mut schema_file = std::fs::File::open(&file_path)
+                    .map_err(|err| ConnectorError::from_source(err, "Opening Prisma schema file."))?;
+                let mut schema_string = String::new();
+                schema_file
+                    .read_to_string(&mut schema_string)
+                    .map_err(|err| ConnectorError::from_source(err, "Reading Prisma schema file."))?;
+                let (datasource, url, _, _) = crate::parse_configuration(&schema_string)?;
+                std::path::Path::new(file_path)
+                    .parent()
+                    .map(|config_dir| {
+                        datasource
+                            .active_connector
+                            .set_config_dir(config_dir, &url)
+                            .into_owned()
+                    })
+                    .unwrap_or(url)
+            }
+        };
+
+        let skipped = self
+            .with_connector_for_url(
+                url,
+                Box::new(move |connector| connector.seed(params.name, params.script, params.force)),
+            )
+            .await?;
+
+        Ok(SeedResult { skipped })
+    }
 }
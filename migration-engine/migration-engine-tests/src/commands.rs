@@ -6,6 +6,7 @@ mod evaluate_data_loss;
 mod list_migration_directories;
 mod mark_migration_applied;
 mod mark_migration_rolled_back;
+mod migration_status;
 mod reset;
 mod schema_push;
 
@@ -17,5 +18,6 @@ pub(crate) use evaluate_data_loss::*;
 pub(crate) use list_migration_directories::*;
 pub(crate) use mark_migration_applied::*;
 pub(crate) use mark_migration_rolled_back::*;
+pub(crate) use migration_status::*;
 pub(crate) use reset::*;
 pub(crate) use schema_push::*;
@@ -73,6 +73,21 @@ impl<'a> ApplyMigrationsAssertion<'a> {
         self
     }
 
+    /// Assert the number of steps recorded in the structured migration summary for the
+    /// migration at `idx` (in application order).
+    #[track_caller]
+    pub fn assert_migration_steps_count(self, idx: usize, count: u32) -> Self {
+        let migration = &self.output.migrations[idx];
+
+        assert_eq!(
+            migration.steps_count, count,
+            "Assertion failed. Expected {} steps for migration `{}`, found {}.",
+            count, migration.name, migration.steps_count
+        );
+
+        self
+    }
+
     pub fn into_output(self) -> ApplyMigrationsOutput {
         self.output
     }
@@ -0,0 +1,67 @@
+use migration_core::{
+    commands::{migration_status, MigrationStatusInput, MigrationStatusOutput},
+    migration_connector::MigrationConnector,
+    CoreResult,
+};
+use tempfile::TempDir;
+
+#[must_use = "This struct does nothing on its own. See MigrationStatus::send()"]
+pub struct MigrationStatus<'a> {
+    api: &'a mut dyn MigrationConnector,
+    migrations_directory: &'a TempDir,
+    check_drift: bool,
+}
+
+impl<'a> MigrationStatus<'a> {
+    pub fn new(api: &'a mut dyn MigrationConnector, migrations_directory: &'a TempDir) -> Self {
+        MigrationStatus {
+            api,
+            migrations_directory,
+            check_drift: false,
+        }
+    }
+
+    pub fn check_drift(mut self, check_drift: bool) -> Self {
+        self.check_drift = check_drift;
+
+        self
+    }
+
+    pub async fn send(self) -> CoreResult<MigrationStatusAssertions<'a>> {
+        let output = migration_status(
+            MigrationStatusInput {
+                migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
+                check_drift: self.check_drift,
+            },
+            self.api,
+        )
+        .await?;
+
+        Ok(MigrationStatusAssertions {
+            output,
+            _migrations_directory: self.migrations_directory,
+        })
+    }
+
+    #[track_caller]
+    pub fn send_sync(self) -> MigrationStatusAssertions<'a> {
+        test_setup::runtime::run_with_thread_local_runtime(self.send()).unwrap()
+    }
+}
+
+pub struct MigrationStatusAssertions<'a> {
+    output: MigrationStatusOutput,
+    _migrations_directory: &'a TempDir,
+}
+
+impl std::fmt::Debug for MigrationStatusAssertions<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MigrationStatusAssertions {{ .. }}")
+    }
+}
+
+impl<'a> MigrationStatusAssertions<'a> {
+    pub fn into_output(self) -> MigrationStatusOutput {
+        self.output
+    }
+}
@@ -1,13 +1,16 @@
 use migration_core::{
-    commands::schema_push, json_rpc::types::*, migration_connector::MigrationConnector, CoreError, CoreResult,
+    commands::schema_push, json_rpc::types::*, metrics::Metrics, migration_connector::MigrationConnector, CoreError,
+    CoreResult,
 };
-use std::{borrow::Cow, fmt::Debug};
+use std::{borrow::Cow, fmt::Debug, sync::Arc};
 use tracing_futures::Instrument;
 
 pub struct SchemaPush<'a> {
     api: &'a mut dyn MigrationConnector,
     schema: String,
     force: bool,
+    models: Option<Vec<String>>,
+    metrics: Arc<Metrics>,
     /// Purely for logging diagnostics.
     migration_id: Option<&'a str>,
 }
@@ -18,6 +21,8 @@ impl<'a> SchemaPush<'a> {
             api,
             schema,
             force: false,
+            models: None,
+            metrics: Arc::new(Metrics::default()),
             migration_id: None,
         }
     }
@@ -27,6 +32,18 @@ impl<'a> SchemaPush<'a> {
         self
     }
 
+    /// Restrict the push to the tables backing these models (see `SchemaPushInput::models`).
+    pub fn models(mut self, models: &[&str]) -> Self {
+        self.models = Some(models.iter().map(|s| s.to_string()).collect());
+        self
+    }
+
+    /// Supply a metrics registry to inspect after `send()`, instead of the default throwaway one.
+    pub fn metrics(mut self, metrics: Arc<Metrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
     pub fn migration_id(mut self, migration_id: Option<&'a str>) -> Self {
         self.migration_id = migration_id;
         self
@@ -36,9 +53,10 @@ impl<'a> SchemaPush<'a> {
         let input = SchemaPushInput {
             schema: self.schema,
             force: self.force,
+            models: self.models,
         };
 
-        let fut = schema_push(input, self.api)
+        let fut = schema_push(input, self.api, self.metrics)
             .instrument(tracing::info_span!("SchemaPush", migration_id = ?self.migration_id));
 
         let output = test_setup::runtime::run_with_thread_local_runtime(fut)?;
@@ -123,6 +141,16 @@ impl SchemaPushAssertion {
         self
     }
 
+    #[track_caller]
+    pub fn assert_executed_steps_count(self, expected: u32) -> Self {
+        assert_eq!(
+            self.result.executed_steps, expected,
+            "Assertion failed. Expected {} executed steps, found {}.",
+            expected, self.result.executed_steps,
+        );
+        self
+    }
+
     #[track_caller]
     pub fn assert_executable(self) -> Self {
         assert!(
@@ -1,7 +1,8 @@
 pub use crate::assertions::{MigrationsAssertions, ResultSetExt, SchemaAssertion};
 pub use expect_test::expect;
 pub use migration_core::json_rpc::types::{
-    DbExecuteDatasourceType, DbExecuteParams, DiffParams, DiffResult, SchemaContainer, UrlContainer,
+    DbExecuteDatasourceType, DbExecuteParams, DiffParams, DiffResult, GetMetricsParams, GetMetricsResult,
+    SchemaContainer, SeedParams, SeedResult, UrlContainer,
 };
 pub use test_macros::test_connector;
 pub use test_setup::{runtime::run_with_thread_local_runtime as tok, BitFlags, Capabilities, Tags};
@@ -27,9 +28,21 @@ use std::{
 use tempfile::TempDir;
 use test_setup::{DatasourceBlock, TestApiArgs};
 
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct TestConnectorHost {
     pub printed_messages: std::sync::Mutex<Vec<String>>,
+    /// What `confirm()` should answer. Defaults to `true`, matching the default trait
+    /// implementation, so tests that do not care about confirmation behavior are unaffected.
+    pub confirm_result: std::sync::atomic::AtomicBool,
+}
+
+impl Default for TestConnectorHost {
+    fn default() -> Self {
+        TestConnectorHost {
+            printed_messages: Default::default(),
+            confirm_result: std::sync::atomic::AtomicBool::new(true),
+        }
+    }
 }
 
 impl ConnectorHost for TestConnectorHost {
@@ -39,6 +52,11 @@ impl ConnectorHost for TestConnectorHost {
         self.printed_messages.lock().unwrap().push(message.to_owned());
         Box::pin(std::future::ready(Ok(())))
     }
+
+    fn confirm<'a>(&'a self, _prompt: &'a str) -> BoxFuture<'a, ConnectorResult<bool>> {
+        let result = self.confirm_result.load(std::sync::atomic::Ordering::SeqCst);
+        Box::pin(std::future::ready(Ok(result)))
+    }
 }
 
 pub struct TestApi {
@@ -216,6 +234,10 @@ impl TestApi {
         &mut self.connector
     }
 
+    pub fn migration_status<'a>(&'a mut self, migrations_directory: &'a TempDir) -> MigrationStatus<'a> {
+        MigrationStatus::new(&mut self.connector, migrations_directory)
+    }
+
     /// Assert facts about the database schema
     #[track_caller]
     pub fn assert_schema(&mut self) -> SchemaAssertion {
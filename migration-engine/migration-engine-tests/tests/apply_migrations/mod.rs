@@ -63,7 +63,9 @@ fn applying_two_migrations_works(api: TestApi) {
 
     api.apply_migrations(&migrations_directory)
         .send_sync()
-        .assert_applied_migrations(&["initial", "second-migration"]);
+        .assert_applied_migrations(&["initial", "second-migration"])
+        .assert_migration_steps_count(0, 1)
+        .assert_migration_steps_count(1, 1);
 
     api.apply_migrations(&migrations_directory)
         .send_sync()
@@ -1,8 +1,50 @@
 use migration_core::migration_api;
-use migration_engine_tests::{multi_engine_test_api::*, test_api::SchemaContainer};
+use migration_engine_tests::{
+    multi_engine_test_api::*,
+    test_api::{SchemaContainer, TestApi as SingleEngineTestApi},
+};
 use test_macros::test_connector;
 use url::Url;
 
+#[test_connector(tags(Sqlite))]
+fn a_pre_existing_migrations_table_missing_newer_columns_gets_healed(mut api: SingleEngineTestApi) {
+    // Simulate a `_prisma_migrations` table created by an old version of the engine, missing the
+    // `applied_steps_count` and `logs` columns that later versions rely on.
+    api.raw_cmd(
+        r#"
+        CREATE TABLE "_prisma_migrations" (
+            "id"                TEXT PRIMARY KEY NOT NULL,
+            "checksum"          TEXT NOT NULL,
+            "finished_at"       DATETIME,
+            "migration_name"    TEXT NOT NULL,
+            "rolled_back_at"    DATETIME,
+            "started_at"        DATETIME NOT NULL DEFAULT current_timestamp
+        );
+        "#,
+    );
+
+    api.raw_cmd(
+        r#"
+        INSERT INTO "_prisma_migrations" ("id", "checksum", "finished_at", "migration_name")
+        VALUES ('deadbeef-dead-beef-dead-beefdeadbeef', 'checksum', current_timestamp, 'init');
+        "#,
+    );
+
+    let dm = api.datamodel_with_provider(
+        r#"
+        model Cat {
+            id Int @id
+        }
+    "#,
+    );
+
+    let dir = api.create_migrations_directory();
+    api.create_migration("init", &dm, &dir).send_sync();
+
+    // The old row must survive the healing, and the deploy must succeed afterwards.
+    api.apply_migrations(&dir).send_sync().assert_applied_migrations(&["init"]);
+}
+
 #[test_connector(tags(Postgres))]
 fn connecting_to_a_postgres_database_with_missing_schema_creates_it(api: TestApi) {
     // Check that the "unexpected" schema does not exist.
@@ -83,3 +125,66 @@ fn connecting_to_a_postgres_database_with_missing_schema_creates_it(api: TestApi
         assert!(schema_exists)
     }
 }
+
+#[test_connector(tags(Postgres))]
+fn a_full_dev_cycle_works_against_a_non_default_schema_that_does_not_exist_yet(api: TestApi) {
+    let mut url: Url = api.connection_string().parse().unwrap();
+
+    let mut new_qs = String::with_capacity(url.query().map(|q| q.len()).unwrap_or(16));
+
+    for (k, v) in url.query_pairs() {
+        if k == "schema" {
+            new_qs.push_str("schema=brandnew&");
+        } else {
+            new_qs.push_str(&k);
+            new_qs.push('=');
+            new_qs.push_str(&v);
+            new_qs.push('&');
+        }
+    }
+
+    url.set_query(Some(new_qs.trim_end_matches('&')));
+
+    let dm = format!(
+        r#"
+        datasource db {{
+            provider = "postgresql"
+            url = "{}"
+        }}
+
+        model Cat {{
+            id Int @id
+        }}
+        "#,
+        url
+    );
+
+    let mut engine = api.new_engine_with_connection_strings(url.to_string(), None);
+    let dir = api.create_migrations_directory();
+
+    // Neither `create_migration` (which needs a shadow database) nor `apply_migrations` (which
+    // creates the `_prisma_migrations` table) should choke on the `brandnew` schema not existing
+    // yet: both go through connection paths that create it on demand.
+    engine.create_migration("init", &dm, &dir).send_sync();
+    engine
+        .apply_migrations(&dir)
+        .send_sync()
+        .assert_applied_migrations(&["init"]);
+
+    let schema_exists_result = api
+        .query_raw(
+            "SELECT EXISTS(SELECT 1 FROM pg_namespace WHERE nspname = 'brandnew')",
+            &[],
+        )
+        .unwrap();
+
+    let schema_exists = schema_exists_result
+        .into_single()
+        .unwrap()
+        .at(0)
+        .unwrap()
+        .as_bool()
+        .unwrap();
+
+    assert!(schema_exists);
+}
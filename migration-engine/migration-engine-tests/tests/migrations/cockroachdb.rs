@@ -30,6 +30,7 @@ fn db_push_on_cockroach_db_with_postgres_provider_works(api: TestApi) {
     let output = tok(connector.schema_push(migration_core::json_rpc::types::SchemaPushInput {
         force: false,
         schema: schema.clone(),
+        models: None,
     }))
     .unwrap();
 
@@ -38,7 +39,12 @@ fn db_push_on_cockroach_db_with_postgres_provider_works(api: TestApi) {
     assert!(output.executed_steps > 0);
 
     let output =
-        tok(connector.schema_push(migration_core::json_rpc::types::SchemaPushInput { force: false, schema })).unwrap();
+        tok(connector.schema_push(migration_core::json_rpc::types::SchemaPushInput {
+            force: false,
+            schema,
+            models: None,
+        }))
+        .unwrap();
 
     assert!(output.warnings.is_empty());
     assert!(output.unexecutable.is_empty());
@@ -1109,6 +1115,80 @@ fn alter_sequence(api: TestApi) {
     api.schema_push(schema2).send().assert_green().assert_no_steps();
 }
 
+#[test_connector(tags(CockroachDb))]
+fn alter_sequence_cache_only(api: TestApi) {
+    let schema1 = r#"
+        datasource db {
+            provider = "cockroachdb"
+            url = env("TEST_DATABASE_URL")
+        }
+
+        model Test {
+            Id Int @id @default(sequence(minValue: 10, maxValue: 39, cache: 4, increment: 3, start: 12))
+        }
+    "#;
+
+    let schema2 = r#"
+        datasource db {
+            provider = "cockroachdb"
+            url = env("TEST_DATABASE_URL")
+        }
+
+        model Test {
+            Id Int @id @default(sequence(minValue: 10, maxValue: 39, cache: 20, increment: 3, start: 12))
+        }
+    "#;
+
+    api.schema_push(schema1)
+        .send()
+        .assert_green()
+        .assert_has_executed_steps();
+    api.schema_push(schema1).send().assert_green().assert_no_steps();
+
+    api.schema_push(schema2)
+        .send()
+        .assert_green()
+        .assert_has_executed_steps();
+    api.schema_push(schema2).send().assert_green().assert_no_steps();
+}
+
+#[test_connector(tags(CockroachDb))]
+fn alter_sequence_increment_only(api: TestApi) {
+    let schema1 = r#"
+        datasource db {
+            provider = "cockroachdb"
+            url = env("TEST_DATABASE_URL")
+        }
+
+        model Test {
+            Id Int @id @default(sequence(minValue: 10, maxValue: 39, cache: 4, increment: 3, start: 12))
+        }
+    "#;
+
+    let schema2 = r#"
+        datasource db {
+            provider = "cockroachdb"
+            url = env("TEST_DATABASE_URL")
+        }
+
+        model Test {
+            Id Int @id @default(sequence(minValue: 10, maxValue: 39, cache: 4, increment: 7, start: 12))
+        }
+    "#;
+
+    api.schema_push(schema1)
+        .send()
+        .assert_green()
+        .assert_has_executed_steps();
+    api.schema_push(schema1).send().assert_green().assert_no_steps();
+
+    api.schema_push(schema2)
+        .send()
+        .assert_green()
+        .assert_has_executed_steps();
+    api.schema_push(schema2).send().assert_green().assert_no_steps();
+}
+
 // https://github.com/prisma/prisma/issues/13842
 #[test_connector(tags(CockroachDb))]
 fn mapped_enum_defaults_must_work(api: TestApi) {
@@ -1230,3 +1310,37 @@ fn sequence_with_multiple_models_works(api: TestApi) {
     api.schema_push(schema).send().assert_green();
     api.schema_push(schema).send().assert_green().assert_no_steps();
 }
+
+// Scalar list defaults, including empty arrays, strings with quotes and commas, and enum
+// arrays, must round-trip through describe -> diff with zero drift, the same way they already do
+// on Postgres (see `scalar_list_defaults_work` in `tests/migrations/postgres.rs`).
+#[test_connector(tags(CockroachDb))]
+fn scalar_list_defaults_round_trip_without_drift(api: TestApi) {
+    let schema = r#"
+        datasource db {
+          provider = "cockroachdb"
+          url = env("DATABASE_URL")
+        }
+
+        enum Color {
+            RED
+            GREEN
+            BLUE
+        }
+
+        model Model {
+            id Int @id
+            int_empty Int[] @default([])
+            int Int[] @default([0, 1, 1, 2, 3, 5, 8, 13, 21])
+            string String[] @default(["it's a \"quote\"", "a, comma", "plain"])
+            colors Color[] @default([GREEN, BLUE])
+            colors_empty Color[] @default([])
+        }
+    "#;
+
+    api.schema_push(schema)
+        .send()
+        .assert_green()
+        .assert_has_executed_steps();
+    api.schema_push(schema).send().assert_green().assert_no_steps();
+}
@@ -187,6 +187,21 @@ fn default_dbgenerated_should_work(api: TestApi) {
     });
 }
 
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn dbgenerated_timezone_expression_produces_zero_drift(api: TestApi) {
+    // The database echoes this expression back with different casing/casts (e.g. wrapping the
+    // string literal in `::text`), which should not be treated as drift on the next diff.
+    let dm = r#"
+        model A {
+            id DateTime @id @default(dbgenerated("now() at time zone 'utc'"))
+        }
+    "#;
+
+    api.schema_push_w_datasource(dm).send().assert_green();
+
+    api.schema_push_w_datasource(dm).send().assert_green().assert_no_steps();
+}
+
 #[test_connector(tags(CockroachDb))]
 fn default_dbgenerated_should_work_cockroach(api: TestApi) {
     let dm = r#"
@@ -521,3 +536,39 @@ fn escaped_string_defaults_are_not_arbitrarily_migrated(api: TestApi) {
         );
     }
 }
+
+#[test_connector(exclude(Vitess))]
+fn explicit_default_null_does_not_diff_against_no_default(api: TestApi) {
+    api.raw_cmd("CREATE TABLE a (id INTEGER PRIMARY KEY, with_default INTEGER DEFAULT NULL, without_default INTEGER)");
+
+    let dm = r#"
+        model A {
+            id             Int  @id
+            withDefault    Int? @map("with_default")
+            withoutDefault Int? @map("without_default")
+
+            @@map("a")
+        }
+    "#;
+
+    api.schema_push_w_datasource(dm).send().assert_green().assert_no_steps();
+}
+
+#[test_connector(tags(Mysql), exclude(Vitess))]
+fn mysql_does_not_synthesize_defaults_for_text_and_blob_columns(api: TestApi) {
+    // TEXT/BLOB columns cannot carry a `DEFAULT` on MySQL/MariaDB at all, so the describer
+    // must never synthesize one for them even if `information_schema` reports something.
+    api.raw_cmd("CREATE TABLE a (id INTEGER PRIMARY KEY, notes TEXT, payload BLOB)");
+
+    let dm = r#"
+        model A {
+            id      Int     @id
+            notes   String? @db.Text
+            payload Bytes?  @db.Blob
+
+            @@map("a")
+        }
+    "#;
+
+    api.schema_push_w_datasource(dm).send().assert_green().assert_no_steps();
+}
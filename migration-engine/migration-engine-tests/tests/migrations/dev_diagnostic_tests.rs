@@ -526,6 +526,72 @@ fn with_an_invalid_unapplied_migration_should_report_it(api: TestApi) {
     assert!(err.message.starts_with(&expected_msg));
 }
 
+// The error surfaced when a shadow database replay fails should point at which migration in the
+// history broke (N of M), a hint at the failing statement, and make clear only the shadow database
+// was touched.
+#[test_connector(tags(Postgres, Mssql))]
+fn an_invalid_unapplied_migration_reports_its_position_and_statement_excerpt(api: TestApi) {
+    let directory = api.create_migrations_directory();
+
+    let dm1 = api.datamodel_with_provider(
+        r#"
+        model catcat {
+            id      Int @id
+            name    String
+        }
+    "#,
+    );
+
+    api.create_migration("initial", &dm1, &directory).send_sync();
+
+    api.apply_migrations(&directory)
+        .send_sync()
+        .assert_applied_migrations(&["initial"]);
+
+    let dm2 = api.datamodel_with_provider(
+        r#"
+        model catcat {
+            id          Int @id
+            name        String
+            fluffiness  Float
+        }
+    "#,
+    );
+
+    let CreateMigrationOutput {
+        generated_migration_name,
+    } = api
+        .create_migration("second-migration", &dm2, &directory)
+        .send_sync()
+        .modify_migration(|script| {
+            *script = "CREATE BROKEN".into();
+        })
+        .into_output();
+
+    let err = api
+        .dev_diagnostic(&directory)
+        .send_unwrap_err()
+        .to_user_facing()
+        .unwrap_known();
+
+    assert_eq!(err.error_code, MigrationDoesNotApplyCleanly::ERROR_CODE);
+    assert!(err.message.contains(&format!(
+        "Migration `{}` failed to apply cleanly to the shadow database.",
+        generated_migration_name.unwrap()
+    )));
+    assert!(
+        err.message.contains("This is migration 2 of 2 in your migration history."),
+        "{}",
+        err.message
+    );
+    assert!(err.message.contains("CREATE BROKEN"), "{}", err.message);
+    assert!(
+        err.message.contains("Your main database has not been modified."),
+        "{}",
+        err.message
+    );
+}
+
 #[test_connector(tags(Postgres))]
 fn drift_can_be_detected_without_migrations_table_dev(api: TestApi) {
     let directory = api.create_migrations_directory();
@@ -133,6 +133,33 @@ fn one_to_many_self_relations_do_not_create_a_unique_index(api: TestApi) {
     }
 }
 
+#[test_connector(tags(Postgres, Mysql))]
+fn a_model_with_two_named_self_relations_migrates_cleanly(api: TestApi) {
+    let dm = r#"
+        model Employee {
+            id        Int        @id @default(autoincrement())
+            managerId Int?
+            mentorId  Int?
+            manager   Employee?  @relation("EmployeeManager", fields: [managerId], references: [id])
+            reports   Employee[] @relation("EmployeeManager")
+            mentor    Employee?  @relation("EmployeeMentor", fields: [mentorId], references: [id])
+            mentees   Employee[] @relation("EmployeeMentor")
+        }
+    "#;
+
+    api.schema_push_w_datasource(dm).send().assert_green();
+
+    api.assert_schema().assert_table("Employee", |table| {
+        table
+            .assert_foreign_key_on_columns(&["managerId"], |fk| fk.assert_references("Employee", &["id"]))
+            .assert_foreign_key_on_columns(&["mentorId"], |fk| fk.assert_references("Employee", &["id"]))
+    });
+
+    // Pushing the same schema again should be a no-op: the generated constraint and index
+    // names must be stable across runs.
+    api.schema_push_w_datasource(dm).send().assert_green().assert_no_steps();
+}
+
 #[test_connector(preview_features("referentialIntegrity"))]
 fn model_with_multiple_indexes_works(api: TestApi) {
     let dm = r#"
@@ -249,6 +249,33 @@ fn gin_raw_ops(api: TestApi) {
     api.schema_push_w_datasource(dm).send().assert_no_steps();
 }
 
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn gin_trgm_ops(api: TestApi) {
+    let dm = r#"
+        model A {
+          id   Int     @id @default(autoincrement())
+          name String
+
+          @@index([name(ops: raw("gin_trgm_ops"))], type: Gin)
+        }
+    "#;
+
+    api.schema_push_w_datasource(dm).send().assert_green();
+
+    api.assert_schema().assert_table("A", |table| {
+        table
+            .assert_has_column("name")
+            .assert_index_on_columns(&["name"], |idx| {
+                idx.assert_algorithm(SqlIndexAlgorithm::Gin)
+                    .assert_column("name", |attrs| {
+                        attrs.assert_ops(SQLOperatorClassKind::GinTrgmOps)
+                    })
+            })
+    });
+
+    api.schema_push_w_datasource(dm).send().assert_no_steps();
+}
+
 #[test_connector(tags(Postgres), exclude(CockroachDb))]
 fn gin_raw_ops_default(api: TestApi) {
     let dm = r#"
@@ -275,3 +302,51 @@ fn gin_raw_ops_default(api: TestApi) {
 
     api.schema_push_w_datasource(dm).send().assert_no_steps();
 }
+
+// Round-trip check for an index that was created directly through SQL (as it would be by a
+// pre-existing database that gets introspected), rather than pushed from a Prisma schema first:
+// the differ must recognize the existing opclass and produce no steps when the equivalent
+// schema is pushed.
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn sql_created_gin_index_with_ops_is_a_migration_no_op(mut api: TestApi) {
+    api.raw_cmd("CREATE TABLE \"A\" (id SERIAL PRIMARY KEY, data JSONB NOT NULL)");
+    api.raw_cmd("CREATE INDEX \"A_data_idx\" ON \"A\" USING GIN (data jsonb_path_ops)");
+
+    let dm = r#"
+        model A {
+          id   Int  @id @default(autoincrement())
+          data Json
+
+          @@index([data(ops: JsonbPathOps)], type: Gin, map: "A_data_idx")
+        }
+    "#;
+
+    api.schema_push_w_datasource(dm).send().assert_green().assert_no_steps();
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb, Postgres9))]
+fn compound_index_with_one_default_opclass(api: TestApi) {
+    let dm = r#"
+        model A {
+          id   Int      @id @default(autoincrement())
+          data Json
+          sata String[]
+
+          @@index([data(ops: JsonbPathOps), sata], type: Gin)
+        }
+    "#;
+
+    api.schema_push_w_datasource(dm).send().assert_green();
+
+    api.assert_schema().assert_table("A", |table| {
+        table
+            .assert_has_column("data")
+            .assert_index_on_columns(&["data", "sata"], |idx| {
+                idx.assert_algorithm(SqlIndexAlgorithm::Gin)
+                    .assert_column("data", |attrs| attrs.assert_ops(SQLOperatorClassKind::JsonbPathOps))
+                    .assert_column("sata", |attrs| attrs.assert_ops(SQLOperatorClassKind::ArrayOps))
+            })
+    });
+
+    api.schema_push_w_datasource(dm).send().assert_no_steps();
+}
@@ -13,11 +13,8 @@ impl TestApi {
     fn new(_args: TestApiArgs) -> Self {
         let host = Arc::new(migration_core::migration_connector::EmptyHost);
         let rt = tokio::runtime::Runtime::new().unwrap();
-        TestApi {
-            _args,
-            api: migration_core::rpc_api(None, host),
-            rt,
-        }
+        let (api, _generic_api) = migration_core::rpc_api(None, host);
+        TestApi { _args, api, rt }
     }
 
     fn send_request(&mut self, request: &str) -> Option<String> {
@@ -56,3 +53,14 @@ fn test_create_database(mut api: TestApi) {
     let response = api.send_request(&request).unwrap();
     assert!(response.starts_with(r#"{"jsonrpc":"2.0","result""#)); // success
 }
+
+#[test_connector(tags(Sqlite))]
+fn test_debug_sleep(mut api: TestApi) {
+    let request = r#"{"jsonrpc":"2.0","id":1,"method":"debugSleep","params":{"durationMs":1}}"#;
+
+    let response = api.send_request(request).unwrap();
+
+    let expected = expect![[r#"{"jsonrpc":"2.0","result":{},"id":1}"#]];
+
+    expected.assert_eq(&response);
+}
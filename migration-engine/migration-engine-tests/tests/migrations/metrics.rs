@@ -0,0 +1,60 @@
+use migration_engine_tests::test_api::*;
+
+#[test]
+fn schema_push_updates_the_metrics_registry() {
+    let tmpdir = tempfile::TempDir::new().unwrap();
+    let url = format!("file:{}/db.sqlite", tmpdir.path().to_string_lossy());
+    let schema = format!(
+        r#"
+        datasource db {{
+            provider = "sqlite"
+            url = "{}"
+        }}
+
+        model Cat {{
+            id   Int    @id
+            name String
+        }}
+    "#,
+        url
+    );
+
+    let generic_api = migration_core::migration_api(Some(schema.clone()), None).unwrap();
+
+    let before: serde_json::Value = serde_json::from_str(
+        &tok(generic_api.get_metrics(GetMetricsParams { format: None }))
+            .unwrap()
+            .content,
+    )
+    .unwrap();
+    assert_eq!(before["describe_calls_total"], 0);
+    assert_eq!(before["diff_steps_total"], 0);
+    assert_eq!(before["statements_applied_total"], 0);
+
+    let push_result = tok(generic_api.schema_push(migration_core::json_rpc::types::SchemaPushInput {
+        schema,
+        force: false,
+        models: None,
+    }))
+    .unwrap();
+    assert!(push_result.executed_steps > 0);
+
+    let after: serde_json::Value = serde_json::from_str(
+        &tok(generic_api.get_metrics(GetMetricsParams { format: None }))
+            .unwrap()
+            .content,
+    )
+    .unwrap();
+    // One describe of the live (empty) database, one of the target datamodel.
+    assert_eq!(after["describe_calls_total"], 2);
+    assert!(after["diff_steps_total"].as_u64().unwrap() > 0);
+    assert_eq!(after["statements_applied_total"], serde_json::json!(push_result.executed_steps));
+
+    let prometheus = tok(generic_api.get_metrics(GetMetricsParams {
+        format: Some("prometheus".to_owned()),
+    }))
+    .unwrap()
+    .content;
+    assert!(prometheus.contains("# TYPE prisma_migrate_diff_steps_total counter"));
+    assert!(prometheus.contains("# TYPE prisma_migrate_statements_applied_total counter"));
+}
@@ -0,0 +1,105 @@
+use migration_core::commands::DriftStatus;
+use migration_engine_tests::test_api::*;
+
+#[test_connector(tags(Postgres))]
+fn migration_status_reports_unapplied_orphaned_and_drift(mut api: TestApi) {
+    let directory = api.create_migrations_directory();
+
+    let dm1 = api.datamodel_with_provider(
+        r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#,
+    );
+
+    api.create_migration("initial", &dm1, &directory).send_sync();
+
+    let dm2 = api.datamodel_with_provider(
+        r#"
+        model Cat {
+            id          Int @id
+            name        String
+            fluffiness  Float
+        }
+    "#,
+    );
+
+    let orphaned_name = api
+        .create_migration("second-migration", &dm2, &directory)
+        .send_sync()
+        .into_output()
+        .generated_migration_name
+        .unwrap();
+
+    api.apply_migrations(&directory)
+        .send_sync()
+        .assert_applied_migrations(&["initial", "second-migration"]);
+
+    // Delete the directory entry for an already-applied migration: it becomes orphaned.
+    std::fs::remove_dir_all(directory.path().join(&orphaned_name)).unwrap();
+
+    // Add a directory entry for a migration that was never applied.
+    let dm3 = api.datamodel_with_provider(
+        r#"
+        model Cat {
+            id          Int @id
+            name        String
+            fluffiness  Float
+            weight      Float
+        }
+    "#,
+    );
+
+    api.create_migration("third-migration", &dm3, &directory).send_sync();
+
+    // Manually drift the live schema away from what the (remaining) history would produce.
+    api.raw_cmd("ALTER TABLE \"Cat\" ADD COLUMN \"extra\" INTEGER");
+
+    let output = api
+        .migration_status(&directory)
+        .check_drift(true)
+        .send_sync()
+        .into_output();
+
+    let unapplied_names: Vec<_> = output
+        .unapplied_migrations
+        .iter()
+        .map(|entry| entry.migration_name.as_str())
+        .collect();
+    assert_eq!(unapplied_names, vec!["third-migration"]);
+
+    let orphaned_names: Vec<_> = output
+        .orphaned_migrations
+        .iter()
+        .map(|entry| entry.migration_name.as_str())
+        .collect();
+    assert_eq!(orphaned_names, vec![orphaned_name.as_str()]);
+    assert!(output.orphaned_migrations[0].applied_at.is_some());
+
+    assert!(matches!(output.drift, Some(DriftStatus::Drifted { summary: _ })));
+}
+
+#[test_connector]
+fn migration_status_without_check_drift_does_not_run_the_drift_check(mut api: TestApi) {
+    let directory = api.create_migrations_directory();
+
+    let dm = api.datamodel_with_provider(
+        r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#,
+    );
+
+    api.create_migration("initial", &dm, &directory).send_sync();
+    api.apply_migrations(&directory).send_sync();
+
+    let output = api.migration_status(&directory).send_sync().into_output();
+
+    assert!(output.unapplied_migrations.is_empty());
+    assert!(output.orphaned_migrations.is_empty());
+    assert!(output.drift.is_none());
+}
@@ -14,10 +14,12 @@ mod indexes;
 mod json;
 mod jsonrpc;
 mod mariadb;
+mod metrics;
 mod mark_migration_applied_tests;
 mod mark_migration_rolled_back_tests;
 mod migrate_lock;
 mod migration_persistence_tests;
+mod migration_status_tests;
 mod mssql;
 mod mysql;
 mod postgres;
@@ -26,6 +28,7 @@ mod relations;
 mod reset_tests;
 mod shadow_database_url_configuration;
 mod soft_resets;
+mod seed;
 mod sql;
 mod sqlite;
 mod squashing_tests;
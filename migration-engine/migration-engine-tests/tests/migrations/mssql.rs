@@ -254,3 +254,163 @@ fn prisma_9537(api: TestApi) {
         .send()
         .assert_green();
 }
+
+// Dropping `@default(autoincrement())` on an identity column forces SQL Server to redefine the
+// table (copy into a temporary table, drop the original, rename). The identity's current value
+// must survive that round trip, so we reseed it explicitly with DBCC CHECKIDENT.
+#[test_connector(tags(Mssql))]
+fn identity_current_value_is_preserved_across_table_redefine(api: TestApi) {
+    let schema = api.schema_name();
+
+    api.raw_cmd(&format!(
+        r#"
+        CREATE TABLE [{schema}].[Order] (
+            id INTEGER NOT NULL IDENTITY(100,10),
+            CONSTRAINT [Order_pkey] PRIMARY KEY (id)
+        );
+        "#,
+    ));
+
+    api.raw_cmd(&format!(
+        "SET IDENTITY_INSERT [{schema}].[Order] ON; \
+         INSERT INTO [{schema}].[Order] (id) VALUES (150); \
+         SET IDENTITY_INSERT [{schema}].[Order] OFF;",
+    ));
+
+    let target_schema = r#"
+        datasource db {
+            provider = "sqlserver"
+            url = env("TEST_DATABASE_URL")
+        }
+
+        model Order {
+            id Int @id
+        }
+    "#;
+
+    let migration = api.connector_diff(DiffTarget::Database, DiffTarget::Datamodel(target_schema));
+
+    assert!(
+        migration.contains("DBCC CHECKIDENT") && migration.contains("150"),
+        "expected the redefine migration to reseed the identity column to its current value, got:\n{}",
+        migration
+    );
+
+    // Check that the migration applies cleanly.
+    api.raw_cmd(&migration);
+}
+
+#[test_connector(tags(Mssql))]
+fn dropping_two_mutually_referencing_tables_drops_both_foreign_keys_before_either_table(api: TestApi) {
+    // `A` and `B` reference each other, so neither table can be dropped before the other's
+    // foreign key pointing at it is gone.
+    let schema = api.schema_name();
+
+    api.raw_cmd(&format!(
+        r#"
+        CREATE TABLE [{schema}].[A] (id INT NOT NULL PRIMARY KEY, b_id INT);
+        CREATE TABLE [{schema}].[B] (id INT NOT NULL PRIMARY KEY, a_id INT);
+        ALTER TABLE [{schema}].[A] ADD CONSTRAINT [A_b_id_fkey] FOREIGN KEY (b_id) REFERENCES [{schema}].[B](id);
+        ALTER TABLE [{schema}].[B] ADD CONSTRAINT [B_a_id_fkey] FOREIGN KEY (a_id) REFERENCES [{schema}].[A](id);
+        "#,
+        schema = schema,
+    ));
+
+    let target_schema = r#"
+        datasource db {
+            provider = "sqlserver"
+            url = env("TEST_DATABASE_URL")
+        }
+    "#;
+
+    let migration = api.connector_diff(DiffTarget::Database, DiffTarget::Datamodel(target_schema));
+
+    // The catalog order of the two symmetrical foreign keys isn't something we want to pin down
+    // here, but both must be dropped, and both must come before either `DROP TABLE`.
+    let drop_a_fkey = migration.find("DROP CONSTRAINT [A_b_id_fkey]").unwrap();
+    let drop_b_fkey = migration.find("DROP CONSTRAINT [B_a_id_fkey]").unwrap();
+    let drop_a_table = migration.find(&format!("DROP TABLE [{schema}].[A]", schema = schema)).unwrap();
+    let drop_b_table = migration.find(&format!("DROP TABLE [{schema}].[B]", schema = schema)).unwrap();
+
+    assert!(drop_a_fkey < drop_a_table && drop_a_fkey < drop_b_table);
+    assert!(drop_b_fkey < drop_a_table && drop_b_fkey < drop_b_table);
+}
+
+#[test_connector(tags(Mssql))]
+fn dropping_a_table_with_a_self_referential_foreign_key_drops_the_foreign_key_first(api: TestApi) {
+    let schema = api.schema_name();
+
+    api.raw_cmd(&format!(
+        r#"
+        CREATE TABLE [{schema}].[Employee] (
+            id INT NOT NULL PRIMARY KEY,
+            manager_id INT,
+            CONSTRAINT [Employee_manager_id_fkey] FOREIGN KEY (manager_id) REFERENCES [{schema}].[Employee](id)
+        );
+        "#,
+        schema = schema,
+    ));
+
+    let target_schema = r#"
+        datasource db {
+            provider = "sqlserver"
+            url = env("TEST_DATABASE_URL")
+        }
+    "#;
+
+    let migration = api.connector_diff(DiffTarget::Database, DiffTarget::Datamodel(target_schema));
+
+    let drop_fkey = migration.find("DROP CONSTRAINT [Employee_manager_id_fkey]").unwrap();
+    let drop_table = migration
+        .find(&format!("DROP TABLE [{schema}].[Employee]", schema = schema))
+        .unwrap();
+
+    assert!(drop_fkey < drop_table);
+}
+
+// Filtered indexes require `QUOTED_IDENTIFIER ON` and `ANSI_NULLS ON` in the session. Creating a
+// migration replays migration history against a shadow database, and applying migrations runs
+// them against the main connection: both connections must have these options set, or this fails
+// with SQL Server error 1934.
+#[test_connector(tags(Mssql))]
+fn filtered_index_migrations_apply_through_the_shadow_and_direct_paths(api: TestApi) {
+    let dm = r#"
+        model A {
+            id Int @id
+            og Int?
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory();
+
+    api.create_migration("01init", dm, &migrations_directory).send_sync();
+
+    api.apply_migrations(&migrations_directory)
+        .send_sync()
+        .assert_applied_migrations(&["01init"]);
+
+    let filtered_index = format!(
+        "CREATE INDEX [A_og_filtered_idx] ON [{}].[A] ([og]) WHERE [og] IS NOT NULL;",
+        api.schema_name()
+    );
+
+    // Creating this migration replays `01init` into the shadow database before diffing, so it
+    // exercises the shadow connection's session options.
+    api.create_migration("02filtered_index", dm, &migrations_directory)
+        .draft(true)
+        .send_sync()
+        .modify_migration(|contents| {
+            contents.clear();
+            contents.push_str(&filtered_index);
+        });
+
+    // Applying it runs the filtered index migration on the main connection, exercising the
+    // direct path's session options.
+    api.apply_migrations(&migrations_directory)
+        .send_sync()
+        .assert_applied_migrations(&["02filtered_index"]);
+
+    api.assert_schema().assert_table("A", |table| {
+        table.assert_index_on_columns(&["og"], |index| index.assert_name("A_og_filtered_idx"))
+    });
+}
@@ -1,5 +1,6 @@
 use indoc::indoc;
 use migration_core::json_rpc::types::*;
+use migration_core::migration_connector::DiffTarget as ConnectorDiffTarget;
 use migration_engine_tests::test_api::*;
 use std::fmt::Write as _;
 
@@ -45,6 +46,70 @@ fn indexes_on_foreign_key_fields_are_not_created_twice(api: TestApi) {
     api.assert_schema().assert_equals(&sql_schema);
 }
 
+#[test_connector(tags(Mysql), exclude(Vitess))]
+fn dropping_two_mutually_referencing_tables_drops_both_foreign_keys_before_either_table(api: TestApi) {
+    // `A` and `B` reference each other, so neither table can be dropped before the other's
+    // foreign key pointing at it is gone.
+    let setup = r#"
+        CREATE TABLE `A` (id INTEGER PRIMARY KEY, b_id INTEGER);
+        CREATE TABLE `B` (id INTEGER PRIMARY KEY, a_id INTEGER);
+        ALTER TABLE `A` ADD CONSTRAINT `A_b_id_fkey` FOREIGN KEY (b_id) REFERENCES `B`(id);
+        ALTER TABLE `B` ADD CONSTRAINT `B_a_id_fkey` FOREIGN KEY (a_id) REFERENCES `A`(id);
+    "#;
+
+    api.raw_cmd(setup);
+
+    let target_schema = r#"
+        datasource db {
+            provider = "mysql"
+            url = env("TEST_DATABASE_URL")
+        }
+    "#;
+
+    let migration = api.connector_diff(ConnectorDiffTarget::Database, ConnectorDiffTarget::Datamodel(target_schema));
+
+    // The catalog order of the two symmetrical foreign keys isn't something we want to pin down
+    // here, but both must be dropped, and both must come before either `DROP TABLE`.
+    let drop_a_fkey = migration.find("DROP FOREIGN KEY `A_b_id_fkey`").unwrap();
+    let drop_b_fkey = migration.find("DROP FOREIGN KEY `B_a_id_fkey`").unwrap();
+    let drop_a_table = migration.find("DROP TABLE `A`").unwrap();
+    let drop_b_table = migration.find("DROP TABLE `B`").unwrap();
+
+    assert!(drop_a_fkey < drop_a_table && drop_a_fkey < drop_b_table);
+    assert!(drop_b_fkey < drop_a_table && drop_b_fkey < drop_b_table);
+}
+
+#[test_connector(tags(Mysql), exclude(Vitess))]
+fn dropping_a_table_with_a_self_referential_foreign_key_drops_the_foreign_key_first(api: TestApi) {
+    let setup = r#"
+        CREATE TABLE `Employee` (
+            id INTEGER PRIMARY KEY,
+            manager_id INTEGER,
+            CONSTRAINT `Employee_manager_id_fkey` FOREIGN KEY (manager_id) REFERENCES `Employee`(id)
+        );
+    "#;
+
+    api.raw_cmd(setup);
+
+    let target_schema = r#"
+        datasource db {
+            provider = "mysql"
+            url = env("TEST_DATABASE_URL")
+        }
+    "#;
+
+    let migration = api.connector_diff(ConnectorDiffTarget::Database, ConnectorDiffTarget::Datamodel(target_schema));
+    let expected = expect![[r#"
+        -- DropForeignKey
+        ALTER TABLE `Employee` DROP FOREIGN KEY `Employee_manager_id_fkey`;
+
+        -- DropTable
+        DROP TABLE `Employee`;
+    "#]];
+
+    expected.assert_eq(&migration);
+}
+
 // We have to test this because one enum on MySQL can map to multiple enums in the database.
 #[test_connector(tags(Mysql))]
 fn enum_creation_is_idempotent(api: TestApi) {
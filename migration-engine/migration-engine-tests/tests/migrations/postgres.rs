@@ -1,5 +1,6 @@
 use migration_core::migration_connector::DiffTarget;
 use migration_engine_tests::test_api::*;
+use prisma_value::PrismaValue;
 use quaint::Value;
 use sql_schema_describer::ColumnTypeFamily;
 use std::fmt::Write;
@@ -153,6 +154,41 @@ fn native_type_columns_can_be_created(api: TestApi) {
     api.schema_push_w_datasource(dm).send().assert_green().assert_no_steps();
 }
 
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn money_column_with_default_diffs_clean(api: TestApi) {
+    let dm = r#"
+        model A {
+            id      Int     @id
+            balance Decimal @default(12.5) @db.Money
+        }
+    "#;
+
+    let expect = expect![[r#"
+        -- CreateTable
+        CREATE TABLE "A" (
+            "id" INTEGER NOT NULL,
+            "balance" MONEY NOT NULL DEFAULT '12.5'::MONEY,
+
+            CONSTRAINT "A_pkey" PRIMARY KEY ("id")
+        );
+    "#]];
+    api.expect_sql_for_schema(dm, &expect);
+
+    api.schema_push_w_datasource(dm).send().assert_green();
+
+    api.assert_schema().assert_table("A", |table| {
+        table.assert_column("balance", |col| {
+            col.assert_full_data_type("money")
+                .assert_default_value(&PrismaValue::Float("12.5".parse().unwrap()))
+                // The column's family must never regress to Float: that would silently invite
+                // precision loss on a column that is inherently monetary data.
+                .assert_type_family(ColumnTypeFamily::Decimal)
+        })
+    });
+
+    api.schema_push_w_datasource(dm).send().assert_green().assert_no_steps();
+}
+
 #[test_connector(tags(Postgres))]
 fn uuids_do_not_generate_drift_issue_5282(api: TestApi) {
     if !api.is_cockroach() {
@@ -186,6 +222,62 @@ fn uuids_do_not_generate_drift_issue_5282(api: TestApi) {
         .assert_no_steps();
 }
 
+// A plain `Decimal` field (no `@db.Decimal(p, s)`) lowers to the connector's unconstrained
+// default native type, so it should match an unconstrained `numeric` column with no migration
+// step.
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn bare_decimal_field_matches_unconstrained_numeric_column(api: TestApi) {
+    api.raw_cmd("CREATE TABLE \"A\" (id INTEGER PRIMARY KEY, value NUMERIC NOT NULL)");
+
+    let dm = r#"
+        model A {
+            id    Int     @id
+            value Decimal
+        }
+    "#;
+
+    api.schema_push_w_datasource(dm).send().assert_green().assert_no_steps();
+}
+
+// Same as above, but the database column has an explicit precision. A bare `Decimal` field does
+// not request any particular precision, so it should be treated as compatible with whatever
+// precision is already there, and no migration step should be generated.
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn bare_decimal_field_matches_constrained_numeric_column(api: TestApi) {
+    api.raw_cmd("CREATE TABLE \"A\" (id INTEGER PRIMARY KEY, value NUMERIC(10,2) NOT NULL)");
+
+    let dm = r#"
+        model A {
+            id    Int     @id
+            value Decimal
+        }
+    "#;
+
+    api.schema_push_w_datasource(dm).send().assert_green().assert_no_steps();
+}
+
+// When the datamodel explicitly asks for a precision that differs from what is in the database,
+// that is a real change and a migration step must still be generated.
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn explicit_decimal_precision_differing_from_database_generates_a_step(api: TestApi) {
+    api.raw_cmd("CREATE TABLE \"A\" (id INTEGER PRIMARY KEY, value NUMERIC NOT NULL)");
+
+    let dm = r#"
+        model A {
+            id    Int             @id
+            value Decimal         @db.Decimal(10, 2)
+        }
+    "#;
+
+    api.schema_push_w_datasource(dm)
+        .send()
+        .assert_green()
+        .assert_has_executed_steps();
+
+    api.assert_schema()
+        .assert_table("A", |table| table.assert_column("value", |col| col.assert_full_data_type("numeric")));
+}
+
 // CockroachDB does not support uuid-ossp functions in a separate schema.
 #[test_connector(tags(Postgres), exclude(CockroachDb))]
 fn functions_with_schema_prefix_in_dbgenerated_are_idempotent(api: TestApi) {
@@ -483,6 +575,86 @@ fn failing_enum_migrations_should_not_be_partially_applied(api: TestApi) {
     }
 }
 
+// On PostgreSQL < 12, `ALTER TYPE ... ADD VALUE` cannot run inside a transaction block under any
+// circumstances, and a hand-written migration script is applied as a single implicit transaction,
+// so adding a value and using it in the same script fails. The error message should point users
+// at the fix (splitting the migration in two) instead of just surfacing the raw database error.
+#[test_connector(tags(Postgres9))]
+fn adding_and_using_an_enum_value_in_the_same_migration_fails_on_old_postgres(api: TestApi) {
+    let dm = r#"
+        model Cat {
+            id String @id
+            mood Mood
+        }
+
+        enum Mood {
+            HAPPY
+        }
+    "#;
+    let migrations_directory = api.create_migrations_directory();
+
+    api.create_migration("01init", dm, &migrations_directory).send_sync();
+    api.apply_migrations(&migrations_directory)
+        .send_sync()
+        .assert_applied_migrations(&["01init"]);
+
+    let migration = r#"
+        ALTER TYPE "Mood" ADD VALUE 'ANGRY';
+        ALTER TABLE "Cat" ALTER COLUMN "mood" SET DEFAULT 'ANGRY';
+    "#;
+
+    api.create_migration("02addvalue", dm, &migrations_directory)
+        .draft(true)
+        .send_sync()
+        .modify_migration(|contents| {
+            contents.clear();
+            contents.push_str(migration);
+        });
+
+    let err = api.apply_migrations(&migrations_directory).send_unwrap_err().to_string();
+
+    assert!(err.contains("ADD VALUE"));
+    assert!(err.contains("Move the ALTER TYPE ... ADD VALUE statement into its own migration"));
+}
+
+// On PostgreSQL 12+, the restriction on running `ALTER TYPE ... ADD VALUE` inside a transaction
+// only applies if the new value is used in the same transaction that added it — which is not the
+// case here, since the second statement only uses a pre-existing value.
+#[test_connector(tags(Postgres12))]
+fn adding_and_using_an_enum_value_in_separate_statements_works_on_new_postgres(api: TestApi) {
+    let dm = r#"
+        model Cat {
+            id String @id
+            mood Mood
+        }
+
+        enum Mood {
+            HAPPY
+        }
+    "#;
+    let migrations_directory = api.create_migrations_directory();
+
+    api.create_migration("01init", dm, &migrations_directory).send_sync();
+    api.apply_migrations(&migrations_directory)
+        .send_sync()
+        .assert_applied_migrations(&["01init"]);
+
+    let migration = r#"
+        ALTER TYPE "Mood" ADD VALUE 'ANGRY';
+        ALTER TABLE "Cat" ALTER COLUMN "mood" SET DEFAULT 'HAPPY';
+    "#;
+
+    api.create_migration("02addvalue", dm, &migrations_directory)
+        .draft(true)
+        .send_sync()
+        .modify_migration(|contents| {
+            contents.clear();
+            contents.push_str(migration);
+        });
+
+    api.apply_migrations(&migrations_directory).send_sync();
+}
+
 #[test_connector(tags(Postgres), exclude(CockroachDb))]
 fn connecting_to_a_postgres_database_with_the_cockroach_connector_fails(_api: TestApi) {
     let dm = r#"
@@ -654,6 +826,132 @@ fn scalar_list_default_diffing(api: TestApi) {
     api.schema_push(schema_2).send().assert_green().assert_no_steps();
 }
 
+// `scalar_list_defaults_work` above covers most families, but not string elements containing
+// quotes and commas, which stress the array literal parser and renderer's quoting the most.
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn scalar_list_string_defaults_with_quotes_and_commas_round_trip(api: TestApi) {
+    let schema = r#"
+        datasource db {
+          provider = "postgresql"
+          url = env("DATABASE_URL")
+        }
+
+        model Model {
+            id Int @id
+            string String[] @default(["it's a \"quote\"", "a, comma, here", "plain"])
+        }
+    "#;
+
+    api.schema_push(schema)
+        .send()
+        .assert_green()
+        .assert_has_executed_steps();
+    api.schema_push(schema).send().assert_green().assert_no_steps();
+
+    api.assert_schema().assert_table("Model", |table| {
+        table.assert_column("string", |col| {
+            col.assert_default_value(&PrismaValue::List(vec![
+                PrismaValue::String("it's a \"quote\"".to_owned()),
+                PrismaValue::String("a, comma, here".to_owned()),
+                PrismaValue::String("plain".to_owned()),
+            ]))
+        })
+    });
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn dropping_a_referencing_table_drops_its_foreign_keys_first(api: TestApi) {
+    let setup = r#"
+        CREATE TABLE "A" (id INTEGER PRIMARY KEY);
+        CREATE TABLE "B" (id INTEGER PRIMARY KEY, a_id INTEGER NOT NULL REFERENCES "A"(id));
+    "#;
+
+    api.raw_cmd(setup);
+
+    let target_schema = r#"
+        datasource db {
+            provider = "postgresql"
+            url = env("TEST_DATABASE_URL")
+        }
+
+        model A {
+            id Int @id
+        }
+    "#;
+
+    let migration = api.connector_diff(DiffTarget::Database, DiffTarget::Datamodel(target_schema));
+    let expected = expect![[r#"
+        -- DropForeignKey
+        ALTER TABLE "B" DROP CONSTRAINT "B_a_id_fkey";
+
+        -- DropTable
+        DROP TABLE "B";
+    "#]];
+
+    expected.assert_eq(&migration);
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn dropping_two_mutually_referencing_tables_drops_both_foreign_keys_before_either_table(api: TestApi) {
+    // `A` and `B` reference each other, so neither table can be dropped before the other's
+    // foreign key pointing at it is gone. Both `DropForeignKey` steps must come before both
+    // `DropTable` steps.
+    let setup = r#"
+        CREATE TABLE "A" (id INTEGER PRIMARY KEY, b_id INTEGER);
+        CREATE TABLE "B" (id INTEGER PRIMARY KEY, a_id INTEGER);
+        ALTER TABLE "A" ADD CONSTRAINT "A_b_id_fkey" FOREIGN KEY (b_id) REFERENCES "B"(id);
+        ALTER TABLE "B" ADD CONSTRAINT "B_a_id_fkey" FOREIGN KEY (a_id) REFERENCES "A"(id);
+    "#;
+
+    api.raw_cmd(setup);
+
+    let target_schema = r#"
+        datasource db {
+            provider = "postgresql"
+            url = env("TEST_DATABASE_URL")
+        }
+    "#;
+
+    let migration = api.connector_diff(DiffTarget::Database, DiffTarget::Datamodel(target_schema));
+
+    // The catalog order of the two symmetrical foreign keys isn't something we want to pin down
+    // here, but both must be dropped, and both must come before either `DROP TABLE`.
+    let drop_a_fkey = migration.find(r#"DROP CONSTRAINT "A_b_id_fkey""#).unwrap();
+    let drop_b_fkey = migration.find(r#"DROP CONSTRAINT "B_a_id_fkey""#).unwrap();
+    let drop_a_table = migration.find(r#"DROP TABLE "A""#).unwrap();
+    let drop_b_table = migration.find(r#"DROP TABLE "B""#).unwrap();
+
+    assert!(drop_a_fkey < drop_a_table && drop_a_fkey < drop_b_table);
+    assert!(drop_b_fkey < drop_a_table && drop_b_fkey < drop_b_table);
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn dropping_a_table_with_a_self_referential_foreign_key_drops_the_foreign_key_first(api: TestApi) {
+    let setup = r#"
+        CREATE TABLE "Employee" (id INTEGER PRIMARY KEY, manager_id INTEGER REFERENCES "Employee"(id));
+    "#;
+
+    api.raw_cmd(setup);
+
+    let target_schema = r#"
+        datasource db {
+            provider = "postgresql"
+            url = env("TEST_DATABASE_URL")
+        }
+    "#;
+
+    let migration = api.connector_diff(DiffTarget::Database, DiffTarget::Datamodel(target_schema));
+    let expected = expect![[r#"
+        -- DropForeignKey
+        ALTER TABLE "Employee" DROP CONSTRAINT "Employee_manager_id_fkey";
+
+        -- DropTable
+        DROP TABLE "Employee";
+    "#]];
+
+    expected.assert_eq(&migration);
+}
+
 // https://github.com/prisma/prisma/issues/12095
 #[test_connector(tags(Postgres), exclude(CockroachDb))]
 fn json_defaults_with_escaped_quotes_work(api: TestApi) {
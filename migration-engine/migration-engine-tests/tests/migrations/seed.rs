@@ -0,0 +1,142 @@
+use migration_engine_tests::test_api::*;
+use quaint::{prelude::Queryable, single::Quaint};
+
+#[test]
+fn seed_input_source_takes_expected_json_shape() {
+    let value = SeedParams {
+        datasource_type: DbExecuteDatasourceType::Url(UrlContainer {
+            url: "uiuiui".to_owned(),
+        }),
+        name: "seed.sql".to_owned(),
+        script: "SQL goes here".to_owned(),
+        force: false,
+    };
+
+    let expected = expect![[r#"
+        {
+          "datasourceType": {
+            "tag": "url",
+            "url": "uiuiui"
+          },
+          "name": "seed.sql",
+          "script": "SQL goes here",
+          "force": false
+        }"#]];
+
+    expected.assert_eq(&serde_json::to_string_pretty(&value).unwrap());
+}
+
+#[test]
+fn seed_runs_the_script_the_first_time() {
+    let tmpdir = tempfile::TempDir::new().unwrap();
+    let url = format!("file:{}/db1.sqlite", tmpdir.path().to_string_lossy());
+    let script = r#"
+        CREATE TABLE "dogs" ( id INTEGER PRIMARY KEY, name TEXT );
+        INSERT INTO "dogs" ("name") VALUES ('snoopy'), ('marmaduke');
+    "#;
+
+    let generic_api = migration_core::migration_api(None, None).unwrap();
+    let result = tok(generic_api.seed(SeedParams {
+        datasource_type: DbExecuteDatasourceType::Url(UrlContainer { url: url.clone() }),
+        name: "dogs.sql".to_owned(),
+        script: script.to_owned(),
+        force: false,
+    }))
+    .unwrap();
+
+    assert!(!result.skipped);
+
+    let q = tok(quaint::single::Quaint::new(&url)).unwrap();
+    let result = tok(q.query_raw("SELECT name FROM dogs;", &[])).unwrap();
+    let mut rows = result.into_iter();
+    assert_eq!(rows.next().unwrap()[0].to_string().unwrap(), "snoopy");
+    assert_eq!(rows.next().unwrap()[0].to_string().unwrap(), "marmaduke");
+}
+
+#[test]
+fn seeding_twice_with_the_same_script_skips_the_second_run() {
+    let tmpdir = tempfile::TempDir::new().unwrap();
+    let url = format!("file:{}/db1.sqlite", tmpdir.path().to_string_lossy());
+    let script = r#"
+        CREATE TABLE "dogs" ( id INTEGER PRIMARY KEY, name TEXT );
+        INSERT INTO "dogs" ("name") VALUES ('snoopy');
+    "#;
+
+    let generic_api = migration_core::migration_api(None, None).unwrap();
+
+    let first_run = tok(generic_api.seed(SeedParams {
+        datasource_type: DbExecuteDatasourceType::Url(UrlContainer { url: url.clone() }),
+        name: "dogs.sql".to_owned(),
+        script: script.to_owned(),
+        force: false,
+    }))
+    .unwrap();
+    assert!(!first_run.skipped);
+
+    let second_run = tok(generic_api.seed(SeedParams {
+        datasource_type: DbExecuteDatasourceType::Url(UrlContainer { url: url.clone() }),
+        name: "dogs.sql".to_owned(),
+        script: script.to_owned(),
+        force: false,
+    }))
+    .unwrap();
+    assert!(second_run.skipped);
+
+    // Had the second run executed, its `CREATE TABLE` would have failed on the existing table;
+    // the row count also shows that the insert ran only once.
+    let q = tok(quaint::single::Quaint::new(&url)).unwrap();
+    let result = tok(q.query_raw("SELECT COUNT(*) FROM dogs;", &[])).unwrap();
+    let mut rows = result.into_iter();
+    assert_eq!(rows.next().unwrap()[0].as_integer().unwrap(), 1);
+}
+
+#[test]
+fn force_reruns_a_seed_that_would_otherwise_be_skipped() {
+    let tmpdir = tempfile::TempDir::new().unwrap();
+    let url = format!("file:{}/db1.sqlite", tmpdir.path().to_string_lossy());
+    let script = r#"
+        CREATE TABLE IF NOT EXISTS "dogs" ( id INTEGER PRIMARY KEY, name TEXT );
+        INSERT INTO "dogs" ("name") VALUES ('snoopy');
+    "#;
+
+    let generic_api = migration_core::migration_api(None, None).unwrap();
+
+    tok(generic_api.seed(SeedParams {
+        datasource_type: DbExecuteDatasourceType::Url(UrlContainer { url: url.clone() }),
+        name: "dogs.sql".to_owned(),
+        script: script.to_owned(),
+        force: false,
+    }))
+    .unwrap();
+
+    let forced_run = tok(generic_api.seed(SeedParams {
+        datasource_type: DbExecuteDatasourceType::Url(UrlContainer { url: url.clone() }),
+        name: "dogs.sql".to_owned(),
+        script: script.to_owned(),
+        force: true,
+    }))
+    .unwrap();
+    assert!(!forced_run.skipped);
+
+    let q = tok(quaint::single::Quaint::new(&url)).unwrap();
+    let result = tok(q.query_raw("SELECT COUNT(*) FROM dogs;", &[])).unwrap();
+    let mut rows = result.into_iter();
+    assert_eq!(rows.next().unwrap()[0].as_integer().unwrap(), 2);
+}
+
+#[test_connector(tags(Postgres))]
+fn postgres_rejects_a_seed_script_written_for_mssql(api: TestApi) {
+    let script = r#"CREATE TABLE [dogs] ( id INT PRIMARY KEY );"#;
+
+    let generic_api = migration_core::migration_api(None, None).unwrap();
+    let result = tok(generic_api.seed(SeedParams {
+        datasource_type: DbExecuteDatasourceType::Url(UrlContainer {
+            url: api.connection_string().to_owned(),
+        }),
+        name: "dogs.sql".to_owned(),
+        script: script.to_owned(),
+        force: false,
+    }));
+
+    assert!(result.is_err());
+}
@@ -1,6 +1,7 @@
 use indoc::indoc;
 use migration_engine_tests::test_api::*;
 use sql_schema_describer::ColumnTypeFamily;
+use std::sync::{atomic::Ordering, Arc};
 
 const SCHEMA: &str = r#"
 model Cat {
@@ -97,6 +98,44 @@ fn schema_push_warns_about_destructive_changes(api: TestApi) {
         .assert_has_executed_steps();
 }
 
+#[test_connector(preview_features("referentialIntegrity"))]
+fn schema_push_skips_destructive_steps_the_host_denies(api: TestApi) {
+    api.schema_push_w_datasource(SCHEMA)
+        .send()
+        .assert_green()
+        .assert_has_executed_steps();
+
+    let host = Arc::new(TestConnectorHost::default());
+    host.confirm_result.store(false, Ordering::SeqCst);
+    api.connector.set_host(host);
+
+    // Dropping `material` is the only change, and it is destructive, so this migration has
+    // exactly one step, and the host denies it.
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+            boxId Int?
+            box Box? @relation(fields: [boxId], references: [id])
+        }
+
+        model Box {
+            id Int @id
+            cats     Cat[]
+        }
+    "#;
+
+    api.schema_push_w_datasource(dm2)
+        .force(true)
+        .send()
+        // The step was denied, so nothing was executed, and `executedSteps` must reflect that
+        // rather than reporting the full step count of the migration.
+        .assert_executed_steps_count(0);
+
+    // The `material` column drop was destructive, and the host denied it, so the column is still there.
+    api.assert_schema()
+        .assert_table("Box", |table| table.assert_has_column("material"));
+}
+
 #[test_connector(preview_features("referentialIntegrity"))]
 fn schema_push_with_an_unexecutable_migration_returns_a_message_and_aborts(api: TestApi) {
     api.schema_push_w_datasource(SCHEMA)
@@ -437,6 +476,88 @@ fn mysql_should_diff_column_ordering_correctly_issue_10983(api: TestApi) {
     api.schema_push_w_datasource(dm).send().assert_green().assert_no_steps();
 }
 
+#[test_connector(preview_features("referentialIntegrity"))]
+fn schema_push_with_models_filter_pushes_one_model_at_a_time(api: TestApi) {
+    api.schema_push_w_datasource(SCHEMA)
+        .models(&["Box"])
+        .send()
+        .assert_green()
+        .assert_has_executed_steps();
+
+    api.assert_schema().assert_tables_count(1).assert_table("Box", |table| {
+        table.assert_column("material", |col| col.assert_type_family(ColumnTypeFamily::String))
+    });
+
+    api.schema_push_w_datasource(SCHEMA)
+        .models(&["Cat"])
+        .send()
+        .assert_green()
+        .assert_has_executed_steps();
+
+    api.assert_schema()
+        .assert_tables_count(2)
+        .assert_table("Cat", |table| {
+            table.assert_column("boxId", |col| col.assert_type_family(ColumnTypeFamily::Int))
+        })
+        .assert_table("Box", |table| {
+            table.assert_column("material", |col| col.assert_type_family(ColumnTypeFamily::String))
+        });
+}
+
+#[test_connector(tags(Postgres), preview_features("referentialIntegrity"))]
+fn schema_push_with_models_filter_does_not_touch_an_unrelated_enum(api: TestApi) {
+    let dm = indoc! {r#"
+        model Box {
+          id       Int    @id
+          material String
+        }
+
+        model Cat {
+          id     Int       @id
+          status CatStatus
+        }
+
+        enum CatStatus {
+          HUNGRY
+          ASLEEP
+        }
+    "#};
+
+    api.schema_push_w_datasource(dm).send().assert_green().assert_has_executed_steps();
+
+    let dm2 = indoc! {r#"
+        model Box {
+          id       Int    @id
+          material String
+          color    String
+        }
+
+        model Cat {
+          id     Int       @id
+          status CatStatus
+        }
+
+        enum CatStatus {
+          HUNGRY
+          ASLEEP
+          PLAYING
+        }
+    "#};
+
+    // `Cat` (and the `CatStatus` enum it uses) is outside the `models` filter, so the enum
+    // change must not be part of what gets pushed, even though `dm2` as a whole does add a
+    // variant to it.
+    api.schema_push_w_datasource(dm2)
+        .models(&["Box"])
+        .send()
+        .assert_green()
+        .assert_has_executed_steps();
+
+    api.assert_schema()
+        .assert_enum("CatStatus", |enm| enm.assert_values(&["HUNGRY", "ASLEEP"]))
+        .assert_table("Box", |table| table.assert_has_column("color"));
+}
+
 #[test_connector]
 fn issue_repro_extended_indexes(api: TestApi) {
     // https://github.com/prisma/prisma/issues/11631
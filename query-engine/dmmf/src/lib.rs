@@ -42,5 +42,65 @@ pub fn from_precomputed_parts(dml: &datamodel::dml::Datamodel, query_schema: Que
         data_model,
         schema,
         mappings,
+        schema_hash: schema_hash(dml),
+        dmmf_version: DMMF_VERSION,
+    }
+}
+
+/// The version of the [`DataModelMetaFormat`] serialization shape.
+pub const DMMF_VERSION: u32 = 1;
+
+/// A stable hash of a datamodel, computed from its canonical rendering so that whitespace-only
+/// differences in the original schema source do not change it. Two engines built from the same
+/// schema, even reformatted, produce the same hash, so a client can detect a schema mismatch
+/// without parsing the whole DMMF document.
+pub fn schema_hash(dml: &datamodel::dml::Datamodel) -> String {
+    use sha2::{Digest, Sha256};
+    use std::fmt::Write as _;
+
+    let normalized = datamodel::render_datamodel_to_string(dml, None);
+
+    let mut hasher = Sha256::new();
+    hasher.update(normalized.as_bytes());
+    let digest: [u8; 32] = hasher.finalize().into();
+
+    let mut hash = String::with_capacity(digest.len() * 2);
+    for byte in digest {
+        write!(hash, "{:02x}", byte).unwrap();
+    }
+
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::dmmf_from_schema;
+
+    const SCHEMA: &str = r#"
+        datasource pg1 {
+          provider = "postgresql"
+          url      = "postgresql://"
+        }
+
+        model User {
+          id    Int    @id
+          email String @unique
+        }
+    "#;
+
+    #[test]
+    fn schema_hash_is_stable_across_render_calls() {
+        assert_eq!(
+            dmmf_from_schema(SCHEMA).schema_hash,
+            dmmf_from_schema(SCHEMA).schema_hash
+        );
+    }
+
+    #[test]
+    fn schema_hash_changes_with_a_one_character_model_change() {
+        // Renaming the model by a single character is enough to change the hash.
+        let changed = SCHEMA.replacen("model User", "model Users", 1);
+
+        assert_ne!(dmmf_from_schema(SCHEMA).schema_hash, dmmf_from_schema(&changed).schema_hash);
     }
 }
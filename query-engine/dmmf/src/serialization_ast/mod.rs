@@ -21,4 +21,13 @@ pub struct DataModelMetaFormat {
 
     /// The operations map. Derived from the `schema`.
     pub mappings: DmmfOperationMappings,
+
+    /// A stable hash of the datamodel this document was rendered from. Lets a client compare the
+    /// schema it was generated against with the one the connected engine is running, without
+    /// parsing the rest of the document.
+    pub schema_hash: String,
+
+    /// The version of this DMMF document's shape. Bump this whenever a breaking change is made to
+    /// the serialization format so that clients can detect documents they don't know how to read.
+    pub dmmf_version: u32,
 }
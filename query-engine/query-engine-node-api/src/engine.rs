@@ -7,7 +7,7 @@ use query_core::{
     schema::{QuerySchema, QuerySchemaRenderer},
     schema_builder, MetricFormat, MetricRegistry, QueryExecutor, TxId,
 };
-use request_handlers::{GraphQLSchemaRenderer, GraphQlHandler, TxInput};
+use request_handlers::{dmmf, GraphQLSchemaRenderer, GraphQlHandler, TxInput};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::{
@@ -392,6 +392,20 @@ impl QueryEngine {
         .await
     }
 
+    /// A stable hash of the datamodel the engine was constructed with, letting the JS client
+    /// detect a schema mismatch after a hot-reload without fetching and parsing the whole DMMF
+    /// document. Available before and after `connect`.
+    #[napi]
+    pub async fn schema_hash(&self) -> String {
+        let inner = self.inner.read().await;
+        let ast = match &*inner {
+            Inner::Builder(builder) => &builder.datamodel.ast,
+            Inner::Connected(engine) => &engine.datamodel.ast,
+        };
+
+        dmmf::schema_hash(ast)
+    }
+
     #[napi]
     pub async fn metrics(&self, json_options: String) -> napi::Result<String> {
         async_panic_to_js_error(async move {
@@ -1,6 +1,8 @@
 use dmmf_crate::DataModelMetaFormat;
 use query_core::schema::QuerySchemaRef;
 
+pub use dmmf_crate::schema_hash;
+
 pub fn render_dmmf(dml: &datamodel::dml::Datamodel, query_schema: QuerySchemaRef) -> DataModelMetaFormat {
     dmmf_crate::from_precomputed_parts(dml, query_schema)
 }